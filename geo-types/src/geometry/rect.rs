@@ -341,6 +341,36 @@ impl<T: CoordNum> Rect<T> {
         ]
     }
 
+    /// Expand the rectangle by `dx` on the left and right, and by `dy` on the top and bottom.
+    ///
+    /// Negative margins shrink the rectangle. If a margin would shrink it past zero width or
+    /// height, that side is clamped to the middle of the rectangle, leaving a degenerate `Rect`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, Rect};
+    ///
+    /// let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 4. });
+    ///
+    /// assert_eq!(
+    ///     rect.expand(1., 2.),
+    ///     Rect::new(coord! { x: -1., y: -2. }, coord! { x: 11., y: 6. }),
+    /// );
+    /// assert_eq!(
+    ///     rect.expand(-1., -3.),
+    ///     Rect::new(coord! { x: 1., y: 2. }, coord! { x: 9., y: 2. }),
+    /// );
+    /// ```
+    pub fn expand(self, dx: T, dy: T) -> Rect<T> {
+        let (min_x, max_x) = expand_range(self.min.x, self.max.x, dx);
+        let (min_y, max_y) = expand_range(self.min.y, self.max.y, dy);
+        Rect {
+            min: coord! { x: min_x, y: min_y },
+            max: coord! { x: max_x, y: max_y },
+        }
+    }
+
     fn assert_valid_bounds(&self) {
         if !self.has_valid_bounds() {
             panic!("{}", RECT_INVALID_BOUNDS_ERROR);
@@ -374,6 +404,54 @@ impl<T: CoordFloat> Rect<T> {
             y: (self.max.y + self.min.y) / two,
         }
     }
+
+    /// Round the rectangle outward to a grid of square cells of size `cell`, aligned with the
+    /// origin, so that each corner is a multiple of `cell`.
+    ///
+    /// Sides which are already on the grid are kept, so a degenerate `Rect` on the grid stays
+    /// degenerate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell` is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo_types::{coord, Rect};
+    ///
+    /// let rect = Rect::new(coord! { x: -3.5, y: 1. }, coord! { x: 12.1, y: 10. });
+    ///
+    /// assert_eq!(
+    ///     rect.snap_outward_to_grid(5.),
+    ///     Rect::new(coord! { x: -5., y: 0. }, coord! { x: 15., y: 10. }),
+    /// );
+    /// ```
+    pub fn snap_outward_to_grid(self, cell: T) -> Rect<T> {
+        assert!(cell > T::zero(), "grid cell size must be positive");
+        Rect {
+            min: coord! {
+                x: (self.min.x / cell).floor() * cell,
+                y: (self.min.y / cell).floor() * cell,
+            },
+            max: coord! {
+                x: (self.max.x / cell).ceil() * cell,
+                y: (self.max.y / cell).ceil() * cell,
+            },
+        }
+    }
+}
+
+/// Move `min` and `max` apart by `margin`, or if that would invert them, to their middle.
+fn expand_range<T: CoordNum>(min: T, max: T, margin: T) -> (T, T) {
+    let (new_min, new_max) = (min - margin, max + margin);
+    if new_min <= new_max {
+        (new_min, new_max)
+    } else {
+        let two = T::one() + T::one();
+        let middle = min + (max - min) / two;
+        (middle, middle)
+    }
 }
 
 static RECT_INVALID_BOUNDS_ERROR: &str = "Failed to create Rect: 'min' coordinate's x/y value must be smaller or equal to the 'max' x/y value";
@@ -524,4 +602,34 @@ mod test {
             Coord::from((0., 0.))
         );
     }
+    #[test]
+    fn rect_expand() {
+        let rect = Rect::new((0, 0), (10, 4));
+        assert_eq!(rect.expand(2, 0), Rect::new((-2, 0), (12, 4)));
+        assert_eq!(rect.expand(-2, -1), Rect::new((2, 1), (8, 3)));
+        // shrinking to exactly zero height, then past it
+        assert_eq!(rect.expand(0, -2), Rect::new((0, 2), (10, 2)));
+        assert_eq!(rect.expand(-20, -3), Rect::new((5, 2), (5, 2)));
+
+        let rect = Rect::new((-1., 1.), (2., 2.));
+        assert_eq!(rect.expand(-2., 0.5), Rect::new((0.5, 0.5), (0.5, 2.5)));
+    }
+
+    #[test]
+    fn rect_snap_outward_to_grid() {
+        let rect = Rect::new((0.2, -0.2), (1.8, 2.));
+        assert_eq!(rect.snap_outward_to_grid(1.), Rect::new((0., -1.), (2., 2.)));
+        assert_eq!(rect.snap_outward_to_grid(0.5), Rect::new((0., -0.5), (2., 2.)));
+
+        let point = Rect::new((3., 3.), (3., 3.));
+        assert_eq!(point.snap_outward_to_grid(1.5), point);
+        let point = Rect::new((3.2, 3.2), (3.2, 3.2));
+        assert_eq!(point.snap_outward_to_grid(1.), Rect::new((3., 3.), (4., 4.)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rect_snap_outward_to_zero_grid() {
+        Rect::new((0., 0.), (1., 1.)).snap_outward_to_grid(0.);
+    }
 }
@@ -0,0 +1,70 @@
+#[macro_use]
+extern crate criterion;
+extern crate geo;
+extern crate geo_test_fixtures;
+
+use criterion::Criterion;
+use geo::{BooleanOps, BoundingRect, Coord, MultiLineString, Polygon, Rect, RectClip};
+
+/// A rect covering the middle of `rect`, a quarter of its size.
+fn middle(rect: Rect<f64>) -> Rect<f64> {
+    let (width, height) = (rect.width(), rect.height());
+    let min = rect.min()
+        + Coord {
+            x: width / 4.,
+            y: height / 4.,
+        };
+    Rect::new(
+        min,
+        min + Coord {
+            x: width / 2.,
+            y: height / 2.,
+        },
+    )
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let norway = geo_test_fixtures::norway_main::<f64>();
+    let tile = middle(norway.bounding_rect().unwrap());
+
+    c.bench_function("rect clip polygon", |bencher| {
+        let polygon = Polygon::new(norway.clone(), vec![]);
+        bencher.iter(|| {
+            criterion::black_box(
+                criterion::black_box(&polygon).clip_to_rect(criterion::black_box(&tile)),
+            );
+        });
+    });
+
+    c.bench_function("boolean ops intersection polygon", |bencher| {
+        let polygon = Polygon::new(norway.clone(), vec![]);
+        let tile = tile.to_polygon();
+        bencher.iter(|| {
+            criterion::black_box(
+                criterion::black_box(&polygon).intersection(criterion::black_box(&tile)),
+            );
+        });
+    });
+
+    c.bench_function("rect clip line string", |bencher| {
+        let lines = MultiLineString::new(vec![norway.clone()]);
+        bencher.iter(|| {
+            criterion::black_box(
+                criterion::black_box(&lines).clip_to_rect(criterion::black_box(&tile)),
+            );
+        });
+    });
+
+    c.bench_function("boolean ops clip line string", |bencher| {
+        let lines = MultiLineString::new(vec![norway.clone()]);
+        let tile = tile.to_polygon();
+        bencher.iter(|| {
+            criterion::black_box(
+                criterion::black_box(&tile).clip(criterion::black_box(&lines), false),
+            );
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
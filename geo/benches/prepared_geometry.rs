@@ -0,0 +1,90 @@
+#[macro_use]
+extern crate criterion;
+extern crate geo;
+extern crate geo_test_fixtures;
+
+use criterion::Criterion;
+use geo::{BoundingRect, Contains, MultiPoint, Point, Polygon, PreparedGeometry, Relate};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let norway = Polygon::new(geo_test_fixtures::norway_main::<f64>(), vec![]);
+    let rect = norway.bounding_rect().unwrap();
+    let points: Vec<Point<f64>> = (0..100)
+        .flat_map(|i| {
+            (0..100).map(move |j| {
+                Point::new(
+                    rect.min().x + rect.width() * i as f64 / 100.,
+                    rect.min().y + rect.height() * j as f64 / 100.,
+                )
+            })
+        })
+        .collect();
+
+    c.bench_function("unprepared polygon contains 10,000 points", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(
+                points
+                    .iter()
+                    .filter(|point| criterion::black_box(&norway).contains(*point))
+                    .count(),
+            );
+        });
+    });
+
+    c.bench_function("prepared polygon contains 10,000 points", |bencher| {
+        let prepared = PreparedGeometry::from(&norway);
+        bencher.iter(|| {
+            criterion::black_box(
+                points
+                    .iter()
+                    .filter(|point| criterion::black_box(&prepared).contains(*point))
+                    .count(),
+            );
+        });
+    });
+
+    c.bench_function("unprepared polygon relates to 100 points", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(
+                points
+                    .iter()
+                    .step_by(100)
+                    .filter(|point| criterion::black_box(&norway).relate(*point).is_covers())
+                    .count(),
+            );
+        });
+    });
+
+    c.bench_function("prepared polygon relates to 100 points", |bencher| {
+        let prepared = PreparedGeometry::from(&norway);
+        bencher.iter(|| {
+            criterion::black_box(
+                points
+                    .iter()
+                    .step_by(100)
+                    .filter(|point| criterion::black_box(&prepared).relate(*point).is_covers())
+                    .count(),
+            );
+        });
+    });
+
+    c.bench_function(
+        "prepared polygon contains a multi point of 10,000 points",
+        |bencher| {
+            let prepared = PreparedGeometry::from(&norway);
+            let multi_point = MultiPoint::new(points.clone());
+            bencher.iter(|| {
+                criterion::black_box(criterion::black_box(&prepared).contains(&multi_point));
+            });
+        },
+    );
+
+    c.bench_function("prepare polygon", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(PreparedGeometry::from(criterion::black_box(&norway)));
+        });
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
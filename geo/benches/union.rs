@@ -0,0 +1,51 @@
+#[macro_use]
+extern crate criterion;
+extern crate geo;
+
+use criterion::Criterion;
+use geo::{unary_union, BooleanOps, MultiPolygon, Polygon, Rect};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Overlapping squares of random sizes, scattered over a grid.
+fn squares(count: usize) -> Vec<Polygon<f64>> {
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut next = move || rng.gen::<f64>();
+    let side = (count as f64).sqrt();
+    (0..count)
+        .map(|_| {
+            let (x, y) = (next() * side, next() * side);
+            let size = next() + 0.5;
+            Rect::new((x, y), (x + size, y + size)).to_polygon()
+        })
+        .collect()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let polygons = squares(2_000);
+
+    c.bench_function("unary union of 2000 polygons", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(unary_union(criterion::black_box(&polygons)));
+        });
+    });
+
+    c.bench_function("fold union of 2000 polygons", |bencher| {
+        bencher.iter(|| {
+            criterion::black_box(
+                criterion::black_box(&polygons)
+                    .iter()
+                    .fold(MultiPolygon::new(vec![]), |union, polygon| {
+                        union.union(&MultiPolygon::new(vec![polygon.clone()]))
+                    }),
+            );
+        });
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = criterion_benchmark
+}
+criterion_main!(benches);
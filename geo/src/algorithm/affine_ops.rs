@@ -277,6 +277,42 @@ impl<T: CoordNum> AffineTransform<T> {
     }
 }
 
+/// The parts of an [`AffineTransform`], as found by [`AffineTransform::decompose`].
+///
+/// They apply in the order shear, scale, rotation, translation.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AffineDecomposition<T: CoordFloat> {
+    /// The offsets along the `x` and `y` dimensions, applied last.
+    pub translation: (T, T),
+    /// The counter-clockwise rotation around the origin, in **degrees**, between -180 and 180.
+    pub rotation_degrees: T,
+    /// The factors along the `x` and `y` dimensions, applied around the origin.
+    pub scale: (T, T),
+    /// The shear factor of `x` by `y`, applied first: `x += shear * y`.
+    pub shear: T,
+}
+
+impl<T: CoordFloat> AffineDecomposition<T> {
+    /// **Create** the affine transform which these parts make up.
+    pub fn recompose(&self) -> AffineTransform<T> {
+        let origin = Coord::zero();
+        let (xfact, yfact) = self.scale;
+        let (xoff, yoff) = self.translation;
+        // `compose` applies its argument first
+        AffineTransform::translate(xoff, yoff)
+            .compose(&AffineTransform::rotate(self.rotation_degrees, origin))
+            .compose(&AffineTransform::scale(xfact, yfact, origin))
+            .compose(&AffineTransform::new(
+                T::one(),
+                self.shear,
+                T::zero(),
+                T::zero(),
+                T::one(),
+                T::zero(),
+            ))
+    }
+}
+
 impl<T: CoordNum> fmt::Debug for AffineTransform<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("AffineTransform")
@@ -355,6 +391,15 @@ impl<U: CoordFloat> AffineTransform<U> {
     /// xoff = -origin.y * tan(xs)
     /// yoff = -origin.x * tan(ys)
     /// ```
+    ///
+    /// # Degenerate angles
+    ///
+    /// As an angle approaches 90° (or -90°), the shear along its dimension grows without limit.
+    /// An angle of exactly 90° can't be represented in radians, so it gives a very large but
+    /// finite coefficient, of about `1.6e16` for `f64`, rather than infinity. Such a transform
+    /// flattens geometries onto a line, loses the precision of the sheared coordinates, even at
+    /// the origin, and can't be meaningfully inverted; check the angles first if they may be this
+    /// steep.
     pub fn skew(xs: U, ys: U, origin: impl Into<Coord<U>>) -> Self {
         let Coord { x: x0, y: y0 } = origin.into();
         let mut tanx = xs.to_radians().tan();
@@ -385,6 +430,108 @@ impl<U: CoordFloat> AffineTransform<U> {
         self.0 = self.compose(&Self::skew(xs, ys, origin)).0;
         self
     }
+
+    /// **Create** the inverse of this affine transform, which undoes it, or `None` if it has no
+    /// inverse.
+    ///
+    /// Note that this operation is only available for geometries with floating point coordinates.
+    ///
+    /// A transform can't be inverted if it collapses the plane onto a line or a point, such as a
+    /// scale by zero, or a skew by equal and opposite angles. Then the determinant `a * e - b * d`
+    /// is zero, or so close to zero that the inverse can't be represented. A transform which is
+    /// only degenerate up to rounding error has a very large and imprecise inverse instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, AffineOps, AffineTransform};
+    /// use approx::assert_relative_eq;
+    ///
+    /// // from world coordinates to the pixels of a screen, with y pointing down
+    /// let to_screen = AffineTransform::new(2.0, 0.0, 400.0, 0.0, -2.0, 300.0);
+    /// let to_world = to_screen.try_inverse().unwrap();
+    ///
+    /// let click = point!(x: 410.0, y: 280.0);
+    /// assert_relative_eq!(click.affine_transform(&to_world), point!(x: 5.0, y: 10.0));
+    ///
+    /// assert!(AffineTransform::scale(0.0, 1.0, (0.0, 0.0)).try_inverse().is_none());
+    /// ```
+    pub fn try_inverse(&self) -> Option<Self> {
+        let [[a, b, xoff], [d, e, yoff], _] = self.0;
+        let determinant = a * e - b * d;
+        if determinant == U::zero() {
+            return None;
+        }
+        let inverse = Self::new(
+            e / determinant,
+            -b / determinant,
+            (b * yoff - e * xoff) / determinant,
+            -d / determinant,
+            a / determinant,
+            (d * xoff - a * yoff) / determinant,
+        );
+        if inverse.0.iter().flatten().all(|value| value.is_finite()) {
+            Some(inverse)
+        } else {
+            None
+        }
+    }
+
+    /// **Decompose** this affine transform into a shear, scale, rotation and translation, which
+    /// [`AffineDecomposition::recompose`] turns back into the same transform (up to rounding).
+    ///
+    /// Note that this operation is only available for geometries with floating point coordinates.
+    ///
+    /// The parts are found by a QR decomposition of the linear part of the matrix, and apply to a
+    /// coordinate in this order:
+    ///
+    /// 1. shear: `x += shear * y`
+    /// 2. scale: `x *= scale.0`, `y *= scale.1`
+    /// 3. rotation: counter-clockwise by `rotation_degrees`, around the origin
+    /// 4. translation: `x += translation.0`, `y += translation.1`
+    ///
+    /// The x scale is never negative, so a transform which mirrors geometries has a negative y
+    /// scale.
+    ///
+    /// Returns `None` if the transform collapses the x axis to a point (`a` and `d` are both
+    /// zero), when the rotation and shear are undefined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::AffineTransform;
+    /// use approx::assert_relative_eq;
+    ///
+    /// // scale, then rotate, then translate (`compose` applies its argument first)
+    /// let transform = AffineTransform::translate(5.0, -1.0)
+    ///     .compose(&AffineTransform::rotate(30.0, (0.0, 0.0)))
+    ///     .compose(&AffineTransform::scale(2.0, 3.0, (0.0, 0.0)));
+    ///
+    /// let parts = transform.decompose().unwrap();
+    /// assert_relative_eq!(parts.rotation_degrees, 30.0, epsilon = 1e-9);
+    /// assert_relative_eq!(parts.scale.0, 2.0, epsilon = 1e-9);
+    /// assert_relative_eq!(parts.scale.1, 3.0, epsilon = 1e-9);
+    /// assert_relative_eq!(parts.shear, 0.0, epsilon = 1e-9);
+    /// assert_eq!(parts.translation, (5.0, -1.0));
+    /// ```
+    pub fn decompose(&self) -> Option<AffineDecomposition<U>> {
+        let [[a, b, xoff], [d, e, yoff], _] = self.0;
+        // the first column, which is where the x axis goes, gives the rotation and x scale
+        let scale_x = a.hypot(d);
+        if scale_x == U::zero() {
+            return None;
+        }
+        let (sin_theta, cos_theta) = (d / scale_x, a / scale_x);
+        // and what's left of the second column once the rotation is undone gives the rest
+        let r12 = cos_theta * b + sin_theta * e;
+        let r22 = cos_theta * e - sin_theta * b;
+        Some(AffineDecomposition {
+            translation: (xoff, yoff),
+            rotation_degrees: d.atan2(a).to_degrees(),
+            scale: (scale_x, r22),
+            shear: r12 / scale_x,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -436,4 +583,123 @@ mod tests {
         let expected = polygon![(x: 1.0, y: 1.0), (x: 1.0, y: 5.0), (x: 3.0, y: 5.0)];
         assert_eq!(expected, poly);
     }
+
+    #[test]
+    fn skew_near_90_degrees_is_finite() {
+        for degrees in [90., -90., 270., 89.999_999_999] {
+            let transform = AffineTransform::<f64>::skew(degrees, 0., (3., 4.));
+            assert!(transform.0.iter().flatten().all(|c| c.is_finite()));
+            assert!(transform.0[0][1].abs() > 1e10);
+
+            // the sheared coordinates lose precision, but stay finite, and the other dimension is
+            // untouched
+            let point = transform.apply(Coord { x: 1., y: 5. });
+            assert!(point.x.is_finite() && point.y == 5.);
+        }
+    }
+
+    #[test]
+    fn try_inverse() {
+        let transform = AffineTransform::rotate(30., (2., -1.))
+            .compose(&AffineTransform::skew(10., -25., (1., 1.)))
+            .compose(&AffineTransform::scale(3., -0.5, (4., 4.)))
+            .compose(&AffineTransform::translate(-7., 12.));
+        let inverse = transform.try_inverse().unwrap();
+
+        for composed in [transform.compose(&inverse), inverse.compose(&transform)] {
+            for coord in [
+                Coord { x: 0., y: 0. },
+                Coord { x: 1., y: -1. },
+                Coord { x: 123.5, y: 42. },
+                Coord { x: -1e4, y: 3e3 },
+            ] {
+                assert_relative_eq!(composed.apply(coord), coord, epsilon = 1e-9);
+                assert_relative_eq!(inverse.apply(transform.apply(coord)), coord, epsilon = 1e-9);
+            }
+        }
+        assert_relative_eq!(
+            inverse.try_inverse().unwrap().0.concat().as_slice(),
+            transform.0.concat().as_slice(),
+            epsilon = 1e-12
+        );
+        assert!(AffineTransform::<f64>::identity()
+            .try_inverse()
+            .unwrap()
+            .is_identity());
+    }
+
+    #[test]
+    fn try_inverse_degenerate() {
+        assert!(AffineTransform::scale(0., 2., (1., 1.))
+            .try_inverse()
+            .is_none());
+        assert!(AffineTransform::new(1., 2., 3., 2., 4., 5.)
+            .try_inverse()
+            .is_none());
+        assert!(AffineTransform::new(f64::NAN, 0., 0., 0., 1., 0.)
+            .try_inverse()
+            .is_none());
+        // collapses everything onto the line y = x, but because of rounding, the determinant
+        // may not be exactly zero, leaving a huge inverse
+        if let Some(inverse) = AffineTransform::<f64>::skew(45., 45., (0., 0.)).try_inverse() {
+            assert!(inverse.0[0][0].abs() > 1e10);
+        }
+    }
+
+    #[test]
+    fn decompose_round_trip() {
+        let transforms = [
+            AffineTransform::identity(),
+            AffineTransform::translate(3., -4.),
+            AffineTransform::rotate(-135., (2., 7.)),
+            AffineTransform::scale(-2., 0.5, (1., 1.)),
+            AffineTransform::skew(20., -35., (4., -2.)),
+            AffineTransform::rotate(30., (2., -1.))
+                .compose(&AffineTransform::skew(10., -25., (1., 1.)))
+                .compose(&AffineTransform::scale(3., -0.5, (4., 4.)))
+                .compose(&AffineTransform::translate(-7., 12.)),
+            // degenerate, collapsing the plane onto a line, but still decomposable
+            AffineTransform::scale(2., 0., (0., 0.)),
+            AffineTransform::new(1., 2., 0., 3., 4., 0.),
+        ];
+        for transform in transforms {
+            let parts = transform.decompose().unwrap();
+            assert!(parts.scale.0 >= 0.);
+            assert!((-180. ..=180.).contains(&parts.rotation_degrees));
+            let recomposed = parts.recompose();
+            assert_relative_eq!(
+                recomposed.0.concat().as_slice(),
+                transform.0.concat().as_slice(),
+                epsilon = 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn decompose_parts() {
+        let parts = AffineTransform::new(1., 0.5, 0., 0., 1., 0.)
+            .decompose()
+            .unwrap();
+        assert_relative_eq!(parts.shear, 0.5);
+        assert_relative_eq!(parts.rotation_degrees, 0.);
+        assert_eq!(parts.scale, (1., 1.));
+
+        // a rotation by 90 degrees, after mirroring in the x axis
+        let mirror = AffineTransform::rotate(90., (0., 0.))
+            .compose(&AffineTransform::scale(1., -1., (0., 0.)))
+            .decompose()
+            .unwrap();
+        assert_relative_eq!(mirror.rotation_degrees, 90.);
+        assert_relative_eq!(mirror.scale.0, 1.);
+        assert_relative_eq!(mirror.scale.1, -1.);
+        assert_relative_eq!(mirror.shear, 0., epsilon = 1e-15);
+
+        let parts = AffineTransform::translate(3., -4.).decompose().unwrap();
+        assert_eq!(parts.translation, (3., -4.));
+
+        assert_eq!(
+            AffineTransform::new(0., 1., 2., 0., 3., 4.).decompose(),
+            None
+        );
+    }
 }
@@ -4,10 +4,10 @@ use std::{
 };
 
 use crate::{
-    sweep::{compare_crossings, Cross, CrossingsIter, LineOrPoint, SweepPoint},
+    sweep::{Cross, CrossingsIter, LineOrPoint, SweepArith, SweepError, SweepPoint},
     utils::EitherIter,
     winding_order::WindingOrder,
-    GeoFloat,
+    GeoNum,
 };
 use geo_types::{LineString, MultiPolygon, Polygon};
 
@@ -18,11 +18,11 @@ use geo_types::{LineString, MultiPolygon, Polygon};
 /// describe a bounded region, do not intersect in their interior, and are not
 /// degenerate (not a point).
 #[derive(Debug)]
-pub struct RegionAssembly<T: GeoFloat> {
+pub struct RegionAssembly<T: GeoNum> {
     segments: Vec<Segment<T>>,
 }
 
-impl<T: GeoFloat> Default for RegionAssembly<T> {
+impl<T: GeoNum> Default for RegionAssembly<T> {
     fn default() -> Self {
         Self {
             segments: Default::default(),
@@ -30,26 +30,30 @@ impl<T: GeoFloat> Default for RegionAssembly<T> {
     }
 }
 
-impl<T: GeoFloat> RegionAssembly<T> {
+impl<T: GeoNum> RegionAssembly<T> {
     pub fn add_edge(&mut self, edge: LineOrPoint<T>) {
         debug_assert!(edge.is_line());
         trace!("add_edge: {edge:?}");
         self.segments.push(edge.into());
     }
-    pub fn finish(self) -> MultiPolygon<T> {
+    pub fn finish<A: SweepArith<T>>(self) -> Result<MultiPolygon<T>, SweepError<T>> {
         let mut iter = CrossingsIter::new_simple(self.segments.iter());
         let mut snakes = vec![];
 
-        while let Some(pt) = iter.next() {
+        while let Some(pt) = iter.try_next::<A>()? {
+            let failed = SweepError { point: pt };
             let num_segments = iter.intersections().len();
-            debug_assert!(num_segments % 2 == 0, "assembly segments must be eulierian");
-            iter.intersections_mut().sort_unstable_by(compare_crossings);
+            // assembly segments must be eulerian
+            if num_segments % 2 != 0 {
+                return Err(failed);
+            }
+            iter.sort_intersections()?;
 
             let first = &iter.intersections()[0];
             let (prev_region, mut parent_snake_idx) = if first.at_left {
                 // No segment ends here.
                 // We should read prev_region via `prev_active`
-                iter.prev_active(first)
+                iter.prev_active(first)?
                     .map(|(_, seg)| (seg.region.get(), Some(seg.snake_idx.get())))
                     .unwrap_or_else(|| (false, Some(0)))
             } else {
@@ -73,7 +77,7 @@ impl<T: GeoFloat> RegionAssembly<T> {
                     c.cross.region.set(true);
                     if parent_snake_idx.is_none() {
                         parent_snake_idx = Some(
-                            iter.prev_active(c)
+                            iter.prev_active(c)?
                                 .map(|(_, seg)| seg.snake_idx.get())
                                 .unwrap_or(0),
                         );
@@ -83,7 +87,7 @@ impl<T: GeoFloat> RegionAssembly<T> {
                     d.cross.region.set(false);
                     if parent_snake_idx.is_none() {
                         parent_snake_idx = Some(
-                            iter.prev_active(d)
+                            iter.prev_active(d)?
                                 .map(|(_, seg)| seg.snake_idx.get())
                                 .unwrap_or(0),
                         );
@@ -114,19 +118,21 @@ impl<T: GeoFloat> RegionAssembly<T> {
                     (true, false) => {
                         // Connect d -> c
                         let s_idx = d.cross.snake_idx.get();
-                        snakes[s_idx].push(c.line.right());
+                        snakes.get_mut(s_idx).ok_or(failed)?.push(c.line.right());
                         c.cross.snake_idx.set(s_idx);
                     }
                     (false, true) => {
                         // Connect c -> d
                         let s_idx = c.cross.snake_idx.get();
-                        snakes[s_idx].push(d.line.right());
+                        snakes.get_mut(s_idx).ok_or(failed)?.push(d.line.right());
                         d.cross.snake_idx.set(s_idx);
                     }
                     (false, false) => {
                         let c_idx = c.cross.snake_idx.get();
                         let d_idx = d.cross.snake_idx.get();
-                        debug_assert_ne!(c_idx, d_idx);
+                        if c_idx == d_idx || c_idx.max(d_idx) >= snakes.len() {
+                            return Err(failed);
+                        }
                         snakes[c_idx].finish(d_idx);
                         snakes[d_idx].finish(c_idx);
                     }
@@ -134,7 +140,7 @@ impl<T: GeoFloat> RegionAssembly<T> {
             }
         }
 
-        let (rings, snakes_idx_map) = rings_from_snakes(&mut snakes[..]);
+        let (rings, snakes_idx_map) = rings_from_snakes(&mut snakes[..])?;
 
         let mut polygons = vec![];
         let mut children = HashMap::new();
@@ -143,7 +149,9 @@ impl<T: GeoFloat> RegionAssembly<T> {
                 let mut parent_ring_idx;
                 let mut parent_snake_idx = ring.parent_snake_idx;
                 loop {
-                    parent_ring_idx = snakes_idx_map[&parent_snake_idx];
+                    parent_ring_idx = *snakes_idx_map
+                        .get(&parent_snake_idx)
+                        .ok_or(SweepError { point: ring.ls[0] })?;
                     let parent = &rings[parent_ring_idx];
                     if !parent.is_hole {
                         break;
@@ -171,17 +179,17 @@ impl<T: GeoFloat> RegionAssembly<T> {
             polygons.push(Polygon::new(exterior, holes));
         }
 
-        polygons.into()
+        Ok(polygons.into())
     }
 }
 
 #[derive(Debug)]
-pub struct LineAssembly<T: GeoFloat> {
+pub struct LineAssembly<T: GeoNum> {
     segments: Vec<VecDeque<SweepPoint<T>>>,
     end_points: BTreeMap<(usize, SweepPoint<T>), (usize, bool)>,
 }
 
-impl<T: GeoFloat> LineAssembly<T> {
+impl<T: GeoNum> LineAssembly<T> {
     pub fn add_edge(&mut self, geom: LineOrPoint<T>, geom_idx: usize) {
         // Try to find a line-string with either end-point
         if let Some((seg_idx, at_front)) = self.end_points.remove(&(geom_idx, geom.left())) {
@@ -218,7 +226,7 @@ impl<T: GeoFloat> LineAssembly<T> {
     }
 }
 
-impl<T: GeoFloat> Default for LineAssembly<T> {
+impl<T: GeoNum> Default for LineAssembly<T> {
     fn default() -> Self {
         Self {
             segments: Default::default(),
@@ -228,16 +236,13 @@ impl<T: GeoFloat> Default for LineAssembly<T> {
 }
 
 #[derive(Debug, Clone)]
-struct Ring<T: GeoFloat> {
+struct Ring<T: GeoNum> {
     ls: LineString<T>,
     is_hole: bool,
     parent_snake_idx: usize,
 }
 
-fn split_ring<T: GeoFloat, F: FnMut(LineString<T>)>(
-    ls: &LineString<T>,
-    mut cb: F,
-) -> LineString<T> {
+fn split_ring<T: GeoNum, F: FnMut(LineString<T>)>(ls: &LineString<T>, mut cb: F) -> LineString<T> {
     let mut pts_map = BTreeMap::new();
     let mut exterior = vec![];
     for coord in ls.0.iter().copied() {
@@ -256,23 +261,24 @@ fn split_ring<T: GeoFloat, F: FnMut(LineString<T>)>(
     LineString::from(exterior)
 }
 
-fn rings_from_snakes<T: GeoFloat>(
+#[allow(clippy::type_complexity)]
+fn rings_from_snakes<T: GeoNum>(
     snakes: &mut [Snake<T>],
-) -> (Vec<Ring<T>>, HashMap<usize, usize>) {
+) -> Result<(Vec<Ring<T>>, HashMap<usize, usize>), SweepError<T>> {
     let mut snake_idx_map = HashMap::new();
     let mut rings = vec![];
     for idx in 0..snakes.len() {
         if let Some(ls) = Snake::into_ring(snakes, idx, |midx| {
             snake_idx_map.insert(midx, rings.len());
-        }) {
+        })? {
             rings.push(ls);
         }
     }
-    (rings, snake_idx_map)
+    Ok((rings, snake_idx_map))
 }
 
 #[derive(Debug, Clone)]
-struct Snake<T: GeoFloat> {
+struct Snake<T: GeoNum> {
     points: Vec<SweepPoint<T>>,
     start_pair: usize,
     end_pair: Option<usize>,
@@ -280,7 +286,7 @@ struct Snake<T: GeoFloat> {
     parent_snake_idx: usize,
 }
 
-impl<T: GeoFloat> Snake<T> {
+impl<T: GeoNum> Snake<T> {
     pub fn new(
         start: SweepPoint<T>,
         end: SweepPoint<T>,
@@ -304,40 +310,45 @@ impl<T: GeoFloat> Snake<T> {
         self.end_pair = Some(other)
     }
 
+    /// Join the snakes from `start_idx` into a ring, or `None` if they already have been, or
+    /// an error at its first point if they don't form one.
     pub fn into_ring<F: FnMut(usize)>(
         slice: &mut [Self],
         start_idx: usize,
         mut idx_cb: F,
-    ) -> Option<Ring<T>> {
+    ) -> Result<Option<Ring<T>>, SweepError<T>> {
         let mut output = vec![];
 
         let mut idx = start_idx;
         let mut at_start = true;
-        let (parent_snake_idx, is_hole) = {
+        let (parent_snake_idx, is_hole, failed) = {
             let el = &slice[idx];
             if el.points.is_empty() {
-                return None;
+                return Ok(None);
             }
+            let failed = SweepError::from(el.points[0]);
             let last_el = &slice[el.start_pair];
 
             let start_l = LineOrPoint::new(el.points[0], el.points[1]);
             let end_l = LineOrPoint::new(el.points[0], last_el.points[1]);
             use std::cmp::Ordering;
-            let ls_winding = match start_l.partial_cmp(&end_l).unwrap() {
-                Ordering::Less => WindingOrder::CounterClockwise,
-                Ordering::Greater => WindingOrder::Clockwise,
-                _ => unreachable!(),
+            let ls_winding = match start_l.partial_cmp(&end_l) {
+                Some(Ordering::Less) => WindingOrder::CounterClockwise,
+                Some(Ordering::Greater) => WindingOrder::Clockwise,
+                _ => return Err(failed),
             };
-            (el.parent_snake_idx, el.region != ls_winding)
+            (el.parent_snake_idx, el.region != ls_winding, failed)
         };
         loop {
-            let el = &mut slice[idx];
-            debug_assert!(!el.points.is_empty());
+            let el = slice.get_mut(idx).ok_or(failed)?;
+            if el.points.is_empty() {
+                return Err(failed);
+            }
             idx_cb(idx);
 
             let iter = el.points.drain(..);
             let iter = if at_start {
-                idx = el.end_pair.unwrap();
+                idx = el.end_pair.ok_or(failed)?;
                 EitherIter::A(iter)
             } else {
                 idx = el.start_pair;
@@ -352,22 +363,22 @@ impl<T: GeoFloat> Snake<T> {
         }
 
         let ls = LineString::new(output);
-        Some(Ring {
+        Ok(Some(Ring {
             ls,
             is_hole,
             parent_snake_idx,
-        })
+        }))
     }
 }
 
 #[derive(Debug, Clone)]
-struct Segment<T: GeoFloat> {
+struct Segment<T: GeoNum> {
     geom: LineOrPoint<T>,
     region: Cell<bool>,
     snake_idx: Cell<usize>,
 }
 
-impl<T: GeoFloat> From<LineOrPoint<T>> for Segment<T> {
+impl<T: GeoNum> From<LineOrPoint<T>> for Segment<T> {
     fn from(geom: LineOrPoint<T>) -> Self {
         Segment {
             geom,
@@ -377,7 +388,7 @@ impl<T: GeoFloat> From<LineOrPoint<T>> for Segment<T> {
     }
 }
 
-impl<T: GeoFloat> Cross for Segment<T> {
+impl<T: GeoNum> Cross for Segment<T> {
     type Scalar = T;
 
     fn line(&self) -> LineOrPoint<Self::Scalar> {
@@ -0,0 +1,172 @@
+use super::{check_coords, try_boolean_op_with, try_clip_with, BooleanOpsError, OpType};
+use crate::dimensions::HasDimensions;
+use crate::sweep::FloatArith;
+use crate::{
+    GeoFloat, Geometry, Intersects, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
+};
+
+/// Boolean Operations on any [`Geometry`], dispatching on the dimensions of the inputs.
+///
+/// Polygons, multi-polygons, rects and triangles are combined by
+/// [`BooleanOps`](crate::BooleanOps). The other supported combinations are:
+///
+/// - the `intersection` of a 1-D and a 2-D geometry, which is the lines clipped to the area, as
+///   a [`MultiLineString`]
+/// - the `difference` of a 2-D geometry from a 1-D geometry, which is the lines clipped to
+///   outside the area, as a [`MultiLineString`]
+/// - the `intersection` of points with any geometry, or the `difference` of any geometry from
+///   points, which keeps the points which do, or don't, intersect the geometry, as a
+///   [`MultiPoint`]
+/// - the `difference` of a lower dimensional geometry from a higher dimensional one, which
+///   leaves the latter unchanged
+///
+/// Every other combination, such as the union of a line and a polygon, is a
+/// [`BooleanOpsError::UnsupportedDimensions`]. As with
+/// [`BooleanOps::try_boolean_op`](crate::BooleanOps::try_boolean_op), non-finite coordinates
+/// and failures of the sweep are errors rather than panics.
+///
+/// A [`GeometryCollection`](crate::GeometryCollection) is treated as the geometry of its
+/// (possibly nested) members, which must all have the same dimensions, or it is a
+/// [`BooleanOpsError::MixedCollection`]. An empty collection is treated as an empty
+/// [`MultiPolygon`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, polygon, Geometry, GeometryBooleanOps, MultiLineString, MultiPoint};
+///
+/// let square: Geometry = polygon![
+///     (x: 0., y: 0.),
+///     (x: 2., y: 0.),
+///     (x: 2., y: 2.),
+///     (x: 0., y: 2.),
+/// ]
+/// .into();
+/// let line: Geometry = line_string![(x: -1., y: 1.), (x: 3., y: 1.)].into();
+/// let points: Geometry = MultiPoint::new(vec![point!(x: 1., y: 1.), point!(x: 5., y: 5.)]).into();
+///
+/// let clipped = line.intersection(&square).unwrap();
+/// assert_eq!(
+///     clipped,
+///     Geometry::MultiLineString(MultiLineString::new(vec![
+///         line_string![(x: 0., y: 1.), (x: 2., y: 1.)]
+///     ]))
+/// );
+///
+/// let inside = points.intersection(&square).unwrap();
+/// assert_eq!(
+///     inside,
+///     Geometry::MultiPoint(MultiPoint::new(vec![point!(x: 1., y: 1.)]))
+/// );
+///
+/// assert!(line.union(&square).is_err());
+/// ```
+pub trait GeometryBooleanOps<T: GeoFloat> {
+    fn boolean_op(&self, other: &Self, op: OpType) -> Result<Geometry<T>, BooleanOpsError<T>>;
+    fn intersection(&self, other: &Self) -> Result<Geometry<T>, BooleanOpsError<T>> {
+        self.boolean_op(other, OpType::Intersection)
+    }
+    fn union(&self, other: &Self) -> Result<Geometry<T>, BooleanOpsError<T>> {
+        self.boolean_op(other, OpType::Union)
+    }
+    fn xor(&self, other: &Self) -> Result<Geometry<T>, BooleanOpsError<T>> {
+        self.boolean_op(other, OpType::Xor)
+    }
+    fn difference(&self, other: &Self) -> Result<Geometry<T>, BooleanOpsError<T>> {
+        self.boolean_op(other, OpType::Difference)
+    }
+}
+
+impl<T: GeoFloat> GeometryBooleanOps<T> for Geometry<T> {
+    fn boolean_op(&self, other: &Self, op: OpType) -> Result<Geometry<T>, BooleanOpsError<T>> {
+        let unsupported = BooleanOpsError::UnsupportedDimensions {
+            op,
+            dimensions: (self.dimensions(), other.dimensions()),
+        };
+        check_coords(self, 0, None)?;
+        check_coords(other, 1, None)?;
+        let result = match (Operand::new(self)?, Operand::new(other)?, op) {
+            (Operand::Area(a), Operand::Area(b), op) => {
+                try_boolean_op_with::<FloatArith, _>(&a.0, &b.0, op)?.into()
+            }
+            (Operand::Line(lines), Operand::Area(area), OpType::Intersection)
+            | (Operand::Area(area), Operand::Line(lines), OpType::Intersection) => {
+                try_clip_with::<FloatArith, _>(&area.0, &lines, false)?.into()
+            }
+            (Operand::Line(lines), Operand::Area(area), OpType::Difference) => {
+                try_clip_with::<FloatArith, _>(&area.0, &lines, true)?.into()
+            }
+            (Operand::Points(points), other, OpType::Intersection)
+            | (other, Operand::Points(points), OpType::Intersection) => {
+                filter(points, |point| other.intersects(point)).into()
+            }
+            (Operand::Points(points), other, OpType::Difference) => {
+                filter(points, |point| !other.intersects(point)).into()
+            }
+            (Operand::Area(area), _, OpType::Difference) => area.into(),
+            (Operand::Line(lines), Operand::Points(_), OpType::Difference) => lines.into(),
+            _ => return Err(unsupported),
+        };
+        Ok(result)
+    }
+}
+
+/// A [`Geometry`] as the multi-geometry of its dimensions.
+enum Operand<T: GeoFloat> {
+    Area(MultiPolygon<T>),
+    Line(MultiLineString<T>),
+    Points(MultiPoint<T>),
+}
+
+impl<T: GeoFloat> Operand<T> {
+    fn new(geometry: &Geometry<T>) -> Result<Self, BooleanOpsError<T>> {
+        let mut area = MultiPolygon::new(vec![]);
+        let mut lines = MultiLineString::new(vec![]);
+        let mut points = MultiPoint::new(vec![]);
+        add_members(geometry, &mut area, &mut lines, &mut points);
+        match (area.0.is_empty(), lines.0.is_empty(), points.0.is_empty()) {
+            (_, true, true) => Ok(Operand::Area(area)),
+            (true, false, true) => Ok(Operand::Line(lines)),
+            (true, true, false) => Ok(Operand::Points(points)),
+            _ => Err(BooleanOpsError::MixedCollection),
+        }
+    }
+
+    fn intersects(&self, point: &Point<T>) -> bool {
+        match self {
+            Operand::Area(area) => point.intersects(area),
+            Operand::Line(lines) => point.intersects(lines),
+            Operand::Points(points) => point.intersects(points),
+        }
+    }
+}
+
+fn add_members<T: GeoFloat>(
+    geometry: &Geometry<T>,
+    area: &mut MultiPolygon<T>,
+    lines: &mut MultiLineString<T>,
+    points: &mut MultiPoint<T>,
+) {
+    match geometry {
+        Geometry::Point(point) => points.0.push(*point),
+        Geometry::MultiPoint(multi_point) => points.0.extend(multi_point.iter().copied()),
+        Geometry::Line(line) => lines.0.push(LineString::from(*line)),
+        Geometry::LineString(line_string) => lines.0.push(line_string.clone()),
+        Geometry::MultiLineString(multi_line_string) => {
+            lines.0.extend(multi_line_string.iter().cloned())
+        }
+        Geometry::Polygon(polygon) => area.0.push(polygon.clone()),
+        Geometry::MultiPolygon(multi_polygon) => area.0.extend(multi_polygon.iter().cloned()),
+        Geometry::Rect(rect) => area.0.push(rect.to_polygon()),
+        Geometry::Triangle(triangle) => area.0.push(triangle.to_polygon()),
+        Geometry::GeometryCollection(collection) => {
+            for member in collection {
+                add_members(member, area, lines, points);
+            }
+        }
+    }
+}
+
+fn filter<T: GeoFloat>(points: MultiPoint<T>, keep: impl Fn(&Point<T>) -> bool) -> MultiPoint<T> {
+    MultiPoint::new(points.0.into_iter().filter(|point| keep(point)).collect())
+}
@@ -1,6 +1,10 @@
+use std::{error, fmt};
+
 use geo_types::{MultiLineString, MultiPolygon};
 
-use crate::{CoordsIter, GeoFloat, GeoNum, Polygon};
+use crate::dimensions::Dimensions;
+use crate::sweep::{FloatArith, NumArith, SweepArith, SweepError, SweepNum};
+use crate::{Coord, CoordNum, CoordsIter, GeoFloat, GeoNum, Polygon};
 
 /// Boolean Operations on geometry.
 ///
@@ -22,6 +26,62 @@ use crate::{CoordsIter, GeoFloat, GeoNum, Polygon};
 /// In particular, taking `union` with an empty geom should remove degeneracies
 /// and fix invalid polygons as long the interior-exterior requirement above is
 /// satisfied.
+///
+/// # Winding order
+///
+/// The winding order of the rings is ignored: whether a region lies inside an
+/// input is decided by the parity of the number of rings crossed to reach it.
+/// Inputs whose polygons (or rings) are inconsistently wound produce the same
+/// result as correctly wound ones, so there is no need to
+/// [`orient`](crate::Orient) them beforehand.
+///
+/// # Integer coordinates
+///
+/// The operations are also implemented for `i64` coordinates, for example pre-scaled to a
+/// fixed grid, with the intersections of edges computed exactly, and then
+/// [snap rounded](crate::sweep::SweepNum::snap_round) to the grid. Unlike with floats, this
+/// can't produce inconsistent topology, and the result doesn't depend on the order of the
+/// vertices of the input. The coordinates must have magnitudes of less than 2<sup>30</sup>:
+/// larger ones are a [`BooleanOpsError::CoordOutOfRange`] from the `try_` methods, and a panic
+/// from the others.
+///
+/// ```
+/// use geo::{polygon, BooleanOps};
+///
+/// let square = polygon![(x: 0, y: 0), (x: 10, y: 0), (x: 10, y: 10), (x: 0, y: 10)];
+/// let triangle = polygon![(x: 5, y: -5), (x: 12, y: 4), (x: 5, y: 4)];
+///
+/// // the edges cross at (8 8/9, 0) and (10, 1 3/7), which are rounded to (9, 0) and (10, 1)
+/// assert_eq!(
+///     square.intersection(&triangle),
+///     polygon![(x: 9, y: 0), (x: 10, y: 1), (x: 10, y: 4), (x: 5, y: 4), (x: 5, y: 0)].into(),
+/// );
+/// ```
+///
+/// # Panics
+///
+/// The methods other than the `try_` ones panic on input they can't handle: a non-finite
+/// coordinate, an integer coordinate out of range, or degenerate input which breaks the
+/// invariants of the sweep. Some of these invariants used to be checked only by debug
+/// assertions, so release builds could return a result, possibly with broken topology, on
+/// input on which they now panic. Use the `try_` methods, such as
+/// [`try_boolean_op`](Self::try_boolean_op), to handle these as errors instead.
+///
+/// # Generic scalars
+///
+/// The operations are implemented for the scalars implementing [`SweepNum`], which are `f32`,
+/// `f64` and `i64`, rather than for any [`GeoFloat`], so code generic over the scalar needs a
+/// `SweepNum` bound. A custom float scalar can implement `SweepNum` by delegating to
+/// [`line_intersection`](crate::line_intersection::line_intersection).
+///
+/// ```
+/// use geo::sweep::SweepNum;
+/// use geo::{BooleanOps, GeoFloat, MultiPolygon, Polygon};
+///
+/// fn union<T: GeoFloat + SweepNum>(a: &Polygon<T>, b: &Polygon<T>) -> MultiPolygon<T> {
+///     a.union(b)
+/// }
+/// ```
 pub trait BooleanOps: Sized {
     type Scalar: GeoNum;
 
@@ -39,15 +99,106 @@ pub trait BooleanOps: Sized {
         self.boolean_op(other, OpType::Difference)
     }
 
+    /// Like [`BooleanOps::boolean_op`], but returning an error instead of panicking on input
+    /// which can't be handled.
+    ///
+    /// The inputs are first checked for non-finite coordinates, which are a
+    /// [`BooleanOpsError::NonFiniteCoord`], and coordinates too large for the scalar's
+    /// arithmetic, which are a [`BooleanOpsError::CoordOutOfRange`]. Degenerate input can still break the invariants of
+    /// the sweep, which is then stopped, and a [`BooleanOpsError::SweepFailed`] returned.
+    ///
+    /// The default implementation calls [`BooleanOps::boolean_op`], so only catches errors if
+    /// it is overridden, as it is for [`Polygon`] and [`MultiPolygon`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, BooleanOps, BooleanOpsError};
+    ///
+    /// let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+    /// let broken = polygon![(x: 5., y: 5.), (x: f64::INFINITY, y: 5.), (x: 15., y: 15.)];
+    ///
+    /// assert!(square.try_union(&square).is_ok());
+    /// assert!(matches!(
+    ///     square.try_union(&broken),
+    ///     Err(BooleanOpsError::NonFiniteCoord { input: 1, coord }) if coord.y == 5.
+    /// ));
+    /// ```
+    fn try_boolean_op(
+        &self,
+        other: &Self,
+        op: OpType,
+    ) -> Result<MultiPolygon<Self::Scalar>, BooleanOpsError<Self::Scalar>> {
+        Ok(self.boolean_op(other, op))
+    }
+    fn try_intersection(
+        &self,
+        other: &Self,
+    ) -> Result<MultiPolygon<Self::Scalar>, BooleanOpsError<Self::Scalar>> {
+        self.try_boolean_op(other, OpType::Intersection)
+    }
+    fn try_union(
+        &self,
+        other: &Self,
+    ) -> Result<MultiPolygon<Self::Scalar>, BooleanOpsError<Self::Scalar>> {
+        self.try_boolean_op(other, OpType::Union)
+    }
+    fn try_xor(
+        &self,
+        other: &Self,
+    ) -> Result<MultiPolygon<Self::Scalar>, BooleanOpsError<Self::Scalar>> {
+        self.try_boolean_op(other, OpType::Xor)
+    }
+    fn try_difference(
+        &self,
+        other: &Self,
+    ) -> Result<MultiPolygon<Self::Scalar>, BooleanOpsError<Self::Scalar>> {
+        self.try_boolean_op(other, OpType::Difference)
+    }
+
     /// Clip a 1-D geometry with self.
     ///
     /// Returns the set-theoeretic intersection of `self` and `ls` if `invert`
     /// is false, and the difference (`ls - self`) otherwise.
+    ///
+    /// Parts of `ls` lying on the boundary of `self` are part of the
+    /// intersection, not the difference, so the two outputs together cover
+    /// `ls` exactly once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{line_string, polygon, BooleanOps, MultiLineString};
+    ///
+    /// let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+    /// // a road along the bottom of the square, then through it
+    /// let road = MultiLineString::new(vec![
+    ///     line_string![(x: -5., y: 0.), (x: 5., y: 0.), (x: 5., y: 15.)],
+    /// ]);
+    ///
+    /// let inside = square.clip(&road, false);
+    /// let outside = square.clip(&road, true);
+    /// assert_eq!(
+    ///     inside,
+    ///     MultiLineString::new(vec![line_string![(x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 10.)]])
+    /// );
+    /// assert_eq!(outside.0.len(), 2);
+    /// ```
     fn clip(
         &self,
         ls: &MultiLineString<Self::Scalar>,
         invert: bool,
     ) -> MultiLineString<Self::Scalar>;
+
+    /// Like [`BooleanOps::clip`], but returning an error instead of panicking, as with
+    /// [`BooleanOps::try_boolean_op`].
+    fn try_clip(
+        &self,
+        ls: &MultiLineString<Self::Scalar>,
+        invert: bool,
+    ) -> Result<MultiLineString<Self::Scalar>, BooleanOpsError<Self::Scalar>> {
+        Ok(self.clip(ls, invert))
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -58,15 +209,162 @@ pub enum OpType {
     Xor,
 }
 
-impl<T: GeoFloat> BooleanOps for Polygon<T> {
+/// The reason a boolean operation could not be computed, by [`BooleanOps::try_boolean_op`] or
+/// [`GeometryBooleanOps`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BooleanOpsError<T: CoordNum> {
+    /// A coordinate of the first (`input` 0) or second (`input` 1) geometry is infinite or NaN.
+    NonFiniteCoord { input: usize, coord: Coord<T> },
+    /// A coordinate of the `input`th geometry is greater in magnitude than the
+    /// [`max_magnitude`](crate::sweep::SweepNum::max_magnitude) of the scalar.
+    CoordOutOfRange { input: usize, coord: Coord<T> },
+    /// The sweep over the edges of the geometries failed at `coord`, which can happen with
+    /// degenerate or nearly degenerate input.
+    SweepFailed { coord: Coord<T> },
+    /// The operation is not supported between geometries of these dimensions.
+    UnsupportedDimensions {
+        op: OpType,
+        dimensions: (Dimensions, Dimensions),
+    },
+    /// A `GeometryCollection` has members of different dimensions.
+    MixedCollection,
+}
+
+impl<T: CoordNum> fmt::Display for BooleanOpsError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BooleanOpsError::NonFiniteCoord { input, coord } => write!(
+                f,
+                "input {} has a non-finite coordinate ({:?}, {:?})",
+                input, coord.x, coord.y
+            ),
+            BooleanOpsError::CoordOutOfRange { input, coord } => write!(
+                f,
+                "input {} has a coordinate ({:?}, {:?}) too large to compute with",
+                input, coord.x, coord.y
+            ),
+            BooleanOpsError::SweepFailed { coord } => write!(
+                f,
+                "sweep failed at ({:?}, {:?}), the input may be degenerate",
+                coord.x, coord.y
+            ),
+            BooleanOpsError::UnsupportedDimensions { op, dimensions } => write!(
+                f,
+                "{:?} is not supported between {:?} and {:?} geometries",
+                op, dimensions.0, dimensions.1
+            ),
+            BooleanOpsError::MixedCollection => {
+                write!(f, "geometry collection has members of different dimensions")
+            }
+        }
+    }
+}
+
+impl<T: CoordNum> error::Error for BooleanOpsError<T> {}
+
+impl<T: GeoNum> From<SweepError<T>> for BooleanOpsError<T> {
+    fn from(err: SweepError<T>) -> Self {
+        BooleanOpsError::SweepFailed { coord: err.point }
+    }
+}
+
+/// Check that all the coordinates of `geometry`, the `input`th of an operation, are finite, as
+/// the sweep can't order the others, and of magnitudes at most `max`, if it is limited.
+fn check_coords<'a, T, G>(
+    geometry: &'a G,
+    input: usize,
+    max: Option<T>,
+) -> Result<(), BooleanOpsError<T>>
+where
+    T: GeoNum,
+    G: CoordsIter<'a, Scalar = T>,
+{
+    let is_finite = |x: T| x.to_f64().map_or(false, f64::is_finite);
+    let in_range = |x: T| max.map_or(true, |max| x <= max && T::zero() - max <= x);
+    for coord in geometry.coords_iter() {
+        if !is_finite(coord.x) || !is_finite(coord.y) {
+            return Err(BooleanOpsError::NonFiniteCoord { input, coord });
+        }
+        if !in_range(coord.x) || !in_range(coord.y) {
+            return Err(BooleanOpsError::CoordOutOfRange { input, coord });
+        }
+    }
+    Ok(())
+}
+
+/// The boolean operation `op` between the polygons of `first` and `second`, computing with the
+/// arithmetic `A`.
+pub(crate) fn try_boolean_op_with<A, T>(
+    first: &[Polygon<T>],
+    second: &[Polygon<T>],
+    op: OpType,
+) -> Result<MultiPolygon<T>, BooleanOpsError<T>>
+where
+    A: SweepArith<T>,
+    T: GeoNum,
+{
+    let capacity = first.iter().chain(second).map(|p| p.coords_count()).sum();
+    let mut bop = Proc::new(BoolOp::from(op), capacity);
+    for (idx, polygons) in [first, second].into_iter().enumerate() {
+        for polygon in polygons {
+            check_coords(polygon, idx, A::max_magnitude())?;
+            bop.add_polygon(polygon, idx);
+        }
+    }
+    Ok(bop.sweep::<A>()?)
+}
+
+/// Clip `ls` with the polygons of `area`, computing with the arithmetic `A`.
+pub(crate) fn try_clip_with<A, T>(
+    area: &[Polygon<T>],
+    ls: &MultiLineString<T>,
+    invert: bool,
+) -> Result<MultiLineString<T>, BooleanOpsError<T>>
+where
+    A: SweepArith<T>,
+    T: GeoNum,
+{
+    let capacity = area.iter().map(|p| p.coords_count()).sum::<usize>() + ls.coords_count();
+    let mut bop = Proc::new(ClipOp::new(invert), capacity);
+    for polygon in area {
+        check_coords(polygon, 0, A::max_magnitude())?;
+        bop.add_polygon(polygon, 0);
+    }
+    check_coords(ls, 1, A::max_magnitude())?;
+    ls.0.iter().enumerate().for_each(|(idx, l)| {
+        bop.add_line_string(l, idx + 1);
+    });
+    Ok(bop.sweep::<A>()?)
+}
+
+/// [`BooleanOps::boolean_op`] for any [`GeoFloat`], for the crate's algorithms which don't
+/// require [`SweepNum`].
+pub(crate) fn float_boolean_op<T: GeoFloat>(
+    first: &[Polygon<T>],
+    second: &[Polygon<T>],
+    op: OpType,
+) -> MultiPolygon<T> {
+    try_boolean_op_with::<FloatArith, _>(first, second, op).unwrap_or_else(|err| panic!("{err}"))
+}
+
+impl<T: SweepNum> BooleanOps for Polygon<T> {
     type Scalar = T;
 
     fn boolean_op(&self, other: &Self, op: OpType) -> MultiPolygon<Self::Scalar> {
-        let spec = BoolOp::from(op);
-        let mut bop = Proc::new(spec, self.coords_count() + other.coords_count());
-        bop.add_polygon(self, 0);
-        bop.add_polygon(other, 1);
-        bop.sweep()
+        self.try_boolean_op(other, op)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_boolean_op(
+        &self,
+        other: &Self,
+        op: OpType,
+    ) -> Result<MultiPolygon<Self::Scalar>, BooleanOpsError<Self::Scalar>> {
+        try_boolean_op_with::<NumArith, _>(
+            std::slice::from_ref(self),
+            std::slice::from_ref(other),
+            op,
+        )
     }
 
     fn clip(
@@ -74,24 +372,33 @@ impl<T: GeoFloat> BooleanOps for Polygon<T> {
         ls: &MultiLineString<Self::Scalar>,
         invert: bool,
     ) -> MultiLineString<Self::Scalar> {
-        let spec = ClipOp::new(invert);
-        let mut bop = Proc::new(spec, self.coords_count() + ls.coords_count());
-        bop.add_polygon(self, 0);
-        ls.0.iter().enumerate().for_each(|(idx, l)| {
-            bop.add_line_string(l, idx + 1);
-        });
-        bop.sweep()
+        self.try_clip(ls, invert)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_clip(
+        &self,
+        ls: &MultiLineString<Self::Scalar>,
+        invert: bool,
+    ) -> Result<MultiLineString<Self::Scalar>, BooleanOpsError<Self::Scalar>> {
+        try_clip_with::<NumArith, _>(std::slice::from_ref(self), ls, invert)
     }
 }
-impl<T: GeoFloat> BooleanOps for MultiPolygon<T> {
+
+impl<T: SweepNum> BooleanOps for MultiPolygon<T> {
     type Scalar = T;
 
     fn boolean_op(&self, other: &Self, op: OpType) -> MultiPolygon<Self::Scalar> {
-        let spec = BoolOp::from(op);
-        let mut bop = Proc::new(spec, self.coords_count() + other.coords_count());
-        bop.add_multi_polygon(self, 0);
-        bop.add_multi_polygon(other, 1);
-        bop.sweep()
+        self.try_boolean_op(other, op)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_boolean_op(
+        &self,
+        other: &Self,
+        op: OpType,
+    ) -> Result<MultiPolygon<Self::Scalar>, BooleanOpsError<Self::Scalar>> {
+        try_boolean_op_with::<NumArith, _>(&self.0, &other.0, op)
     }
 
     fn clip(
@@ -99,16 +406,26 @@ impl<T: GeoFloat> BooleanOps for MultiPolygon<T> {
         ls: &MultiLineString<Self::Scalar>,
         invert: bool,
     ) -> MultiLineString<Self::Scalar> {
-        let spec = ClipOp::new(invert);
-        let mut bop = Proc::new(spec, self.coords_count() + ls.coords_count());
-        bop.add_multi_polygon(self, 0);
-        ls.0.iter().enumerate().for_each(|(idx, l)| {
-            bop.add_line_string(l, idx + 1);
-        });
-        bop.sweep()
+        self.try_clip(ls, invert)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn try_clip(
+        &self,
+        ls: &MultiLineString<Self::Scalar>,
+        invert: bool,
+    ) -> Result<MultiLineString<Self::Scalar>, BooleanOpsError<Self::Scalar>> {
+        try_clip_with::<NumArith, _>(&self.0, ls, invert)
     }
 }
 
+mod geometry;
+pub use geometry::GeometryBooleanOps;
+
+mod unary_union;
+pub(crate) use unary_union::try_unary_union;
+pub use unary_union::unary_union;
+
 mod op;
 use op::*;
 mod assembly;
@@ -1,18 +1,18 @@
 use std::{cell::Cell, cmp::Ordering, fmt::Debug};
 
-use super::{MultiPolygon, Spec};
+use super::Spec;
 use crate::{
-    sweep::{compare_crossings, Cross, Crossing, CrossingsIter, LineOrPoint, SweepPoint},
-    CoordsIter, GeoFloat as Float, LineString, Polygon,
+    sweep::{Cross, Crossing, CrossingsIter, LineOrPoint, SweepArith, SweepError, SweepPoint},
+    CoordsIter, GeoNum, Line, LineString, Polygon,
 };
 
 #[derive(Debug, Clone)]
-pub struct Proc<T: Float, S: Spec<T>> {
+pub struct Proc<T: GeoNum, S: Spec<T>> {
     spec: S,
     edges: Vec<Edge<T, S>>,
 }
 
-impl<T: Float, S: Spec<T>> Proc<T, S> {
+impl<T: GeoNum, S: Spec<T>> Proc<T, S> {
     pub fn new(spec: S, capacity: usize) -> Self {
         Proc {
             spec,
@@ -20,11 +20,6 @@ impl<T: Float, S: Spec<T>> Proc<T, S> {
         }
     }
 
-    // idx: whether it is from first input or second input
-    pub(crate) fn add_multi_polygon(&mut self, mp: &MultiPolygon<T>, idx: usize) {
-        mp.0.iter().for_each(|p| self.add_polygon(p, idx));
-    }
-
     // idx: whether it is from first input or second input
     pub(crate) fn add_polygon(&mut self, poly: &Polygon<T>, idx: usize) {
         self.add_closed_ring(poly.exterior(), idx, false);
@@ -62,16 +57,37 @@ impl<T: Float, S: Spec<T>> Proc<T, S> {
         self.add_line_string(ring, idx);
     }
 
-    pub fn sweep(mut self) -> S::Output {
+    /// Sweep the edges, computing with the arithmetic `A`.
+    pub fn sweep<A: SweepArith<T>>(mut self) -> Result<S::Output, SweepError<T>> {
+        let lines: Vec<_> = self.edges.iter().map(|edge| edge.geom.line()).collect();
+        if let Some(paths) = A::snap_round(&lines) {
+            self.edges = self
+                .edges
+                .iter()
+                .zip(paths)
+                .flat_map(|(edge, path)| {
+                    path.windows(2)
+                        .map(|piece| LineOrPoint::from(Line::new(piece[0], piece[1])))
+                        .filter(LineOrPoint::is_line)
+                        .map(|geom| Edge {
+                            geom,
+                            idx: edge.idx,
+                            _region: edge._region.clone(),
+                            _region_2: edge._region_2.clone(),
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect();
+        }
         let mut iter = CrossingsIter::from_iter(self.edges.iter());
 
-        while let Some(pt) = iter.next() {
+        while let Some(pt) = iter.try_next::<A>()? {
             debug!(
                 "\n\nSweep point: {pt:?}, {n} intersection segments",
                 n = iter.intersections_mut().len(),
                 pt = SweepPoint::from(pt),
             );
-            iter.intersections_mut().sort_unstable_by(compare_crossings);
+            iter.sort_intersections()?;
 
             for (idx, it) in iter.intersections().iter().enumerate() {
                 let it: &Crossing<_> = it;
@@ -81,6 +97,8 @@ impl<T: Float, S: Spec<T>> Proc<T, S> {
             // Process all end-segments.
             let mut idx = 0;
             let mut next_region = None;
+            // The indices of the geometries of a group of overlapping end-segments.
+            let mut overlap_idxs = vec![];
             trace!("end segments:");
             while idx < iter.intersections().len() {
                 let c = &iter.intersections()[idx];
@@ -99,6 +117,7 @@ impl<T: Float, S: Spec<T>> Proc<T, S> {
                     );
                 }
                 next_region = Some(self.spec.cross(next_region.unwrap(), cross.idx));
+                overlap_idxs.push(cross.idx);
                 trace!("next_region: {reg:?}", reg = next_region.unwrap());
                 let has_overlap = (idx + 1) < iter.intersections().len()
                     && c.line.partial_cmp(&iter.intersections()[idx + 1].line)
@@ -111,8 +130,9 @@ impl<T: Float, S: Spec<T>> Proc<T, S> {
                         next_region = next_region.unwrap()
                     );
                     self.spec
-                        .output([prev_region, next_region.unwrap()], c.line, c.cross.idx);
+                        .output([prev_region, next_region.unwrap()], c.line, &overlap_idxs);
                     next_region = None;
+                    overlap_idxs.clear();
                 }
                 idx += 1;
             }
@@ -121,7 +141,6 @@ impl<T: Float, S: Spec<T>> Proc<T, S> {
                 continue;
             }
             let botmost_start_segment = iter.intersections_mut()[idx].clone();
-            debug_assert!(botmost_start_segment.at_left);
 
             trace!(
                 "Bottom most start-edge: {botmost:?} of {cr:?}",
@@ -129,7 +148,7 @@ impl<T: Float, S: Spec<T>> Proc<T, S> {
                 cr = botmost_start_segment.cross,
             );
 
-            let prev = iter.prev_active(&botmost_start_segment);
+            let prev = iter.prev_active(&botmost_start_segment)?;
             trace!(
                 "prev-active(bot-most): {prev:?}",
                 prev = prev.map(|(_, p)| p.geom)
@@ -169,19 +188,19 @@ impl<T: Float, S: Spec<T>> Proc<T, S> {
                 idx += 1;
             }
         }
-        self.spec.finish()
+        self.spec.finish::<A>()
     }
 }
 
 #[derive(Clone)]
-struct Edge<T: Float, S: Spec<T>> {
+struct Edge<T: GeoNum, S: Spec<T>> {
     geom: LineOrPoint<T>,
     idx: usize,
     _region: Cell<S::Region>,
     _region_2: Cell<S::Region>,
 }
 
-impl<T: Float, S: Spec<T>> Edge<T, S> {
+impl<T: GeoNum, S: Spec<T>> Edge<T, S> {
     fn get_region(&self, piece: LineOrPoint<T>) -> S::Region {
         // Note: This is related to the ordering of intersection
         // with respect to the complete geometry. Due to
@@ -214,7 +233,7 @@ impl<T: Float, S: Spec<T>> Edge<T, S> {
     }
 }
 
-impl<T: Float, S: Spec<T>> std::fmt::Debug for Edge<T, S> {
+impl<T: GeoNum, S: Spec<T>> std::fmt::Debug for Edge<T, S> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let line = self.geom.line();
         f.debug_struct("Edge")
@@ -231,7 +250,7 @@ impl<T: Float, S: Spec<T>> std::fmt::Debug for Edge<T, S> {
     }
 }
 
-impl<T: Float, S: Spec<T>> Cross for Edge<T, S> {
+impl<T: GeoNum, S: Spec<T>> Cross for Edge<T, S> {
     type Scalar = T;
 
     fn line(&self) -> LineOrPoint<Self::Scalar> {
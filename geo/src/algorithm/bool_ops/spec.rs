@@ -2,23 +2,28 @@ use geo_types::{MultiLineString, MultiPolygon};
 use std::fmt::Debug;
 
 use super::*;
-use crate::{sweep::LineOrPoint, GeoFloat, OpType};
+use crate::{
+    sweep::{LineOrPoint, SweepArith, SweepError},
+    GeoNum, OpType,
+};
 
-pub trait Spec<T: GeoFloat> {
+pub trait Spec<T: GeoNum> {
     type Region: Copy + Debug;
     type Output;
 
     fn infinity(&self) -> Self::Region;
     fn cross(&self, prev_region: Self::Region, idx: usize) -> Self::Region;
-    fn output(&mut self, regions: [Self::Region; 2], geom: LineOrPoint<T>, idx: usize);
-    fn finish(self) -> Self::Output;
+    /// Output `geom`, an edge between the `regions` below and above it, of the geometries with
+    /// indices `idxs`, which has more than one index if they overlap.
+    fn output(&mut self, regions: [Self::Region; 2], geom: LineOrPoint<T>, idxs: &[usize]);
+    fn finish<A: SweepArith<T>>(self) -> Result<Self::Output, SweepError<T>>;
 }
 
-pub struct BoolOp<T: GeoFloat> {
+pub struct BoolOp<T: GeoNum> {
     ty: OpType,
     assembly: RegionAssembly<T>,
 }
-impl<T: GeoFloat> From<OpType> for BoolOp<T> {
+impl<T: GeoNum> From<OpType> for BoolOp<T> {
     fn from(ty: OpType) -> Self {
         Self {
             ty,
@@ -26,7 +31,7 @@ impl<T: GeoFloat> From<OpType> for BoolOp<T> {
         }
     }
 }
-impl<T: GeoFloat> Spec<T> for BoolOp<T> {
+impl<T: GeoNum> Spec<T> for BoolOp<T> {
     type Region = Region;
     type Output = MultiPolygon<T>;
 
@@ -42,23 +47,23 @@ impl<T: GeoFloat> Spec<T> for BoolOp<T> {
         prev_region
     }
 
-    fn output(&mut self, regions: [Self::Region; 2], geom: LineOrPoint<T>, _idx: usize) {
+    fn output(&mut self, regions: [Self::Region; 2], geom: LineOrPoint<T>, _idxs: &[usize]) {
         if regions[0].is_ty(self.ty) ^ regions[1].is_ty(self.ty) {
             self.assembly.add_edge(geom)
         }
     }
 
-    fn finish(self) -> Self::Output {
-        self.assembly.finish()
+    fn finish<A: SweepArith<T>>(self) -> Result<Self::Output, SweepError<T>> {
+        self.assembly.finish::<A>()
     }
 }
 
-pub struct ClipOp<T: GeoFloat> {
+pub struct ClipOp<T: GeoNum> {
     invert: bool,
     assembly: LineAssembly<T>,
 }
 
-impl<T: GeoFloat> ClipOp<T> {
+impl<T: GeoNum> ClipOp<T> {
     pub fn new(invert: bool) -> Self {
         Self {
             invert,
@@ -67,7 +72,7 @@ impl<T: GeoFloat> ClipOp<T> {
     }
 }
 
-impl<T: GeoFloat> Spec<T> for ClipOp<T> {
+impl<T: GeoNum> Spec<T> for ClipOp<T> {
     type Region = Region;
     type Output = MultiLineString<T>;
 
@@ -85,14 +90,20 @@ impl<T: GeoFloat> Spec<T> for ClipOp<T> {
         prev_region
     }
 
-    fn output(&mut self, regions: [Self::Region; 2], geom: LineOrPoint<T>, idx: usize) {
-        if idx > 0 && (regions[0].is_first && regions[1].is_first) != self.invert {
-            self.assembly.add_edge(geom, idx);
+    fn output(&mut self, regions: [Self::Region; 2], geom: LineOrPoint<T>, idxs: &[usize]) {
+        // an edge on the boundary of the polygon has its interior on one side, and is inside
+        if (regions[0].is_first || regions[1].is_first) == self.invert {
+            return;
+        }
+        for &idx in idxs {
+            if idx > 0 {
+                self.assembly.add_edge(geom, idx);
+            }
         }
     }
 
-    fn finish(self) -> Self::Output {
-        MultiLineString::new(self.assembly.finish())
+    fn finish<A: SweepArith<T>>(self) -> Result<Self::Output, SweepError<T>> {
+        Ok(MultiLineString::new(self.assembly.finish()))
     }
 }
 
@@ -1,5 +1,7 @@
-use crate::{LineString, MultiPolygon, Polygon};
+use crate::{GeoFloat, LineString, MultiPolygon, Polygon};
 use log::{error, info};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 use std::{
     error::Error,
@@ -42,11 +44,7 @@ fn check_sweep<T: GeoFloat + FromStr + Default + Display>(
     let poly2 = MultiPolygon::try_from_wkt_str(wkt2)
         .or_else(|_| Polygon::<T>::try_from_wkt_str(wkt2).map(MultiPolygon::from))
         .unwrap();
-    let mut bop = Proc::new(BoolOp::from(ty), 0);
-    bop.add_multi_polygon(&poly1, 0);
-    bop.add_multi_polygon(&poly2, 1);
-
-    let geom = bop.sweep();
+    let geom = float_boolean_op(&poly1.0, &poly2.0, ty);
 
     info!("Got {n} rings", n = geom.0.len());
     info!("{wkt}", wkt = geom.to_wkt());
@@ -230,3 +228,386 @@ fn test_issue_buffer_box() {
     let wkt2 = "MULTIPOLYGON(((-164.93595896333647 149.53568721641966,-51.873865625542294 153.2197777241044,-153.80312445248086 153.2197777241044,-266.86521779027504 149.53568721641966,-164.93595896333647 149.53568721641966)))";
     check_sweep::<f64>(wkt1, wkt2, OpType::Union).unwrap();
 }
+
+#[test]
+fn test_mixed_winding() -> Result<()> {
+    // The first polygon is wound clockwise with a counter-clockwise hole, and
+    // the second the other way around.
+    let mixed = MultiPolygon::<f64>::try_from_wkt_str("MULTIPOLYGON(((0 0,0 10,10 10,10 0,0 0),(2 2,8 2,8 8,2 8,2 2)),((20 0,30 0,30 10,20 10,20 0),(22 2,22 8,28 8,28 2,22 2)))")?;
+    let wound = MultiPolygon::<f64>::try_from_wkt_str("MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(2 2,2 8,8 8,8 2,2 2)),((20 0,30 0,30 10,20 10,20 0),(22 2,22 8,28 8,28 2,22 2)))")?;
+    let bar = MultiPolygon::<f64>::try_from_wkt_str("MULTIPOLYGON(((5 5,25 5,25 6,5 6,5 5)))")?;
+
+    let union = mixed.union(&bar);
+    let expected = MultiPolygon::<f64>::try_from_wkt_str("MULTIPOLYGON(((0 0,10 0,10 5,20 5,20 0,30 0,30 10,20 10,20 6,10 6,10 10,0 10,0 0),(2 2,2 8,8 8,8 6,5 6,5 5,8 5,8 2,2 2),(22 2,22 5,25 5,25 6,22 6,22 8,28 8,28 2,22 2)))")?;
+    assert!(union.xor(&expected).0.is_empty(), "{}", union.to_wkt());
+
+    for ty in [
+        OpType::Intersection,
+        OpType::Union,
+        OpType::Difference,
+        OpType::Xor,
+    ] {
+        let output = mixed.boolean_op(&bar, ty);
+        let expected = wound.boolean_op(&bar, ty);
+        assert!(
+            output.xor(&expected).0.is_empty(),
+            "{ty:?}: {} != {}",
+            output.to_wkt(),
+            expected.to_wkt()
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_geometry_dispatch() -> Result<()> {
+    use crate::dimensions::Dimensions;
+    use crate::{Geometry, GeometryCollection, Rect};
+
+    let square = Geometry::<f64>::try_from_wkt_str("POLYGON((0 0,10 0,10 10,0 10,0 0))")?;
+    let rect = Geometry::Rect(Rect::new((5., 5.), (15., 15.)));
+    let line = Geometry::<f64>::try_from_wkt_str("LINESTRING(-5 5,15 5)")?;
+    let points = Geometry::<f64>::try_from_wkt_str("MULTIPOINT(5 5,10 10,20 20)")?;
+
+    let union = match square.union(&rect)? {
+        Geometry::MultiPolygon(union) => union,
+        other => panic!("expected a multi-polygon, got {other:?}"),
+    };
+    let expected = MultiPolygon::<f64>::try_from_wkt_str(
+        "MULTIPOLYGON(((0 0,10 0,10 5,15 5,15 15,5 15,5 10,0 10,0 0)))",
+    )?;
+    assert!(union.xor(&expected).0.is_empty(), "{}", union.to_wkt());
+
+    assert_eq!(
+        line.intersection(&square)?,
+        Geometry::try_from_wkt_str("MULTILINESTRING((0 5,10 5))")?
+    );
+    assert_eq!(
+        square.intersection(&line)?,
+        Geometry::try_from_wkt_str("MULTILINESTRING((0 5,10 5))")?
+    );
+    assert_eq!(
+        line.difference(&square)?,
+        Geometry::try_from_wkt_str("MULTILINESTRING((-5 5,0 5),(10 5,15 5))")?
+    );
+
+    assert_eq!(
+        points.intersection(&square)?,
+        Geometry::try_from_wkt_str("MULTIPOINT(5 5,10 10)")?
+    );
+    assert_eq!(
+        line.intersection(&points)?,
+        Geometry::try_from_wkt_str("MULTIPOINT(5 5)")?
+    );
+    assert_eq!(
+        points.difference(&square)?,
+        Geometry::try_from_wkt_str("MULTIPOINT(20 20)")?
+    );
+    assert_eq!(
+        square.difference(&line)?,
+        Geometry::try_from_wkt_str("MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0)))")?
+    );
+
+    assert_eq!(
+        line.union(&square),
+        Err(BooleanOpsError::UnsupportedDimensions {
+            op: OpType::Union,
+            dimensions: (Dimensions::OneDimensional, Dimensions::TwoDimensional),
+        })
+    );
+    assert!(line.intersection(&line).is_err());
+    assert!(square.difference(&points).is_ok());
+
+    let nested = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+        Geometry::GeometryCollection(GeometryCollection::new_from(vec![square.clone()])),
+        rect.clone(),
+    ]));
+    assert_eq!(
+        points.intersection(&nested)?,
+        Geometry::try_from_wkt_str("MULTIPOINT(5 5,10 10)")?
+    );
+    let empty = Geometry::GeometryCollection(GeometryCollection::new_from(vec![]));
+    assert_eq!(
+        line.intersection(&empty)?,
+        Geometry::MultiLineString(MultiLineString::new(vec![]))
+    );
+    let mixed = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+        square.clone(),
+        line.clone(),
+    ]));
+    assert_eq!(
+        mixed.intersection(&square),
+        Err(BooleanOpsError::MixedCollection)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_clip_boundary() -> Result<()> {
+    let square = Polygon::<f64>::try_from_wkt_str("POLYGON((0 0,10 0,10 10,0 10,0 0))")?;
+    for (wkt, inside, outside) in [
+        (
+            "LINESTRING(-5 0,15 0)",
+            "MULTILINESTRING((0 0,10 0))",
+            "MULTILINESTRING((-5 0,0 0),(10 0,15 0))",
+        ),
+        (
+            "LINESTRING(0 -5,0 15)",
+            "MULTILINESTRING((0 0,0 10))",
+            "MULTILINESTRING((0 -5,0 0),(0 10,0 15))",
+        ),
+        (
+            "LINESTRING(-5 10,5 10,5 5,15 5)",
+            "MULTILINESTRING((0 10,5 10,5 5,10 5))",
+            "MULTILINESTRING((-5 10,0 10),(10 5,15 5))",
+        ),
+    ] {
+        let ls = MultiLineString::from(LineString::<f64>::try_from_wkt_str(wkt)?);
+        assert_eq!(
+            square.clip(&ls, false),
+            MultiLineString::try_from_wkt_str(inside)?,
+            "{wkt}"
+        );
+        assert_eq!(
+            square.clip(&ls, true),
+            MultiLineString::try_from_wkt_str(outside)?,
+            "{wkt}"
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_clip_covers_length() -> Result<()> {
+    use crate::EuclideanLength;
+
+    let donut = MultiPolygon::<f64>::try_from_wkt_str(
+        "MULTIPOLYGON(((0 0,10 0,10 10,0 10,0 0),(2 2,8 2,8 8,2 8,2 2)),((12 0,20 0,16 6,12 0)))",
+    )?;
+    let mut rng = StdRng::seed_from_u64(3);
+    let mut next = move || rng.gen_range(0..48) as f64 / 2. - 2.;
+    // random lines, some along the edges of the polygons on the half-unit grid
+    let lines: Vec<LineString<f64>> = (0..50)
+        .map(|_| LineString::from(vec![(next(), next()), (next(), next()), (next(), next())]))
+        .chain([
+            LineString::from(vec![(-1., 0.), (22., 0.)]),
+            LineString::from(vec![(2., -1.), (2., 12.)]),
+            LineString::from(vec![(8., 8.), (2., 8.), (2., 5.)]),
+            LineString::from(vec![(14., 3.), (16., 6.), (18., 3.)]),
+        ])
+        .collect();
+    for line in lines {
+        let ls = MultiLineString::new(vec![line]);
+        let inside = donut.clip(&ls, false);
+        let outside = donut.clip(&ls, true);
+        let total = inside.euclidean_length() + outside.euclidean_length();
+        assert!(
+            (total - ls.euclidean_length()).abs() < 1e-9,
+            "{}: {} + {}",
+            ls.to_wkt(),
+            inside.to_wkt(),
+            outside.to_wkt()
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_try_non_finite() -> Result<()> {
+    let square = Polygon::<f64>::try_from_wkt_str("POLYGON((0 0,10 0,10 10,0 10,0 0))")?;
+    for bad in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let broken = Polygon::new(
+            LineString::from(vec![(5., 5.), (bad, 5.), (15., 15.), (5., 15.)]),
+            vec![],
+        );
+        // these panicked, for NaN, or never returned, for infinity
+        for ty in [
+            OpType::Intersection,
+            OpType::Union,
+            OpType::Difference,
+            OpType::Xor,
+        ] {
+            match square.try_boolean_op(&broken, ty) {
+                Err(BooleanOpsError::NonFiniteCoord { input: 1, coord }) => {
+                    assert_eq!(coord.y, 5.);
+                    assert!(!coord.x.is_finite());
+                }
+                other => panic!("{ty:?} of {bad}: {other:?}"),
+            }
+        }
+        assert!(matches!(
+            MultiPolygon::from(broken).try_union(&MultiPolygon::from(square.clone())),
+            Err(BooleanOpsError::NonFiniteCoord { input: 0, .. })
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_try_sweep_failure() -> Result<()> {
+    // the same input as `test_issue_buffer_box`, which panics in the sweep
+    let wkt1 = "MULTIPOLYGON(((-164.93595896333647 152.85701803397086,-164.93595896333647 149.53568721641966,-51.873865625542294 153.2197777241044,-51.873865625542294 255.14903655104297,-153.80312445248086 255.14903655104297,-266.86521779027504 251.46494604335822,-266.86521779027504 149.53568721641966,-164.93595896333647 152.85701803397086)))";
+    let wkt2 = "MULTIPOLYGON(((-164.93595896333647 149.53568721641966,-51.873865625542294 153.2197777241044,-153.80312445248086 153.2197777241044,-266.86521779027504 149.53568721641966,-164.93595896333647 149.53568721641966)))";
+    let poly1 = MultiPolygon::<f64>::try_from_wkt_str(wkt1)?;
+    let poly2 = MultiPolygon::<f64>::try_from_wkt_str(wkt2)?;
+    assert!(matches!(
+        poly1.try_union(&poly2),
+        Err(BooleanOpsError::SweepFailed { .. })
+    ));
+    Ok(())
+}
+
+/// The rings of `mp`, each rotated to start at its least coordinate and counter-clockwise, and
+/// then sorted, which are the same for any two representations of the same region.
+fn normalized_rings(mp: &MultiPolygon<i64>) -> Vec<Vec<Coord<i64>>> {
+    use crate::Winding;
+    let mut rings: Vec<_> = mp
+        .iter()
+        .flat_map(|polygon| std::iter::once(polygon.exterior()).chain(polygon.interiors()))
+        .map(|ring| {
+            let mut coords = ring.0[1..].to_vec();
+            if ring.is_cw() {
+                coords.reverse();
+            }
+            let (start, _) = coords
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, c)| (c.x, c.y))
+                .unwrap();
+            coords.rotate_left(start);
+            coords
+        })
+        .collect();
+    rings.sort_by_key(|ring| ring.iter().map(|c| (c.x, c.y)).collect::<Vec<_>>());
+    rings
+}
+
+#[test]
+fn test_integer_vertex_order() -> Result<()> {
+    // the edges of these cross away from the integer grid
+    let star = Polygon::<i64>::try_from_wkt_str(
+        "POLYGON((0 0,17 3,30 -7,25 11,41 22,19 19,12 37,9 18,-11 13,8 7,0 0))",
+    )?;
+    let other = Polygon::<i64>::try_from_wkt_str(
+        "POLYGON((-3 5,36 1,33 29,4 31,-3 5),(5 10,13 26,27 9,5 10))",
+    )?;
+
+    // all rotations of the exterior, either way round
+    let orders = |polygon: &Polygon<i64>| {
+        let ring = &polygon.exterior().0[1..];
+        (0..ring.len())
+            .flat_map(|start| {
+                let mut coords = ring.to_vec();
+                coords.rotate_left(start);
+                let mut reversed = coords.clone();
+                reversed.reverse();
+                [coords, reversed]
+            })
+            .map(|coords| Polygon::new(LineString::new(coords), polygon.interiors().to_vec()))
+            .collect::<Vec<_>>()
+    };
+
+    for op in [
+        OpType::Intersection,
+        OpType::Union,
+        OpType::Difference,
+        OpType::Xor,
+    ] {
+        let expected = normalized_rings(&star.boolean_op(&other, op));
+        assert!(!expected.is_empty());
+        for star in orders(&star) {
+            for other in orders(&other) {
+                assert_eq!(normalized_rings(&star.boolean_op(&other, op)), expected);
+                let swapped = match op {
+                    OpType::Difference => continue,
+                    op => other.boolean_op(&star, op),
+                };
+                assert_eq!(normalized_rings(&swapped), expected);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_integer_magnitude() {
+    use crate::{coord, Rect};
+    // two overlapping squares, reaching `max`, or past it by `extra`
+    let squares = |max: i64, extra: i64| {
+        let a = Rect::new(
+            coord! { x: -max, y: -max },
+            coord! { x: max / 2, y: max / 2 },
+        );
+        let b = Rect::new(
+            coord! { x: -max / 2, y: -max / 2 },
+            coord! { x: max + extra, y: max },
+        );
+        (a.to_polygon(), b.to_polygon())
+    };
+
+    let max = i64::max_magnitude().unwrap();
+    let (a, b) = squares(max, 0);
+    let intersection = Rect::new(
+        coord! { x: -max / 2, y: -max / 2 },
+        coord! { x: max / 2, y: max / 2 },
+    );
+    assert_eq!(
+        a.try_intersection(&b)
+            .map(|result| normalized_rings(&result)),
+        Ok(normalized_rings(&intersection.to_polygon().into()))
+    );
+    assert_eq!(a.try_union(&b).map(|union| union.0.len()), Ok(1));
+
+    // these failed the sweep, or never returned
+    for extra in [1, 1 << 30, 1 << 40, i64::MAX - max] {
+        let (a, b) = squares(max, extra);
+        assert!(matches!(
+            a.try_union(&b),
+            Err(BooleanOpsError::CoordOutOfRange { input: 1, coord }) if coord.x == max + extra
+        ));
+        assert!(matches!(
+            b.try_difference(&a),
+            Err(BooleanOpsError::CoordOutOfRange { input: 0, .. })
+        ));
+    }
+    let (a, _) = squares(i64::MAX, 0);
+    assert!(matches!(
+        a.try_intersection(&a),
+        Err(BooleanOpsError::CoordOutOfRange { input: 0, .. })
+    ));
+}
+
+#[test]
+fn test_integer_degenerate() {
+    // small random polygons on a coarse grid, with many coincident vertices and edges
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut next = move |n: i64| rng.gen_range(0..n);
+    let mut polygons = |count: usize| {
+        MultiPolygon::new(
+            (0..count)
+                .map(|_| {
+                    let coords = (0..3 + next(6))
+                        .map(|_| (next(6), next(6)).into())
+                        .collect();
+                    Polygon::new(LineString::new(coords), vec![])
+                })
+                .collect(),
+        )
+    };
+    for _ in 0..200 {
+        let (a, b) = (polygons(3), polygons(3));
+        for op in [
+            OpType::Intersection,
+            OpType::Union,
+            OpType::Difference,
+            OpType::Xor,
+        ] {
+            assert!(
+                a.try_boolean_op(&b, op).is_ok(),
+                "{op:?} failed:\n{}\n{}",
+                a.to_wkt(),
+                b.to_wkt()
+            );
+        }
+    }
+}
@@ -0,0 +1,153 @@
+use std::cmp::Ordering;
+
+use geo_types::private_utils::get_bounding_rect;
+
+use super::{try_boolean_op_with, BooleanOpsError, OpType};
+use crate::sweep::FloatArith;
+use crate::{BoundingRect, Coord, GeoFloat, MultiPolygon, Polygon};
+
+/// Union many polygons at once.
+///
+/// This is a cascaded union: the polygons are recursively split into two halves of nearby
+/// polygons, by the centres of their bounding rects, which are unioned, and their unions then
+/// unioned. As each union only has the vertices of nearby polygons, which mostly disappear into
+/// its interior, this is much faster for large numbers of overlapping polygons than folding
+/// [`BooleanOps::union`](crate::BooleanOps::union) over them, which unions each polygon with
+/// all the others so far.
+///
+/// The result is the same region as that fold, and is empty if there are no polygons.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{unary_union, Area, MultiPolygon, Rect};
+///
+/// // a row of overlapping squares
+/// let squares = MultiPolygon::new(
+///     (0..100)
+///         .map(|i| Rect::new((i as f64, 0.), (i as f64 + 2., 2.)).to_polygon())
+///         .collect(),
+/// );
+///
+/// let union = unary_union(&squares);
+/// assert_eq!(union.0.len(), 1);
+/// assert_eq!(union.unsigned_area(), 202.);
+/// ```
+pub fn unary_union<'a, T>(polygons: impl IntoIterator<Item = &'a Polygon<T>>) -> MultiPolygon<T>
+where
+    T: GeoFloat + 'a,
+{
+    try_unary_union(polygons).unwrap_or_else(|err| panic!("{err}"))
+}
+
+/// [`unary_union`], but returning an error instead of panicking, as with
+/// [`BooleanOps::try_boolean_op`](crate::BooleanOps::try_boolean_op).
+pub(crate) fn try_unary_union<'a, T>(
+    polygons: impl IntoIterator<Item = &'a Polygon<T>>,
+) -> Result<MultiPolygon<T>, BooleanOpsError<T>>
+where
+    T: GeoFloat + 'a,
+{
+    let mut polygons: Vec<(Coord<T>, &Polygon<T>)> = polygons
+        .into_iter()
+        .filter_map(|polygon| Some((polygon.bounding_rect()?.center(), polygon)))
+        .collect();
+    cascade(&mut polygons)
+}
+
+fn cascade<T: GeoFloat>(
+    polygons: &mut [(Coord<T>, &Polygon<T>)],
+) -> Result<MultiPolygon<T>, BooleanOpsError<T>> {
+    match polygons {
+        [] => Ok(MultiPolygon::new(vec![])),
+        [(_, polygon)] => union(std::slice::from_ref(*polygon), &[]),
+        _ => {
+            // split across the wider extent of the centres
+            let centers = get_bounding_rect(polygons.iter().map(|(center, _)| *center)).unwrap();
+            let middle = polygons.len() / 2;
+            if centers.width() >= centers.height() {
+                polygons.select_nth_unstable_by(middle, |(a, _), (b, _)| compare(a.x, b.x));
+            } else {
+                polygons.select_nth_unstable_by(middle, |(a, _), (b, _)| compare(a.y, b.y));
+            }
+            let (first, second) = polygons.split_at_mut(middle);
+            union(&cascade(first)?.0, &cascade(second)?.0)
+        }
+    }
+}
+
+fn union<T: GeoFloat>(
+    first: &[Polygon<T>],
+    second: &[Polygon<T>],
+) -> Result<MultiPolygon<T>, BooleanOpsError<T>> {
+    try_boolean_op_with::<FloatArith, _>(first, second, OpType::Union)
+}
+
+fn compare<T: GeoFloat>(a: T, b: T) -> Ordering {
+    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Area, BooleanOps, Rect};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn empty() {
+        let polygons: Vec<Polygon> = vec![];
+        assert_eq!(unary_union(&polygons), MultiPolygon::new(vec![]));
+        assert_eq!(
+            unary_union(&MultiPolygon::<f64>::new(vec![])),
+            MultiPolygon::new(vec![])
+        );
+    }
+
+    #[test]
+    fn matches_fold() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut next = move || rng.gen_range(0..1000) as f64 / 10.;
+        let polygons: Vec<Polygon> = (0..200)
+            .map(|_| {
+                let (x, y) = (next(), next());
+                let size = next() / 10. + 1.;
+                Rect::new((x, y), (x + size, y + size)).to_polygon()
+            })
+            .collect();
+
+        let cascaded = unary_union(&polygons);
+        let folded = polygons
+            .iter()
+            .fold(MultiPolygon::new(vec![]), |union, polygon| {
+                union.union(&MultiPolygon::new(vec![polygon.clone()]))
+            });
+        assert!(cascaded.xor(&folded).unsigned_area() < 1e-6);
+        assert_eq!(cascaded.0.len(), folded.0.len());
+    }
+
+    #[test]
+    fn single_polygon() {
+        let square = Rect::new((0., 0.), (1., 1.)).to_polygon();
+        let union = unary_union([&square]);
+        assert_eq!(union.0.len(), 1);
+        assert_eq!(union.unsigned_area(), 1.);
+    }
+
+    #[test]
+    fn grid() {
+        // a 10 by 10 grid of overlapping squares, dissolved into one polygon without holes
+        let squares = MultiPolygon::new(
+            (0..100)
+                .map(|i| {
+                    let (x, y) = ((i % 10) as f64 * 1.5, (i / 10) as f64 * 1.5);
+                    Rect::new((x, y), (x + 2., y + 2.)).to_polygon()
+                })
+                .collect(),
+        );
+        let union = unary_union(&squares);
+        assert_eq!(union.0.len(), 1);
+        assert!(union.0[0].interiors().is_empty());
+        assert_eq!(union.unsigned_area(), 15.5 * 15.5);
+    }
+}
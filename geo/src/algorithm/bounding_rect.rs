@@ -187,15 +187,63 @@ where
     type Output = Option<Rect<T>>;
 
     fn bounding_rect(&self) -> Self::Output {
-        self.iter().fold(None, |acc, next| {
-            let next_bounding_rect = next.bounding_rect();
-
-            match (acc, next_bounding_rect) {
-                (None, None) => None,
-                (Some(r), None) | (None, Some(r)) => Some(r),
-                (Some(r1), Some(r2)) => Some(bounding_rect_merge(r1, r2)),
-            }
-        })
+        let mut accumulator = BoundingRectAccumulator::new();
+        for geometry in self {
+            accumulator.add(geometry);
+        }
+        accumulator.finish()
+    }
+}
+
+/// The bounding rectangle of many geometries, added one at a time, so that the extent of a
+/// stream of geometries can be found without collecting them.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, line_string, point, BoundingRectAccumulator, Rect};
+///
+/// let mut accumulator = BoundingRectAccumulator::new();
+/// assert_eq!(accumulator.finish(), None);
+///
+/// accumulator.add(&point!(x: 1., y: 5.));
+/// accumulator.add(&line_string![(x: -2., y: 0.), (x: 3., y: 1.)]);
+/// assert_eq!(
+///     accumulator.finish(),
+///     Some(Rect::new(coord! { x: -2., y: 0. }, coord! { x: 3., y: 5. }))
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingRectAccumulator<T: CoordNum> {
+    rect: Option<Rect<T>>,
+}
+
+impl<T: CoordNum> BoundingRectAccumulator<T> {
+    /// An accumulator to which no geometries have been added.
+    pub fn new() -> Self {
+        Self { rect: None }
+    }
+
+    /// Grow the bounding rectangle to cover `geometry`, which doesn't change it if `geometry` is
+    /// empty.
+    pub fn add<G: BoundingRect<T>>(&mut self, geometry: &G) {
+        self.rect = match (self.rect, geometry.bounding_rect().into()) {
+            (None, None) => None,
+            (Some(r), None) | (None, Some(r)) => Some(r),
+            (Some(r1), Some(r2)) => Some(bounding_rect_merge(r1, r2)),
+        };
+    }
+
+    /// The bounding rectangle of the geometries added, or `None` if there were none, or they
+    /// were all empty.
+    pub fn finish(self) -> Option<Rect<T>> {
+        self.rect
+    }
+}
+
+impl<T: CoordNum> Default for BoundingRectAccumulator<T> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -217,11 +265,11 @@ fn bounding_rect_merge<T: CoordNum>(a: Rect<T>, b: Rect<T>) -> Rect<T> {
 mod test {
     use super::bounding_rect_merge;
     use crate::line_string;
-    use crate::BoundingRect;
     use crate::{
         coord, point, polygon, Geometry, GeometryCollection, Line, LineString, MultiLineString,
         MultiPoint, MultiPolygon, Polygon, Rect,
     };
+    use crate::{BoundingRect, BoundingRectAccumulator};
 
     #[test]
     fn empty_linestring_test() {
@@ -339,4 +387,31 @@ mod test {
             .bounding_rect(),
         );
     }
+
+    #[test]
+    fn accumulator_test() {
+        let accumulator = BoundingRectAccumulator::<f64>::default();
+        assert_eq!(accumulator.finish(), None);
+
+        let mut accumulator = BoundingRectAccumulator::new();
+        accumulator.add(&LineString::<f64>::new(vec![]));
+        assert_eq!(accumulator.finish(), None);
+
+        let geometries = (0..1000).map(|i| {
+            let i = i as f64;
+            Line::new(coord! { x: i, y: -i }, coord! { x: i + 1., y: i / 2. })
+        });
+        let mut accumulator = geometries.fold(BoundingRectAccumulator::new(), |mut acc, line| {
+            acc.add(&line);
+            acc
+        });
+        accumulator.add(&MultiPoint::<f64>::new(vec![]));
+        assert_eq!(
+            accumulator.finish(),
+            Some(Rect::new(
+                coord! { x: 0., y: -999. },
+                coord! { x: 1000., y: 499.5 }
+            ))
+        );
+    }
 }
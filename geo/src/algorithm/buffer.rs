@@ -0,0 +1,940 @@
+use crate::bool_ops::float_boolean_op;
+use crate::convex_hull::quick_hull;
+use crate::{
+    Coord, GeoFloat, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, OpType, Point,
+    Polygon,
+};
+
+/// The number of segments used to approximate a quarter circle by [`Buffer::buffer`].
+pub const DEFAULT_QUADRANT_SEGMENTS: usize = 8;
+
+/// Grow or shrink a geometry by a distance.
+///
+/// The buffer of a geometry is the set of all points lying within `distance` of it. It is
+/// computed as the union of the geometry with a disk of radius `distance` swept along each of its
+/// segments, so the result has round joins and round caps. The disk is approximated by a regular
+/// polygon whose vertices lie on the circle, using `quadrant_segments` segments per quarter
+/// circle. Other joins and caps can be chosen with [`Buffer::buffer_with_style`].
+///
+/// For areal geometries (`Polygon` and `MultiPolygon`), a negative `distance` shrinks the
+/// geometry instead, removing all points within `-distance` of its boundary. A geometry which is
+/// shrunk away entirely produces an empty `MultiPolygon`. For geometries without an area, a
+/// non-positive `distance` produces an empty `MultiPolygon`.
+///
+/// The pieces of the buffer are dissolved with [`BooleanOps::union`], so the output has no
+/// overlapping or self-intersecting parts.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{line_string, Area, Buffer};
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+///
+/// // A 10 x 2 rectangle with two half-disks of radius 1 at its ends
+/// let buffered = line_string.buffer(1.);
+/// assert_relative_eq!(
+///     buffered.unsigned_area(),
+///     20. + std::f64::consts::PI,
+///     epsilon = 0.05
+/// );
+/// ```
+pub trait Buffer<T: GeoFloat> {
+    /// Buffer the geometry, approximating each quarter circle with
+    /// [`DEFAULT_QUADRANT_SEGMENTS`] segments.
+    fn buffer(&self, distance: T) -> MultiPolygon<T> {
+        self.buffer_with_style(distance, BufferStyle::default())
+    }
+
+    /// Buffer the geometry, approximating each quarter circle with `quadrant_segments` segments.
+    ///
+    /// A `quadrant_segments` of `0` is treated as `1`.
+    fn buffer_with_quadrant_segments(
+        &self,
+        distance: T,
+        quadrant_segments: usize,
+    ) -> MultiPolygon<T> {
+        let style = BufferStyle {
+            quadrant_segments,
+            ..BufferStyle::default()
+        };
+        self.buffer_with_style(distance, style)
+    }
+
+    /// Buffer the geometry, with the joins and caps given by `style`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::{line_string, Area, Buffer, BufferStyle, CapStyle, JoinStyle};
+    ///
+    /// let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+    /// let style = BufferStyle {
+    ///     join: JoinStyle::Miter { limit: 2. },
+    ///     cap: CapStyle::Flat,
+    ///     ..BufferStyle::default()
+    /// };
+    ///
+    /// // Two 10 x 2 rectangles, overlapping in a 1 x 1 square on the inside of the corner, and
+    /// // a 1 x 1 square filling in the outside of the corner
+    /// let buffered = line_string.buffer_with_style(1., style);
+    /// assert_relative_eq!(buffered.unsigned_area(), 40., epsilon = 1e-10);
+    /// ```
+    fn buffer_with_style(&self, distance: T, style: BufferStyle<T>) -> MultiPolygon<T>;
+}
+
+/// The shape of the buffer around the vertices of a geometry, on the outside of each turn.
+///
+/// On the inside of a turn, the buffers of the two segments meeting at the vertex overlap, so the
+/// join makes no difference.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JoinStyle<T: GeoFloat> {
+    /// An arc around the vertex.
+    Round,
+    /// The offset segments are extended until they meet in a point.
+    ///
+    /// At sharp angles that point lies far from the vertex, so if it is more than `limit` times
+    /// the buffer distance away, the join is beveled instead. A `limit` less than `1` bevels
+    /// every join.
+    Miter { limit: T },
+    /// The ends of the offset segments are joined by a straight line.
+    Bevel,
+}
+
+/// The shape of the buffer at the ends of an open `LineString`, and around a `Point`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CapStyle {
+    /// A half disk around the end.
+    ///
+    /// A `Point` is buffered to a disk.
+    Round,
+    /// The buffer ends at the end of the line.
+    ///
+    /// A `Point` is buffered to nothing.
+    Flat,
+    /// The buffer extends beyond the end of the line by the buffer distance.
+    ///
+    /// A `Point` is buffered to an axis aligned square.
+    Square,
+}
+
+/// The options for [`Buffer::buffer_with_style`].
+///
+/// The default style has round joins and caps, with [`DEFAULT_QUADRANT_SEGMENTS`] segments per
+/// quarter circle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BufferStyle<T: GeoFloat> {
+    /// The shape of the buffer around the vertices
+    pub join: JoinStyle<T>,
+    /// The shape of the buffer at the ends of open lines
+    pub cap: CapStyle,
+    /// The number of segments approximating each quarter circle of round joins and caps. A
+    /// `quadrant_segments` of `0` is treated as `1`.
+    pub quadrant_segments: usize,
+}
+
+impl<T: GeoFloat> Default for BufferStyle<T> {
+    fn default() -> Self {
+        BufferStyle {
+            join: JoinStyle::Round,
+            cap: CapStyle::Round,
+            quadrant_segments: DEFAULT_QUADRANT_SEGMENTS,
+        }
+    }
+}
+
+impl<T: GeoFloat> Buffer<T> for Point<T> {
+    fn buffer_with_style(&self, distance: T, style: BufferStyle<T>) -> MultiPolygon<T> {
+        if distance <= T::zero() {
+            return MultiPolygon::new(vec![]);
+        }
+        let stroke = Stroke::new(distance, style);
+        MultiPolygon::new(stroke.around(self.0).into_iter().collect())
+    }
+}
+
+impl<T: GeoFloat> Buffer<T> for MultiPoint<T> {
+    fn buffer_with_style(&self, distance: T, style: BufferStyle<T>) -> MultiPolygon<T> {
+        if distance <= T::zero() {
+            return MultiPolygon::new(vec![]);
+        }
+        let stroke = Stroke::new(distance, style);
+        let pieces: Vec<_> = self
+            .iter()
+            .filter_map(|point| stroke.around(point.0))
+            .collect();
+        union_all(pieces)
+    }
+}
+
+impl<T: GeoFloat> Buffer<T> for Line<T> {
+    fn buffer_with_style(&self, distance: T, style: BufferStyle<T>) -> MultiPolygon<T> {
+        LineString::new(vec![self.start, self.end]).buffer_with_style(distance, style)
+    }
+}
+
+impl<T: GeoFloat> Buffer<T> for LineString<T> {
+    fn buffer_with_style(&self, distance: T, style: BufferStyle<T>) -> MultiPolygon<T> {
+        if distance <= T::zero() {
+            return MultiPolygon::new(vec![]);
+        }
+        let stroke = Stroke::new(distance, style);
+        union_all(stroke.along_line_string(self))
+    }
+}
+
+impl<T: GeoFloat> Buffer<T> for MultiLineString<T> {
+    fn buffer_with_style(&self, distance: T, style: BufferStyle<T>) -> MultiPolygon<T> {
+        if distance <= T::zero() {
+            return MultiPolygon::new(vec![]);
+        }
+        let stroke = Stroke::new(distance, style);
+        let pieces: Vec<_> = self
+            .iter()
+            .flat_map(|line_string| stroke.along_line_string(line_string))
+            .collect();
+        union_all(pieces)
+    }
+}
+
+impl<T: GeoFloat> Buffer<T> for Polygon<T> {
+    fn buffer_with_style(&self, distance: T, style: BufferStyle<T>) -> MultiPolygon<T> {
+        MultiPolygon::new(vec![self.clone()]).buffer_with_style(distance, style)
+    }
+}
+
+impl<T: GeoFloat> Buffer<T> for MultiPolygon<T> {
+    fn buffer_with_style(&self, distance: T, style: BufferStyle<T>) -> MultiPolygon<T> {
+        if distance == T::zero() {
+            return union_all(self.0.clone());
+        }
+
+        // The pieces covering all points within `distance` of the boundary. Rings are closed, so
+        // only the join style matters.
+        let stroke = Stroke::new(distance.abs(), style);
+        let rings = self
+            .iter()
+            .flat_map(|polygon| std::iter::once(polygon.exterior()).chain(polygon.interiors()));
+        let pieces: Vec<_> = rings
+            .flat_map(|ring| stroke.along_line_string(ring))
+            .collect();
+
+        let boundary = union_all(pieces);
+        if distance > T::zero() {
+            float_boolean_op(&boundary.0, &union_all(self.0.clone()).0, OpType::Union)
+        } else {
+            float_boolean_op(
+                &union_all(self.0.clone()).0,
+                &boundary.0,
+                OpType::Difference,
+            )
+        }
+    }
+}
+
+/// The pieces whose union is the buffer of a geometry with a given style.
+struct Stroke<T: GeoFloat> {
+    disk: Disk<T>,
+    distance: T,
+    join: JoinStyle<T>,
+    cap: CapStyle,
+}
+
+impl<T: GeoFloat> Stroke<T> {
+    fn new(distance: T, style: BufferStyle<T>) -> Self {
+        Stroke {
+            disk: Disk::new(distance, style.quadrant_segments),
+            distance,
+            join: style.join,
+            cap: style.cap,
+        }
+    }
+
+    /// The buffer of a single coordinate, shaped by the cap style.
+    fn around(&self, center: Coord<T>) -> Option<Polygon<T>> {
+        let d = self.distance;
+        match self.cap {
+            CapStyle::Round => Some(self.disk.around(center)),
+            CapStyle::Flat => None,
+            CapStyle::Square => Some(quadrilateral(
+                center + Coord { x: -d, y: -d },
+                center + Coord { x: d, y: -d },
+                center + Coord { x: d, y: d },
+                center + Coord { x: -d, y: d },
+            )),
+        }
+    }
+
+    fn along_line_string(&self, line_string: &LineString<T>) -> Vec<Polygon<T>> {
+        // With round joins, the disk swept along each segment also covers the joins, and the caps
+        // of an open line string. Sweeping the disk is simpler and more robust than assembling
+        // the same shape from rectangles and disks.
+        if self.join == JoinStyle::Round && (self.cap == CapStyle::Round || line_string.is_closed())
+        {
+            return self.disk.along_line_string(line_string).collect();
+        }
+
+        let mut coords = line_string.0.clone();
+        coords.dedup();
+        if coords.len() == 1 {
+            return self.around(coords[0]).into_iter().collect();
+        }
+        let segments: Vec<_> = coords
+            .windows(2)
+            .map(|pair| Segment::new(pair[0], pair[1], self.distance))
+            .collect();
+        let (first, last) = match (segments.first(), segments.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return vec![],
+        };
+
+        let mut pieces: Vec<_> = segments.iter().map(Segment::rectangle).collect();
+        pieces.extend(
+            segments
+                .windows(2)
+                .filter_map(|pair| self.join(&pair[0], &pair[1])),
+        );
+        if line_string.is_closed() {
+            pieces.extend(self.join(last, first));
+        } else {
+            pieces.extend(self.cap(first.start, first.normal, -first.tangent));
+            pieces.extend(self.cap(last.end, last.normal, last.tangent));
+        }
+        pieces
+    }
+
+    /// The piece filling the gap between the buffers of two consecutive segments, on the outside
+    /// of the turn between them.
+    fn join(&self, incoming: &Segment<T>, outgoing: &Segment<T>) -> Option<Polygon<T>> {
+        let vertex = incoming.end;
+        let turn =
+            incoming.tangent.x * outgoing.tangent.y - incoming.tangent.y * outgoing.tangent.x;
+        if self.join == JoinStyle::Round {
+            // a segment which doubles back on the last needs a round join too
+            let straight = turn == T::zero()
+                && incoming.tangent.x * outgoing.tangent.x
+                    + incoming.tangent.y * outgoing.tangent.y
+                    > T::zero();
+            return (!straight).then(|| self.disk.around(vertex));
+        }
+        if turn == T::zero() {
+            return None;
+        }
+
+        // the corners of the segments' rectangles on the outside of the turn, computed exactly
+        // as in `Segment::rectangle` so that the pieces share their vertices
+        let (from, to, normals) = if turn > T::zero() {
+            (
+                vertex - incoming.normal,
+                vertex - outgoing.normal,
+                -(incoming.normal + outgoing.normal),
+            )
+        } else {
+            (
+                vertex + incoming.normal,
+                vertex + outgoing.normal,
+                incoming.normal + outgoing.normal,
+            )
+        };
+        let bevel = Polygon::new(LineString::new(vec![vertex, from, to, vertex]), vec![]);
+        let limit = match self.join {
+            JoinStyle::Miter { limit } => limit,
+            _ => return Some(bevel),
+        };
+
+        // The normals have length `distance`, and their sum bisects the angle between them, so
+        // the offset lines meet at `2 * distance² / |normals|²` times the sum from the vertex.
+        let length_squared = normals.x * normals.x + normals.y * normals.y;
+        let two = T::one() + T::one();
+        // equivalent to `|miter| / distance > limit`, without any square roots
+        if two * two * self.distance * self.distance > limit * limit * length_squared {
+            return Some(bevel);
+        }
+        let miter = vertex + normals * (two * self.distance * self.distance / length_squared);
+        Some(quadrilateral(vertex, from, miter, to))
+    }
+
+    /// The piece beyond the `end` of a line, whose direction there is `tangent`.
+    fn cap(&self, end: Coord<T>, normal: Coord<T>, tangent: Coord<T>) -> Option<Polygon<T>> {
+        match self.cap {
+            CapStyle::Round => Some(self.disk.around(end)),
+            CapStyle::Flat => None,
+            CapStyle::Square => Some(quadrilateral(
+                end - normal,
+                end + normal,
+                end + normal + tangent,
+                end - normal + tangent,
+            )),
+        }
+    }
+}
+
+/// A segment of a line, with its offsets.
+struct Segment<T: GeoFloat> {
+    start: Coord<T>,
+    end: Coord<T>,
+    /// The direction of the segment, scaled to the buffer distance
+    tangent: Coord<T>,
+    /// The tangent rotated a quarter turn counter-clockwise, to the left of the segment
+    normal: Coord<T>,
+}
+
+impl<T: GeoFloat> Segment<T> {
+    fn new(start: Coord<T>, end: Coord<T>, distance: T) -> Self {
+        let delta = end - start;
+        let tangent = delta * (distance / delta.x.hypot(delta.y));
+        Segment {
+            start,
+            end,
+            tangent,
+            normal: Coord {
+                x: -tangent.y,
+                y: tangent.x,
+            },
+        }
+    }
+
+    /// The segment offset to both sides, with flat ends.
+    fn rectangle(&self) -> Polygon<T> {
+        quadrilateral(
+            self.start - self.normal,
+            self.end - self.normal,
+            self.end + self.normal,
+            self.start + self.normal,
+        )
+    }
+}
+
+fn quadrilateral<T: GeoFloat>(a: Coord<T>, b: Coord<T>, c: Coord<T>, d: Coord<T>) -> Polygon<T> {
+    Polygon::new(LineString::new(vec![a, b, c, d, a]), vec![])
+}
+
+/// A regular polygon approximating a disk centered on the origin.
+struct Disk<T: GeoFloat> {
+    offsets: Vec<Coord<T>>,
+}
+
+impl<T: GeoFloat> Disk<T> {
+    fn new(radius: T, quadrant_segments: usize) -> Self {
+        let quadrant_segments = quadrant_segments.max(1);
+        let step = T::from(std::f64::consts::FRAC_PI_2 / quadrant_segments as f64).unwrap();
+        let quadrant: Vec<_> = (0..quadrant_segments)
+            .map(|i| match i {
+                0 => Coord {
+                    x: radius,
+                    y: T::zero(),
+                },
+                _ => {
+                    let angle = step * T::from(i).unwrap();
+                    Coord {
+                        x: radius * angle.cos(),
+                        y: radius * angle.sin(),
+                    }
+                }
+            })
+            .collect();
+        // The other quadrants are exact rotations of the first, so that the offsets along the axes
+        // are exact and the disk is symmetric. The offsets are also computed only once, so every
+        // piece built from this disk shares exactly the same vertices around a shared coordinate.
+        // Both keep the union of the pieces free of nearly coincident vertices.
+        let offsets = quadrant
+            .iter()
+            .copied()
+            .chain(quadrant.iter().map(|c| Coord { x: -c.y, y: c.x }))
+            .chain(quadrant.iter().map(|c| Coord { x: -c.x, y: -c.y }))
+            .chain(quadrant.iter().map(|c| Coord { x: c.y, y: -c.x }))
+            .collect();
+        Disk { offsets }
+    }
+
+    /// The disk translated to `center`.
+    fn around(&self, center: Coord<T>) -> Polygon<T> {
+        let mut coords: Vec<_> = self.offsets.iter().map(|&offset| center + offset).collect();
+        coords.push(coords[0]);
+        Polygon::new(LineString::new(coords), vec![])
+    }
+
+    /// The disk swept along `line`, i.e. the convex hull of the disk around both of its ends.
+    fn along(&self, line: Line<T>) -> Polygon<T> {
+        if line.start == line.end {
+            return self.around(line.start);
+        }
+        let mut coords: Vec<_> = self
+            .offsets
+            .iter()
+            .flat_map(|&offset| [line.start + offset, line.end + offset])
+            .collect();
+        Polygon::new(quick_hull(&mut coords), vec![])
+    }
+
+    fn along_line_string<'a>(
+        &'a self,
+        line_string: &'a LineString<T>,
+    ) -> impl Iterator<Item = Polygon<T>> + 'a {
+        let single_coord = match line_string.0.as_slice() {
+            [coord] => Some(self.around(*coord)),
+            _ => None,
+        };
+        line_string
+            .lines()
+            .map(|line| self.along(line))
+            .chain(single_coord)
+    }
+}
+
+/// Dissolve `polygons` with [`BooleanOps::union`].
+///
+/// The polygons are merged pairwise in a balanced tree rather than one at a time, so that each
+/// union combines inputs of similar complexity. This keeps the intermediate results small, and
+/// is considerably more robust than accumulating a single, ever growing, result.
+fn union_all<T: GeoFloat>(polygons: Vec<Polygon<T>>) -> MultiPolygon<T> {
+    let mut merged: Vec<_> = polygons
+        .into_iter()
+        .map(|polygon| MultiPolygon::new(vec![polygon]))
+        .collect();
+    while merged.len() > 1 {
+        let mut pairs = merged.into_iter();
+        merged = Vec::new();
+        while let Some(first) = pairs.next() {
+            merged.push(match pairs.next() {
+                Some(second) => float_boolean_op(&first.0, &second.0, OpType::Union),
+                None => first,
+            });
+        }
+    }
+    merged.pop().unwrap_or_else(|| MultiPolygon::new(vec![]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point, polygon};
+    use crate::{Area, Contains, EuclideanDistance, Intersects};
+    use std::f64::consts::PI;
+
+    /// The area of a regular polygon with `n` vertices on a circle of radius `r`.
+    fn disk_area(r: f64, quadrant_segments: usize) -> f64 {
+        let n = (4 * quadrant_segments) as f64;
+        n * r * r * (2. * PI / n).sin() / 2.
+    }
+
+    #[test]
+    fn point_buffer() {
+        let buffered = point!(x: 1., y: 2.).buffer(3.);
+        assert_eq!(buffered.0.len(), 1);
+        assert_eq!(
+            buffered.0[0].exterior().0.len(),
+            4 * DEFAULT_QUADRANT_SEGMENTS + 1
+        );
+        assert_relative_eq!(
+            buffered.unsigned_area(),
+            disk_area(3., DEFAULT_QUADRANT_SEGMENTS),
+            epsilon = 1e-10
+        );
+        for coord in buffered.0[0].exterior() {
+            assert_relative_eq!(
+                Point::from(*coord).euclidean_distance(&point!(x: 1., y: 2.)),
+                3.,
+                epsilon = 1e-10
+            );
+        }
+    }
+
+    #[test]
+    fn non_positive_distance_without_area() {
+        assert!(point!(x: 0., y: 0.).buffer(0.).0.is_empty());
+        assert!(point!(x: 0., y: 0.).buffer(-1.).0.is_empty());
+        assert!(line_string![(x: 0., y: 0.), (x: 1., y: 1.)]
+            .buffer(-1.)
+            .0
+            .is_empty());
+    }
+
+    #[test]
+    fn line_buffer() {
+        let line = Line::new(coord! { x: 0., y: 0. }, coord! { x: 10., y: 0. });
+        let buffered = line.buffer_with_quadrant_segments(1., 16);
+        assert_relative_eq!(
+            buffered.unsigned_area(),
+            20. + disk_area(1., 16),
+            epsilon = 1e-10
+        );
+        assert!(buffered.contains(&point!(x: 5., y: 0.99)));
+        assert!(buffered.contains(&point!(x: -0.99, y: 0.)));
+        assert!(!buffered.intersects(&point!(x: 5., y: 1.01)));
+    }
+
+    #[test]
+    fn line_string_buffer_is_dissolved() {
+        // a zig-zag whose segment buffers overlap heavily
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 1.),
+            (x: 0., y: 2.),
+            (x: 4., y: 3.),
+            (x: 0., y: 4.),
+        ];
+        let buffered = line_string.buffer(1.);
+        assert_eq!(buffered.0.len(), 1);
+        assert!(buffered.0[0].interiors().is_empty());
+        for coord in line_string.coords() {
+            assert!(buffered.contains(coord));
+        }
+        // the area of the union is much less than the sum of the pieces
+        assert!(buffered.unsigned_area() < 4. * (2. * 17f64.sqrt() + PI));
+    }
+
+    #[test]
+    fn closed_line_string_buffer_has_hole() {
+        let ring = line_string![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+            (x: 0., y: 0.),
+        ];
+        let buffered = ring.buffer(1.);
+        assert_eq!(buffered.0.len(), 1);
+        assert_eq!(buffered.0[0].interiors().len(), 1);
+        // 12 x 12 square with rounded corners, minus an 8 x 8 square
+        assert_relative_eq!(
+            buffered.unsigned_area(),
+            144. - 4. + disk_area(1., DEFAULT_QUADRANT_SEGMENTS) - 64.,
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn polygon_positive_buffer() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+        ];
+        let buffered = polygon.buffer(1.);
+        assert_eq!(buffered.0.len(), 1);
+        assert!(buffered.0[0].interiors().is_empty());
+        assert_relative_eq!(
+            buffered.unsigned_area(),
+            100. + 40. + disk_area(1., DEFAULT_QUADRANT_SEGMENTS),
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn polygon_negative_buffer() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+        ];
+        let buffered = polygon.buffer(-1.);
+        assert_eq!(buffered.0.len(), 1);
+        assert_relative_eq!(buffered.unsigned_area(), 64., epsilon = 1e-10);
+    }
+
+    #[test]
+    fn polygon_negative_buffer_collapses() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 1.),
+            (x: 0., y: 1.),
+        ];
+        assert!(polygon.buffer(-1.).0.is_empty());
+        assert!(polygon.buffer(-0.5).0.is_empty());
+    }
+
+    #[test]
+    fn polygon_negative_buffer_splits() {
+        // two squares joined by a thin corridor
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 1.5),
+            (x: 6., y: 1.5),
+            (x: 6., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 4.),
+            (x: 6., y: 4.),
+            (x: 6., y: 2.5),
+            (x: 4., y: 2.5),
+            (x: 4., y: 4.),
+            (x: 0., y: 4.),
+        ];
+        let buffered = polygon.buffer(-1.);
+        assert_eq!(buffered.0.len(), 2);
+        // each square shrinks to 2 x 2, with a small bulge towards the corridor
+        assert!(buffered.unsigned_area() > 2. * 4.);
+        assert!(buffered.unsigned_area() < 2. * 4.5);
+    }
+
+    #[test]
+    fn polygon_concave_corner() {
+        // an L shape, with a reflex corner at (1, 1)
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 1.),
+            (x: 1., y: 1.),
+            (x: 1., y: 2.),
+            (x: 0., y: 2.),
+        ];
+
+        // The offsets of the two edges meeting at the reflex corner overlap in a 0.5 x 0.5
+        // square, which must only be counted once. Each of the five convex corners is rounded by
+        // a quarter of the disk.
+        let grown = polygon.buffer(0.5);
+        assert_eq!(grown.0.len(), 1);
+        assert!(grown.0[0].interiors().is_empty());
+        assert_relative_eq!(
+            grown.unsigned_area(),
+            3. + 8. * 0.5 - 0.25 + 1.25 * disk_area(0.5, DEFAULT_QUADRANT_SEGMENTS),
+            epsilon = 1e-10
+        );
+        assert!(!grown.intersects(&point!(x: 1.6, y: 1.6)));
+
+        // Shrinking rounds the reflex corner instead: beside the mitred inset, which is an L of
+        // area 1.25, it keeps the points of the 0.25 x 0.25 square at the corner that are further
+        // than 0.25 from (1, 1).
+        let shrunk = polygon.buffer(-0.25);
+        assert_eq!(shrunk.0.len(), 1);
+        assert!(shrunk.0[0].interiors().is_empty());
+        assert_relative_eq!(
+            shrunk.unsigned_area(),
+            1.25 + 0.0625 - disk_area(0.25, DEFAULT_QUADRANT_SEGMENTS) / 4.,
+            epsilon = 1e-10
+        );
+        assert!(shrunk.contains(&point!(x: 0.8, y: 0.8)));
+        assert!(!shrunk.intersects(&point!(x: 0.9, y: 0.9)));
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 3., y: 3.), (x: 7., y: 3.), (x: 7., y: 7.), (x: 3., y: 7.)]],
+        );
+        // the hole shrinks to a 2 x 2 square
+        let grown = polygon.buffer(1.);
+        assert_eq!(grown.0.len(), 1);
+        assert_eq!(grown.0[0].interiors().len(), 1);
+        assert_relative_eq!(
+            Polygon::new(grown.0[0].interiors()[0].clone(), vec![]).unsigned_area(),
+            4.
+        );
+
+        // the hole fills in entirely
+        let grown = polygon.buffer(2.5);
+        assert_eq!(grown.0.len(), 1);
+        assert!(grown.0[0].interiors().is_empty());
+
+        // the hole grows to a 6 x 6 square with rounded corners
+        let shrunk = polygon.buffer(-1.);
+        assert_eq!(shrunk.0.len(), 1);
+        assert_eq!(shrunk.0[0].interiors().len(), 1);
+        assert_relative_eq!(
+            Polygon::new(shrunk.0[0].exterior().clone(), vec![]).unsigned_area(),
+            64.
+        );
+    }
+
+    #[test]
+    fn multi_polygon_buffers_merge() {
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+            polygon![(x: 2., y: 0.), (x: 3., y: 0.), (x: 3., y: 1.), (x: 2., y: 1.)],
+        ]);
+        assert_eq!(multi_polygon.buffer(0.25).0.len(), 2);
+        assert_eq!(multi_polygon.buffer(1.).0.len(), 1);
+        assert_eq!(multi_polygon.buffer(-0.25).0.len(), 2);
+        assert!(multi_polygon.buffer(-0.5).0.is_empty());
+    }
+
+    fn style(join: JoinStyle<f64>, cap: CapStyle) -> BufferStyle<f64> {
+        BufferStyle {
+            join,
+            cap,
+            ..BufferStyle::default()
+        }
+    }
+
+    #[test]
+    fn right_angle_joins() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        // the two 10 x 2 rectangles overlap in a 1 x 1 square on the inside of the corner
+        let rectangles = 40. - 1.;
+        let outside_corner = point!(x: 10.9, y: -0.9);
+
+        let round = line_string.buffer_with_style(1., style(JoinStyle::Round, CapStyle::Flat));
+        let bevel = line_string.buffer_with_style(1., style(JoinStyle::Bevel, CapStyle::Flat));
+        let miter = line_string
+            .buffer_with_style(1., style(JoinStyle::Miter { limit: 2. }, CapStyle::Flat));
+        for buffered in [&round, &bevel, &miter] {
+            assert_eq!(buffered.0.len(), 1);
+            assert!(buffered.0[0].interiors().is_empty());
+        }
+
+        // a quarter of the disk fills the outside of the corner
+        assert_relative_eq!(
+            round.unsigned_area(),
+            rectangles + disk_area(1., DEFAULT_QUADRANT_SEGMENTS) / 4.,
+            epsilon = 1e-10
+        );
+        assert!(!round.intersects(&outside_corner));
+        // the arc adds a vertex for each segment but the last
+        assert_eq!(
+            round.0[0].exterior().0.len(),
+            bevel.0[0].exterior().0.len() + DEFAULT_QUADRANT_SEGMENTS - 1
+        );
+
+        // a right triangle cuts the corner off
+        assert_relative_eq!(bevel.unsigned_area(), rectangles + 0.5, epsilon = 1e-10);
+        assert!(!bevel.intersects(&outside_corner));
+        assert!(bevel.contains(&point!(x: 10.4, y: -0.4)));
+        // seven vertices, and the closing coordinate
+        assert_eq!(bevel.0[0].exterior().0.len(), 8);
+
+        // a square makes the corner sharp
+        assert_relative_eq!(miter.unsigned_area(), rectangles + 1., epsilon = 1e-10);
+        assert!(miter.contains(&outside_corner));
+        // the six vertices of an L, the closing coordinate, and the ends of the square's edges,
+        // which are kept where it meets the rectangles
+        assert_eq!(miter.0[0].exterior().0.len(), 9);
+    }
+
+    #[test]
+    fn miter_limit() {
+        // a hairpin turn, with an angle of about 5.7° between the segments
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 0., y: 1.)];
+        let buffer = |join| line_string.buffer_with_style(1., style(join, CapStyle::Flat));
+
+        // the miter would extend about 20 times the distance beyond the vertex
+        let bevel = buffer(JoinStyle::Bevel);
+        let limited = buffer(JoinStyle::Miter { limit: 5. });
+        assert_relative_eq!(
+            limited.unsigned_area(),
+            bevel.unsigned_area(),
+            epsilon = 1e-10
+        );
+        assert!(!limited.intersects(&point!(x: 15., y: -0.9)));
+
+        let spike = buffer(JoinStyle::Miter { limit: 25. });
+        assert!(spike.unsigned_area() > bevel.unsigned_area() + 10.);
+        // the spike runs along the outside of the first segment's buffer
+        assert!(spike.contains(&point!(x: 25., y: -0.9)));
+        assert!(!spike.intersects(&point!(x: 31., y: -1.)));
+    }
+
+    #[test]
+    fn cap_styles() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        let buffer = |cap| line_string.buffer_with_style(1., style(JoinStyle::Bevel, cap));
+        assert_relative_eq!(buffer(CapStyle::Flat).unsigned_area(), 20., epsilon = 1e-10);
+        assert_relative_eq!(
+            buffer(CapStyle::Square).unsigned_area(),
+            24.,
+            epsilon = 1e-10
+        );
+        assert_relative_eq!(
+            buffer(CapStyle::Round).unsigned_area(),
+            20. + disk_area(1., DEFAULT_QUADRANT_SEGMENTS),
+            epsilon = 1e-10
+        );
+        assert!(buffer(CapStyle::Square).contains(&point!(x: 10.9, y: 0.9)));
+
+        // the default style is round joins and caps
+        assert_relative_eq!(
+            line_string
+                .buffer_with_style(1., style(JoinStyle::Round, CapStyle::Round))
+                .unsigned_area(),
+            line_string.buffer(1.).unsigned_area()
+        );
+
+        let point = point!(x: 1., y: 1.);
+        assert!(point
+            .buffer_with_style(1., style(JoinStyle::Round, CapStyle::Flat))
+            .0
+            .is_empty());
+        assert_eq!(
+            point.buffer_with_style(1., style(JoinStyle::Round, CapStyle::Square)),
+            MultiPolygon::new(vec![polygon![
+                (x: 0., y: 0.),
+                (x: 2., y: 0.),
+                (x: 2., y: 2.),
+                (x: 0., y: 2.),
+            ]])
+        );
+    }
+
+    #[test]
+    fn closed_line_string_has_no_caps() {
+        let ring = line_string![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+            (x: 0., y: 0.),
+        ];
+        let buffered =
+            ring.buffer_with_style(1., style(JoinStyle::Miter { limit: 2. }, CapStyle::Square));
+        assert_eq!(buffered.0.len(), 1);
+        assert_relative_eq!(buffered.unsigned_area(), 144. - 64., epsilon = 1e-10);
+    }
+
+    #[test]
+    fn polygon_joins() {
+        let square = polygon![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 10.),
+            (x: 0., y: 10.),
+        ];
+        let miter = style(JoinStyle::Miter { limit: 2. }, CapStyle::Round);
+        assert_relative_eq!(
+            square.buffer_with_style(1., miter).unsigned_area(),
+            144.,
+            epsilon = 1e-10
+        );
+        let bevel = style(JoinStyle::Bevel, CapStyle::Round);
+        assert_relative_eq!(
+            square.buffer_with_style(1., bevel).unsigned_area(),
+            144. - 4. * 0.5,
+            epsilon = 1e-10
+        );
+
+        // shrinking an L shape, the joins shape its reflex corner
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 1.),
+            (x: 1., y: 1.),
+            (x: 1., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        assert_relative_eq!(
+            polygon.buffer_with_style(-0.25, miter).unsigned_area(),
+            1.25,
+            epsilon = 1e-10
+        );
+        assert_relative_eq!(
+            polygon.buffer_with_style(-0.25, bevel).unsigned_area(),
+            1.25 + 0.25 * 0.25 / 2.,
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn empty_geometries() {
+        assert!(LineString::<f64>::new(vec![]).buffer(1.).0.is_empty());
+        assert!(Polygon::<f64>::new(LineString::new(vec![]), vec![])
+            .buffer(1.)
+            .0
+            .is_empty());
+        assert!(MultiPolygon::<f64>::new(vec![]).buffer(-1.).0.is_empty());
+    }
+}
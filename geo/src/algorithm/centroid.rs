@@ -439,7 +439,77 @@ where
     }
 }
 
-struct CentroidOperation<T: GeoFloat>(Option<WeightedCentroid<T>>);
+/// Calculation of the weighted centroid of a set of points: their mean position when each one
+/// counts in proportion to its weight.
+///
+/// Where [`Centroid`] treats all the points of a [`MultiPoint`] equally, this allows e.g. the
+/// population of each of a set of towns to pull the centre towards the larger ones.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, MultiPoint, WeightedCentroid};
+///
+/// let towns = MultiPoint::new(vec![point!(x: 0., y: 0.), point!(x: 4., y: 0.)]);
+///
+/// assert_eq!(
+///     towns.weighted_centroid(&[1., 3.]),
+///     Some(point!(x: 3., y: 0.)),
+/// );
+/// // one weight per point is needed
+/// assert_eq!(towns.weighted_centroid(&[1.]), None);
+/// ```
+pub trait WeightedCentroid<T: GeoFloat> {
+    /// The weighted centroid, or `None` if there isn't exactly one weight per point, or the
+    /// weights sum to zero.
+    fn weighted_centroid(&self, weights: &[T]) -> Option<Point<T>>;
+}
+
+impl<T> WeightedCentroid<T> for MultiPoint<T>
+where
+    T: GeoFloat,
+{
+    fn weighted_centroid(&self, weights: &[T]) -> Option<Point<T>> {
+        weighted_centroid(&self.0, weights)
+    }
+}
+
+/// The weighted centroid of `points`, with `weights[i]` the weight of `points[i]`.
+///
+/// Returns `None` if the slices differ in length, or the weights sum to zero. See
+/// [`WeightedCentroid`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::centroid::weighted_centroid;
+/// use geo::point;
+///
+/// let points = [point!(x: 0., y: 0.), point!(x: 0., y: 4.), point!(x: 4., y: 0.)];
+///
+/// assert_eq!(
+///     weighted_centroid(&points, &[2., 1., 1.]),
+///     Some(point!(x: 1., y: 1.)),
+/// );
+/// assert_eq!(weighted_centroid(&points, &[0., 0., 0.]), None);
+/// ```
+pub fn weighted_centroid<T: GeoFloat>(points: &[Point<T>], weights: &[T]) -> Option<Point<T>> {
+    if points.len() != weights.len() {
+        return None;
+    }
+    let (accumulated, weight) = points.iter().zip(weights).fold(
+        (Coord::zero(), T::zero()),
+        |(accumulated, weight), (point, &point_weight)| {
+            (accumulated + point.0 * point_weight, weight + point_weight)
+        },
+    );
+    if weight.is_zero() {
+        return None;
+    }
+    Some(Point::from(accumulated / weight))
+}
+
+struct CentroidOperation<T: GeoFloat>(Option<CentroidAccumulator<T>>);
 impl<T: GeoFloat> CentroidOperation<T> {
     fn new() -> Self {
         CentroidOperation(None)
@@ -632,7 +702,7 @@ impl<T: GeoFloat> CentroidOperation<T> {
     }
 
     fn add_centroid(&mut self, dimensions: Dimensions, centroid: Coord<T>, weight: T) {
-        let weighted_centroid = WeightedCentroid {
+        let weighted_centroid = CentroidAccumulator {
             dimensions,
             weight,
             accumulated: centroid * weight,
@@ -640,7 +710,7 @@ impl<T: GeoFloat> CentroidOperation<T> {
         self.add_weighted_centroid(weighted_centroid);
     }
 
-    fn add_weighted_centroid(&mut self, other: WeightedCentroid<T>) {
+    fn add_weighted_centroid(&mut self, other: CentroidAccumulator<T>) {
         match self.0.as_mut() {
             Some(centroid) => centroid.add_assign(other),
             None => self.0 = Some(other),
@@ -649,7 +719,7 @@ impl<T: GeoFloat> CentroidOperation<T> {
 }
 
 // Aggregated state for accumulating the centroid of a geometry or collection of geometries.
-struct WeightedCentroid<T: GeoFloat> {
+struct CentroidAccumulator<T: GeoFloat> {
     weight: T,
     accumulated: Coord<T>,
     /// Collections of Geometries can have different dimensionality. Centroids must be considered
@@ -665,8 +735,8 @@ struct WeightedCentroid<T: GeoFloat> {
     dimensions: Dimensions,
 }
 
-impl<T: GeoFloat> WeightedCentroid<T> {
-    fn add_assign(&mut self, b: WeightedCentroid<T>) {
+impl<T: GeoFloat> CentroidAccumulator<T> {
+    fn add_assign(&mut self, b: CentroidAccumulator<T>) {
         match self.dimensions.cmp(&b.dimensions) {
             Ordering::Less => *self = b,
             Ordering::Greater => {}
@@ -677,7 +747,7 @@ impl<T: GeoFloat> WeightedCentroid<T> {
         }
     }
 
-    fn sub_assign(&mut self, b: WeightedCentroid<T>) {
+    fn sub_assign(&mut self, b: CentroidAccumulator<T>) {
         match self.dimensions.cmp(&b.dimensions) {
             Ordering::Less => *self = b,
             Ordering::Greater => {}
@@ -1034,6 +1104,44 @@ mod test {
         assert_eq!(collection.centroid().unwrap(), point!(x: 1.0, y: 1.0));
     }
     #[test]
+    fn weighted_multi_point() {
+        let multi_point =
+            MultiPoint::new(vec![p(0., 0.), p(2., 0.), p(2., 2.), p(0., 2.), p(5., 1.)]);
+        // equal weights, of any size, give the usual centroid
+        assert_relative_eq!(
+            multi_point.weighted_centroid(&[1.; 5]).unwrap(),
+            multi_point.centroid().unwrap()
+        );
+        assert_relative_eq!(
+            multi_point.weighted_centroid(&[0.25; 5]).unwrap(),
+            multi_point.centroid().unwrap()
+        );
+        // the heavy point pulls the centroid most of the way to it
+        assert_relative_eq!(
+            multi_point
+                .weighted_centroid(&[1., 1., 1., 1., 16.])
+                .unwrap(),
+            p(4.2, 1.)
+        );
+        // the unweighted points are ignored
+        assert_relative_eq!(
+            multi_point
+                .weighted_centroid(&[0., 1., 1., 0., 0.])
+                .unwrap(),
+            p(2., 1.)
+        );
+    }
+    #[test]
+    fn weighted_multi_point_invalid_weights() {
+        let multi_point = MultiPoint::new(vec![p(0., 0.), p(2., 0.)]);
+        assert_eq!(multi_point.weighted_centroid(&[1.]), None);
+        assert_eq!(multi_point.weighted_centroid(&[1., 1., 1.]), None);
+        assert_eq!(multi_point.weighted_centroid(&[0., 0.]), None);
+        assert_eq!(multi_point.weighted_centroid(&[1., -1.]), None);
+        assert_eq!(MultiPoint::<f64>::new(vec![]).weighted_centroid(&[]), None);
+        assert_eq!(weighted_centroid(&[p(1., 1.)], &[2.]), Some(p(1., 1.)));
+    }
+    #[test]
     fn triangles() {
         // boring triangle
         assert_eq!(
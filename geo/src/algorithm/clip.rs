@@ -0,0 +1,464 @@
+use crate::algorithm::area::twice_signed_ring_area;
+use crate::{
+    BoundingRect, Coord, GeoFloat, Intersects, Line, LineString, MultiLineString, MultiPolygon,
+    Polygon, Rect, Triangle,
+};
+
+/// Clip a geometry to an axis-aligned [`Rect`], keeping only the parts of it which lie inside.
+///
+/// Polygonal geometries are clipped with the [Sutherland–Hodgman] algorithm, and return a
+/// [`MultiPolygon`]. Linear geometries are clipped with the [Liang–Barsky] algorithm, and return
+/// a [`MultiLineString`], since a line can enter and leave the `Rect` several times.
+///
+/// These are much cheaper than the general [`BooleanOps`](crate::BooleanOps), which makes them
+/// suitable for cutting geometries into tiles. A geometry whose bounding rectangle does not
+/// intersect the `Rect` is clipped to an empty result, and a geometry whose bounding rectangle is
+/// inside the `Rect` is returned as-is, without visiting its coordinates.
+///
+/// Sutherland–Hodgman clips each ring of a polygon separately, so where a concave polygon leaves
+/// the `Rect` and comes back, the pieces inside stay joined by edges running along the boundary of
+/// the `Rect`. The result covers exactly the intersection of the polygon and the `Rect`, but it
+/// may not be [valid](crate::IsValid). Rings which are clipped to nothing, or to no area, are
+/// removed.
+///
+/// [Sutherland–Hodgman]: https://en.wikipedia.org/wiki/Sutherland%E2%80%93Hodgman_algorithm
+/// [Liang–Barsky]: https://en.wikipedia.org/wiki/Liang%E2%80%93Barsky_algorithm
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon, Area, Rect, RectClip};
+///
+/// let tile = Rect::new((0., 0.), (10., 10.));
+///
+/// let line_string = line_string![(x: -5., y: 5.), (x: 5., y: 5.), (x: 5., y: 15.)];
+/// assert_eq!(
+///     line_string.clip_to_rect(&tile).0,
+///     vec![line_string![(x: 0., y: 5.), (x: 5., y: 5.), (x: 5., y: 10.)]],
+/// );
+///
+/// let polygon = polygon![(x: 5., y: 5.), (x: 15., y: 5.), (x: 15., y: 15.), (x: 5., y: 15.)];
+/// assert_eq!(polygon.clip_to_rect(&tile).unsigned_area(), 25.);
+/// ```
+pub trait RectClip<T: GeoFloat> {
+    type Output;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output;
+}
+
+impl<T: GeoFloat> RectClip<T> for Line<T> {
+    type Output = MultiLineString<T>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output {
+        let pieces = match clip_segment(*self, rect) {
+            Some(line) => vec![LineString::new(vec![line.start, line.end])],
+            None => vec![],
+        };
+        MultiLineString::new(pieces)
+    }
+}
+
+impl<T: GeoFloat> RectClip<T> for LineString<T> {
+    type Output = MultiLineString<T>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output {
+        let mut pieces = vec![];
+        clip_line_string(self, rect, &mut pieces);
+        MultiLineString::new(pieces)
+    }
+}
+
+impl<T: GeoFloat> RectClip<T> for MultiLineString<T> {
+    type Output = MultiLineString<T>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output {
+        let mut pieces = vec![];
+        for line_string in self {
+            clip_line_string(line_string, rect, &mut pieces);
+        }
+        MultiLineString::new(pieces)
+    }
+}
+
+impl<T: GeoFloat> RectClip<T> for Polygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output {
+        MultiPolygon::new(clip_polygon(self, rect).into_iter().collect())
+    }
+}
+
+impl<T: GeoFloat> RectClip<T> for MultiPolygon<T> {
+    type Output = MultiPolygon<T>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output {
+        MultiPolygon::new(
+            self.iter()
+                .filter_map(|polygon| clip_polygon(polygon, rect))
+                .collect(),
+        )
+    }
+}
+
+impl<T: GeoFloat> RectClip<T> for Rect<T> {
+    type Output = MultiPolygon<T>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output {
+        self.to_polygon().clip_to_rect(rect)
+    }
+}
+
+impl<T: GeoFloat> RectClip<T> for Triangle<T> {
+    type Output = MultiPolygon<T>;
+
+    fn clip_to_rect(&self, rect: &Rect<T>) -> Self::Output {
+        self.to_polygon().clip_to_rect(rect)
+    }
+}
+
+enum Overlap {
+    Outside,
+    Inside,
+    Partial,
+}
+
+/// How a geometry with the bounding rectangle `bounds` overlaps `rect`.
+fn overlap<T: GeoFloat>(bounds: Option<Rect<T>>, rect: &Rect<T>) -> Overlap {
+    match bounds {
+        None => Overlap::Outside,
+        Some(bounds) if !bounds.intersects(rect) => Overlap::Outside,
+        Some(bounds)
+            if rect.min().x <= bounds.min().x
+                && rect.min().y <= bounds.min().y
+                && bounds.max().x <= rect.max().x
+                && bounds.max().y <= rect.max().y =>
+        {
+            Overlap::Inside
+        }
+        Some(_) => Overlap::Partial,
+    }
+}
+
+/// The part of `line` inside `rect`, using the Liang–Barsky algorithm.
+///
+/// A line which only touches the boundary of `rect` at a single point is clipped to nothing.
+fn clip_segment<T: GeoFloat>(line: Line<T>, rect: &Rect<T>) -> Option<Line<T>> {
+    let delta = line.delta();
+    let (min, max) = (rect.min(), rect.max());
+    let mut t_enter = T::zero();
+    let mut t_exit = T::one();
+    // each boundary of the rect, as `p * t <= q` for the points `line.start + delta * t` inside it
+    let boundaries = [
+        (-delta.x, line.start.x - min.x),
+        (delta.x, max.x - line.start.x),
+        (-delta.y, line.start.y - min.y),
+        (delta.y, max.y - line.start.y),
+    ];
+    for (p, q) in boundaries {
+        if p == T::zero() {
+            // parallel to this boundary
+            if q < T::zero() {
+                return None;
+            }
+        } else {
+            let t = q / p;
+            if p < T::zero() {
+                t_enter = t_enter.max(t);
+            } else {
+                t_exit = t_exit.min(t);
+            }
+        }
+    }
+
+    let is_point = delta.x == T::zero() && delta.y == T::zero();
+    if t_enter > t_exit || (t_enter == t_exit && !is_point) {
+        return None;
+    }
+    let start = if t_enter == T::zero() {
+        line.start
+    } else {
+        line.start + delta * t_enter
+    };
+    let end = if t_exit == T::one() {
+        line.end
+    } else {
+        line.start + delta * t_exit
+    };
+    Some(Line::new(start, end))
+}
+
+/// Push the pieces of `line_string` inside `rect` on to `pieces`.
+fn clip_line_string<T: GeoFloat>(
+    line_string: &LineString<T>,
+    rect: &Rect<T>,
+    pieces: &mut Vec<LineString<T>>,
+) {
+    match overlap(line_string.bounding_rect(), rect) {
+        Overlap::Outside => return,
+        Overlap::Inside => {
+            pieces.push(line_string.clone());
+            return;
+        }
+        Overlap::Partial => {}
+    }
+
+    let mut piece: Vec<Coord<T>> = vec![];
+    for line in line_string.lines() {
+        let clipped = match clip_segment(line, rect) {
+            Some(clipped) => clipped,
+            None => continue,
+        };
+        // start a new piece unless this one continues from the end of the last
+        if piece.last() != Some(&clipped.start) {
+            if piece.len() > 1 {
+                pieces.push(LineString::new(piece));
+            }
+            piece = vec![clipped.start];
+        }
+        piece.push(clipped.end);
+    }
+    if piece.len() > 1 {
+        pieces.push(LineString::new(piece));
+    }
+}
+
+/// The part of `polygon` inside `rect`, using the Sutherland–Hodgman algorithm on each of its
+/// rings, or `None` if nothing is left of its exterior.
+fn clip_polygon<T: GeoFloat>(polygon: &Polygon<T>, rect: &Rect<T>) -> Option<Polygon<T>> {
+    match overlap(polygon.bounding_rect(), rect) {
+        Overlap::Outside => return None,
+        Overlap::Inside => return Some(polygon.clone()),
+        Overlap::Partial => {}
+    }
+
+    let exterior = clip_ring(polygon.exterior(), rect)?;
+    let interiors = polygon
+        .interiors()
+        .iter()
+        .filter_map(|ring| match overlap(ring.bounding_rect(), rect) {
+            Overlap::Outside => None,
+            Overlap::Inside => Some(ring.clone()),
+            Overlap::Partial => clip_ring(ring, rect),
+        })
+        .collect();
+    Some(Polygon::new(exterior, interiors))
+}
+
+/// The part of the closed `ring` inside `rect`, or `None` if it has no area.
+fn clip_ring<T: GeoFloat>(ring: &LineString<T>, rect: &Rect<T>) -> Option<LineString<T>> {
+    let (min, max) = (rect.min(), rect.max());
+    // the closing coordinate is implied while clipping
+    let mut coords = match ring.0.split_last() {
+        Some((last, rest)) if rest.first() == Some(last) => rest.to_vec(),
+        _ => ring.0.clone(),
+    };
+
+    coords = clip_ring_to_boundary(&coords, |c| c.x >= min.x, |a, b| at_x(a, b, min.x));
+    coords = clip_ring_to_boundary(&coords, |c| c.x <= max.x, |a, b| at_x(a, b, max.x));
+    coords = clip_ring_to_boundary(&coords, |c| c.y >= min.y, |a, b| at_y(a, b, min.y));
+    coords = clip_ring_to_boundary(&coords, |c| c.y <= max.y, |a, b| at_y(a, b, max.y));
+
+    let mut clipped = LineString::new(coords);
+    clipped.close();
+    if twice_signed_ring_area(&clipped) == T::zero() {
+        return None;
+    }
+    Some(clipped)
+}
+
+/// One step of Sutherland–Hodgman: the part of the (open) ring `coords` on the `inside` of a
+/// single boundary, where `crossing` finds the point at which an edge crosses that boundary.
+fn clip_ring_to_boundary<T: GeoFloat>(
+    coords: &[Coord<T>],
+    inside: impl Fn(Coord<T>) -> bool,
+    crossing: impl Fn(Coord<T>, Coord<T>) -> Coord<T>,
+) -> Vec<Coord<T>> {
+    let mut clipped = Vec::with_capacity(coords.len() + 2);
+    let mut previous = match coords.last() {
+        Some(&previous) => previous,
+        None => return clipped,
+    };
+    for &current in coords {
+        match (inside(previous), inside(current)) {
+            (true, true) => clipped.push(current),
+            (true, false) => clipped.push(crossing(previous, current)),
+            (false, true) => {
+                clipped.push(crossing(previous, current));
+                clipped.push(current);
+            }
+            (false, false) => {}
+        }
+        previous = current;
+    }
+    clipped
+}
+
+/// The point at which the edge from `a` to `b` crosses the vertical line through `x`.
+fn at_x<T: GeoFloat>(a: Coord<T>, b: Coord<T>, x: T) -> Coord<T> {
+    let t = (x - a.x) / (b.x - a.x);
+    Coord {
+        x,
+        y: a.y + (b.y - a.y) * t,
+    }
+}
+
+/// The point at which the edge from `a` to `b` crosses the horizontal line through `y`.
+fn at_y<T: GeoFloat>(a: Coord<T>, b: Coord<T>, y: T) -> Coord<T> {
+    let t = (y - a.y) / (b.y - a.y);
+    Coord {
+        x: a.x + (b.x - a.x) * t,
+        y,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, Area, BooleanOps};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn tile() -> Rect<f64> {
+        Rect::new((0., 0.), (10., 10.))
+    }
+
+    #[test]
+    fn clip_line() {
+        let line = Line::new((-5., -5.), (15., 15.));
+        assert_eq!(
+            line.clip_to_rect(&tile()).0,
+            vec![line_string![(x: 0., y: 0.), (x: 10., y: 10.)]]
+        );
+
+        // outside, but with a bounding rect which overlaps the tile
+        let line = Line::new((-5., 6.), (6., 15.));
+        assert!(line.clip_to_rect(&tile()).0.is_empty());
+
+        // only touching a corner
+        let line = Line::new((-5., 5.), (5., 15.));
+        assert!(line.clip_to_rect(&tile()).0.is_empty());
+
+        // along the boundary
+        let line = Line::new((-5., 10.), (5., 10.));
+        assert_eq!(
+            line.clip_to_rect(&tile()).0,
+            vec![line_string![(x: 0., y: 10.), (x: 5., y: 10.)]]
+        );
+    }
+
+    #[test]
+    fn clip_line_string() {
+        // leaving and re-entering the tile
+        let line_string = line_string![
+            (x: 5., y: 5.),
+            (x: 15., y: 5.),
+            (x: 15., y: 8.),
+            (x: 5., y: 8.),
+            (x: 5., y: 12.),
+        ];
+        assert_eq!(
+            line_string.clip_to_rect(&tile()).0,
+            vec![
+                line_string![(x: 5., y: 5.), (x: 10., y: 5.)],
+                line_string![(x: 10., y: 8.), (x: 5., y: 8.), (x: 5., y: 10.)],
+            ]
+        );
+
+        let multi = MultiLineString::new(vec![
+            line_string.clone(),
+            line_string![(x: 20., y: 20.), (x: 30., y: 30.)],
+            line_string![(x: 1., y: 1.), (x: 2., y: 2.)],
+        ]);
+        assert_eq!(multi.clip_to_rect(&tile()).0.len(), 3);
+    }
+
+    #[test]
+    fn inside_and_outside() {
+        let line_string = line_string![(x: 1., y: 1.), (x: 9., y: 1.), (x: 9., y: 9.)];
+        assert_eq!(
+            line_string.clip_to_rect(&tile()).0,
+            vec![line_string.clone()]
+        );
+
+        let polygon = polygon![(x: 1., y: 1.), (x: 9., y: 1.), (x: 9., y: 9.)];
+        assert_eq!(polygon.clip_to_rect(&tile()).0, vec![polygon.clone()]);
+
+        let far = Rect::new((20., 20.), (30., 30.));
+        assert!(line_string.clip_to_rect(&far).0.is_empty());
+        assert!(polygon.clip_to_rect(&far).0.is_empty());
+
+        let empty = LineString::<f64>::new(vec![]);
+        assert!(empty.clip_to_rect(&tile()).0.is_empty());
+        let empty = Polygon::<f64>::new(empty, vec![]);
+        assert!(empty.clip_to_rect(&tile()).0.is_empty());
+    }
+
+    #[test]
+    fn clip_polygon_with_holes() {
+        let polygon = polygon!(
+            exterior: [(x: -5., y: -5.), (x: 5., y: -5.), (x: 5., y: 5.), (x: -5., y: 5.)],
+            interiors: [
+                // partly inside the tile
+                [(x: -1., y: -1.), (x: 1., y: -1.), (x: 1., y: 1.), (x: -1., y: 1.)],
+                // outside the tile
+                [(x: -3., y: -3.), (x: -2., y: -3.), (x: -2., y: -2.), (x: -3., y: -2.)],
+                // inside the tile
+                [(x: 2., y: 2.), (x: 3., y: 2.), (x: 3., y: 3.), (x: 2., y: 3.)],
+            ],
+        );
+        let clipped = polygon.clip_to_rect(&tile());
+        assert_eq!(clipped.0.len(), 1);
+        assert_eq!(clipped.0[0].interiors().len(), 2);
+        assert_relative_eq!(clipped.unsigned_area(), 25. - 1. - 1.);
+        assert!(clipped.0[0].exterior().is_closed());
+    }
+
+    #[test]
+    fn clip_concave_polygon() {
+        // a "U" whose arms both stick out of the top of the tile
+        let polygon = polygon![
+            (x: 2., y: 2.),
+            (x: 8., y: 2.),
+            (x: 8., y: 15.),
+            (x: 6., y: 15.),
+            (x: 6., y: 4.),
+            (x: 4., y: 4.),
+            (x: 4., y: 15.),
+            (x: 2., y: 15.),
+        ];
+        let clipped = polygon.clip_to_rect(&tile());
+        assert_eq!(clipped.0.len(), 1);
+        assert_relative_eq!(clipped.unsigned_area(), 6. * 2. + 2. * 2. * 6.);
+        assert!(clipped.0[0]
+            .exterior()
+            .coords()
+            .all(|coord| (0. ..=10.).contains(&coord.x) && (0. ..=10.).contains(&coord.y)));
+    }
+
+    #[test]
+    fn matches_boolean_ops() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut next = move || rng.gen::<f64>();
+
+        for steps in 3..30 {
+            // a star-shaped polygon around the corner of the tile
+            let polygon: Polygon = Polygon::new(
+                (0..steps)
+                    .map(|i| {
+                        let angle = std::f64::consts::TAU * i as f64 / steps as f64;
+                        let radius = 2. + 8. * next();
+                        (10. + radius * angle.cos(), 10. + radius * angle.sin())
+                    })
+                    .collect(),
+                vec![],
+            );
+            let clipped = polygon.clip_to_rect(&tile());
+            let intersection = polygon.intersection(&tile().to_polygon());
+            assert_relative_eq!(
+                clipped.unsigned_area(),
+                intersection.unsigned_area(),
+                epsilon = 1e-9
+            );
+        }
+    }
+}
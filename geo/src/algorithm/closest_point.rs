@@ -24,6 +24,28 @@ use std::iter;
 /// let closest = horizontal_line.closest_point(&p);
 /// assert_eq!(closest, Closest::SinglePoint(Point::new(0.0, 0.0)));
 /// ```
+///
+/// Geometries made of several parts, such as a `LineString`'s segments or a
+/// `GeometryCollection`'s members, return the best of their parts' closest points: the first
+/// `Closest::Intersection`, or else the nearest `Closest::SinglePoint`. If several parts are
+/// equally near, the first of them wins. Parts whose closest point is `Closest::Indeterminate`,
+/// such as zero-length lines, are skipped, so only an empty geometry, or one made of
+/// only such parts, has an indeterminate closest point.
+///
+/// ```rust
+/// # use geo::ClosestPoint;
+/// # use geo::{point, Closest, Geometry, GeometryCollection};
+/// let collection = GeometryCollection::new_from(vec![
+///     Geometry::Point(point!(x: 0.0, y: 1.0)),
+///     Geometry::Point(point!(x: 1.0, y: 0.0)),
+/// ]);
+///
+/// let closest = collection.closest_point(&point!(x: 0.0, y: 0.0));
+/// assert_eq!(closest, Closest::SinglePoint(point!(x: 0.0, y: 1.0)));
+///
+/// let empty = GeometryCollection::<f64>::new_from(vec![]);
+/// assert_eq!(empty.closest_point(&point!(x: 0.0, y: 0.0)), Closest::Indeterminate);
+/// ```
 pub trait ClosestPoint<F: GeoFloat, Rhs = Point<F>> {
     /// Find the closest point between `self` and `p`.
     fn closest_point(&self, p: &Rhs) -> Closest<F>;
@@ -90,7 +112,8 @@ impl<F: GeoFloat> ClosestPoint<F> for Line<F> {
 
 /// A generic function which takes some iterator of points and gives you the
 /// "best" `Closest` it can find. Where "best" is the first intersection or
-/// the `Closest::SinglePoint` which is closest to `p`.
+/// the `Closest::SinglePoint` which is closest to `p`, or the first of those
+/// which are equally close.
 ///
 /// If the iterator is empty, we get `Closest::Indeterminate`.
 fn closest_of<C, F, I>(iter: I, p: Point<F>) -> Closest<F>
@@ -103,7 +126,7 @@ where
 
     for element in iter {
         let got = element.closest_point(&p);
-        best = got.best_of_two(&best, p);
+        best = best.best_of_two(&got, p);
         if matches!(best, Closest::Intersection(_)) {
             // short circuit - nothing can be closer than an intersection
             return best;
@@ -345,4 +368,56 @@ mod tests {
         let result = multi_polygon.closest_point(&point!(x: 10.5, y: 10.5));
         assert_eq!(result, Closest::Intersection(point!(x: 10.5, y: 10.5)));
     }
+
+    #[test]
+    fn equidistant_parts_first_wins() {
+        let p = point!(x: 0.0, y: 0.0);
+        let points = MultiPoint::from(vec![(0.0, 1.0), (1.0, 0.0), (0.0, -1.0)]);
+        assert_eq!(
+            points.closest_point(&p),
+            Closest::SinglePoint(point!(x: 0.0, y: 1.0))
+        );
+
+        let mut reversed = points.clone();
+        reversed.0.reverse();
+        assert_eq!(
+            reversed.closest_point(&p),
+            Closest::SinglePoint(point!(x: 0.0, y: -1.0))
+        );
+    }
+
+    #[test]
+    fn geometry_collection() {
+        let collection = GeometryCollection::new_from(vec![
+            Geometry::Line(Line::new((5.0, 5.0), (5.0, 5.0))),
+            Geometry::Rect(Rect::new((10.0, 0.0), (12.0, 2.0))),
+            Geometry::Triangle(Triangle::from([(0.0, 3.0), (2.0, 3.0), (1.0, 5.0)])),
+        ]);
+
+        // the zero-length line is skipped
+        assert_eq!(
+            collection.closest_point(&point!(x: 5.0, y: 4.0)),
+            Closest::SinglePoint(point!(x: 2.0, y: 3.0))
+        );
+        assert_eq!(
+            collection.closest_point(&point!(x: 11.0, y: 1.0)),
+            Closest::Intersection(point!(x: 11.0, y: 1.0))
+        );
+        assert_eq!(
+            Geometry::GeometryCollection(collection).closest_point(&point!(x: 9.0, y: 1.0)),
+            Closest::SinglePoint(point!(x: 10.0, y: 1.0))
+        );
+
+        let degenerate =
+            GeometryCollection::new_from(vec![Geometry::Line(Line::new((5.0, 5.0), (5.0, 5.0)))]);
+        assert_eq!(
+            degenerate.closest_point(&point!(x: 0.0, y: 0.0)),
+            Closest::Indeterminate
+        );
+        let empty = Geometry::GeometryCollection(GeometryCollection::<f64>::new_from(vec![]));
+        assert_eq!(
+            empty.closest_point(&point!(x: 0.0, y: 0.0)),
+            Closest::Indeterminate
+        );
+    }
 }
@@ -0,0 +1,581 @@
+use std::error;
+use std::fmt;
+
+use rstar::primitives::GeomWithData;
+use rstar::RTree;
+
+use crate::{Coord, EuclideanDistance, GeoFloat, Point};
+
+/// How the centroids of [`k_means`] are chosen before the first iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KMeansInit {
+    /// `k` of the points, chosen uniformly at random.
+    Random,
+    /// [k-means++](https://en.wikipedia.org/wiki/K-means%2B%2B): after the first point, which
+    /// is chosen uniformly at random, each point is chosen with a probability proportional to
+    /// the square of its distance from the nearest point chosen so far. This spreads the
+    /// centroids out, which usually gives better clusters in fewer iterations.
+    KMeansPlusPlus,
+}
+
+/// Options for [`k_means`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KMeansOptions {
+    /// The most times the centroids are moved. Defaults to 100.
+    pub max_iterations: usize,
+    /// The seed of the random choice of the initial centroids: the same points and options
+    /// always give the same clusters. Defaults to 0.
+    pub seed: u64,
+    /// How the initial centroids are chosen. Defaults to [`KMeansInit::KMeansPlusPlus`].
+    pub init: KMeansInit,
+}
+
+impl Default for KMeansOptions {
+    fn default() -> Self {
+        Self {
+            max_iterations: 100,
+            seed: 0,
+            init: KMeansInit::KMeansPlusPlus,
+        }
+    }
+}
+
+/// The clusters found by [`k_means`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct KMeans<T: GeoFloat> {
+    /// The index of the cluster of each point, in the same order as the points.
+    pub assignments: Vec<usize>,
+    /// The centroid of each cluster.
+    pub centroids: Vec<Point<T>>,
+    /// The number of times the centroids were moved. This is
+    /// [`KMeansOptions::max_iterations`] if the clusters were still changing.
+    pub iterations: usize,
+}
+
+/// The number of clusters passed to [`k_means`] is zero, or more than the number of points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidClusterCountError {
+    /// The number of clusters
+    pub k: usize,
+    /// The number of points
+    pub len: usize,
+}
+
+impl fmt::Display for InvalidClusterCountError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "cannot divide {} points into {} clusters",
+            self.len, self.k
+        )
+    }
+}
+
+impl error::Error for InvalidClusterCountError {}
+
+/// Divide `points` into `k` clusters using [k-means clustering](https://en.wikipedia.org/wiki/K-means_clustering).
+///
+/// Starting from `k` of the points, chosen as set out by [`KMeansOptions::init`], each point is
+/// assigned to the cluster with the nearest centroid. Each iteration then moves every centroid
+/// to the [centroid](crate::Centroid) of its cluster, and assigns the points again, stopping
+/// once no point changes cluster, or after [`KMeansOptions::max_iterations`] iterations. The
+/// centroid of a cluster left with no points moves to the point furthest from its own centroid
+/// instead.
+///
+/// The result depends on the initial centroids, so isn't always the best division of the
+/// points, but is the same for the same points and options.
+///
+/// Returns an error if `k` is zero, or more than the number of points.
+///
+/// # Examples
+///
+/// ```
+/// use geo::clustering::{k_means, KMeansOptions};
+/// use geo::point;
+///
+/// let points = vec![
+///     point!(x: 0., y: 0.),
+///     point!(x: 1., y: 0.),
+///     point!(x: 0., y: 1.),
+///     point!(x: 10., y: 10.),
+///     point!(x: 11., y: 10.),
+/// ];
+///
+/// let clusters = k_means(&points, 2, &KMeansOptions::default()).unwrap();
+/// let a = clusters.assignments[0];
+/// let b = clusters.assignments[3];
+/// assert_ne!(a, b);
+/// assert_eq!(clusters.assignments, vec![a, a, a, b, b]);
+/// assert_eq!(clusters.centroids[b], point!(x: 10.5, y: 10.));
+///
+/// assert!(k_means(&points, 6, &KMeansOptions::default()).is_err());
+/// ```
+pub fn k_means<T: GeoFloat>(
+    points: &[Point<T>],
+    k: usize,
+    options: &KMeansOptions,
+) -> Result<KMeans<T>, InvalidClusterCountError> {
+    if k == 0 || k > points.len() {
+        return Err(InvalidClusterCountError {
+            k,
+            len: points.len(),
+        });
+    }
+
+    let mut rng = SplitMix64(options.seed);
+    let mut centroids = match options.init {
+        KMeansInit::Random => random_init(points, k, &mut rng),
+        KMeansInit::KMeansPlusPlus => k_means_plus_plus_init(points, k, &mut rng),
+    };
+
+    let mut assignments = assign(points, &centroids);
+    let mut iterations = 0;
+    while iterations < options.max_iterations {
+        iterations += 1;
+        update_centroids(points, &assignments, &mut centroids);
+        let next = assign(points, &centroids);
+        if next == assignments {
+            break;
+        }
+        assignments = next;
+    }
+
+    Ok(KMeans {
+        assignments,
+        centroids,
+        iterations,
+    })
+}
+
+/// The index of the centroid nearest to each point, the first if there's a tie.
+fn assign<T: GeoFloat>(points: &[Point<T>], centroids: &[Point<T>]) -> Vec<usize> {
+    points
+        .iter()
+        .map(|point| nearest(centroids, point))
+        .collect()
+}
+
+fn nearest<T: GeoFloat>(centroids: &[Point<T>], point: &Point<T>) -> usize {
+    centroids
+        .iter()
+        .map(|centroid| centroid.euclidean_distance(point))
+        .enumerate()
+        .fold(
+            (0, T::infinity()),
+            |(best, best_distance), (i, distance)| {
+                if distance < best_distance {
+                    (i, distance)
+                } else {
+                    (best, best_distance)
+                }
+            },
+        )
+        .0
+}
+
+fn update_centroids<T: GeoFloat>(
+    points: &[Point<T>],
+    assignments: &[usize],
+    centroids: &mut [Point<T>],
+) {
+    let mut sums = vec![(Coord::zero(), 0usize); centroids.len()];
+    for (point, &assignment) in points.iter().zip(assignments) {
+        let (sum, count) = &mut sums[assignment];
+        *sum = *sum + point.0;
+        *count += 1;
+    }
+    for (centroid, (sum, count)) in centroids.iter_mut().zip(&sums) {
+        if *count > 0 {
+            *centroid = Point::from(*sum / T::from(*count).unwrap());
+        }
+    }
+    // an empty cluster takes the point which fits its own cluster worst
+    for (cluster, _) in sums
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, count))| *count == 0)
+    {
+        let furthest = points
+            .iter()
+            .zip(assignments)
+            .map(|(point, &assignment)| point.euclidean_distance(&centroids[assignment]))
+            .enumerate()
+            .fold(
+                (0, T::neg_infinity()),
+                |(best, best_distance), (i, distance)| {
+                    if distance > best_distance {
+                        (i, distance)
+                    } else {
+                        (best, best_distance)
+                    }
+                },
+            )
+            .0;
+        centroids[cluster] = points[furthest];
+    }
+}
+
+fn random_init<T: GeoFloat>(points: &[Point<T>], k: usize, rng: &mut SplitMix64) -> Vec<Point<T>> {
+    // the start of a Fisher–Yates shuffle of the indices
+    let mut indices: Vec<usize> = (0..points.len()).collect();
+    for i in 0..k {
+        let j = i + rng.below(points.len() - i);
+        indices.swap(i, j);
+    }
+    indices[..k].iter().map(|&i| points[i]).collect()
+}
+
+fn k_means_plus_plus_init<T: GeoFloat>(
+    points: &[Point<T>],
+    k: usize,
+    rng: &mut SplitMix64,
+) -> Vec<Point<T>> {
+    let mut centroids = vec![points[rng.below(points.len())]];
+    // the square of the distance from each point to the nearest centroid so far
+    let mut weights: Vec<T> = points
+        .iter()
+        .map(|point| point.euclidean_distance(&centroids[0]).powi(2))
+        .collect();
+    while centroids.len() < k {
+        let total = weights
+            .iter()
+            .fold(T::zero(), |total, &weight| total + weight);
+        let chosen = if total > T::zero() {
+            let mut target = T::from(rng.next_f64()).unwrap() * total;
+            weights
+                .iter()
+                .position(|&weight| {
+                    target = target - weight;
+                    weight > T::zero() && target < T::zero()
+                })
+                // rounding may leave a little of the target over
+                .unwrap_or_else(|| {
+                    weights
+                        .iter()
+                        .rposition(|weight| *weight > T::zero())
+                        .unwrap()
+                })
+        } else {
+            // every point is on a centroid already
+            rng.below(points.len())
+        };
+        let centroid = points[chosen];
+        for (point, weight) in points.iter().zip(weights.iter_mut()) {
+            *weight = weight.min(point.euclidean_distance(&centroid).powi(2));
+        }
+        centroids.push(centroid);
+    }
+    centroids
+}
+
+/// Divide `points` into clusters using [DBSCAN](https://en.wikipedia.org/wiki/DBSCAN), leaving
+/// out the points which are noise.
+///
+/// Based on: Ester, M., Kriegel, H.-P., Sander, J., and Xu, X. (1996). *A density-based
+/// algorithm for discovering clusters in large spatial databases with noise.* In Proceedings of
+/// the Second International Conference on Knowledge Discovery and Data Mining, pages 226-231.
+///
+/// A point with at least `min_points` points, counting itself, within a distance of `eps` is a
+/// core point. A cluster is made up of core points which are within `eps` of one another, along
+/// with any other points within `eps` of one of them. The points left over are noise, which
+/// makes this a density-based alternative to [`OutlierDetection`](crate::OutlierDetection).
+/// Unlike [`k_means`], the number of clusters doesn't need to be known beforehand.
+///
+/// Returns the index of the cluster of each point, in the same order as the points, or `None`
+/// for noise. The clusters are numbered from 0, in the order of their first point. A point
+/// within `eps` of the core points of two clusters is put in the first.
+///
+/// Neighbours are found with an R-tree of the points, so this takes around `O(n log n)` time
+/// for `n` points when few points are within `eps` of each.
+///
+/// # Examples
+///
+/// ```
+/// use geo::clustering::dbscan;
+/// use geo::point;
+///
+/// let points = vec![
+///     point!(x: 0., y: 0.),
+///     point!(x: 1., y: 0.),
+///     point!(x: 0., y: 1.),
+///     point!(x: 5., y: 5.),
+///     point!(x: 10., y: 10.),
+///     point!(x: 11., y: 10.),
+///     point!(x: 10., y: 11.),
+/// ];
+///
+/// let labels = dbscan(&points, 1.5, 3);
+/// assert_eq!(
+///     labels,
+///     vec![Some(0), Some(0), Some(0), None, Some(1), Some(1), Some(1)]
+/// );
+///
+/// let noise: Vec<usize> = (0..points.len()).filter(|i| labels[*i].is_none()).collect();
+/// assert_eq!(noise, vec![3]);
+/// ```
+pub fn dbscan<T: GeoFloat>(points: &[Point<T>], eps: T, min_points: usize) -> Vec<Option<usize>> {
+    let tree = RTree::bulk_load(
+        points
+            .iter()
+            .enumerate()
+            .map(|(index, point)| GeomWithData::new(*point, index))
+            .collect(),
+    );
+    let neighbours = |point: &Point<T>| -> Vec<usize> {
+        tree.locate_within_distance(*point, eps * eps)
+            .map(|neighbour| neighbour.data)
+            .collect()
+    };
+
+    let mut labels = vec![None; points.len()];
+    let mut visited = vec![false; points.len()];
+    let mut clusters = 0;
+    for (index, point) in points.iter().enumerate() {
+        if visited[index] {
+            continue;
+        }
+        visited[index] = true;
+        let mut queue = neighbours(point);
+        if queue.len() < min_points {
+            // noise, unless it's later found to be near a core point
+            continue;
+        }
+
+        let cluster = clusters;
+        clusters += 1;
+        labels[index] = Some(cluster);
+        while let Some(neighbour) = queue.pop() {
+            if labels[neighbour].is_none() {
+                labels[neighbour] = Some(cluster);
+            }
+            if visited[neighbour] {
+                continue;
+            }
+            visited[neighbour] = true;
+            let next = neighbours(&points[neighbour]);
+            if next.len() >= min_points {
+                queue.extend(next);
+            }
+        }
+    }
+    labels
+}
+
+/// A small, fast pseudorandom number generator, so that the clusters depend only on the seed.
+///
+/// See <https://prng.di.unimi.it/splitmix64.c>.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// A number in `[0, n)`.
+    fn below(&mut self, n: usize) -> usize {
+        ((self.next_f64() * n as f64) as usize).min(n - 1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, Centroid, MultiPoint};
+    use approx::assert_relative_eq;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// Three tight groups of points around (0, 0), (10, 0) and (5, 10).
+    fn three_groups() -> Vec<Point> {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut next = move || rng.gen_range(0..100) as f64 / 100.;
+        [(0., 0.), (10., 0.), (5., 10.)]
+            .iter()
+            .flat_map(|&(x, y)| {
+                (0..20)
+                    .map(|_| point!(x: x + next(), y: y + next()))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn separates_groups() {
+        let points = three_groups();
+        for init in [KMeansInit::Random, KMeansInit::KMeansPlusPlus] {
+            for seed in 0..10 {
+                let options = KMeansOptions {
+                    seed,
+                    init,
+                    ..Default::default()
+                };
+                let clusters = k_means(&points, 3, &options).unwrap();
+                assert!(clusters.iterations < options.max_iterations);
+                // having converged, each centroid is that of its cluster
+                for (cluster, centroid) in clusters.centroids.iter().enumerate() {
+                    let members: MultiPoint = points
+                        .iter()
+                        .zip(&clusters.assignments)
+                        .filter(|(_, assignment)| **assignment == cluster)
+                        .map(|(point, _)| *point)
+                        .collect();
+                    assert_relative_eq!(*centroid, members.centroid().unwrap());
+                }
+                // the groups are far apart, so k-means++ always finds them
+                if init == KMeansInit::KMeansPlusPlus {
+                    for assignments in clusters.assignments.chunks(20) {
+                        assert!(assignments.iter().all(|a| *a == assignments[0]));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn deterministic() {
+        let points = three_groups();
+        for init in [KMeansInit::Random, KMeansInit::KMeansPlusPlus] {
+            let options = KMeansOptions {
+                seed: 42,
+                init,
+                ..Default::default()
+            };
+            let first = k_means(&points, 4, &options).unwrap();
+            assert_eq!(k_means(&points, 4, &options).unwrap(), first);
+        }
+    }
+
+    #[test]
+    fn invalid_k() {
+        let points = vec![point!(x: 0., y: 0.), point!(x: 1., y: 1.)];
+        let options = KMeansOptions::default();
+        assert_eq!(
+            k_means(&points, 0, &options),
+            Err(InvalidClusterCountError { k: 0, len: 2 })
+        );
+        assert_eq!(
+            k_means(&points, 3, &options),
+            Err(InvalidClusterCountError { k: 3, len: 2 })
+        );
+        assert!(k_means::<f64>(&[], 1, &options).is_err());
+    }
+
+    #[test]
+    fn one_cluster_per_point() {
+        let points = vec![
+            point!(x: 0., y: 0.),
+            point!(x: 5., y: 1.),
+            point!(x: 2., y: 7.),
+        ];
+        for init in [KMeansInit::Random, KMeansInit::KMeansPlusPlus] {
+            let options = KMeansOptions {
+                init,
+                ..Default::default()
+            };
+            let clusters = k_means(&points, 3, &options).unwrap();
+            let mut assignments = clusters.assignments.clone();
+            assignments.sort_unstable();
+            assert_eq!(assignments, vec![0, 1, 2]);
+            for (point, assignment) in points.iter().zip(&clusters.assignments) {
+                assert_eq!(clusters.centroids[*assignment], *point);
+            }
+        }
+    }
+
+    #[test]
+    fn duplicate_points() {
+        // k-means++ runs out of distinct points, and some clusters start out empty
+        let points = vec![point!(x: 1., y: 1.); 5];
+        let clusters = k_means(&points, 3, &KMeansOptions::default()).unwrap();
+        assert_eq!(clusters.centroids, vec![point!(x: 1., y: 1.); 3]);
+        assert!(clusters.assignments.iter().all(|a| *a == 0));
+    }
+
+    #[test]
+    fn max_iterations() {
+        let points = three_groups();
+        let options = KMeansOptions {
+            max_iterations: 1,
+            ..Default::default()
+        };
+        let clusters = k_means(&points, 3, &options).unwrap();
+        assert_eq!(clusters.iterations, 1);
+        assert_eq!(clusters.assignments.len(), points.len());
+
+        let options = KMeansOptions {
+            max_iterations: 0,
+            ..Default::default()
+        };
+        assert_eq!(k_means(&points, 3, &options).unwrap().iterations, 0);
+    }
+
+    /// Two groups of points, with noise around and between them.
+    fn two_groups_and_noise() -> (Vec<Point>, Vec<Option<usize>>) {
+        let mut points = vec![];
+        let mut labels = vec![];
+        for (cluster, (x, y)) in [(0., 0.), (20., 5.)].into_iter().enumerate() {
+            for i in 0..5 {
+                for j in 0..5 {
+                    points.push(point!(x: x + i as f64 * 0.5, y: y + j as f64 * 0.5));
+                    labels.push(Some(cluster));
+                }
+            }
+        }
+        for (x, y) in [(10., 2.), (-5., -5.), (30., 0.), (2., 8.)] {
+            points.push(point!(x: x, y: y));
+            labels.push(None);
+        }
+        (points, labels)
+    }
+
+    #[test]
+    fn dbscan_two_groups_and_noise() {
+        let (points, expected) = two_groups_and_noise();
+        assert_eq!(dbscan(&points, 0.8, 4), expected);
+
+        // the order of the points decides the order of the clusters
+        let reversed: Vec<Point> = points.iter().rev().copied().collect();
+        let labels = dbscan(&reversed, 0.8, 4);
+        let swapped: Vec<Option<usize>> = expected
+            .iter()
+            .rev()
+            .map(|label| label.map(|cluster| 1 - cluster))
+            .collect();
+        assert_eq!(labels, swapped);
+    }
+
+    #[test]
+    fn dbscan_border_points() {
+        // the last point is within `eps` of the core point at the end of the line, but isn't a
+        // core point itself
+        let points = vec![
+            point!(x: 0., y: 0.),
+            point!(x: 1., y: 0.),
+            point!(x: 2., y: 0.),
+            point!(x: 3., y: 0.),
+            point!(x: 4., y: 0.),
+        ];
+        assert_eq!(dbscan(&points, 1., 3), vec![Some(0); 5]);
+        // with nothing dense enough, every point is noise
+        assert_eq!(dbscan(&points, 1., 4), vec![None; 5]);
+        // a point is always within `eps` of itself
+        assert_eq!(
+            dbscan(&points, 0.5, 1),
+            vec![Some(0), Some(1), Some(2), Some(3), Some(4)]
+        );
+    }
+
+    #[test]
+    fn dbscan_empty() {
+        assert_eq!(dbscan::<f64>(&[], 1., 3), vec![]);
+    }
+}
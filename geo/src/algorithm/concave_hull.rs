@@ -1,10 +1,11 @@
 use crate::convex_hull::qhull;
-use crate::utils::partial_min;
+use crate::utils::{lex_cmp, partial_min};
 use crate::{
     coord, Centroid, Coord, CoordNum, EuclideanDistance, EuclideanLength, GeoFloat, Line,
     LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon,
 };
 use rstar::{RTree, RTreeNum};
+use std::cmp::Ordering;
 use std::collections::VecDeque;
 
 /// Returns a polygon which covers a geometry. Unlike convex hulls, which also cover
@@ -16,6 +17,15 @@ use std::collections::VecDeque;
 /// and also uses ideas from the following paper:
 /// www.iis.sinica.edu.tw/page/jise/2012/201205_10.pdf
 ///
+/// The `concavity` controls how far the hull may bend inwards: smaller values give tighter,
+/// more concave hulls, and larger ones approach the convex hull. Where several points are
+/// equally good candidates, the hull is built from the lowest by `x`, then `y`, so the same
+/// points always give the same hull.
+///
+/// # Panics
+///
+/// Panics if `concavity` isn't positive.
+///
 /// # Examples
 /// ```
 /// use geo::{line_string, polygon};
@@ -131,12 +141,16 @@ where
     match peek {
         None => None,
         Some(&point) => {
+            // Candidates come out of the trees in an order that depends on how they were built,
+            // so ties in distance are broken by coordinate to keep the hull deterministic.
             let closest_point =
                 candidates.fold(Point::new(point.x, point.y), |acc_point, candidate| {
                     let candidate_point = Point::new(candidate.x, candidate.y);
-                    if line.euclidean_distance(&acc_point)
-                        > line.euclidean_distance(&candidate_point)
-                    {
+                    let ordering = line
+                        .euclidean_distance(&candidate_point)
+                        .partial_cmp(&line.euclidean_distance(&acc_point))
+                        .map(|ordering| ordering.then_with(|| lex_cmp(candidate, &acc_point.0)));
+                    if ordering == Some(Ordering::Less) {
                         candidate_point
                     } else {
                         acc_point
@@ -154,9 +168,15 @@ where
             let closest_edge_option = match peeked_edge {
                 None => None,
                 Some(&edge) => Some(edges_nearby_point.fold(*edge, |acc, candidate| {
-                    if closest_point.euclidean_distance(&acc)
-                        > closest_point.euclidean_distance(candidate)
-                    {
+                    let ordering = closest_point
+                        .euclidean_distance(candidate)
+                        .partial_cmp(&closest_point.euclidean_distance(&acc))
+                        .map(|ordering| {
+                            ordering
+                                .then_with(|| lex_cmp(&candidate.start, &acc.start))
+                                .then_with(|| lex_cmp(&candidate.end, &acc.end))
+                        });
+                    if ordering == Some(Ordering::Less) {
                         *candidate
                     } else {
                         acc
@@ -191,6 +211,10 @@ fn concave_hull<T>(coords: &mut [Coord<T>], concavity: T) -> LineString<T>
 where
     T: GeoFloat + RTreeNum,
 {
+    assert!(
+        concavity > T::zero(),
+        "concavity must be positive, got {concavity:?}"
+    );
     let hull = qhull::quick_hull(coords);
 
     if coords.len() < 4 {
@@ -446,4 +470,38 @@ mod test {
         ];
         assert_eq!(res.exterior().0, correct);
     }
+
+    #[test]
+    fn input_order_test() {
+        // these have equally close candidates, which used to give a different hull depending
+        // on the order the points were given in
+        let mut v = vec![
+            coord! { x: 0.0, y: 1.0 },
+            coord! { x: 1.0, y: 1.0 },
+            coord! { x: 3.0, y: 0.0 },
+            coord! { x: 3.0, y: 2.0 },
+            coord! { x: 4.0, y: 3.0 },
+        ];
+        let correct = line_string![
+            (x: 3.0, y: 0.0),
+            (x: 4.0, y: 3.0),
+            (x: 0.0, y: 1.0),
+            (x: 1.0, y: 1.0),
+            (x: 3.0, y: 0.0),
+        ];
+        for _ in 0..v.len() {
+            v.rotate_left(1);
+            assert_eq!(concave_hull(&mut v.clone(), 1.0), correct);
+            v.reverse();
+            assert_eq!(concave_hull(&mut v.clone(), 1.0), correct);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "concavity must be positive")]
+    fn non_positive_concavity_test() {
+        let square =
+            polygon![(x: 0.0, y: 0.0), (x: 4.0, y: 0.0), (x: 4.0, y: 4.0), (x: 0.0, y: 4.0)];
+        square.concave_hull(0.0);
+    }
 }
@@ -5,6 +5,12 @@
 /// `self`. In other words, the [DE-9IM] intersection matrix
 /// of `(rhs, self)` is `T*F**F***`.
 ///
+/// So `rhs` may touch the boundary of `self`, as long as some of it lies in the interior: a
+/// `Polygon` contains itself, and a `Line` from one of its vertices into its interior. A
+/// `Point` has no boundary, so a `Point` on the boundary of `self` isn't contained. Use
+/// [`ContainsProperly`](crate::ContainsProperly) to exclude `rhs` touching the boundary, or
+/// [`Covers`](crate::Covers) to include `rhs` lying only on it.
+///
 /// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
 ///
 /// # Examples
@@ -0,0 +1,247 @@
+use crate::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::geometry::*;
+use crate::{geometry_delegate_impl, GeoFloat, GeoNum, HasDimensions, Relate};
+
+/// Checks if `rhs` lies entirely in the interior of `self`, without touching its boundary.
+///
+/// [`Contains`](crate::Contains) follows the OGC definition, which only requires the interiors
+/// to meet and no point of `rhs` to lie outside `self`, so a `Line` from the boundary of a
+/// `Polygon` into its interior is contained, as is the `Polygon` itself. Neither is contained
+/// properly. A `Point` has no boundary, so a `Point` on the boundary of a `Polygon` is neither
+/// contained nor contained properly, and for `Point`s the two are the same. This matters when
+/// tiling, where geometries on a boundary shared by two tiles mustn't be counted in both.
+///
+/// In other words, the [DE-9IM] intersection matrix for `(self, rhs)` is `[T**FF*FF*]`.
+///
+/// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Contains, ContainsProperly};
+/// use geo::{point, polygon, Line};
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+///
+/// assert!(polygon.contains_properly(&point!(x: 1., y: 1.)));
+/// assert!(!polygon.contains_properly(&point!(x: 2., y: 1.)));
+///
+/// // from a vertex into the interior
+/// let line = Line::new((0., 0.), (1., 1.));
+/// assert!(polygon.contains(&line));
+/// assert!(!polygon.contains_properly(&line));
+///
+/// assert!(polygon.contains(&polygon));
+/// assert!(!polygon.contains_properly(&polygon));
+/// ```
+pub trait ContainsProperly<Rhs = Self> {
+    fn contains_properly(&self, rhs: &Rhs) -> bool;
+}
+
+macro_rules! impl_contains_properly_coord_for {
+    ($($for:ty),*) => {
+        $(
+            impl<T> ContainsProperly<Coord<T>> for $for
+            where
+                T: GeoNum,
+            {
+                fn contains_properly(&self, coord: &Coord<T>) -> bool {
+                    self.coordinate_position(coord) == CoordPos::Inside
+                }
+            }
+
+            impl<T> ContainsProperly<Point<T>> for $for
+            where
+                T: GeoNum,
+            {
+                fn contains_properly(&self, point: &Point<T>) -> bool {
+                    self.contains_properly(&point.0)
+                }
+            }
+
+            impl<T> ContainsProperly<MultiPoint<T>> for $for
+            where
+                T: GeoNum,
+            {
+                fn contains_properly(&self, multi_point: &MultiPoint<T>) -> bool {
+                    !multi_point.is_empty()
+                        && multi_point.iter().all(|point| self.contains_properly(point))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_contains_properly_from_relate {
+    ($($for:ty),*) => {
+        $(
+            impl_contains_properly_from_relate!(@targets $for, [Line<T>, LineString<T>, Polygon<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+        )*
+    };
+    (@targets $for:ty, [$($target:ty),*]) => {
+        $(
+            impl<T> ContainsProperly<$target> for $for
+            where
+                T: GeoFloat,
+            {
+                fn contains_properly(&self, target: &$target) -> bool {
+                    self.relate(target).is_contains_properly()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_contains_properly_geometry_for {
+    ($($for:ty),*) => {
+        $(
+            impl<T> ContainsProperly<Geometry<T>> for $for
+            where
+                T: GeoFloat,
+            {
+                fn contains_properly(&self, geometry: &Geometry<T>) -> bool {
+                    match geometry {
+                        Geometry::Point(g) => self.contains_properly(g),
+                        Geometry::Line(g) => self.contains_properly(g),
+                        Geometry::LineString(g) => self.contains_properly(g),
+                        Geometry::Polygon(g) => self.contains_properly(g),
+                        Geometry::MultiPoint(g) => self.contains_properly(g),
+                        Geometry::MultiLineString(g) => self.contains_properly(g),
+                        Geometry::MultiPolygon(g) => self.contains_properly(g),
+                        Geometry::GeometryCollection(g) => self.contains_properly(g),
+                        Geometry::Rect(g) => self.contains_properly(g),
+                        Geometry::Triangle(g) => self.contains_properly(g),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_contains_properly_coord_for!(
+    Point<T>,
+    Line<T>,
+    LineString<T>,
+    Polygon<T>,
+    MultiPoint<T>,
+    MultiLineString<T>,
+    MultiPolygon<T>,
+    GeometryCollection<T>,
+    Rect<T>,
+    Triangle<T>,
+    Geometry<T>
+);
+impl_contains_properly_from_relate!(
+    Point<T>,
+    Line<T>,
+    LineString<T>,
+    Polygon<T>,
+    MultiPoint<T>,
+    MultiLineString<T>,
+    MultiPolygon<T>,
+    GeometryCollection<T>,
+    Rect<T>,
+    Triangle<T>
+);
+impl_contains_properly_geometry_for!(
+    Point<T>,
+    Line<T>,
+    LineString<T>,
+    Polygon<T>,
+    MultiPoint<T>,
+    MultiLineString<T>,
+    MultiPolygon<T>,
+    GeometryCollection<T>,
+    Rect<T>,
+    Triangle<T>,
+    Geometry<T>
+);
+
+// ┌──────────────────────────────┐
+// │ Implementations for Geometry │
+// └──────────────────────────────┘
+
+macro_rules! impl_contains_properly_for_geometry {
+    ($($target:ty),*) => {
+        $(
+            impl<T> ContainsProperly<$target> for Geometry<T>
+            where
+                T: GeoFloat,
+            {
+                geometry_delegate_impl! {
+                    fn contains_properly(&self, target: &$target) -> bool;
+                }
+            }
+        )*
+    };
+}
+
+impl_contains_properly_for_geometry!(
+    Line<T>,
+    LineString<T>,
+    Polygon<T>,
+    MultiLineString<T>,
+    MultiPolygon<T>,
+    GeometryCollection<T>,
+    Rect<T>,
+    Triangle<T>
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Contains};
+
+    #[test]
+    fn points() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let inside = point!(x: 1., y: 3.);
+        let on_edge = point!(x: 4., y: 2.);
+        let on_vertex = point!(x: 4., y: 4.);
+
+        assert!(polygon.contains_properly(&inside));
+        assert!(!polygon.contains_properly(&on_edge));
+        assert!(!polygon.contains_properly(&on_vertex));
+        assert!(!polygon.contains_properly(&point!(x: 5., y: 2.)));
+        // the same as contains, as points have no boundary
+        for point in [inside, on_edge, on_vertex] {
+            assert_eq!(polygon.contains_properly(&point), polygon.contains(&point));
+            assert_eq!(
+                Geometry::from(polygon.clone()).contains_properly(&Geometry::from(point)),
+                polygon.contains(&point)
+            );
+        }
+
+        let multi_point = MultiPoint::from(vec![inside, on_edge]);
+        assert!(!polygon.contains_properly(&multi_point));
+        assert!(polygon.contains_properly(&MultiPoint::from(vec![inside])));
+        assert!(!polygon.contains_properly(&MultiPoint::<f64>::new(vec![])));
+
+        // the ends of a line string are its boundary
+        let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+        assert!(line_string.contains_properly(&point!(x: 2., y: 0.)));
+        assert!(!line_string.contains_properly(&point!(x: 2., y: 2.)));
+    }
+
+    #[test]
+    fn touching_the_boundary() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let geometries: Vec<(Geometry, bool)> = vec![
+            (Line::new((1., 1.), (3., 3.)).into(), true),
+            // from an edge, and from a vertex, into the interior
+            (Line::new((2., 0.), (2., 2.)).into(), false),
+            (Line::new((0., 0.), (2., 2.)).into(), false),
+            (Rect::new((1., 1.), (3., 3.)).into(), true),
+            (Rect::new((0., 1.), (3., 3.)).into(), false),
+            (polygon.clone().into(), false),
+        ];
+        for (geometry, properly) in &geometries {
+            assert!(polygon.contains(geometry), "{geometry:?}");
+            assert_eq!(
+                polygon.contains_properly(geometry),
+                *properly,
+                "{geometry:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use super::quick_hull;
+use crate::kernels::*;
+use crate::utils::lex_cmp;
+use crate::{Coord, GeoNum, Polygon};
+
+/// Builds the convex hull of points as they arrive, without recomputing it from scratch for
+/// each new point.
+///
+/// Only the corners of the hull so far are kept: a point which falls inside the hull, or on its
+/// boundary, is dropped, and one outside replaces the corners it can see. Each push takes time
+/// in proportion to the number of corners, rather than to the number of points pushed.
+///
+/// [`hull`](Self::hull) gives the same polygon as [`ConvexHull`](crate::ConvexHull) would for
+/// all the points pushed so far.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, ConvexHull, ConvexHullBuilder, MultiPoint};
+///
+/// let points = vec![
+///     point!(x: 0.0, y: 0.0),
+///     point!(x: 4.0, y: 0.0),
+///     point!(x: 1.0, y: 1.0),
+///     point!(x: 4.0, y: 4.0),
+///     point!(x: 0.0, y: 4.0),
+///     point!(x: 2.0, y: 5.0),
+/// ];
+///
+/// let mut builder = ConvexHullBuilder::new();
+/// for point in &points {
+///     builder.push(*point);
+/// }
+///
+/// assert_eq!(builder.hull(), MultiPoint::new(points).convex_hull());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConvexHullBuilder<T: GeoNum> {
+    /// The corners of the hull, counter-clockwise, with no three in a line.
+    corners: Vec<Coord<T>>,
+    pushed: usize,
+}
+
+impl<T: GeoNum> Default for ConvexHullBuilder<T> {
+    fn default() -> Self {
+        Self {
+            corners: vec![],
+            pushed: 0,
+        }
+    }
+}
+
+impl<T: GeoNum> ConvexHullBuilder<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a point, growing the hull to cover it if need be.
+    pub fn push<C: Into<Coord<T>>>(&mut self, coord: C) {
+        let coord = coord.into();
+        self.pushed += 1;
+        match self.corners[..] {
+            [] => self.corners.push(coord),
+            [a] => {
+                if a != coord {
+                    self.corners.push(coord)
+                }
+            }
+            [a, b] => match T::Ker::orient2d(a, b, coord) {
+                Orientation::CounterClockwise => self.corners.push(coord),
+                Orientation::Clockwise => self.corners.insert(1, coord),
+                Orientation::Collinear => {
+                    // all in a line, so keep the two ends
+                    let mut ends = [a, b, coord];
+                    ends.sort_unstable_by(lex_cmp);
+                    self.corners = vec![ends[0], ends[2]];
+                }
+            },
+            _ => self.push_outside(coord),
+        }
+    }
+
+    /// Replaces the corners which `coord` can see past the hull's edges with `coord` itself, if
+    /// it's outside the hull.
+    fn push_outside(&mut self, coord: Coord<T>) {
+        let n = self.corners.len();
+        let edge = |i: usize| T::Ker::orient2d(self.corners[i], self.corners[(i + 1) % n], coord);
+        // Outside the hull, the edges which don't have `coord` on their left run on from one
+        // another: those it can see, and at either end perhaps one which it's in line with.
+        let seen = match (0..n).find(|i| edge(*i) == Orientation::Clockwise) {
+            Some(seen) => seen,
+            // inside the hull, or on its boundary
+            None => return,
+        };
+        let mut first = seen;
+        while edge((first + n - 1) % n) != Orientation::CounterClockwise {
+            first = (first + n - 1) % n;
+        }
+        let mut last = seen;
+        while edge((last + 1) % n) != Orientation::CounterClockwise {
+            last = (last + 1) % n;
+        }
+
+        // keep the corners from the end of the last edge round to the start of the first
+        let kept = n - (last + n - first) % n;
+        let mut corners = Vec::with_capacity(kept + 1);
+        corners.extend((0..kept).map(|i| self.corners[(last + 1 + i) % n]));
+        corners.push(coord);
+        self.corners = corners;
+    }
+
+    /// The convex hull of the points pushed so far.
+    pub fn hull(&self) -> Polygon<T> {
+        let mut coords = self.corners.clone();
+        // `ConvexHull` orders the hull of fewer than four points differently
+        if self.pushed >= 4 {
+            while coords.len() < 4 {
+                coords.push(coords[0]);
+            }
+        }
+        Polygon::new(quick_hull(&mut coords), vec![])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, ConvexHull, LineString, MultiPoint, Point};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn assert_same_hull_as_convex_hull(coords: &[Coord<i64>]) {
+        let mut builder = ConvexHullBuilder::new();
+        for (i, coord) in coords.iter().enumerate() {
+            builder.push(*coord);
+            let so_far = MultiPoint::from(coords[..=i].to_vec()).convex_hull();
+            assert_eq!(builder.hull(), so_far, "{:?}", &coords[..=i]);
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let builder = ConvexHullBuilder::<f64>::new();
+        assert_eq!(
+            builder.hull(),
+            Polygon::new(LineString::new(vec![]), vec![])
+        );
+    }
+
+    #[test]
+    fn square() {
+        assert_same_hull_as_convex_hull(&[
+            coord! { x: 2, y: 2 },
+            coord! { x: 0, y: 0 },
+            coord! { x: 4, y: 0 },
+            coord! { x: 2, y: 0 },
+            coord! { x: 4, y: 4 },
+            coord! { x: 1, y: 3 },
+            coord! { x: 0, y: 4 },
+            coord! { x: 4, y: 2 },
+        ]);
+    }
+
+    #[test]
+    fn in_a_line() {
+        assert_same_hull_as_convex_hull(&[
+            coord! { x: 1, y: 1 },
+            coord! { x: 1, y: 1 },
+            coord! { x: 2, y: 2 },
+            coord! { x: 0, y: 0 },
+            coord! { x: 1, y: 1 },
+            coord! { x: 3, y: 3 },
+            coord! { x: 3, y: 0 },
+        ]);
+        assert_same_hull_as_convex_hull(&[coord! { x: 1, y: 1 }; 5]);
+    }
+
+    #[test]
+    fn growing_along_an_edge() {
+        // each point is in line with an edge of the hull so far
+        assert_same_hull_as_convex_hull(&[
+            coord! { x: 0, y: 0 },
+            coord! { x: 1, y: 0 },
+            coord! { x: 0, y: 1 },
+            coord! { x: 2, y: 0 },
+            coord! { x: 3, y: 0 },
+            coord! { x: 0, y: 3 },
+            coord! { x: 3, y: 3 },
+            coord! { x: -1, y: 3 },
+        ]);
+    }
+
+    #[test]
+    fn random_points() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut next = move || rng.gen_range(0..20i64);
+        for _ in 0..50 {
+            let coords: Vec<Coord<i64>> =
+                (0..40).map(|_| coord! { x: next(), y: next() }).collect();
+            assert_same_hull_as_convex_hull(&coords);
+        }
+    }
+
+    #[test]
+    fn points() {
+        let mut builder = ConvexHullBuilder::new();
+        builder.push(Point::new(0.5, 0.5));
+        builder.push(Point::new(1.5, 0.5));
+        builder.push(Point::new(0.5, 1.5));
+        assert_eq!(
+            builder.hull(),
+            MultiPoint::from(vec![(0.5, 0.5), (1.5, 0.5), (0.5, 1.5)]).convex_hull()
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use super::{swap_remove_to_first, trivial_hull};
+use super::trivial_hull;
 use crate::kernels::*;
 use crate::{Coord, GeoNum, LineString};
 
@@ -18,7 +18,7 @@ use crate::{Coord, GeoNum, LineString};
 /// [doi:10.1016/0020-0190(72)90045-2](https://doi.org/10.1016%2F0020-0190%2872%2990045-2)
 ///
 /// [Graham's scan]: //en.wikipedia.org/wiki/Graham_scan
-pub fn graham_hull<T>(mut points: &mut [Coord<T>], include_on_hull: bool) -> LineString<T>
+pub fn graham_hull<T>(points: &mut [Coord<T>], include_on_hull: bool) -> LineString<T>
 where
     T: GeoNum,
 {
@@ -27,6 +27,31 @@ where
         return trivial_hull(points, include_on_hull);
     }
 
+    let mut output: LineString<T> = graham_hull_indices(points, include_on_hull)
+        .into_iter()
+        .map(|idx| points[idx])
+        .collect();
+
+    // Close and output the line string
+    output.close();
+    output
+}
+
+/// The indices of the points on the convex hull, found with
+/// the [Graham's scan] as in [`graham_hull`], without closing
+/// the hull: counter-clockwise from the lexicographically
+/// least point. Unless `include_on_hull` is set, only the
+/// first of any repeated points is given.
+///
+/// [Graham's scan]: //en.wikipedia.org/wiki/Graham_scan
+pub(crate) fn graham_hull_indices<T>(points: &[Coord<T>], include_on_hull: bool) -> Vec<usize>
+where
+    T: GeoNum,
+{
+    if points.is_empty() {
+        return vec![];
+    }
+
     // Allocate output vector
     let mut output = Vec::with_capacity(points.len());
 
@@ -34,27 +59,36 @@ where
     use crate::utils::least_index;
     use std::cmp::Ordering;
     let min_idx = least_index(points);
-    let head = swap_remove_to_first(&mut points, min_idx);
-    output.push(*head);
+    let head = points[min_idx];
+    output.push(min_idx);
 
     // Sort rest of the points by angle it makes with head
     // point. If two points are collinear with head, we sort
-    // by distance. We use kernel predicates here.
-    let cmp = |q: &Coord<T>, r: &Coord<T>| match T::Ker::orient2d(*q, *head, *r) {
+    // by distance. We use kernel predicates here. The sort is
+    // stable, so repeated points stay in their input order.
+    let cmp = |q: &usize, r: &usize| match T::Ker::orient2d(points[*q], head, points[*r]) {
         Orientation::CounterClockwise => Ordering::Greater,
         Orientation::Clockwise => Ordering::Less,
         Orientation::Collinear => {
-            let dist1 = T::Ker::square_euclidean_distance(*head, *q);
-            let dist2 = T::Ker::square_euclidean_distance(*head, *r);
+            let dist1 = T::Ker::square_euclidean_distance(head, points[*q]);
+            let dist2 = T::Ker::square_euclidean_distance(head, points[*r]);
             dist1.partial_cmp(&dist2).unwrap()
         }
     };
-    points.sort_unstable_by(cmp);
+    let mut rest: Vec<usize> = (0..points.len()).filter(|idx| *idx != min_idx).collect();
+    rest.sort_by(cmp);
 
-    for pt in points.iter() {
+    for idx in rest {
+        let pt = points[idx];
+        // Repeated points: keep the first of them, unless all
+        // the points on the hull are to be included. This also
+        // covers the lex. least point added before this loop.
+        if !include_on_hull && pt == points[output[output.len() - 1]] {
+            continue;
+        }
         while output.len() > 1 {
             let len = output.len();
-            match T::Ker::orient2d(output[len - 2], output[len - 1], *pt) {
+            match T::Ker::orient2d(points[output[len - 2]], points[output[len - 1]], pt) {
                 Orientation::CounterClockwise => {
                     break;
                 }
@@ -70,18 +104,8 @@ where
                 }
             }
         }
-        // Corner case: if the lex. least point added before
-        // this loop is repeated, then we should not end up
-        // adding it here (because output.len() == 1 in the
-        // first iteration)
-        if include_on_hull || pt != output.last().unwrap() {
-            output.push(*pt);
-        }
+        output.push(idx);
     }
-
-    // Close and output the line string
-    let mut output = LineString::new(output);
-    output.close();
     output
 }
 
@@ -1,4 +1,4 @@
-use crate::geometry::{Coord, LineString, Polygon};
+use crate::geometry::{Coord, LineString, MultiPoint, Point, Polygon};
 use crate::kernels::*;
 use crate::GeoNum;
 
@@ -59,12 +59,64 @@ where
     }
 }
 
+/// Returns the indices of the points on the convex hull of a collection of points, so the hull
+/// can be traced back to the input, say to carry over attributes of the points.
+///
+/// The indices are in counter-clockwise order around the hull, starting from the lowest point
+/// by `x`, then `y`, and the first index isn't repeated at the end. Like [`ConvexHull`], this is
+/// the strict hull: points which lie on an edge of the hull, between two of its corners, aren't
+/// included. Of repeated points, only the first is given.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, ConvexHullIndices, MultiPoint};
+///
+/// let points = MultiPoint::new(vec![
+///     point!(x: 2.0, y: 2.0),
+///     point!(x: 4.0, y: 4.0),
+///     point!(x: 0.0, y: 4.0),
+///     // on the edge between the first and last corners
+///     point!(x: 0.0, y: 2.0),
+///     point!(x: 1.0, y: 1.0),
+///     point!(x: 4.0, y: 0.0),
+///     point!(x: 0.0, y: 0.0),
+/// ]);
+///
+/// assert_eq!(points.convex_hull_indices(), vec![6, 5, 1, 2]);
+/// ```
+pub trait ConvexHullIndices {
+    fn convex_hull_indices(&self) -> Vec<usize>;
+}
+
+impl<T: GeoNum> ConvexHullIndices for [Coord<T>] {
+    fn convex_hull_indices(&self) -> Vec<usize> {
+        graham::graham_hull_indices(self, false)
+    }
+}
+
+impl<T: GeoNum> ConvexHullIndices for [Point<T>] {
+    fn convex_hull_indices(&self) -> Vec<usize> {
+        let coords: Vec<Coord<T>> = self.iter().map(|point| point.0).collect();
+        coords.convex_hull_indices()
+    }
+}
+
+impl<T: GeoNum> ConvexHullIndices for MultiPoint<T> {
+    fn convex_hull_indices(&self) -> Vec<usize> {
+        self.0.convex_hull_indices()
+    }
+}
+
 pub mod qhull;
 pub use qhull::quick_hull;
 
 pub mod graham;
 pub use graham::graham_hull;
 
+mod builder;
+pub use builder::ConvexHullBuilder;
+
 // Helper function that outputs the convex hull in the
 // trivial case: input with at most 3 points. It ensures the
 // output is ccw, and does not repeat points unless
@@ -78,10 +78,14 @@ where
     // Construct orthogonal vector to `p_b` - `p_a` We
     // compute inner product of this with `v` - `p_a` to
     // find the farthest point from the line segment a-b.
+    // Of points equally far, which lie in a line parallel
+    // to a-b, take the one farthest towards b: the others
+    // can't lie between it and a corner of the hull.
     let p_orth = coord! {
         x: p_a.y - p_b.y,
         y: p_b.x - p_a.x,
     };
+    let p_along = p_b - p_a;
 
     let furthest_idx = set
         .iter()
@@ -90,7 +94,10 @@ where
                 x: pt.x - p_a.x,
                 y: pt.y - p_a.y,
             };
-            p_orth.x * p_diff.x + p_orth.y * p_diff.y
+            (
+                p_orth.x * p_diff.x + p_orth.y * p_diff.y,
+                p_along.x * p_diff.x + p_along.y * p_diff.y,
+            )
         })
         .enumerate()
         .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
@@ -226,4 +233,23 @@ mod test {
         let res = quick_hull(&mut v);
         assert!(res.is_strictly_ccw_convex());
     }
+
+    #[test]
+    fn quick_hull_equally_far_points() {
+        // (1, 0) and (2, 0) are as far below the line from (-1, 3) to (3, 3) as the corners
+        // either side of them, but aren't corners themselves
+        let mut v = vec![
+            coord! { x: 0, y: 0 },
+            coord! { x: 1, y: 0 },
+            coord! { x: 0, y: 1 },
+            coord! { x: 2, y: 0 },
+            coord! { x: 3, y: 0 },
+            coord! { x: 0, y: 3 },
+            coord! { x: 3, y: 3 },
+            coord! { x: -1, y: 3 },
+        ];
+        let res = quick_hull(&mut v);
+        assert!(res.is_strictly_ccw_convex());
+        assert_eq!(res.0.len(), 5);
+    }
 }
@@ -301,3 +301,77 @@ fn collection() {
         ]
     );
 }
+
+#[test]
+fn convex_hull_indices_test() {
+    let points = vec![
+        coord! { x: 1, y: 1 },
+        coord! { x: 4, y: 0 },
+        coord! { x: 4, y: 4 },
+        coord! { x: 2, y: 0 },
+        coord! { x: 0, y: 4 },
+        coord! { x: 0, y: 0 },
+    ];
+    // (2, 0) is on the edge from (0, 0) to (4, 0)
+    assert_eq!(points.convex_hull_indices(), vec![5, 1, 2, 4]);
+
+    let points: Vec<Point<i64>> = points.into_iter().map(Point::from).collect();
+    assert_eq!(points.convex_hull_indices(), vec![5, 1, 2, 4]);
+    assert_eq!(
+        MultiPoint::new(points).convex_hull_indices(),
+        vec![5, 1, 2, 4]
+    );
+}
+
+#[test]
+fn convex_hull_indices_repeated_points_test() {
+    let points = [
+        coord! { x: 4, y: 4 },
+        coord! { x: 0, y: 0 },
+        coord! { x: 4, y: 0 },
+        coord! { x: 0, y: 0 },
+        coord! { x: 4, y: 4 },
+        coord! { x: 0, y: 4 },
+        coord! { x: 4, y: 0 },
+    ];
+    assert_eq!(points.convex_hull_indices(), vec![1, 2, 0, 5]);
+}
+
+#[test]
+fn convex_hull_indices_degenerate_test() {
+    let no_points: Vec<Coord<f64>> = vec![];
+    assert!(no_points.convex_hull_indices().is_empty());
+    assert_eq!([coord! { x: 1., y: 1. }].convex_hull_indices(), vec![0]);
+    let repeated = [coord! { x: 1., y: 1. }, coord! { x: 1., y: 1. }];
+    assert_eq!(repeated.convex_hull_indices(), vec![0]);
+    // all on a line
+    let collinear = [
+        coord! { x: 1., y: 1. },
+        coord! { x: 3., y: 3. },
+        coord! { x: 0., y: 0. },
+        coord! { x: 2., y: 2. },
+    ];
+    assert_eq!(collinear.convex_hull_indices(), vec![2, 1]);
+}
+
+#[test]
+fn convex_hull_indices_match_convex_hull_test() {
+    let points = geo_test_fixtures::poly1::<f64>().0;
+    let hull = points.convex_hull_indices();
+    let mut hull_coords: Vec<Coord<f64>> = hull.iter().map(|idx| points[*idx]).collect();
+    hull_coords.push(hull_coords[0]);
+    let mut correct = LineString::from(points.clone())
+        .convex_hull()
+        .exterior()
+        .0
+        .clone();
+    // the same ring, though it may start from another corner
+    let start = correct
+        .iter()
+        .position(|coord| *coord == hull_coords[0])
+        .unwrap();
+    correct.pop();
+    correct.rotate_left(start);
+    correct.push(correct[0]);
+    assert_eq!(hull_coords, correct);
+}
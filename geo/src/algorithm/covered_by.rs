@@ -0,0 +1,52 @@
+use crate::algorithm::Covers;
+
+/// Tests if a geometry is completely covered by another geometry: no point of it lies outside
+/// the other.
+///
+/// Unlike [`Within`](crate::Within), a geometry lying only on the boundary of the other is
+/// covered by it. In other words, the [DE-9IM] intersection matrix for (Self, Rhs) is one of
+/// `[T*F**F***]`, `[*TF**F***]`, `[**FT*F***]` or `[**F*TF***]`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon};
+/// use geo::algorithm::{CoveredBy, Within};
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let edge_point = point!(x: 2., y: 1.);
+///
+/// assert!(edge_point.is_covered_by(&polygon));
+/// assert!(!edge_point.is_within(&polygon));
+/// ```
+///
+/// `CoveredBy` is equivalent to [`Covers`] with the arguments swapped.
+///
+/// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+pub trait CoveredBy<Other> {
+    fn is_covered_by(&self, b: &Other) -> bool;
+}
+
+impl<G1, G2> CoveredBy<G2> for G1
+where
+    G2: Covers<G1>,
+{
+    fn is_covered_by(&self, b: &G2) -> bool {
+        b.covers(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{line_string, point, Rect};
+
+    #[test]
+    fn basic() {
+        let rect = Rect::new((0.0, 0.0), (3.0, 3.0));
+        assert!(point!(x: 3.0, y: 1.0).is_covered_by(&rect.to_polygon()));
+        assert!(line_string![(x: 0.0, y: 0.0), (x: 3.0, y: 0.0)].is_covered_by(&rect));
+        assert!(rect.is_covered_by(&rect));
+        assert!(!point!(x: 4.0, y: 1.0).is_covered_by(&rect));
+    }
+}
@@ -0,0 +1,435 @@
+use crate::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::geometry::*;
+use crate::{geometry_delegate_impl, Contains, GeoFloat, GeoNum, HasDimensions, Relate};
+
+/// Checks if `rhs` is completely covered by `self`: no point of `rhs` lies outside `self`.
+///
+/// Unlike [`Contains`], this doesn't require the interiors to meet, so a geometry lying only
+/// on the boundary of `self` is covered, though it isn't contained. Every (non-empty) geometry
+/// covers itself. In other words, the [DE-9IM] intersection matrix for `(self, rhs)` is one of
+/// `[T*****FF*]`, `[*T****FF*]`, `[***T**FF*]` or `[****T*FF*]`.
+///
+/// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+///
+/// # Examples
+///
+/// ```
+/// use geo::{Contains, Covers};
+/// use geo::{line_string, point, polygon};
+///
+/// let polygon = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let edge_point = point!(x: 2., y: 1.);
+///
+/// assert!(polygon.covers(&edge_point));
+/// assert!(!polygon.contains(&edge_point));
+///
+/// // the end of a line string is on its boundary
+/// let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.)];
+/// assert!(line_string.covers(&point!(x: 2., y: 0.)));
+/// assert!(line_string.covers(&line_string));
+/// ```
+pub trait Covers<Rhs = Self> {
+    fn covers(&self, rhs: &Rhs) -> bool;
+}
+
+macro_rules! impl_covers_coord_for {
+    ($($for:ty),*) => {
+        $(
+            impl<T> Covers<Coord<T>> for $for
+            where
+                T: GeoNum,
+            {
+                fn covers(&self, coord: &Coord<T>) -> bool {
+                    self.coordinate_position(coord) != CoordPos::Outside
+                }
+            }
+
+            impl<T> Covers<Point<T>> for $for
+            where
+                T: GeoNum,
+            {
+                fn covers(&self, point: &Point<T>) -> bool {
+                    self.covers(&point.0)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_covers_multi_point_for {
+    ($($for:ty),*) => {
+        $(
+            impl<T> Covers<MultiPoint<T>> for $for
+            where
+                T: GeoNum,
+            {
+                fn covers(&self, multi_point: &MultiPoint<T>) -> bool {
+                    !multi_point.is_empty() && multi_point.iter().all(|point| self.covers(point))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_covers_from_relate {
+    ($for:ty, [$($target:ty),*]) => {
+        $(
+            impl<T> Covers<$target> for $for
+            where
+                T: GeoFloat,
+            {
+                fn covers(&self, target: &$target) -> bool {
+                    self.relate(target).is_covers()
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_covers_geometry_for {
+    ($($for:ty),*) => {
+        $(
+            impl<T> Covers<Geometry<T>> for $for
+            where
+                T: GeoFloat,
+            {
+                fn covers(&self, geometry: &Geometry<T>) -> bool {
+                    match geometry {
+                        Geometry::Point(g) => self.covers(g),
+                        Geometry::Line(g) => self.covers(g),
+                        Geometry::LineString(g) => self.covers(g),
+                        Geometry::Polygon(g) => self.covers(g),
+                        Geometry::MultiPoint(g) => self.covers(g),
+                        Geometry::MultiLineString(g) => self.covers(g),
+                        Geometry::MultiPolygon(g) => self.covers(g),
+                        Geometry::GeometryCollection(g) => self.covers(g),
+                        Geometry::Rect(g) => self.covers(g),
+                        Geometry::Triangle(g) => self.covers(g),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_covers_coord_for!(
+    Point<T>,
+    Line<T>,
+    LineString<T>,
+    Polygon<T>,
+    MultiPoint<T>,
+    MultiLineString<T>,
+    MultiPolygon<T>,
+    GeometryCollection<T>,
+    Rect<T>,
+    Triangle<T>,
+    Geometry<T>
+);
+impl_covers_multi_point_for!(
+    MultiPoint<T>,
+    Line<T>,
+    LineString<T>,
+    Polygon<T>,
+    MultiLineString<T>,
+    MultiPolygon<T>,
+    GeometryCollection<T>,
+    Rect<T>,
+    Triangle<T>
+);
+impl_covers_geometry_for!(
+    Point<T>,
+    Line<T>,
+    LineString<T>,
+    Polygon<T>,
+    MultiPoint<T>,
+    MultiLineString<T>,
+    MultiPolygon<T>,
+    GeometryCollection<T>,
+    Rect<T>,
+    Triangle<T>,
+    Geometry<T>
+);
+
+// ┌────────────────────────────────┐
+// │ Implementations for Point      │
+// └────────────────────────────────┘
+
+// A point has no boundary, so covering is the same as containing.
+macro_rules! impl_covers_from_contains_for_point {
+    ($($target:ty),*) => {
+        $(
+            impl<T> Covers<$target> for Point<T>
+            where
+                T: GeoFloat,
+            {
+                fn covers(&self, target: &$target) -> bool {
+                    self.contains(target)
+                }
+            }
+        )*
+    };
+}
+
+impl_covers_from_contains_for_point!(
+    Line<T>,
+    LineString<T>,
+    Polygon<T>,
+    MultiPoint<T>,
+    MultiLineString<T>,
+    MultiPolygon<T>,
+    GeometryCollection<T>,
+    Rect<T>,
+    Triangle<T>
+);
+
+// ┌────────────────────────────────┐
+// │ Implementations for MultiPoint │
+// └────────────────────────────────┘
+
+impl_covers_from_relate!(MultiPoint<T>, [Line<T>, LineString<T>, Polygon<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+
+// ┌──────────────────────────┐
+// │ Implementations for Line │
+// └──────────────────────────┘
+
+impl_covers_from_relate!(Line<T>, [Line<T>, LineString<T>, Polygon<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+
+// ┌────────────────────────────────┐
+// │ Implementations for LineString │
+// └────────────────────────────────┘
+
+impl<T> Covers<Line<T>> for LineString<T>
+where
+    T: GeoNum,
+{
+    fn covers(&self, line: &Line<T>) -> bool {
+        if line.start == line.end {
+            // a degenerate line is a point, which may be an end of the line string
+            self.covers(&line.start)
+        } else {
+            // a line with any length along the line string meets its interior
+            self.contains(line)
+        }
+    }
+}
+
+impl<T> Covers<LineString<T>> for LineString<T>
+where
+    T: GeoNum,
+{
+    fn covers(&self, rhs: &LineString<T>) -> bool {
+        match rhs.0.len() {
+            0 => false,
+            1 => self.covers(&rhs.0[0]),
+            _ => rhs.lines().all(|line| self.covers(&line)),
+        }
+    }
+}
+
+impl_covers_from_relate!(LineString<T>, [Polygon<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+
+// ┌─────────────────────────────────────┐
+// │ Implementations for MultiLineString │
+// └─────────────────────────────────────┘
+
+impl_covers_from_relate!(MultiLineString<T>, [Line<T>, LineString<T>, Polygon<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+
+// ┌───────────────────────────────────────────┐
+// │ Implementations for Polygon, MultiPolygon │
+// └───────────────────────────────────────────┘
+
+impl_covers_from_relate!(Polygon<T>, [Line<T>, LineString<T>, Polygon<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+impl_covers_from_relate!(MultiPolygon<T>, [Line<T>, LineString<T>, Polygon<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+
+// ┌──────────────────────────┐
+// │ Implementations for Rect │
+// └──────────────────────────┘
+
+impl<T> Covers<Rect<T>> for Rect<T>
+where
+    T: GeoNum,
+{
+    fn covers(&self, other: &Rect<T>) -> bool {
+        self.min().x <= other.min().x
+            && self.max().x >= other.max().x
+            && self.min().y <= other.min().y
+            && self.max().y >= other.max().y
+    }
+}
+
+impl_covers_from_relate!(Rect<T>, [Line<T>, LineString<T>, Polygon<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Triangle<T>]);
+
+// ┌──────────────────────────────────────────────────────┐
+// │ Implementations for Triangle and GeometryCollection │
+// └──────────────────────────────────────────────────────┘
+
+impl_covers_from_relate!(Triangle<T>, [Line<T>, LineString<T>, Polygon<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+impl_covers_from_relate!(GeometryCollection<T>, [Line<T>, LineString<T>, Polygon<T>, MultiLineString<T>, MultiPolygon<T>, GeometryCollection<T>, Rect<T>, Triangle<T>]);
+
+// ┌──────────────────────────────┐
+// │ Implementations for Geometry │
+// └──────────────────────────────┘
+
+macro_rules! impl_covers_for_geometry {
+    ($($target:ty),*) => {
+        $(
+            impl<T> Covers<$target> for Geometry<T>
+            where
+                T: GeoFloat,
+            {
+                geometry_delegate_impl! {
+                    fn covers(&self, target: &$target) -> bool;
+                }
+            }
+        )*
+    };
+}
+
+impl_covers_for_geometry!(
+    Line<T>,
+    LineString<T>,
+    Polygon<T>,
+    MultiPoint<T>,
+    MultiLineString<T>,
+    MultiPolygon<T>,
+    GeometryCollection<T>,
+    Rect<T>,
+    Triangle<T>
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point, polygon};
+
+    #[test]
+    fn boundary_is_covered() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let edge_point = point!(x: 2., y: 1.);
+        assert!(polygon.covers(&edge_point));
+        assert!(!polygon.contains(&edge_point));
+        assert!(polygon.covers(&edge_point.0));
+        assert!(!polygon.covers(&point!(x: 3., y: 1.)));
+
+        let edge = Line::new((0., 0.), (2., 0.));
+        assert!(polygon.covers(&edge));
+        assert!(!polygon.contains(&edge));
+        assert!(MultiPolygon::new(vec![polygon.clone()]).covers(&edge));
+        assert!(Rect::new((0., 0.), (2., 2.)).covers(&edge));
+        assert!(Triangle::new(
+            coord! { x: 0., y: 0. },
+            coord! { x: 2., y: 0. },
+            coord! { x: 0., y: 2. }
+        )
+        .covers(&edge));
+        assert!(!Triangle::new(
+            coord! { x: 0., y: 0. },
+            coord! { x: 2., y: 0. },
+            coord! { x: 0., y: 2. }
+        )
+        .covers(&polygon));
+
+        let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+        let end = point!(x: 2., y: 2.);
+        assert!(line_string.covers(&end));
+        assert!(!line_string.contains(&end));
+        assert!(line_string.covers(&Line::new((2., 2.), (2., 2.))));
+        assert!(line_string.covers(&line_string![(x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)]));
+        assert!(!line_string.covers(&line_string![(x: 1., y: 0.), (x: 3., y: 0.)]));
+    }
+
+    #[test]
+    fn covers_itself() {
+        let geometries: Vec<Geometry> = vec![
+            point!(x: 1., y: 1.).into(),
+            Line::new((0., 0.), (1., 1.)).into(),
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)].into(),
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)].into(),
+            MultiPoint::from(vec![(0., 0.), (1., 1.)]).into(),
+            MultiLineString::new(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+                line_string![(x: 1., y: 1.), (x: 2., y: 0.)],
+            ])
+            .into(),
+            MultiPolygon::new(vec![
+                polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+                polygon![(x: 2., y: 0.), (x: 3., y: 0.), (x: 3., y: 1.)],
+            ])
+            .into(),
+            Rect::new((0., 0.), (1., 1.)).into(),
+            Triangle::new(
+                coord! { x: 0., y: 0. },
+                coord! { x: 1., y: 0. },
+                coord! { x: 0., y: 1. },
+            )
+            .into(),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                point!(x: 5., y: 5.).into(),
+                Line::new((0., 0.), (1., 1.)).into(),
+            ])),
+        ];
+        for geometry in &geometries {
+            assert!(geometry.covers(geometry), "{geometry:?}");
+        }
+        assert!(point!(x: 1., y: 1.).covers(&point!(x: 1., y: 1.)));
+        let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+        assert!(line_string.covers(&line_string));
+        let multi_point = MultiPoint::from(vec![(0., 0.), (1., 1.)]);
+        assert!(multi_point.covers(&multi_point));
+    }
+
+    #[test]
+    fn fast_paths_agree_with_relate() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+        let lines = [
+            Line::new((0., 0.), (1., 0.)),
+            Line::new((1., 0.), (2., 1.)),
+            Line::new((2., 2.), (2., 2.)),
+            Line::new((1., 1.), (1., 1.)),
+            Line::new((2., 0.), (2., 3.)),
+        ];
+        for line in &lines {
+            assert_eq!(
+                line_string.covers(line),
+                line_string.relate(line).is_covers(),
+                "{line:?}"
+            );
+        }
+
+        let rect = Rect::new((0., 0.), (2., 2.));
+        let rects = [
+            Rect::new((0., 0.), (2., 2.)),
+            Rect::new((0., 0.), (1., 1.)),
+            Rect::new((1., 0.), (2., 2.)),
+            Rect::new((1., 1.), (3., 3.)),
+        ];
+        for other in &rects {
+            assert_eq!(
+                rect.covers(other),
+                rect.relate(other).is_covers(),
+                "{other:?}"
+            );
+        }
+
+        let polygon = rect.to_polygon();
+        for coord in [
+            coord! { x: 0., y: 1. },
+            coord! { x: 1., y: 1. },
+            coord! { x: 3., y: 1. },
+        ] {
+            let multi_point = MultiPoint::new(vec![coord.into(), point!(x: 2., y: 2.)]);
+            assert_eq!(
+                polygon.covers(&multi_point),
+                polygon.relate(&multi_point).is_covers(),
+                "{coord:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        assert!(!polygon.covers(&MultiPoint::<f64>::new(vec![])));
+        assert!(!polygon.covers(&LineString::<f64>::new(vec![])));
+    }
+}
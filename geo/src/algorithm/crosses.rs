@@ -0,0 +1,352 @@
+use crate::dimensions::{Dimensions, HasDimensions};
+use crate::line_intersection::LineIntersection;
+use crate::sweep::{Cross, Intersections, LineOrPoint};
+use crate::utils::lex_cmp;
+use crate::{
+    BoundingRect, Coord, GeoFloat, Geometry, Intersects, Line, LineString, LinesIter,
+    MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Relate, Triangle,
+};
+
+/// Tests if a geometry crosses another: their interiors meet, in fewer dimensions than the
+/// larger of the two, and each has some of its interior outside the other.
+///
+/// Following the [OGC Simple Features] definition, the crossing is tested with the [DE-9IM]
+/// intersection matrix for (Self, Rhs), which must be:
+/// - `[T*T******]` for points crossing a line or an area
+/// - `[T*****T**]` for a line or an area crossed by points
+/// - `[0********]` for two lines
+/// - `[T*T******]` for a line crossing an area, and `[T*****T**]` the other way around
+///
+/// Two lines which only touch, at an end of either, don't cross, and neither do lines which
+/// share a stretch, however they meet elsewhere. Geometries of the same dimension, other than
+/// lines, never cross.
+///
+/// This is the same as [`IntersectionMatrix::is_crosses`] on the result of [`Relate`]. When
+/// both geometries are lines, it's answered by sweeping over their segments, without computing
+/// the whole matrix.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon, Crosses};
+///
+/// let pipeline = line_string![(x: 0., y: 5.), (x: 20., y: 5.)];
+/// let river = polygon![(x: 8., y: 0.), (x: 12., y: 0.), (x: 12., y: 10.), (x: 8., y: 10.)];
+/// let road = line_string![(x: 15., y: 0.), (x: 15., y: 10.)];
+/// let track = line_string![(x: 0., y: 0.), (x: 0., y: 10.)];
+///
+/// assert!(pipeline.crosses(&river));
+/// assert!(pipeline.crosses(&road));
+/// // the pipeline starts on the track, but doesn't cross it
+/// assert!(!pipeline.crosses(&track));
+/// ```
+///
+/// [OGC Simple Features]: https://www.ogc.org/standards/sfa
+/// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+/// [`IntersectionMatrix::is_crosses`]: crate::relate::IntersectionMatrix::is_crosses
+pub trait Crosses<F, Rhs> {
+    fn crosses(&self, other: &Rhs) -> bool;
+}
+
+fn crosses_by_relate<F, G1, G2>(a: &G1, b: &G2) -> bool
+where
+    F: GeoFloat,
+    G1: Relate<F, G2> + BoundingRect<F> + HasDimensions,
+    G2: BoundingRect<F> + HasDimensions,
+{
+    let dimensions = a.dimensions();
+    if dimensions == Dimensions::Empty
+        || b.is_empty()
+        || (dimensions == b.dimensions() && dimensions != Dimensions::OneDimensional)
+    {
+        return false;
+    }
+    let rects: (Option<Rect<F>>, Option<Rect<F>>) =
+        (a.bounding_rect().into(), b.bounding_rect().into());
+    match rects {
+        (Some(a_rect), Some(b_rect)) if a_rect.intersects(&b_rect) => a.relate(b).is_crosses(),
+        _ => false,
+    }
+}
+
+macro_rules! crosses_by_relate_impl {
+    ($k:ty, [$($t:ty),*]) => {
+        $(
+            impl<F: GeoFloat> Crosses<F, $t> for $k {
+                fn crosses(&self, other: &$t) -> bool {
+                    crosses_by_relate(self, other)
+                }
+            }
+        )*
+    };
+}
+
+crosses_by_relate_impl!(Point<F>, [Line<F>, LineString<F>, MultiLineString<F>, Polygon<F>, MultiPolygon<F>, Rect<F>, Triangle<F>]);
+crosses_by_relate_impl!(MultiPoint<F>, [Line<F>, LineString<F>, MultiLineString<F>, Polygon<F>, MultiPolygon<F>, Rect<F>, Triangle<F>]);
+crosses_by_relate_impl!(Line<F>, [Point<F>, MultiPoint<F>, Polygon<F>, MultiPolygon<F>, Rect<F>, Triangle<F>]);
+crosses_by_relate_impl!(LineString<F>, [Point<F>, MultiPoint<F>, Polygon<F>, MultiPolygon<F>, Rect<F>, Triangle<F>]);
+crosses_by_relate_impl!(MultiLineString<F>, [Point<F>, MultiPoint<F>, Polygon<F>, MultiPolygon<F>, Rect<F>, Triangle<F>]);
+crosses_by_relate_impl!(Polygon<F>, [Point<F>, MultiPoint<F>, Line<F>, LineString<F>, MultiLineString<F>]);
+crosses_by_relate_impl!(MultiPolygon<F>, [Point<F>, MultiPoint<F>, Line<F>, LineString<F>, MultiLineString<F>]);
+crosses_by_relate_impl!(Rect<F>, [Point<F>, MultiPoint<F>, Line<F>, LineString<F>, MultiLineString<F>]);
+crosses_by_relate_impl!(Triangle<F>, [Point<F>, MultiPoint<F>, Line<F>, LineString<F>, MultiLineString<F>]);
+crosses_by_relate_impl!(Geometry<F>, [Geometry<F>]);
+
+/// The lines, line strings and multi line strings, whose crossings can be found by a sweep.
+trait Lineal<F: GeoFloat>: BoundingRect<F> + HasDimensions + Sized {
+    fn segments(&self) -> Vec<Line<F>>;
+
+    /// The ends of the geometry, which are its boundary by the "mod 2" rule: the ends which an
+    /// odd number of its (unclosed) parts share. `None` if any part has no length.
+    fn boundary(&self) -> Option<Vec<Coord<F>>>;
+}
+
+impl<F: GeoFloat> Lineal<F> for Line<F> {
+    fn segments(&self) -> Vec<Line<F>> {
+        vec![*self]
+    }
+
+    fn boundary(&self) -> Option<Vec<Coord<F>>> {
+        (self.start != self.end).then(|| vec![self.start, self.end])
+    }
+}
+
+impl<F: GeoFloat> Lineal<F> for LineString<F> {
+    fn segments(&self) -> Vec<Line<F>> {
+        self.lines_iter().collect()
+    }
+
+    fn boundary(&self) -> Option<Vec<Coord<F>>> {
+        if self.dimensions() != Dimensions::OneDimensional {
+            None
+        } else if self.is_closed() {
+            Some(vec![])
+        } else {
+            Some(vec![self.0[0], self.0[self.0.len() - 1]])
+        }
+    }
+}
+
+impl<F: GeoFloat> Lineal<F> for MultiLineString<F> {
+    fn segments(&self) -> Vec<Line<F>> {
+        self.lines_iter().collect()
+    }
+
+    fn boundary(&self) -> Option<Vec<Coord<F>>> {
+        let mut ends = vec![];
+        for line_string in self {
+            ends.extend(line_string.boundary()?);
+        }
+        ends.sort_by(lex_cmp);
+        let mut boundary: Vec<Coord<F>> = vec![];
+        for end in ends {
+            // remove pairs of the same end as they're found
+            if boundary.last() == Some(&end) {
+                boundary.pop();
+            } else {
+                boundary.push(end);
+            }
+        }
+        Some(boundary)
+    }
+}
+
+/// A segment of one of the two geometries being swept.
+#[derive(Debug, Clone, Copy)]
+struct Segment<F: GeoFloat> {
+    line: Line<F>,
+    of_first: bool,
+}
+
+impl<F: GeoFloat> Cross for Segment<F> {
+    type Scalar = F;
+
+    fn line(&self) -> LineOrPoint<F> {
+        self.line.into()
+    }
+}
+
+fn lineal_crosses<F, A, B>(a: &A, b: &B) -> bool
+where
+    F: GeoFloat,
+    A: Lineal<F> + Relate<F, B>,
+    B: Lineal<F>,
+{
+    let (a_boundary, b_boundary) = match (a.boundary(), b.boundary()) {
+        (Some(a_boundary), Some(b_boundary)) => (a_boundary, b_boundary),
+        // a part without length is a point, which the sweep doesn't handle
+        _ => return crosses_by_relate(a, b),
+    };
+    let rects: (Option<Rect<F>>, Option<Rect<F>>) =
+        (a.bounding_rect().into(), b.bounding_rect().into());
+    match rects {
+        (Some(a_rect), Some(b_rect)) if a_rect.intersects(&b_rect) => {}
+        _ => return false,
+    }
+
+    let segments = a
+        .segments()
+        .into_iter()
+        .map(|line| (line, true))
+        .chain(b.segments().into_iter().map(|line| (line, false)))
+        .filter(|(line, _)| line.start != line.end)
+        .map(|(line, of_first)| Segment { line, of_first });
+    let mut crosses = false;
+    for (s1, s2, intersection) in Intersections::from_iter(segments) {
+        if s1.of_first == s2.of_first {
+            continue;
+        }
+        match intersection {
+            // the interiors share a stretch, so meet in one dimension
+            LineIntersection::Collinear { .. } => return false,
+            LineIntersection::SinglePoint { intersection, .. } => {
+                crosses = crosses
+                    || !(a_boundary.contains(&intersection) || b_boundary.contains(&intersection));
+            }
+        }
+    }
+    crosses
+}
+
+macro_rules! lineal_crosses_impl {
+    ($k:ty, [$($t:ty),*]) => {
+        $(
+            impl<F: GeoFloat> Crosses<F, $t> for $k {
+                fn crosses(&self, other: &$t) -> bool {
+                    lineal_crosses(self, other)
+                }
+            }
+        )*
+    };
+}
+
+lineal_crosses_impl!(Line<F>, [Line<F>, LineString<F>, MultiLineString<F>]);
+lineal_crosses_impl!(LineString<F>, [Line<F>, LineString<F>, MultiLineString<F>]);
+lineal_crosses_impl!(MultiLineString<F>, [Line<F>, LineString<F>, MultiLineString<F>]);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point, polygon, MultiPoint};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn lines() {
+        let pipeline = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        // through a segment, and through a vertex
+        assert!(pipeline.crosses(&Line::new((5., -5.), (5., 5.))));
+        assert!(pipeline.crosses(&line_string![(x: 5., y: 5.), (x: 15., y: -5.)]));
+        // ending on the other's interior, or end to end
+        assert!(!pipeline.crosses(&Line::new((5., -5.), (5., 0.))));
+        assert!(!Line::new((5., -5.), (5., 0.)).crosses(&pipeline));
+        assert!(!pipeline.crosses(&Line::new((10., 10.), (20., 20.))));
+        // sharing a stretch, even if they cross elsewhere
+        assert!(!pipeline.crosses(
+            &line_string![(x: 5., y: 0.), (x: 15., y: 0.), (x: 15., y: 5.), (x: 5., y: 5.)]
+        ));
+        // a ring has no ends, so is crossed where another line's end meets it
+        let ring = line_string![(x: 5., y: -5.), (x: 5., y: 5.), (x: 0., y: 5.), (x: 0., y: -5.), (x: 5., y: -5.)];
+        assert!(Line::new((-5., 0.), (5., 0.)).crosses(&ring));
+        assert!(!Line::new((0., 0.), (5., 0.)).crosses(&ring));
+        // disjoint
+        assert!(!pipeline.crosses(&Line::new((20., 0.), (20., 10.))));
+    }
+
+    #[test]
+    fn multi_line_string_boundary() {
+        // the parts share an end, which is then in the interior of the multi line string
+        let a = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 5., y: 0.)],
+            line_string![(x: 5., y: 0.), (x: 10., y: 0.)],
+        ]);
+        let b = Line::new((5., -5.), (5., 5.));
+        assert!(a.crosses(&b));
+        assert!(b.crosses(&a));
+        // but not where only one of them ends
+        assert!(!a.crosses(&Line::new((0., -5.), (0., 5.))));
+    }
+
+    #[test]
+    fn lines_and_areas() {
+        let river = polygon![(x: 8., y: 0.), (x: 12., y: 0.), (x: 12., y: 10.), (x: 8., y: 10.)];
+        let pipeline = line_string![(x: 0., y: 5.), (x: 20., y: 5.)];
+        assert!(pipeline.crosses(&river));
+        assert!(river.crosses(&pipeline));
+        assert!(
+            MultiLineString::new(vec![pipeline]).crosses(&MultiPolygon::new(vec![river.clone()]))
+        );
+        // into the river, without reaching the other bank
+        assert!(Line::new((0., 5.), (10., 5.)).crosses(&river));
+        assert!(Line::new((0., 5.), (10., 5.)).crosses(&Rect::new((8., 0.), (12., 10.))));
+        // along the bank, up to it, or within the river
+        assert!(!Line::new((8., 0.), (8., 10.)).crosses(&river));
+        assert!(!Line::new((0., 5.), (8., 5.)).crosses(&river));
+        assert!(!Line::new((9., 5.), (11., 5.)).crosses(&river));
+        // areas don't cross each other
+        assert!(!Geometry::from(river.clone()).crosses(&Geometry::from(river)));
+    }
+
+    #[test]
+    fn points() {
+        let pipeline = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        let points = MultiPoint::new(vec![point!(x: 5., y: 0.), point!(x: 5., y: 5.)]);
+        assert!(points.crosses(&pipeline));
+        assert!(pipeline.crosses(&points));
+        // all on the line, or none of them in its interior
+        assert!(!MultiPoint::new(vec![point!(x: 5., y: 0.)]).crosses(&pipeline));
+        assert!(
+            !MultiPoint::new(vec![point!(x: 0., y: 0.), point!(x: 5., y: 5.)]).crosses(&pipeline)
+        );
+        // a single point can't be both inside and outside
+        assert!(!point!(x: 5., y: 0.).crosses(&pipeline));
+
+        let river = polygon![(x: 8., y: 0.), (x: 12., y: 0.), (x: 12., y: 10.), (x: 8., y: 10.)];
+        let points = MultiPoint::new(vec![point!(x: 10., y: 5.), point!(x: 0., y: 5.)]);
+        assert!(points.crosses(&river));
+        assert!(river.crosses(&points));
+    }
+
+    #[test]
+    fn empty_and_degenerate() {
+        let pipeline = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        assert!(!LineString::new(vec![]).crosses(&pipeline));
+        assert!(!pipeline.crosses(&MultiLineString::new(vec![])));
+        // a line string with no length is a point
+        let point_like = line_string![(x: 5., y: 0.), (x: 5., y: 0.)];
+        assert!(!point_like.crosses(&pipeline));
+        let with_point_like = MultiLineString::new(vec![
+            point_like,
+            line_string![(x: 5., y: 5.), (x: 5., y: -5.)],
+        ]);
+        assert!(pipeline.crosses(&with_point_like));
+    }
+
+    #[test]
+    fn sweep_agrees_with_relate() {
+        // small line strings on a coarse grid, to get plenty of shared vertices and overlaps
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut next = move |n: u64| rng.gen_range(0..n) as f64;
+        // x only increases, and the parts of a multi line string keep apart, as `Relate`
+        // mislabels stretches shared with a geometry which crosses itself
+        let line_string = |next: &mut dyn FnMut(u64) -> f64, start: f64, end: f64| {
+            let mut x = start + next(2);
+            let mut coords = vec![];
+            while x < end {
+                coords.push(coord! { x: x, y: next(4) });
+                x += 1. + next(2);
+            }
+            coords.push(coord! { x: x, y: next(4) });
+            LineString::from(coords)
+        };
+        for _ in 0..2000 {
+            let a = line_string(&mut next, 0., 6.);
+            let b = MultiLineString::new(vec![
+                line_string(&mut next, 0., 2.),
+                line_string(&mut next, 4., 6.),
+            ]);
+            assert_eq!(a.crosses(&b), a.relate(&b).is_crosses(), "{a:?} {b:?}");
+            assert_eq!(b.crosses(&a), b.relate(&a).is_crosses(), "{a:?} {b:?}");
+        }
+    }
+}
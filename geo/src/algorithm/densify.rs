@@ -8,6 +8,9 @@ use crate::{
 ///
 /// Note: `max_distance` must be greater than 0.
 ///
+/// Alternatively, [`densify_into_n`](Densify::densify_into_n) splits every segment into `n`
+/// pieces of equal length, regardless of how long it is.
+///
 /// # Examples
 /// ```
 /// use geo::{coord, Line, LineString};
@@ -23,6 +26,30 @@ pub trait Densify<F: CoordFloat> {
     type Output;
 
     fn densify(&self, max_distance: F) -> Self::Output;
+
+    /// Return a new linear geometry in which every segment is split into `n` segments of equal
+    /// length, so that `n - 1` coordinates are interpolated between each pair of existing ones.
+    ///
+    /// The existing coordinates are all kept. If `n` is 0 or 1, the geometry is unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use geo::{coord, Line, LineString};
+    /// use geo::Densify;
+    ///
+    /// let line_string: LineString<f64> = vec![[0.0, 0.0], [4.0, 0.0], [4.0, 1.0]].into();
+    /// let correct: LineString<f64> = vec![
+    ///     [0.0, 0.0],
+    ///     [2.0, 0.0],
+    ///     [4.0, 0.0],
+    ///     [4.0, 0.5],
+    ///     [4.0, 1.0],
+    /// ]
+    /// .into();
+    /// let densified = line_string.densify_into_n(2);
+    /// assert_eq!(densified, correct);
+    ///```
+    fn densify_into_n(&self, n: usize) -> Self::Output;
 }
 
 // Helper for densification trait
@@ -44,6 +71,16 @@ fn densify_line<T: CoordFloat>(line: Line<T>, container: &mut Vec<Point<T>>, max
     }
 }
 
+// Helper for densification into a fixed number of segments
+fn densify_line_into_n<T: CoordFloat>(line: Line<T>, container: &mut Vec<Point<T>>, n: usize) {
+    container.push(line.start_point());
+    let frac = T::one() / T::from(n).unwrap();
+    for segment_idx in 1..n {
+        let ratio = frac * T::from(segment_idx).unwrap();
+        container.push((line.start + line.delta() * ratio).into());
+    }
+}
+
 impl<T> Densify<T> for MultiPolygon<T>
 where
     T: CoordFloat,
@@ -59,6 +96,14 @@ where
                 .collect(),
         )
     }
+
+    fn densify_into_n(&self, n: usize) -> Self::Output {
+        MultiPolygon::new(
+            self.iter()
+                .map(|polygon| polygon.densify_into_n(n))
+                .collect(),
+        )
+    }
 }
 
 impl<T> Densify<T> for Polygon<T>
@@ -78,6 +123,16 @@ where
             .collect();
         Polygon::new(densified_exterior, densified_interiors)
     }
+
+    fn densify_into_n(&self, n: usize) -> Self::Output {
+        let densified_exterior = self.exterior().densify_into_n(n);
+        let densified_interiors = self
+            .interiors()
+            .iter()
+            .map(|ring| ring.densify_into_n(n))
+            .collect();
+        Polygon::new(densified_exterior, densified_interiors)
+    }
 }
 
 impl<T> Densify<T> for MultiLineString<T>
@@ -95,6 +150,14 @@ where
                 .collect(),
         )
     }
+
+    fn densify_into_n(&self, n: usize) -> Self::Output {
+        MultiLineString::new(
+            self.iter()
+                .map(|linestring| linestring.densify_into_n(n))
+                .collect(),
+        )
+    }
 }
 
 impl<T> Densify<T> for LineString<T>
@@ -113,6 +176,18 @@ where
         new_line.push(self.points().last().unwrap());
         LineString::from(new_line)
     }
+
+    fn densify_into_n(&self, n: usize) -> Self::Output {
+        if n <= 1 || self.0.is_empty() {
+            return self.clone();
+        }
+        let mut new_line = vec![];
+        self.lines()
+            .for_each(|line| densify_line_into_n(line, &mut new_line, n));
+        // we're done, push the last coordinate on to finish
+        new_line.push(self.points().last().unwrap());
+        LineString::from(new_line)
+    }
 }
 
 impl<T> Densify<T> for Line<T>
@@ -130,6 +205,14 @@ where
         new_line.push(self.end_point());
         LineString::from(new_line)
     }
+
+    fn densify_into_n(&self, n: usize) -> Self::Output {
+        let mut new_line = vec![];
+        densify_line_into_n(*self, &mut new_line, n.max(1));
+        // we're done, push the last coordinate on to finish
+        new_line.push(self.end_point());
+        LineString::from(new_line)
+    }
 }
 
 impl<T> Densify<T> for Triangle<T>
@@ -143,6 +226,10 @@ where
     fn densify(&self, max_distance: T) -> Self::Output {
         self.to_polygon().densify(max_distance)
     }
+
+    fn densify_into_n(&self, n: usize) -> Self::Output {
+        self.to_polygon().densify_into_n(n)
+    }
 }
 
 impl<T> Densify<T> for Rect<T>
@@ -156,6 +243,10 @@ where
     fn densify(&self, max_distance: T) -> Self::Output {
         self.to_polygon().densify(max_distance)
     }
+
+    fn densify_into_n(&self, n: usize) -> Self::Output {
+        self.to_polygon().densify_into_n(n)
+    }
 }
 
 #[cfg(test)]
@@ -234,4 +325,69 @@ mod tests {
         let densified = line.densify(max_dist);
         assert_eq!(densified, correct);
     }
+
+    #[test]
+    fn test_line_densify_into_n() {
+        let line: Line<f64> = Line::new(coord! {x: 0.0, y: 0.0}, coord! {x: 3.0, y: 6.0});
+        let correct: LineString<f64> = vec![[0.0, 0.0], [1.0, 2.0], [2.0, 4.0], [3.0, 6.0]].into();
+        assert_eq!(line.densify_into_n(3), correct);
+
+        let unchanged: LineString<f64> = vec![[0.0, 0.0], [3.0, 6.0]].into();
+        assert_eq!(line.densify_into_n(0), unchanged);
+        assert_eq!(line.densify_into_n(1), unchanged);
+    }
+
+    #[test]
+    fn test_linestring_densify_into_n() {
+        // segments of very different lengths are split into the same number of pieces
+        let linestring: LineString<f64> = vec![[0.0, 0.0], [8.0, 0.0], [8.0, 1.0]].into();
+        let correct: LineString<f64> = vec![
+            [0.0, 0.0],
+            [2.0, 0.0],
+            [4.0, 0.0],
+            [6.0, 0.0],
+            [8.0, 0.0],
+            [8.0, 0.25],
+            [8.0, 0.5],
+            [8.0, 0.75],
+            [8.0, 1.0],
+        ]
+        .into();
+        assert_eq!(linestring.densify_into_n(4), correct);
+        assert_eq!(linestring.densify_into_n(1), linestring);
+
+        let empty = LineString::<f64>::new(vec![]);
+        assert_eq!(empty.densify_into_n(4), empty);
+    }
+
+    #[test]
+    fn test_polygon_densify_into_n() {
+        let exterior: LineString<f64> =
+            vec![[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0], [0.0, 0.0]].into();
+        let interior: LineString<f64> = vec![[1.0, 1.0], [3.0, 1.0], [3.0, 3.0], [1.0, 1.0]].into();
+        let polygon = Polygon::new(exterior, vec![interior]);
+        let densified = polygon.densify_into_n(2);
+        assert_eq!(densified.exterior().0.len(), 9);
+        assert_eq!(densified.interiors()[0].0.len(), 7);
+        assert_eq!(densified.exterior().0[1], coord! {x: 2.0, y: 0.0});
+        assert_eq!(densified.interiors()[0].0[5], coord! {x: 2.0, y: 2.0});
+
+        let multi_polygon = MultiPolygon::new(vec![polygon.clone(), polygon.clone()]);
+        let densified_multi = multi_polygon.densify_into_n(2);
+        assert_eq!(densified_multi.0, vec![densified.clone(), densified]);
+        assert_eq!(multi_polygon.densify_into_n(0), multi_polygon);
+    }
+
+    #[test]
+    fn test_multilinestring_densify_into_n() {
+        let linestring: LineString<f64> = vec![[0.0, 0.0], [0.0, 3.0]].into();
+        let multi = MultiLineString::new(vec![linestring.clone(), linestring]);
+        let densified = multi.densify_into_n(3);
+        for linestring in &densified {
+            assert_eq!(
+                linestring,
+                &vec![[0.0, 0.0], [0.0, 1.0], [0.0, 2.0], [0.0, 3.0]].into()
+            );
+        }
+    }
 }
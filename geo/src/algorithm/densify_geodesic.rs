@@ -0,0 +1,269 @@
+use crate::{
+    CoordFloat, GeodesicDistance, GeodesicIntermediate, Line, LineString, MultiLineString,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Return a new linear geometry containing both existing and new interpolated coordinates with
+/// a maximum distance of `max_distance` between them, measured along geodesics on an ellipsoidal
+/// model of the earth.
+///
+/// Unlike [`Densify`](crate::Densify), the new coordinates lie on the geodesic between each pair
+/// of existing coordinates, rather than on the straight line between them in the plane. They are
+/// calculated using [`GeodesicIntermediate`], which is more accurate than the spherical
+/// [`DensifyHaversine`](crate::DensifyHaversine), but slower. Coordinates are expected to be
+/// longitude and latitude, in degrees.
+///
+/// The existing coordinates are kept exactly as they are, so closed rings stay closed.
+///
+/// Note: `max_distance` must be greater than 0.
+///
+/// # Units
+///
+/// - `max_distance`: meters
+///
+/// # Examples
+/// ```
+/// use geo::{coord, Line, LineString};
+/// use geo::DensifyGeodesic;
+///
+/// // New York City to London
+/// let line = Line::new(coord! {x: -74.006, y: 40.7128}, coord! {x: -0.1278, y: 51.5074});
+/// let densified = line.densify_geodesic(1_000_000.0);
+/// // the route is about 5,585 km long, so it is split into 6 pieces
+/// assert_eq!(densified.0.len(), 7);
+/// // which go north of the straight line between the ends
+/// assert!(densified.0[3].y > (40.7128 + 51.5074) / 2.0);
+///```
+pub trait DensifyGeodesic<F: CoordFloat> {
+    type Output;
+
+    fn densify_geodesic(&self, max_distance: F) -> Self::Output;
+}
+
+// Helper for densification trait
+fn densify_line(line: Line, container: &mut Vec<Point>, max_distance: f64) {
+    assert!(max_distance > 0.);
+    let (start, end) = (line.start_point(), line.end_point());
+    container.push(start);
+    let num_segments = (start.geodesic_distance(&end) / max_distance).ceil() as u64;
+    // distance "unit" for this line segment
+    let frac = 1. / num_segments as f64;
+    for segment_idx in 1..num_segments {
+        let ratio = frac * segment_idx as f64;
+        container.push(start.geodesic_intermediate(&end, ratio));
+    }
+}
+
+impl DensifyGeodesic<f64> for MultiPolygon {
+    type Output = MultiPolygon;
+
+    fn densify_geodesic(&self, max_distance: f64) -> Self::Output {
+        MultiPolygon::new(
+            self.iter()
+                .map(|polygon| polygon.densify_geodesic(max_distance))
+                .collect(),
+        )
+    }
+}
+
+impl DensifyGeodesic<f64> for Polygon {
+    type Output = Polygon;
+
+    fn densify_geodesic(&self, max_distance: f64) -> Self::Output {
+        let densified_exterior = self.exterior().densify_geodesic(max_distance);
+        let densified_interiors = self
+            .interiors()
+            .iter()
+            .map(|ring| ring.densify_geodesic(max_distance))
+            .collect();
+        Polygon::new(densified_exterior, densified_interiors)
+    }
+}
+
+impl DensifyGeodesic<f64> for MultiLineString {
+    type Output = MultiLineString;
+
+    fn densify_geodesic(&self, max_distance: f64) -> Self::Output {
+        MultiLineString::new(
+            self.iter()
+                .map(|linestring| linestring.densify_geodesic(max_distance))
+                .collect(),
+        )
+    }
+}
+
+impl DensifyGeodesic<f64> for LineString {
+    type Output = LineString;
+
+    fn densify_geodesic(&self, max_distance: f64) -> Self::Output {
+        if self.0.is_empty() {
+            return self.clone();
+        }
+        let mut new_line = vec![];
+        self.lines()
+            .for_each(|line| densify_line(line, &mut new_line, max_distance));
+        // we're done, push the last coordinate on to finish
+        new_line.push(Point::from(self.0[self.0.len() - 1]));
+        LineString::from(new_line)
+    }
+}
+
+impl DensifyGeodesic<f64> for Line {
+    type Output = LineString;
+
+    fn densify_geodesic(&self, max_distance: f64) -> Self::Output {
+        let mut new_line = vec![];
+        densify_line(*self, &mut new_line, max_distance);
+        // we're done, push the last coordinate on to finish
+        new_line.push(self.end_point());
+        LineString::from(new_line)
+    }
+}
+
+impl DensifyGeodesic<f64> for Triangle {
+    type Output = Polygon;
+
+    fn densify_geodesic(&self, max_distance: f64) -> Self::Output {
+        self.to_polygon().densify_geodesic(max_distance)
+    }
+}
+
+impl DensifyGeodesic<f64> for Rect {
+    type Output = Polygon;
+
+    fn densify_geodesic(&self, max_distance: f64) -> Self::Output {
+        self.to_polygon().densify_geodesic(max_distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coord;
+
+    /// Check that every point of `densified` lies on the geodesic of `line`, in order, and that
+    /// none of its segments is longer than `max_distance`.
+    fn assert_on_geodesic(line: Line, densified: &LineString, max_distance: f64) {
+        let (start, end) = (line.start_point(), line.end_point());
+        let total = start.geodesic_distance(&end);
+        let mut previous = 0.;
+        for point in densified.points() {
+            let (from_start, to_end) = (
+                start.geodesic_distance(&point),
+                point.geodesic_distance(&end),
+            );
+            assert_relative_eq!(from_start + to_end, total, epsilon = 1e-3);
+            assert!(from_start >= previous);
+            previous = from_start;
+        }
+        for segment in densified.lines() {
+            let length = segment
+                .start_point()
+                .geodesic_distance(&segment.end_point());
+            assert!(length <= max_distance + 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_line_densify_geodesic() {
+        let line = Line::new(
+            coord! {x: -74.006, y: 40.7128},
+            coord! {x: -0.1278, y: 51.5074},
+        );
+        let max_distance = 500_000.;
+        let densified = line.densify_geodesic(max_distance);
+        assert_eq!(densified.0.len(), 13);
+        assert_eq!(densified.0[0], line.start);
+        assert_eq!(densified.0[12], line.end);
+        assert_on_geodesic(line, &densified, max_distance);
+    }
+
+    #[test]
+    fn test_linestring_densify_geodesic() {
+        let linestring: LineString =
+            vec![[0.0, 0.0], [0.0, 10.0], [30.0, 60.0], [30.0, 60.1]].into();
+        let max_distance = 200_000.;
+        let densified = linestring.densify_geodesic(max_distance);
+
+        // the new coordinates are on the geodesic of the segment they split
+        let mut remaining = &densified.0[..];
+        for line in linestring.lines() {
+            assert_eq!(remaining[0], line.start);
+            let end = remaining.iter().position(|c| *c == line.end).unwrap();
+            let piece = LineString::new(remaining[..=end].to_vec());
+            assert_on_geodesic(line, &piece, max_distance);
+            remaining = &remaining[end..];
+        }
+        assert_eq!(remaining, &linestring.0[3..]);
+
+        // a short segment is left unchanged
+        assert_eq!(&densified.0[densified.0.len() - 2..], &linestring.0[2..]);
+    }
+
+    #[test]
+    fn test_polygon_densify_geodesic() {
+        let polygon = Rect::new((0., 0.), (10., 10.)).to_polygon();
+        let densified = polygon.densify_geodesic(200_000.);
+        assert!(densified.exterior().is_closed());
+        assert!(densified.exterior().0.len() > polygon.exterior().0.len());
+
+        let multi_polygon = MultiPolygon::new(vec![polygon.clone(), polygon]);
+        let densified_multi = multi_polygon.densify_geodesic(200_000.);
+        assert_eq!(densified_multi.0, vec![densified.clone(), densified]);
+    }
+
+    #[test]
+    fn test_high_latitude_densify_geodesic() {
+        // east to west along the 70th parallel
+        let line: Line = Line::new(coord! {x: 60., y: 70.}, coord! {x: -60., y: 70.});
+        let densified = line.densify_geodesic(100_000.);
+        let interior = &densified.0[1..densified.0.len() - 1];
+        assert!(!interior.is_empty());
+
+        // the geodesic bows toward the pole, furthest halfway along
+        assert!(interior.iter().all(|coord| coord.y > 70.));
+        let northernmost = interior
+            .iter()
+            .max_by(|a, b| a.y.partial_cmp(&b.y).unwrap())
+            .unwrap();
+        // within half a segment, about 2.6° of longitude here, of the meridian halfway between
+        // the ends
+        assert!(northernmost.x.abs() < 3.);
+        assert!(northernmost.y > 79.);
+    }
+
+    #[test]
+    fn test_rings_keep_their_ends() {
+        let exterior: LineString = vec![
+            [0.1, 0.3],
+            [20.7, 0.3],
+            [20.7, 20.9],
+            [0.1, 20.9],
+            [0.1, 0.3],
+        ]
+        .into();
+        let interior: LineString = vec![[5.3, 5.3], [15.1, 5.3], [15.1, 15.7], [5.3, 5.3]].into();
+        let polygon: Polygon = Polygon::new(exterior.clone(), vec![interior.clone()]);
+        let densified = polygon.densify_geodesic(100_000.);
+
+        for (original, ring) in [
+            (&exterior, densified.exterior()),
+            (&interior, &densified.interiors()[0]),
+        ] {
+            assert!(ring.0.len() > original.0.len());
+            assert!(ring.is_closed());
+            // the existing coordinates are copied, not recomputed
+            let (first, last) = (ring.0[0], ring.0[ring.0.len() - 1]);
+            assert_eq!(first.x.to_bits(), original.0[0].x.to_bits());
+            assert_eq!(first.y.to_bits(), original.0[0].y.to_bits());
+            assert_eq!(last.x.to_bits(), first.x.to_bits());
+            assert_eq!(last.y.to_bits(), first.y.to_bits());
+        }
+    }
+
+    #[test]
+    fn test_empty_densify_geodesic() {
+        let empty = LineString::new(vec![]);
+        assert_eq!(empty.densify_geodesic(1.), empty);
+    }
+}
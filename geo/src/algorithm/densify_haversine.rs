@@ -0,0 +1,300 @@
+use num_traits::FromPrimitive;
+
+use crate::{
+    CoordFloat, HaversineDistance, HaversineIntermediate, Line, LineString, MultiLineString,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Return a new linear geometry containing both existing and new interpolated coordinates with
+/// a maximum distance of `max_distance` between them, measured along great circles using the
+/// [haversine formula].
+///
+/// Unlike [`Densify`](crate::Densify), the new coordinates lie on the great circle between each
+/// pair of existing coordinates, rather than on the straight line between them in the plane.
+/// Coordinates are expected to be longitude and latitude, in degrees.
+///
+/// The existing coordinates are kept exactly as they are, so closed rings stay closed.
+///
+/// Note: `max_distance` must be greater than 0.
+///
+/// # Units
+///
+/// - `max_distance`: meters
+///
+/// # Examples
+/// ```
+/// use geo::{coord, Line, LineString};
+/// use geo::DensifyHaversine;
+///
+/// // New York City to London
+/// let line = Line::new(coord! {x: -74.006, y: 40.7128}, coord! {x: -0.1278, y: 51.5074});
+/// let densified = line.densify_haversine(1_000_000.0);
+/// // the route is about 5,570 km long, so it is split into 6 pieces
+/// assert_eq!(densified.0.len(), 7);
+/// // which go north of the straight line between the ends
+/// assert!(densified.0[3].y > (40.7128 + 51.5074) / 2.0);
+///```
+///
+/// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
+pub trait DensifyHaversine<F: CoordFloat> {
+    type Output;
+
+    fn densify_haversine(&self, max_distance: F) -> Self::Output;
+}
+
+// Helper for densification trait
+fn densify_line<T: CoordFloat + FromPrimitive>(
+    line: Line<T>,
+    container: &mut Vec<Point<T>>,
+    max_distance: T,
+) {
+    assert!(max_distance > T::zero());
+    let (start, end) = (line.start_point(), line.end_point());
+    container.push(start);
+    let num_segments = (start.haversine_distance(&end) / max_distance)
+        .ceil()
+        .to_u64()
+        .unwrap();
+    // distance "unit" for this line segment
+    let frac = T::one() / T::from(num_segments).unwrap();
+    for segment_idx in 1..num_segments {
+        let ratio = frac * T::from(segment_idx).unwrap();
+        container.push(start.haversine_intermediate(&end, ratio));
+    }
+}
+
+impl<T> DensifyHaversine<T> for MultiPolygon<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    type Output = MultiPolygon<T>;
+
+    fn densify_haversine(&self, max_distance: T) -> Self::Output {
+        MultiPolygon::new(
+            self.iter()
+                .map(|polygon| polygon.densify_haversine(max_distance))
+                .collect(),
+        )
+    }
+}
+
+impl<T> DensifyHaversine<T> for Polygon<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    type Output = Polygon<T>;
+
+    fn densify_haversine(&self, max_distance: T) -> Self::Output {
+        let densified_exterior = self.exterior().densify_haversine(max_distance);
+        let densified_interiors = self
+            .interiors()
+            .iter()
+            .map(|ring| ring.densify_haversine(max_distance))
+            .collect();
+        Polygon::new(densified_exterior, densified_interiors)
+    }
+}
+
+impl<T> DensifyHaversine<T> for MultiLineString<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    type Output = MultiLineString<T>;
+
+    fn densify_haversine(&self, max_distance: T) -> Self::Output {
+        MultiLineString::new(
+            self.iter()
+                .map(|linestring| linestring.densify_haversine(max_distance))
+                .collect(),
+        )
+    }
+}
+
+impl<T> DensifyHaversine<T> for LineString<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    type Output = LineString<T>;
+
+    fn densify_haversine(&self, max_distance: T) -> Self::Output {
+        if self.0.is_empty() {
+            return self.clone();
+        }
+        let mut new_line = vec![];
+        self.lines()
+            .for_each(|line| densify_line(line, &mut new_line, max_distance));
+        // we're done, push the last coordinate on to finish
+        new_line.push(Point::from(self.0[self.0.len() - 1]));
+        LineString::from(new_line)
+    }
+}
+
+impl<T> DensifyHaversine<T> for Line<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    type Output = LineString<T>;
+
+    fn densify_haversine(&self, max_distance: T) -> Self::Output {
+        let mut new_line = vec![];
+        densify_line(*self, &mut new_line, max_distance);
+        // we're done, push the last coordinate on to finish
+        new_line.push(self.end_point());
+        LineString::from(new_line)
+    }
+}
+
+impl<T> DensifyHaversine<T> for Triangle<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    type Output = Polygon<T>;
+
+    fn densify_haversine(&self, max_distance: T) -> Self::Output {
+        self.to_polygon().densify_haversine(max_distance)
+    }
+}
+
+impl<T> DensifyHaversine<T> for Rect<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    type Output = Polygon<T>;
+
+    fn densify_haversine(&self, max_distance: T) -> Self::Output {
+        self.to_polygon().densify_haversine(max_distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coord;
+
+    /// Check that every point of `densified` lies on the great circle route of `line`, in order,
+    /// and that none of its segments is longer than `max_distance`.
+    fn assert_on_great_circle(line: Line<f64>, densified: &LineString<f64>, max_distance: f64) {
+        let (start, end) = (line.start_point(), line.end_point());
+        let total = start.haversine_distance(&end);
+        let mut previous = 0.;
+        for point in densified.points() {
+            let (from_start, to_end) = (
+                start.haversine_distance(&point),
+                point.haversine_distance(&end),
+            );
+            assert_relative_eq!(from_start + to_end, total, epsilon = 1e-3);
+            assert!(from_start >= previous);
+            previous = from_start;
+        }
+        for segment in densified.lines() {
+            let length = segment
+                .start_point()
+                .haversine_distance(&segment.end_point());
+            assert!(length <= max_distance + 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_line_densify_haversine() {
+        let line: Line<f64> = Line::new(
+            coord! {x: -74.006, y: 40.7128},
+            coord! {x: -0.1278, y: 51.5074},
+        );
+        let max_distance = 500_000.;
+        let densified = line.densify_haversine(max_distance);
+        assert_eq!(densified.0.len(), 13);
+        assert_eq!(densified.0[0], line.start);
+        assert_eq!(densified.0[12], line.end);
+        assert_on_great_circle(line, &densified, max_distance);
+    }
+
+    #[test]
+    fn test_linestring_densify_haversine() {
+        let linestring: LineString<f64> =
+            vec![[0.0, 0.0], [0.0, 10.0], [30.0, 60.0], [30.0, 60.1]].into();
+        let max_distance = 200_000.;
+        let densified = linestring.densify_haversine(max_distance);
+
+        // the new coordinates are on the great circle route of the segment they split
+        let mut remaining = &densified.0[..];
+        for line in linestring.lines() {
+            assert_eq!(remaining[0], line.start);
+            let end = remaining.iter().position(|c| *c == line.end).unwrap();
+            let piece = LineString::new(remaining[..=end].to_vec());
+            assert_on_great_circle(line, &piece, max_distance);
+            remaining = &remaining[end..];
+        }
+        assert_eq!(remaining, &linestring.0[3..]);
+
+        // a short segment is left unchanged
+        assert_eq!(&densified.0[densified.0.len() - 2..], &linestring.0[2..]);
+    }
+
+    #[test]
+    fn test_polygon_densify_haversine() {
+        let polygon: Polygon<f64> = Rect::new((0., 0.), (10., 10.)).to_polygon();
+        let densified = polygon.densify_haversine(200_000.);
+        assert!(densified.exterior().is_closed());
+        assert!(densified.exterior().0.len() > polygon.exterior().0.len());
+
+        let multi_polygon = MultiPolygon::new(vec![polygon.clone(), polygon]);
+        let densified_multi = multi_polygon.densify_haversine(200_000.);
+        assert_eq!(densified_multi.0, vec![densified.clone(), densified]);
+    }
+
+    #[test]
+    fn test_high_latitude_densify_haversine() {
+        // east to west along the 70th parallel
+        let line: Line<f64> = Line::new(coord! {x: 60., y: 70.}, coord! {x: -60., y: 70.});
+        let densified = line.densify_haversine(100_000.);
+        let interior = &densified.0[1..densified.0.len() - 1];
+        assert!(!interior.is_empty());
+
+        // the great circle bows toward the pole, furthest halfway along
+        assert!(interior.iter().all(|coord| coord.y > 70.));
+        let northernmost = interior
+            .iter()
+            .max_by(|a, b| a.y.partial_cmp(&b.y).unwrap())
+            .unwrap();
+        // within half a segment, about 2.6° of longitude here, of the meridian halfway between
+        // the ends
+        assert!(northernmost.x.abs() < 3.);
+        assert!(northernmost.y > 79.);
+    }
+
+    #[test]
+    fn test_rings_keep_their_ends() {
+        let exterior: LineString<f64> = vec![
+            [0.1, 0.3],
+            [20.7, 0.3],
+            [20.7, 20.9],
+            [0.1, 20.9],
+            [0.1, 0.3],
+        ]
+        .into();
+        let interior: LineString<f64> =
+            vec![[5.3, 5.3], [15.1, 5.3], [15.1, 15.7], [5.3, 5.3]].into();
+        let polygon: Polygon<f64> = Polygon::new(exterior.clone(), vec![interior.clone()]);
+        let densified = polygon.densify_haversine(100_000.);
+
+        for (original, ring) in [
+            (&exterior, densified.exterior()),
+            (&interior, &densified.interiors()[0]),
+        ] {
+            assert!(ring.0.len() > original.0.len());
+            assert!(ring.is_closed());
+            // the existing coordinates are copied, not recomputed
+            let (first, last) = (ring.0[0], ring.0[ring.0.len() - 1]);
+            assert_eq!(first.x.to_bits(), original.0[0].x.to_bits());
+            assert_eq!(first.y.to_bits(), original.0[0].y.to_bits());
+            assert_eq!(last.x.to_bits(), first.x.to_bits());
+            assert_eq!(last.y.to_bits(), first.y.to_bits());
+        }
+    }
+
+    #[test]
+    fn test_empty_densify_haversine() {
+        let empty = LineString::<f64>::new(vec![]);
+        assert_eq!(empty.densify_haversine(1.), empty);
+    }
+}
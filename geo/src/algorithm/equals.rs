@@ -0,0 +1,155 @@
+use crate::dimensions::HasDimensions;
+use crate::{BoundingRect, GeoFloat, Rect, Relate};
+
+/// Tests if two geometries are topologically equal: they cover exactly the same points.
+///
+/// Unlike `==`, this doesn't depend on how the geometries are written down, so e.g. a polygon
+/// equals the same polygon with its ring starting at another vertex or running the other way, a
+/// line string equals itself with an extra vertex partway along one of its segments, and a
+/// polygon equals a multi-polygon with it as the only member.
+///
+/// Following the [OGC Simple Features] definition, the [DE-9IM] intersection matrix for
+/// (Self, Rhs) is `[T*F**FFF*]`. As their interiors don't intersect, empty geometries aren't
+/// equal to anything, not even one another.
+///
+/// This is the same as [`IntersectionMatrix::is_equal_topo`] on the result of [`Relate`], but
+/// answers quickly when the dimensions or bounding rectangles differ, without computing the
+/// whole matrix.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon, Equals, MultiPolygon};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let rotated = polygon![(x: 2., y: 2.), (x: 0., y: 2.), (x: 0., y: 0.), (x: 2., y: 0.)];
+///
+/// assert_ne!(square, rotated);
+/// assert!(square.equals(&rotated));
+/// assert!(square.equals(&MultiPolygon::new(vec![rotated])));
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.)];
+/// let split = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)];
+/// assert!(line_string.equals(&split));
+/// ```
+///
+/// [OGC Simple Features]: https://www.ogc.org/standards/sfa
+/// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+/// [`IntersectionMatrix::is_equal_topo`]: crate::relate::IntersectionMatrix::is_equal_topo
+pub trait Equals<F, Rhs> {
+    fn equals(&self, other: &Rhs) -> bool;
+}
+
+impl<F, G1, G2> Equals<F, G2> for G1
+where
+    F: GeoFloat,
+    G1: Relate<F, G2> + BoundingRect<F> + HasDimensions,
+    G2: BoundingRect<F> + HasDimensions,
+{
+    fn equals(&self, other: &G2) -> bool {
+        if self.is_empty() || self.dimensions() != other.dimensions() {
+            return false;
+        }
+        // the same points have the same bounds
+        let rects: (Option<Rect<F>>, Option<Rect<F>>) =
+            (self.bounding_rect().into(), other.bounding_rect().into());
+        match rects {
+            (Some(a), Some(b)) if a == b => self.relate(other).is_equal_topo(),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        coord, line_string, point, polygon, Geometry, GeometryCollection, Line, LineString,
+        MultiLineString, MultiPoint, MultiPolygon, Polygon, Triangle,
+    };
+
+    #[test]
+    fn rotated_ring() {
+        let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let rotated = polygon![(x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.), (x: 0., y: 0.)];
+        assert_ne!(square, rotated);
+        assert!(square.equals(&rotated));
+        assert!(rotated.equals(&square));
+
+        let ring = square.exterior().clone();
+        let rotated_ring = rotated.exterior().clone();
+        assert!(ring.equals(&rotated_ring));
+    }
+
+    #[test]
+    fn extra_collinear_vertex() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+        let split = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+        assert!(line_string.equals(&split));
+        assert!(split.equals(&line_string));
+        assert!(Line::new((0., 0.), (4., 0.)).equals(&line_string![
+            (x: 0., y: 0.),
+            (x: 3., y: 0.),
+            (x: 4., y: 0.)
+        ]));
+
+        let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let split = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        assert!(square.equals(&split));
+    }
+
+    #[test]
+    fn reversed() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+        let reversed: LineString = line_string.0.iter().rev().copied().collect();
+        assert!(line_string.equals(&reversed));
+
+        let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let clockwise = polygon![(x: 0., y: 0.), (x: 0., y: 2.), (x: 2., y: 2.), (x: 2., y: 0.)];
+        assert!(square.equals(&clockwise));
+    }
+
+    #[test]
+    fn mixed_types() {
+        let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let rect = crate::Rect::new((0., 0.), (2., 2.));
+        assert!(square.equals(&rect));
+        assert!(rect.equals(&MultiPolygon::new(vec![square.clone()])));
+        assert!(Geometry::from(square.clone()).equals(&Geometry::from(rect)));
+
+        let triangle = Triangle::new(
+            coord! { x: 0., y: 0. },
+            coord! { x: 2., y: 0. },
+            coord! { x: 0., y: 2. },
+        );
+        assert!(triangle.equals(&polygon![(x: 2., y: 0.), (x: 0., y: 2.), (x: 0., y: 0.)]));
+        assert!(!triangle.equals(&square));
+
+        let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)];
+        let pieces = MultiLineString::new(vec![
+            line_string![(x: 2., y: 2.), (x: 2., y: 0.)],
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+        ]);
+        assert!(line_string.equals(&pieces));
+
+        let point = point!(x: 1., y: 1.);
+        assert!(point.equals(&MultiPoint::new(vec![point, point])));
+        assert!(point.equals(&GeometryCollection::new_from(vec![Geometry::from(point)])));
+    }
+
+    #[test]
+    fn not_equal() {
+        let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        // same bounds, but not the same points
+        let diamond = polygon![(x: 1., y: 0.), (x: 2., y: 1.), (x: 1., y: 2.), (x: 0., y: 1.)];
+        assert!(!square.equals(&diamond));
+        assert!(!square.equals(square.exterior()));
+        assert!(!line_string![(x: 0., y: 0.), (x: 2., y: 0.)]
+            .equals(&line_string![(x: 0., y: 0.), (x: 1., y: 0.)]));
+        assert!(!point!(x: 1., y: 1.).equals(&point!(x: 1., y: 2.)));
+
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert!(!empty.equals(&empty));
+        assert!(!square.equals(&empty));
+    }
+}
@@ -1,9 +1,9 @@
 use crate::utils::{coord_pos_relative_to_ring, CoordPos};
 use crate::EuclideanLength;
-use crate::Intersects;
+use crate::{Contains, Intersects};
 use crate::{
     Coord, GeoFloat, GeoNum, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point,
-    Polygon, Triangle,
+    Polygon, Rect, Triangle,
 };
 use num_traits::{float::FloatConst, Bounded, Float, Signed};
 
@@ -91,6 +91,58 @@ pub trait EuclideanDistance<T, Rhs = Self> {
     /// assert_relative_eq!(distance, 1.1313708498984762);
     /// ```
     fn euclidean_distance(&self, rhs: &Rhs) -> T;
+
+    /// Returns whether two geometries are within `distance` of each other, which is whether
+    /// their [`euclidean_distance`](Self::euclidean_distance) is at most `distance`.
+    ///
+    /// For geometries with many segments, this stops as soon as it finds a pair of segments
+    /// within `distance`, rather than finding the distance between every pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::EuclideanDistance;
+    /// use geo::{line_string, polygon};
+    ///
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 5.), (x: 0., y: 5.)];
+    /// let line_string = line_string![(x: 7., y: 1.), (x: 7., y: 4.), (x: 10., y: 4.)];
+    ///
+    /// assert!(line_string.is_within_distance(&polygon, 2.));
+    /// assert!(!line_string.is_within_distance(&polygon, 1.5));
+    /// ```
+    fn is_within_distance(&self, rhs: &Rhs, distance: T) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.euclidean_distance(rhs) <= distance
+    }
+}
+
+/// Returns the signed distance from a geometry to the boundary of another, which is negative
+/// inside it.
+pub trait SignedEuclideanDistance<T, Rhs> {
+    /// Returns the distance from `self` to the boundary of `rhs`, negated if `self` is inside
+    /// `rhs`, as for a signed distance field.
+    ///
+    /// A `Point` in a hole of a `Polygon` is outside it, so its distance is positive. A `Point`
+    /// on any of the rings of a `Polygon` has a distance of `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::SignedEuclideanDistance;
+    /// use geo::{point, polygon};
+    ///
+    /// let polygon = polygon!(
+    ///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+    ///     interiors: [[(x: 6., y: 6.), (x: 8., y: 6.), (x: 8., y: 8.), (x: 6., y: 8.)]],
+    /// );
+    ///
+    /// assert_eq!(point!(x: 2., y: 3.).signed_euclidean_distance(&polygon), -2.);
+    /// assert_eq!(point!(x: 7., y: 7.5).signed_euclidean_distance(&polygon), 0.5);
+    /// assert_eq!(point!(x: 13., y: 5.).signed_euclidean_distance(&polygon), 3.);
+    /// ```
+    fn signed_euclidean_distance(&self, rhs: &Rhs) -> T;
 }
 
 // ┌───────────────────────────┐
@@ -143,6 +195,13 @@ where
             .map(|p| self.euclidean_distance(p))
             .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val))
     }
+
+    fn is_within_distance(&self, points: &MultiPoint<T>, distance: T) -> bool {
+        points
+            .0
+            .iter()
+            .any(|p| self.is_within_distance(p, distance))
+    }
 }
 
 impl<T> EuclideanDistance<T, Line<T>> for Point<T>
@@ -163,6 +222,17 @@ where
     fn euclidean_distance(&self, linestring: &LineString<T>) -> T {
         ::geo_types::private_utils::point_line_string_euclidean_distance(*self, linestring)
     }
+
+    fn is_within_distance(&self, linestring: &LineString<T>, distance: T) -> bool {
+        // an empty `LineString` is at a distance of zero
+        if linestring.0.is_empty() {
+            return T::zero() <= distance;
+        }
+        linestring.lines().any(|line| {
+            ::geo_types::private_utils::line_segment_distance(self.0, line.start, line.end)
+                <= distance
+        })
+    }
 }
 
 impl<T> EuclideanDistance<T, MultiLineString<T>> for Point<T>
@@ -176,6 +246,10 @@ where
             .map(|ls| self.euclidean_distance(ls))
             .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val))
     }
+
+    fn is_within_distance(&self, mls: &MultiLineString<T>, distance: T) -> bool {
+        mls.0.iter().any(|ls| self.is_within_distance(ls, distance))
+    }
 }
 
 impl<T> EuclideanDistance<T, Polygon<T>> for Point<T>
@@ -207,6 +281,46 @@ where
                     .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val)),
             )
     }
+
+    fn is_within_distance(&self, polygon: &Polygon<T>, distance: T) -> bool {
+        if polygon.exterior().0.is_empty() || polygon.intersects(self) {
+            return T::zero() <= distance;
+        }
+        polygon
+            .interiors()
+            .iter()
+            .chain(std::iter::once(polygon.exterior()))
+            .flat_map(|ring| ring.lines())
+            .any(|line| {
+                ::geo_types::private_utils::line_segment_distance(self.0, line.start, line.end)
+                    <= distance
+            })
+    }
+}
+
+impl<T> SignedEuclideanDistance<T, Polygon<T>> for Point<T>
+where
+    T: GeoFloat,
+{
+    fn signed_euclidean_distance(&self, polygon: &Polygon<T>) -> T {
+        if polygon.exterior().0.is_empty() {
+            return T::zero();
+        }
+        let boundary_distance = polygon
+            .interiors()
+            .iter()
+            .chain(std::iter::once(polygon.exterior()))
+            .flat_map(|ring| ring.lines())
+            .map(|line| {
+                ::geo_types::private_utils::line_segment_distance(self.0, line.start, line.end)
+            })
+            .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val));
+        if polygon.contains(self) {
+            -boundary_distance
+        } else {
+            boundary_distance
+        }
+    }
 }
 
 impl<T> EuclideanDistance<T, MultiPolygon<T>> for Point<T>
@@ -221,6 +335,13 @@ where
             .map(|p| self.euclidean_distance(p))
             .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val))
     }
+
+    fn is_within_distance(&self, mpolygon: &MultiPolygon<T>, distance: T) -> bool {
+        mpolygon
+            .0
+            .iter()
+            .any(|p| self.is_within_distance(p, distance))
+    }
 }
 
 // ┌────────────────────────────────┐
@@ -235,6 +356,10 @@ where
     fn euclidean_distance(&self, point: &Point<T>) -> T {
         point.euclidean_distance(self)
     }
+
+    fn is_within_distance(&self, point: &Point<T>, distance: T) -> bool {
+        point.is_within_distance(self, distance)
+    }
 }
 
 // ┌──────────────────────────┐
@@ -287,6 +412,10 @@ where
     fn euclidean_distance(&self, other: &LineString<T>) -> T {
         other.euclidean_distance(self)
     }
+
+    fn is_within_distance(&self, other: &LineString<T>, distance: T) -> bool {
+        other.is_within_distance(self, distance)
+    }
 }
 
 // Line to Polygon distance
@@ -321,6 +450,17 @@ where
         // return smaller of the two values
         exterior_min.min(interior_min)
     }
+
+    fn is_within_distance(&self, other: &Polygon<T>, distance: T) -> bool {
+        if self.intersects(other) {
+            return T::zero() <= distance;
+        }
+        other
+            .exterior()
+            .lines()
+            .chain(other.interiors().iter().flat_map(|ring| ring.lines()))
+            .any(|line| self.euclidean_distance(&line) <= distance)
+    }
 }
 
 /// Line to MultiPolygon distance
@@ -335,6 +475,13 @@ where
             .map(|p| self.euclidean_distance(p))
             .fold(Bounded::max_value(), |accum, val| accum.min(val))
     }
+
+    fn is_within_distance(&self, mpolygon: &MultiPolygon<T>, distance: T) -> bool {
+        mpolygon
+            .0
+            .iter()
+            .any(|p| self.is_within_distance(p, distance))
+    }
 }
 
 // ┌────────────────────────────────┐
@@ -349,6 +496,10 @@ where
     fn euclidean_distance(&self, point: &Point<T>) -> T {
         point.euclidean_distance(self)
     }
+
+    fn is_within_distance(&self, point: &Point<T>, distance: T) -> bool {
+        point.is_within_distance(self, distance)
+    }
 }
 
 /// LineString to Line
@@ -361,6 +512,11 @@ where
             acc.min(line.euclidean_distance(other))
         })
     }
+
+    fn is_within_distance(&self, other: &Line<T>, distance: T) -> bool {
+        self.lines()
+            .any(|line| line.euclidean_distance(other) <= distance)
+    }
 }
 
 /// LineString-LineString distance
@@ -369,12 +525,20 @@ where
     T: GeoFloat + Signed + RTreeNum,
 {
     fn euclidean_distance(&self, other: &LineString<T>) -> T {
-        if self.intersects(other) {
+        if line_strings_intersect(self, other) {
             T::zero()
         } else {
             nearest_neighbour_distance(self, other)
         }
     }
+
+    fn is_within_distance(&self, other: &LineString<T>, distance: T) -> bool {
+        if line_strings_intersect(self, other) {
+            T::zero() <= distance
+        } else {
+            nearest_neighbour_distances(self, other).any(|d| d <= distance)
+        }
+    }
 }
 
 /// LineString to Polygon
@@ -383,7 +547,7 @@ where
     T: GeoFloat + FloatConst + Signed + RTreeNum,
 {
     fn euclidean_distance(&self, other: &Polygon<T>) -> T {
-        if self.intersects(other) {
+        if line_string_intersects_polygon(self, other) {
             T::zero()
         } else if !other.interiors().is_empty()
             && ring_contains_point(other, Point::from(self.0[0]))
@@ -398,6 +562,21 @@ where
             nearest_neighbour_distance(self, other.exterior())
         }
     }
+
+    fn is_within_distance(&self, other: &Polygon<T>, distance: T) -> bool {
+        if line_string_intersects_polygon(self, other) {
+            T::zero() <= distance
+        } else if !other.interiors().is_empty()
+            && ring_contains_point(other, Point::from(self.0[0]))
+        {
+            other
+                .interiors()
+                .iter()
+                .any(|ring| nearest_neighbour_distances(self, ring).any(|d| d <= distance))
+        } else {
+            nearest_neighbour_distances(self, other.exterior()).any(|d| d <= distance)
+        }
+    }
 }
 
 // ┌─────────────────────────────────────┐
@@ -412,6 +591,10 @@ where
     fn euclidean_distance(&self, point: &Point<T>) -> T {
         point.euclidean_distance(self)
     }
+
+    fn is_within_distance(&self, point: &Point<T>, distance: T) -> bool {
+        point.is_within_distance(self, distance)
+    }
 }
 
 // ┌─────────────────────────────┐
@@ -426,6 +609,10 @@ where
     fn euclidean_distance(&self, point: &Point<T>) -> T {
         point.euclidean_distance(self)
     }
+
+    fn is_within_distance(&self, point: &Point<T>, distance: T) -> bool {
+        point.is_within_distance(self, distance)
+    }
 }
 
 // Polygon to Line distance
@@ -436,6 +623,10 @@ where
     fn euclidean_distance(&self, other: &Line<T>) -> T {
         other.euclidean_distance(self)
     }
+
+    fn is_within_distance(&self, other: &Line<T>, distance: T) -> bool {
+        other.is_within_distance(self, distance)
+    }
 }
 
 /// Polygon to LineString distance
@@ -446,6 +637,10 @@ where
     fn euclidean_distance(&self, other: &LineString<T>) -> T {
         other.euclidean_distance(self)
     }
+
+    fn is_within_distance(&self, other: &LineString<T>, distance: T) -> bool {
+        other.is_within_distance(self, distance)
+    }
 }
 
 // Polygon to Polygon distance
@@ -454,7 +649,7 @@ where
     T: GeoFloat + FloatConst + RTreeNum,
 {
     fn euclidean_distance(&self, poly2: &Polygon<T>) -> T {
-        if self.intersects(poly2) {
+        if polygons_intersect(self, poly2) {
             return T::zero();
         }
         // Containment check
@@ -478,6 +673,32 @@ where
         }
         nearest_neighbour_distance(self.exterior(), poly2.exterior())
     }
+
+    fn is_within_distance(&self, poly2: &Polygon<T>, distance: T) -> bool {
+        if polygons_intersect(self, poly2) {
+            return T::zero() <= distance;
+        }
+        let within = |a: &LineString<T>, b: &LineString<T>| {
+            nearest_neighbour_distances(a, b).any(|d| d <= distance)
+        };
+        // Containment check
+        if !self.interiors().is_empty()
+            && ring_contains_point(self, Point::from(poly2.exterior().0[0]))
+        {
+            return self
+                .interiors()
+                .iter()
+                .any(|ring| within(poly2.exterior(), ring));
+        } else if !poly2.interiors().is_empty()
+            && ring_contains_point(poly2, Point::from(self.exterior().0[0]))
+        {
+            return poly2
+                .interiors()
+                .iter()
+                .any(|ring| within(self.exterior(), ring));
+        }
+        within(self.exterior(), poly2.exterior())
+    }
 }
 
 // ┌──────────────────────────────────┐
@@ -492,6 +713,10 @@ where
     fn euclidean_distance(&self, point: &Point<T>) -> T {
         point.euclidean_distance(self)
     }
+
+    fn is_within_distance(&self, point: &Point<T>, distance: T) -> bool {
+        point.is_within_distance(self, distance)
+    }
 }
 
 /// MultiPolygon to Line distance
@@ -502,12 +727,70 @@ where
     fn euclidean_distance(&self, other: &Line<T>) -> T {
         other.euclidean_distance(self)
     }
+
+    fn is_within_distance(&self, other: &Line<T>, distance: T) -> bool {
+        other.is_within_distance(self, distance)
+    }
+}
+
+// ┌──────────────────────────┐
+// │ Implementations for Rect │
+// └──────────────────────────┘
+
+impl<T> EuclideanDistance<T, Point<T>> for Rect<T>
+where
+    T: GeoFloat,
+{
+    /// Minimum distance from a Rect to a Point, found by clamping the Point to the Rect
+    fn euclidean_distance(&self, point: &Point<T>) -> T {
+        let dx = (self.min().x - point.x())
+            .max(point.x() - self.max().x)
+            .max(T::zero());
+        let dy = (self.min().y - point.y())
+            .max(point.y() - self.max().y)
+            .max(T::zero());
+        dx.hypot(dy)
+    }
+}
+
+impl<T> EuclideanDistance<T, Rect<T>> for Point<T>
+where
+    T: GeoFloat,
+{
+    fn euclidean_distance(&self, rect: &Rect<T>) -> T {
+        rect.euclidean_distance(self)
+    }
+}
+
+/// Rect to Rect distance, found from the gaps between their extents
+impl<T> EuclideanDistance<T, Rect<T>> for Rect<T>
+where
+    T: GeoFloat,
+{
+    fn euclidean_distance(&self, other: &Rect<T>) -> T {
+        let dx = (self.min().x - other.max().x)
+            .max(other.min().x - self.max().x)
+            .max(T::zero());
+        let dy = (self.min().y - other.max().y)
+            .max(other.min().y - self.max().y)
+            .max(T::zero());
+        dx.hypot(dy)
+    }
 }
 
 // ┌──────────────────────────────┐
 // │ Implementations for Triangle │
 // └──────────────────────────────┘
 
+impl<T> EuclideanDistance<T, Triangle<T>> for Point<T>
+where
+    T: GeoFloat,
+{
+    fn euclidean_distance(&self, triangle: &Triangle<T>) -> T {
+        triangle.euclidean_distance(self)
+    }
+}
+
 impl<T> EuclideanDistance<T, Point<T>> for Triangle<T>
 where
     T: GeoFloat,
@@ -524,6 +807,65 @@ where
     }
 }
 
+/// Implement `EuclideanDistance` from a `Rect` or `Triangle` to the given geometries: zero if they
+/// intersect, and otherwise the distance from the nearest of its edges
+macro_rules! impl_euclidean_distance_from_edges {
+    ($from:ident, [$($to:ident),*]) => {
+        $(
+            impl<T> EuclideanDistance<T, $to<T>> for $from<T>
+            where
+                T: GeoFloat + FloatConst + Signed + RTreeNum,
+            {
+                fn euclidean_distance(&self, other: &$to<T>) -> T {
+                    if self.intersects(other) {
+                        return T::zero();
+                    }
+                    self.to_lines()
+                        .iter()
+                        .fold(<T as Bounded>::max_value(), |acc, edge| {
+                            acc.min(edge.euclidean_distance(other))
+                        })
+                }
+
+                fn is_within_distance(&self, other: &$to<T>, distance: T) -> bool {
+                    if self.intersects(other) {
+                        return T::zero() <= distance;
+                    }
+                    self.to_lines()
+                        .iter()
+                        .any(|edge| edge.is_within_distance(other, distance))
+                }
+            }
+        )*
+    };
+}
+
+/// Implement `EuclideanDistance` from the given geometries to a `Rect` or `Triangle`, by
+/// delegating to the implementation from it
+macro_rules! impl_euclidean_distance_to_edges {
+    ($to:ident, [$($from:ident),*]) => {
+        $(
+            impl<T> EuclideanDistance<T, $to<T>> for $from<T>
+            where
+                T: GeoFloat + FloatConst + Signed + RTreeNum,
+            {
+                fn euclidean_distance(&self, other: &$to<T>) -> T {
+                    other.euclidean_distance(self)
+                }
+
+                fn is_within_distance(&self, other: &$to<T>, distance: T) -> bool {
+                    other.is_within_distance(self, distance)
+                }
+            }
+        )*
+    };
+}
+
+impl_euclidean_distance_from_edges!(Rect, [Line, LineString, Polygon, Triangle]);
+impl_euclidean_distance_to_edges!(Rect, [Line, LineString, Polygon, Triangle]);
+impl_euclidean_distance_from_edges!(Triangle, [Line, LineString, Polygon, Triangle]);
+impl_euclidean_distance_to_edges!(Triangle, [Line, LineString, Polygon]);
+
 // ┌───────────┐
 // │ Utilities │
 // └───────────┘
@@ -542,24 +884,113 @@ where
     }
 }
 
+/// Below this many segments in either geometry, whether two geometries intersect is checked
+/// with `Intersects`, which compares every segment of one with every segment of the other,
+/// rather than by building R* trees of their segments
+const RTREE_INTERSECTS_THRESHOLD: usize = 32;
+
+/// Whether any segment of `lines1` intersects any segment of `lines2`, using R* trees to only
+/// compare segments whose bounding boxes intersect
+fn segments_intersect<T>(lines1: Vec<Line<T>>, lines2: Vec<Line<T>>) -> bool
+where
+    T: GeoFloat + RTreeNum,
+{
+    let tree_a: RTree<Line<_>> = RTree::bulk_load(lines1);
+    let tree_b: RTree<Line<_>> = RTree::bulk_load(lines2);
+    tree_a
+        .intersection_candidates_with_other_tree(&tree_b)
+        .any(|(a, b)| a.intersects(b))
+}
+
+/// The same as `Intersects`, but sub-quadratic for large `LineString`s
+fn line_strings_intersect<T>(ls1: &LineString<T>, ls2: &LineString<T>) -> bool
+where
+    T: GeoFloat + RTreeNum,
+{
+    let lines1: Vec<_> = ls1.lines().collect();
+    let lines2: Vec<_> = ls2.lines().collect();
+    if lines1.len().min(lines2.len()) < RTREE_INTERSECTS_THRESHOLD {
+        return ls1.intersects(ls2);
+    }
+    segments_intersect(lines1, lines2)
+}
+
+/// The same as `Intersects`, but sub-quadratic for a large `LineString` and `Polygon`
+fn line_string_intersects_polygon<T>(ls: &LineString<T>, poly: &Polygon<T>) -> bool
+where
+    T: GeoFloat + RTreeNum,
+{
+    let ls_lines: Vec<_> = ls.lines().collect();
+    let poly_lines: Vec<_> = ring_lines(poly).collect();
+    if ls_lines.len().min(poly_lines.len()) < RTREE_INTERSECTS_THRESHOLD {
+        return ls.intersects(poly);
+    }
+    // if the boundaries don't meet, the line string is either entirely inside or outside
+    segments_intersect(ls_lines, poly_lines) || poly.intersects(&ls.0[0])
+}
+
+/// The same as `Intersects`, but sub-quadratic for large `Polygon`s
+fn polygons_intersect<T>(poly1: &Polygon<T>, poly2: &Polygon<T>) -> bool
+where
+    T: GeoFloat + RTreeNum,
+{
+    let lines1: Vec<_> = ring_lines(poly1).collect();
+    let lines2: Vec<_> = ring_lines(poly2).collect();
+    if lines1.len().min(lines2.len()) < RTREE_INTERSECTS_THRESHOLD {
+        return poly1.intersects(poly2);
+    }
+    // if the boundaries don't meet, either exterior is entirely inside or outside the other
+    segments_intersect(lines1, lines2)
+        || poly2
+            .exterior()
+            .0
+            .first()
+            .map_or(false, |coord| poly1.intersects(coord))
+        || poly1
+            .exterior()
+            .0
+            .first()
+            .map_or(false, |coord| poly2.intersects(coord))
+}
+
+/// The segments of all the rings of `poly`
+fn ring_lines<T: GeoFloat>(poly: &Polygon<T>) -> impl Iterator<Item = Line<T>> + '_ {
+    std::iter::once(poly.exterior())
+        .chain(poly.interiors())
+        .flat_map(|ring| ring.lines())
+}
+
 /// Uses an R* tree and nearest-neighbour lookups to calculate minimum distances
 // This is somewhat slow and memory-inefficient, but certainly better than quadratic time
 pub fn nearest_neighbour_distance<T>(geom1: &LineString<T>, geom2: &LineString<T>) -> T
+where
+    T: GeoFloat + RTreeNum,
+{
+    nearest_neighbour_distances(geom1, geom2).fold(<T as Bounded>::max_value(), |acc, distance| {
+        acc.min(distance)
+    })
+}
+
+/// The distances between all geom a points and their nearest geom b lines, and all geom b
+/// points and their nearest geom a lines, computed lazily, so callers can stop early
+fn nearest_neighbour_distances<'a, T>(
+    geom1: &'a LineString<T>,
+    geom2: &'a LineString<T>,
+) -> impl Iterator<Item = T> + 'a
 where
     T: GeoFloat + RTreeNum,
 {
     let tree_a: RTree<Line<_>> = RTree::bulk_load(geom1.lines().collect::<Vec<_>>());
     let tree_b: RTree<Line<_>> = RTree::bulk_load(geom2.lines().collect::<Vec<_>>());
-    // Return minimum distance between all geom a points and geom b lines, and all geom b points and geom a lines
     geom2
         .points()
-        .fold(<T as Bounded>::max_value(), |acc, point| {
+        .map(move |point| {
             let nearest = tree_a.nearest_neighbor(&point).unwrap();
-            acc.min(nearest.euclidean_distance(&point))
+            nearest.euclidean_distance(&point)
         })
-        .min(geom1.points().fold(Bounded::max_value(), |acc, point| {
+        .chain(geom1.points().map(move |point| {
             let nearest = tree_b.nearest_neighbor(&point).unwrap();
-            acc.min(nearest.euclidean_distance(&point))
+            nearest.euclidean_distance(&point)
         }))
 }
 
@@ -570,6 +1001,8 @@ mod test {
     use crate::{EuclideanDistance, Orient};
     use crate::{Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon};
     use geo_types::{coord, polygon, private_utils::line_segment_distance};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
 
     #[test]
     fn line_segment_distance_test() {
@@ -714,6 +1147,32 @@ mod test {
         // 0.41036467732879783 <-- Shapely
         assert_relative_eq!(dist, 0.41036467732879767);
     }
+    #[test]
+    fn point_polygon_signed_distance_test() {
+        // a square with a square hole
+        let poly = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 6., y: 6.), (x: 8., y: 6.), (x: 8., y: 8.), (x: 6., y: 8.)]],
+        );
+        // deep inside, nearer the exterior than the hole
+        assert_relative_eq!(Point::new(3., 4.).signed_euclidean_distance(&poly), -3.);
+        // inside, nearer the hole than the exterior
+        assert_relative_eq!(Point::new(5., 7.).signed_euclidean_distance(&poly), -1.);
+        // inside the hole is outside the polygon
+        assert_relative_eq!(Point::new(7., 7.5).signed_euclidean_distance(&poly), 0.5);
+        // on the exterior and the hole
+        assert_relative_eq!(Point::new(0., 5.).signed_euclidean_distance(&poly), 0.);
+        assert_relative_eq!(Point::new(7., 8.).signed_euclidean_distance(&poly), 0.);
+        // outside
+        assert_relative_eq!(Point::new(13., 14.).signed_euclidean_distance(&poly), 5.);
+        // the magnitude is the distance to the boundary
+        for p in [Point::new(7., 7.5), Point::new(13., 14.)] {
+            assert_relative_eq!(
+                p.signed_euclidean_distance(&poly),
+                p.euclidean_distance(&poly)
+            );
+        }
+    }
 
     #[test]
     fn line_distance_multipolygon_do_not_intersect_test() {
@@ -1171,4 +1630,223 @@ mod test {
         assert_eq!(p1.euclidean_distance(&p4), 50.0f64);
         assert_eq!(p2.euclidean_distance(&p3), 50.0f64);
     }
+
+    #[test]
+    fn is_within_distance_agrees_with_distance() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut random = move || rng.gen::<f64>() * 10.;
+        let star = |random: &mut dyn FnMut() -> f64, x: f64, y: f64| -> LineString<f64> {
+            (0..12)
+                .map(|i| {
+                    let angle = i as f64 * std::f64::consts::PI / 6.;
+                    let radius = 1. + random() / 5.;
+                    (x + radius * angle.cos(), y + radius * angle.sin())
+                })
+                .collect()
+        };
+        for _ in 0..50 {
+            let a = Polygon::new(star(&mut random, 0., 0.), vec![]);
+            let (x, y) = (random(), random());
+            let b = Polygon::new(star(&mut random, x, y), vec![]);
+            let c = b.exterior().clone();
+            let line = Line::new(a.exterior().0[0], c.0[5]);
+            let mut line_string = a.exterior().clone();
+            line_string.0.pop();
+
+            let distances = [
+                a.euclidean_distance(&b),
+                line_string.euclidean_distance(&c),
+                c.euclidean_distance(&a),
+                line.euclidean_distance(&b),
+                line_string.euclidean_distance(&line),
+            ];
+            for (i, &distance) in distances.iter().enumerate() {
+                for threshold in [0., distance * 0.99, distance, distance * 1.01, 20.] {
+                    let within = [
+                        a.is_within_distance(&b, threshold),
+                        line_string.is_within_distance(&c, threshold),
+                        c.is_within_distance(&a, threshold),
+                        line.is_within_distance(&b, threshold),
+                        line_string.is_within_distance(&line, threshold),
+                    ];
+                    assert_eq!(within[i], distance <= threshold);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn is_within_distance_stops_early() {
+        // two long zig-zags, which come close only at their starts
+        let zig_zag = |y: f64| -> LineString<f64> {
+            (0..1000)
+                .map(|i| (i as f64, y + (i % 2) as f64 + i as f64))
+                .collect()
+        };
+        let (a, b) = (zig_zag(0.), zig_zag(-3.));
+        let visited = |distance: Option<f64>| {
+            let mut count = 0;
+            let within = nearest_neighbour_distances(&a, &b)
+                .inspect(|_| count += 1)
+                .any(|d| distance.map_or(false, |distance| d <= distance));
+            (within, count)
+        };
+        let (within, bounded) = visited(Some(3.));
+        let (_, all) = visited(None);
+        assert!(within);
+        assert_eq!(within, a.euclidean_distance(&b) <= 3.);
+        assert!(bounded < all / 100, "{bounded} of {all}");
+        assert!(a.is_within_distance(&b, 3.));
+    }
+
+    #[test]
+    fn rtree_intersects_matches_intersects() {
+        let mut rng = StdRng::seed_from_u64(49);
+        let mut random = move || rng.gen::<f64>();
+        // a jagged ring of 100 vertices, about its centre
+        let mut star = |x: f64, y: f64, size: f64| -> LineString<f64> {
+            let mut ring: LineString<f64> = (0..100)
+                .map(|i| {
+                    let angle = i as f64 * std::f64::consts::PI / 50.;
+                    let radius = size * (1. + random() / 5.);
+                    (x + radius * angle.cos(), y + radius * angle.sin())
+                })
+                .collect();
+            ring.close();
+            ring
+        };
+        for i in 0..100 {
+            let (x, y) = (i as f64 / 20., (i % 7) as f64 / 4.);
+            // sometimes inside the other's hole
+            let a = Polygon::new(star(0., 0., 3.), vec![star(0., 0., 1.5)]);
+            let b = Polygon::new(star(x, y, 0.5 + (i % 3) as f64 / 2.), vec![]);
+            let ls = b.exterior().clone();
+
+            assert_eq!(polygons_intersect(&a, &b), a.intersects(&b));
+            assert_eq!(polygons_intersect(&b, &a), b.intersects(&a));
+            assert_eq!(line_string_intersects_polygon(&ls, &a), ls.intersects(&a));
+            assert_eq!(
+                line_strings_intersect(&ls, a.exterior()),
+                ls.intersects(a.exterior())
+            );
+        }
+    }
+
+    #[test]
+    fn rect_distances() {
+        let rect = Rect::new((0., 0.), (4., 2.));
+        // the nearest point is a corner, or on an edge, or the point is inside
+        assert_relative_eq!(rect.euclidean_distance(&Point::new(7., 6.)), 5.);
+        assert_relative_eq!(Point::new(2., -3.).euclidean_distance(&rect), 3.);
+        assert_relative_eq!(rect.euclidean_distance(&Point::new(1., 1.)), 0.);
+
+        assert_relative_eq!(rect.euclidean_distance(&Rect::new((7., 6.), (8., 9.))), 5.);
+        assert_relative_eq!(rect.euclidean_distance(&Rect::new((-1., 3.), (5., 4.))), 1.);
+        // overlapping
+        assert_relative_eq!(rect.euclidean_distance(&Rect::new((1., 1.), (2., 5.))), 0.);
+
+        // a polygon with the rect inside its hole
+        let polygon = polygon![
+            exterior: [(x: -10., y: -10.), (x: 10., y: -10.), (x: 10., y: 10.), (x: -10., y: 10.)],
+            interiors: [[(x: -1., y: -1.), (x: 6., y: -1.), (x: 6., y: 5.), (x: -1., y: 5.)]],
+        ];
+        assert_relative_eq!(rect.euclidean_distance(&polygon), 1.);
+        assert_relative_eq!(polygon.euclidean_distance(&rect), 1.);
+        assert!(rect.is_within_distance(&polygon, 1.));
+        assert!(!rect.is_within_distance(&polygon, 0.9));
+    }
+
+    #[test]
+    fn triangle_distances() {
+        let triangle = Triangle::from([(0., 0.), (4., 0.), (0., 4.)]);
+        assert_relative_eq!(
+            triangle.euclidean_distance(&Line::new((3., 3.), (5., 5.))),
+            2f64.sqrt()
+        );
+        assert_relative_eq!(
+            Triangle::from([(6., 0.), (9., 0.), (9., 3.)]).euclidean_distance(&triangle),
+            2.
+        );
+        // a line string inside the triangle
+        let line_string = LineString::from(vec![(0.5, 0.5), (1., 1.), (1.5, 0.5)]);
+        assert_relative_eq!(line_string.euclidean_distance(&triangle), 0.);
+        assert!(triangle.is_within_distance(&line_string, 0.));
+    }
+
+    #[test]
+    fn rect_and_triangle_match_polygons() {
+        let mut rng = StdRng::seed_from_u64(50);
+        let mut random = move || rng.gen::<f64>() * 20.;
+        for _ in 0..200 {
+            let rect = Rect::new((random(), random()), (random(), random()));
+            let triangle = Triangle::from([
+                (random(), random()),
+                (random(), random()),
+                (random(), random()),
+            ]);
+            let other_rect = Rect::new((random(), random()), (random(), random()));
+            let point = Point::new(random(), random());
+            let line = Line::new((random(), random()), (random(), random()));
+            let line_string: LineString<f64> = (0..5).map(|_| (random(), random())).collect();
+            let polygon = Polygon::new(
+                LineString::from(vec![
+                    (random(), random()),
+                    (random() + 20., random()),
+                    (random() + 20., random() + 20.),
+                ]),
+                vec![],
+            );
+            let (rect_polygon, triangle_polygon) = (rect.to_polygon(), triangle.to_polygon());
+
+            let pairs = [
+                (
+                    rect.euclidean_distance(&point),
+                    rect_polygon.euclidean_distance(&point),
+                ),
+                (
+                    point.euclidean_distance(&triangle),
+                    point.euclidean_distance(&triangle_polygon),
+                ),
+                (
+                    rect.euclidean_distance(&other_rect),
+                    rect_polygon.euclidean_distance(&other_rect.to_polygon()),
+                ),
+                (
+                    rect.euclidean_distance(&line),
+                    rect_polygon.euclidean_distance(&line),
+                ),
+                (
+                    line.euclidean_distance(&triangle),
+                    line.euclidean_distance(&triangle_polygon),
+                ),
+                (
+                    line_string.euclidean_distance(&rect),
+                    line_string.euclidean_distance(&rect_polygon),
+                ),
+                (
+                    triangle.euclidean_distance(&line_string),
+                    triangle_polygon.euclidean_distance(&line_string),
+                ),
+                (
+                    rect.euclidean_distance(&polygon),
+                    rect_polygon.euclidean_distance(&polygon),
+                ),
+                (
+                    polygon.euclidean_distance(&triangle),
+                    polygon.euclidean_distance(&triangle_polygon),
+                ),
+                (
+                    triangle.euclidean_distance(&rect),
+                    triangle_polygon.euclidean_distance(&rect_polygon),
+                ),
+                (
+                    rect.euclidean_distance(&triangle),
+                    rect_polygon.euclidean_distance(&triangle_polygon),
+                ),
+            ];
+            for (distance, expected) in pairs {
+                assert_relative_eq!(distance, expected, epsilon = 1e-9);
+            }
+        }
+    }
 }
@@ -1,7 +1,9 @@
 use crate::coords_iter::CoordsIter;
+use crate::densify::Densify;
 use crate::euclidean_distance::EuclideanDistance;
-use crate::{GeoFloat, LineString, Point};
+use crate::{GeoFloat, LineString};
 use num_traits::FromPrimitive;
+use std::iter::Sum;
 
 /// Determine the similarity between two `LineStrings` using the [Frechet distance].
 ///
@@ -39,53 +41,181 @@ pub trait FrechetDistance<T, Rhs = Self> {
     ///
     /// [Frechet distance]: https://en.wikipedia.org/wiki/Fr%C3%A9chet_distance
     fn frechet_distance(&self, rhs: &Rhs) -> T;
+
+    /// Determine the similarity between two `LineStrings` using the [Frechet distance], after
+    /// [densifying](crate::Densify) both of them so that no segment is longer than
+    /// `max_segment_length`.
+    ///
+    /// The discrete Frechet distance only considers the vertices of the inputs, so it depends on
+    /// how they are sampled. Resampling both to a common resolution gives a result which is much
+    /// closer to the continuous Frechet distance, at the cost of a longer computation.
+    ///
+    /// Note: `max_segment_length` must be greater than 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::FrechetDistance;
+    /// use geo::line_string;
+    ///
+    /// let line_string_a = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+    /// let line_string_b = line_string![(x: 0., y: 1.), (x: 5., y: 1.), (x: 10., y: 1.)];
+    ///
+    /// // The middle vertex of `line_string_b` is far from any vertex of `line_string_a`
+    /// assert_eq!(26f64.sqrt(), line_string_a.frechet_distance(&line_string_b));
+    ///
+    /// let distance = line_string_a.frechet_distance_densified(&line_string_b, 1.);
+    /// assert_eq!(1., distance);
+    /// ```
+    ///
+    /// [Frechet distance]: https://en.wikipedia.org/wiki/Fr%C3%A9chet_distance
+    fn frechet_distance_densified(&self, rhs: &Rhs, max_segment_length: T) -> T;
+
+    /// Determine the [Frechet distance] between two `LineStrings`, along with the alignment of
+    /// their vertices which achieves it.
+    ///
+    /// The alignment is a list of `(i, j)` pairs, each matching vertex `i` of `self` with vertex
+    /// `j` of `rhs`. It starts at `(0, 0)`, ends at the last vertex of both inputs, and each
+    /// step advances `i`, `j`, or both by one. The distance is the largest distance between any
+    /// pair of matched vertices.
+    ///
+    /// Unlike [`frechet_distance`](Self::frechet_distance), the whole dynamic programming table
+    /// has to be kept in order to recover the alignment, so the memory usage is proportional to
+    /// the product of the lengths of the inputs.
+    ///
+    /// If either input is empty, the distance is zero and the alignment is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::FrechetDistance;
+    /// use geo::line_string;
+    ///
+    /// let line_string_a = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)];
+    /// let line_string_b = line_string![(x: 0., y: 1.), (x: 2., y: 1.)];
+    ///
+    /// let (distance, path) = line_string_a.frechet_distance_with_path(&line_string_b);
+    ///
+    /// assert_eq!(2f64.sqrt(), distance);
+    /// assert_eq!(vec![(0, 0), (1, 0), (2, 1)], path);
+    /// ```
+    ///
+    /// [Frechet distance]: https://en.wikipedia.org/wiki/Fr%C3%A9chet_distance
+    fn frechet_distance_with_path(&self, rhs: &Rhs) -> (T, Vec<(usize, usize)>);
 }
 
 impl<T> FrechetDistance<T, LineString<T>> for LineString<T>
 where
-    T: GeoFloat + FromPrimitive,
+    T: GeoFloat + FromPrimitive + Sum,
 {
     fn frechet_distance(&self, ls: &LineString<T>) -> T {
-        if self.coords_count() != 0 && ls.coords_count() != 0 {
-            let mut data = Data {
-                cache: vec![vec![T::nan(); ls.coords_count()]; self.coords_count()],
-                ls_a: self,
-                ls_b: ls,
-            };
-            data.compute(self.coords_count() - 1, ls.coords_count() - 1)
-        } else {
-            T::zero()
+        discrete_frechet_distance(self, ls)
+    }
+
+    fn frechet_distance_densified(&self, ls: &LineString<T>, max_segment_length: T) -> T {
+        if self.coords_count() == 0 || ls.coords_count() == 0 {
+            return T::zero();
         }
+        discrete_frechet_distance(
+            &self.densify(max_segment_length),
+            &ls.densify(max_segment_length),
+        )
+    }
+
+    fn frechet_distance_with_path(&self, ls: &LineString<T>) -> (T, Vec<(usize, usize)>) {
+        discrete_frechet_path(self, ls)
     }
 }
 
-struct Data<'a, T>
+/// The discrete Frechet distance between the vertices of `ls_a` and `ls_b`.
+///
+/// The dynamic programming table is filled in row by row, and only the previous row is needed to
+/// compute the next one, so the memory usage is linear in the length of `ls_b`.
+fn discrete_frechet_distance<T>(ls_a: &LineString<T>, ls_b: &LineString<T>) -> T
 where
-    T: GeoFloat + FromPrimitive,
+    T: GeoFloat,
 {
-    cache: Vec<Vec<T>>,
-    ls_a: &'a LineString<T>,
-    ls_b: &'a LineString<T>,
+    if ls_a.coords_count() == 0 || ls_b.coords_count() == 0 {
+        return T::zero();
+    }
+
+    let mut prev_row: Vec<T> = Vec::with_capacity(ls_b.coords_count());
+    let mut row: Vec<T> = Vec::with_capacity(ls_b.coords_count());
+    for (i, a) in ls_a.points().enumerate() {
+        row.clear();
+        for (j, b) in ls_b.points().enumerate() {
+            let eucl = a.euclidean_distance(&b);
+            let value = match (i, j) {
+                (0, 0) => eucl,
+                (_, 0) => prev_row[0].max(eucl),
+                (0, _) => row[j - 1].max(eucl),
+                (_, _) => prev_row[j].min(prev_row[j - 1]).min(row[j - 1]).max(eucl),
+            };
+            row.push(value);
+        }
+        std::mem::swap(&mut prev_row, &mut row);
+    }
+    prev_row[ls_b.coords_count() - 1]
 }
 
-impl<'a, T> Data<'a, T>
+/// The discrete Frechet distance between the vertices of `ls_a` and `ls_b`, and the coupling
+/// which achieves it.
+///
+/// This fills in the same table as [`discrete_frechet_distance`], but keeps all of it so that the
+/// optimal coupling can be recovered by walking back from the last cell.
+fn discrete_frechet_path<T>(ls_a: &LineString<T>, ls_b: &LineString<T>) -> (T, Vec<(usize, usize)>)
 where
-    T: GeoFloat + FromPrimitive,
+    T: GeoFloat,
 {
-    fn compute(&mut self, i: usize, j: usize) -> T {
-        if self.cache[i][j].is_nan() {
-            let eucl = Point::from(self.ls_a[i]).euclidean_distance(&Point::from(self.ls_b[j]));
-            self.cache[i][j] = match (i, j) {
+    let (n, m) = (ls_a.coords_count(), ls_b.coords_count());
+    if n == 0 || m == 0 {
+        return (T::zero(), vec![]);
+    }
+
+    // row-major `n` x `m` table
+    let mut table: Vec<T> = Vec::with_capacity(n * m);
+    for (i, a) in ls_a.points().enumerate() {
+        for (j, b) in ls_b.points().enumerate() {
+            let eucl = a.euclidean_distance(&b);
+            let value = match (i, j) {
                 (0, 0) => eucl,
-                (_, 0) => self.compute(i - 1, 0).max(eucl),
-                (0, _) => self.compute(0, j - 1).max(eucl),
-                (_, _) => ((self.compute(i - 1, j).min(self.compute(i - 1, j - 1)))
-                    .min(self.compute(i, j - 1)))
-                .max(eucl),
+                (_, 0) => table[(i - 1) * m].max(eucl),
+                (0, _) => table[j - 1].max(eucl),
+                (_, _) => table[(i - 1) * m + j]
+                    .min(table[(i - 1) * m + j - 1])
+                    .min(table[i * m + j - 1])
+                    .max(eucl),
             };
+            table.push(value);
         }
-        self.cache[i][j]
     }
+
+    let mut path = Vec::with_capacity(n + m - 1);
+    let (mut i, mut j) = (n - 1, m - 1);
+    path.push((i, j));
+    while (i, j) != (0, 0) {
+        (i, j) = match (i, j) {
+            (_, 0) => (i - 1, 0),
+            (0, _) => (0, j - 1),
+            (_, _) => {
+                // prefer the diagonal step on ties, which keeps the path short
+                let diagonal = table[(i - 1) * m + j - 1];
+                let up = table[(i - 1) * m + j];
+                let left = table[i * m + j - 1];
+                if diagonal <= up && diagonal <= left {
+                    (i - 1, j - 1)
+                } else if up <= left {
+                    (i - 1, j)
+                } else {
+                    (i, j - 1)
+                }
+            }
+        };
+        path.push((i, j));
+    }
+    path.reverse();
+
+    (table[n * m - 1], path)
 }
 
 #[cfg(test)]
@@ -131,4 +261,64 @@ mod test {
         let ls_b = LineString::from(vec![(2., 2.), (0., 1.), (2., 4.)]);
         assert_relative_eq!(2., ls_a.frechet_distance(&ls_b));
     }
+
+    #[test]
+    fn test_frechet_densified() {
+        let ls_a = LineString::from(vec![(0., 0.), (10., 0.)]);
+        let ls_b = LineString::from(vec![(0., 1.), (5., 1.), (10., 1.)]);
+        assert_relative_eq!(26f64.sqrt(), ls_a.frechet_distance(&ls_b));
+        assert_relative_eq!(1., ls_a.frechet_distance_densified(&ls_b, 1.));
+        // a coarser resolution gives a result between the two
+        let coarse = ls_a.frechet_distance_densified(&ls_b, 4.);
+        assert!(coarse > 1. && coarse < 26f64.sqrt());
+    }
+
+    #[test]
+    fn test_frechet_densified_empty() {
+        let ls_a = LineString::<f64>::new(vec![]);
+        let ls_b = LineString::from(vec![(0., 1.), (5., 1.)]);
+        assert_relative_eq!(0., ls_a.frechet_distance_densified(&ls_b, 1.));
+        assert_relative_eq!(0., ls_b.frechet_distance_densified(&ls_a, 1.));
+    }
+
+    #[test]
+    fn test_frechet_with_path() {
+        let ls_a = LineString::from(vec![(1., 1.), (2., 1.), (2., 2.), (3., 3.), (5., 3.)]);
+        let ls_b = LineString::from(vec![(2., 2.), (0., 1.), (2., 4.), (3., 4.)]);
+        let (distance, path) = ls_a.frechet_distance_with_path(&ls_b);
+        assert_relative_eq!(ls_a.frechet_distance(&ls_b), distance);
+
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(4, 3)));
+        for window in path.windows(2) {
+            let ((i0, j0), (i1, j1)) = (window[0], window[1]);
+            assert!(i1 == i0 || i1 == i0 + 1);
+            assert!(j1 == j0 || j1 == j0 + 1);
+            assert_ne!((i0, j0), (i1, j1));
+        }
+
+        let points_a = ls_a.clone().into_points();
+        let points_b = ls_b.clone().into_points();
+        let max = path
+            .iter()
+            .map(|&(i, j)| points_a[i].euclidean_distance(&points_b[j]))
+            .fold(0., f64::max);
+        assert_relative_eq!(distance, max);
+    }
+
+    #[test]
+    fn test_frechet_with_path_empty() {
+        let ls_a = LineString::<f64>::new(vec![]);
+        let ls_b = LineString::from(vec![(0., 1.), (5., 1.)]);
+        assert_eq!((0., vec![]), ls_a.frechet_distance_with_path(&ls_b));
+        assert_eq!((0., vec![]), ls_b.frechet_distance_with_path(&ls_a));
+    }
+
+    #[test]
+    fn test_frechet_long_linestrings() {
+        // densifying produces long linestrings, which must not exhaust the stack
+        let ls_a = LineString::from(vec![(0., 0.), (1000., 0.)]);
+        let ls_b = LineString::from(vec![(0., 2.), (1000., 2.)]);
+        assert_relative_eq!(2., ls_a.frechet_distance_densified(&ls_b, 0.5));
+    }
 }
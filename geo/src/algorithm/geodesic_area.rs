@@ -0,0 +1,409 @@
+use crate::geometry::*;
+use geographiclib_rs::{Geodesic, PolygonArea, Winding};
+
+/// Determine the perimeter and area of a geometry on an ellipsoidal model of the earth.
+///
+/// This uses the geodesic measurement methods given by [Karney (2013)], the same algorithm used by
+/// [GeographicLib]'s `PolygonArea`. As opposed to [`ChamberlainDuquetteArea`], which approximates
+/// the earth as a sphere, the result is accurate on the WGS-84 ellipsoid even for polygons spanning
+/// many degrees, and polygons enclosing a pole are handled correctly.
+///
+/// Coordinates are interpreted as longitude (`x`) and latitude (`y`) in degrees.
+///
+/// # Units
+///
+/// - area: meters²
+/// - perimeter: meters
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Polygon};
+/// use geo::GeodesicArea;
+///
+/// // The O2 in London
+/// let mut polygon: Polygon<f64> = polygon![
+///     (x: 0.00388383, y: 51.501574),
+///     (x: 0.00538587, y: 51.502278),
+///     (x: 0.00553607, y: 51.503299),
+///     (x: 0.00467777, y: 51.504181),
+///     (x: 0.00327229, y: 51.504435),
+///     (x: 0.00187754, y: 51.504168),
+///     (x: 0.00087976, y: 51.503380),
+///     (x: 0.00107288, y: 51.502324),
+///     (x: 0.00185608, y: 51.501770),
+///     (x: 0.00388383, y: 51.501574),
+/// ];
+///
+/// // 78,596 meters²
+/// assert_eq!(78_596., polygon.geodesic_area_unsigned().round());
+/// assert_eq!(78_596., polygon.geodesic_area_signed().round());
+///
+/// // 1,017 meters
+/// assert_eq!(1_017., polygon.geodesic_perimeter().round());
+///
+/// polygon.exterior_mut(|line_string| {
+///     line_string.0.reverse();
+/// });
+///
+/// assert_eq!(78_596., polygon.geodesic_area_unsigned().round());
+/// assert_eq!(-78_596., polygon.geodesic_area_signed().round());
+/// ```
+///
+/// [Karney (2013)]: https://arxiv.org/pdf/1109.4448.pdf
+/// [GeographicLib]: https://geographiclib.sourceforge.io/
+/// [`ChamberlainDuquetteArea`]: crate::ChamberlainDuquetteArea
+pub trait GeodesicArea<T> {
+    /// Determine the signed area of a geometry.
+    ///
+    /// A `Polygon` whose exterior ring is wound counter-clockwise has a positive area, and a
+    /// negative area otherwise. Interior rings are always subtracted from the magnitude of the
+    /// exterior's area, regardless of their winding.
+    ///
+    /// Because the sign determines which side of a ring is its inside, the magnitude of the
+    /// result is limited to half the area of the earth.
+    fn geodesic_area_signed(&self) -> T;
+
+    /// Determine the unsigned area of a geometry, regardless of the winding of its rings.
+    fn geodesic_area_unsigned(&self) -> T;
+
+    /// Determine the perimeter of a geometry, i.e. the total length of its exterior and interior
+    /// rings.
+    fn geodesic_perimeter(&self) -> T;
+}
+
+impl GeodesicArea<f64> for Polygon {
+    fn geodesic_area_signed(&self) -> f64 {
+        let (exterior_area, _) = ring_area_perimeter(self.exterior());
+        let interiors_area = self
+            .interiors()
+            .iter()
+            .fold(0., |total, next| total + ring_area_perimeter(next).0.abs());
+        if exterior_area < 0. {
+            exterior_area + interiors_area
+        } else {
+            exterior_area - interiors_area
+        }
+    }
+
+    fn geodesic_area_unsigned(&self) -> f64 {
+        self.geodesic_area_signed().abs()
+    }
+
+    fn geodesic_perimeter(&self) -> f64 {
+        self.interiors()
+            .iter()
+            .fold(ring_area_perimeter(self.exterior()).1, |total, next| {
+                total + ring_area_perimeter(next).1
+            })
+    }
+}
+
+/// The signed area (positive when counter-clockwise) and the perimeter of a ring.
+fn ring_area_perimeter(ring: &LineString) -> (f64, f64) {
+    // `PolygonArea` closes the ring itself, so the repeated closing coordinate is skipped
+    let coords = match ring.0.as_slice() {
+        [first, .., last] if first == last => &ring.0[..ring.0.len() - 1],
+        coords => coords,
+    };
+    if coords.len() < 3 {
+        return (0., 0.);
+    }
+
+    let geoid = Geodesic::wgs84();
+    let mut polygon_area = PolygonArea::new(&geoid, Winding::CounterClockwise);
+    for coord in coords {
+        polygon_area.add_point(coord.y, coord.x);
+    }
+    let (perimeter, area, _) = polygon_area.compute(true);
+    (area, perimeter)
+}
+
+/// Generate a `GeodesicArea` implementation where the result is zero.
+macro_rules! zero_impl {
+    ($type:ident) => {
+        impl GeodesicArea<f64> for $type {
+            fn geodesic_area_signed(&self) -> f64 {
+                0.
+            }
+
+            fn geodesic_area_unsigned(&self) -> f64 {
+                0.
+            }
+
+            fn geodesic_perimeter(&self) -> f64 {
+                0.
+            }
+        }
+    };
+}
+
+/// Generate a `GeodesicArea` implementation which delegates to the `Polygon` implementation.
+macro_rules! to_polygon_impl {
+    ($type:ident) => {
+        impl GeodesicArea<f64> for $type {
+            fn geodesic_area_signed(&self) -> f64 {
+                self.to_polygon().geodesic_area_signed()
+            }
+
+            fn geodesic_area_unsigned(&self) -> f64 {
+                self.to_polygon().geodesic_area_unsigned()
+            }
+
+            fn geodesic_perimeter(&self) -> f64 {
+                self.to_polygon().geodesic_perimeter()
+            }
+        }
+    };
+}
+
+/// Generate a `GeodesicArea` implementation which calculates the area for each of its
+/// sub-components and sums them up.
+macro_rules! sum_impl {
+    ($type:ident) => {
+        impl GeodesicArea<f64> for $type {
+            fn geodesic_area_signed(&self) -> f64 {
+                self.iter()
+                    .fold(0., |total, next| total + next.geodesic_area_signed())
+            }
+
+            fn geodesic_area_unsigned(&self) -> f64 {
+                self.iter()
+                    .fold(0., |total, next| total + next.geodesic_area_unsigned())
+            }
+
+            fn geodesic_perimeter(&self) -> f64 {
+                self.iter()
+                    .fold(0., |total, next| total + next.geodesic_perimeter())
+            }
+        }
+    };
+}
+
+zero_impl!(Point);
+zero_impl!(Line);
+zero_impl!(LineString);
+zero_impl!(MultiPoint);
+zero_impl!(MultiLineString);
+to_polygon_impl!(Triangle);
+sum_impl!(GeometryCollection);
+sum_impl!(MultiPolygon);
+
+/// A `Rect` has no winding, so like its planar [`Area`](crate::Area) its signed area is always
+/// positive.
+impl GeodesicArea<f64> for Rect {
+    fn geodesic_area_signed(&self) -> f64 {
+        self.geodesic_area_unsigned()
+    }
+
+    fn geodesic_area_unsigned(&self) -> f64 {
+        self.to_polygon().geodesic_area_unsigned()
+    }
+
+    fn geodesic_perimeter(&self) -> f64 {
+        self.to_polygon().geodesic_perimeter()
+    }
+}
+
+impl GeodesicArea<f64> for Geometry {
+    crate::geometry_delegate_impl! {
+        fn geodesic_area_signed(&self) -> f64;
+        fn geodesic_area_unsigned(&self) -> f64;
+        fn geodesic_perimeter(&self) -> f64;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, ChamberlainDuquetteArea};
+
+    // Antarctica, from the GeographicLib documentation
+    // https://geographiclib.sourceforge.io/Python/doc/examples.html#computing-the-area-of-a-geodesic-polygon
+    fn antarctica() -> Polygon {
+        polygon![
+            (x: -58., y: -63.1),
+            (x: -74., y: -72.9),
+            (x: -102., y: -71.9),
+            (x: -102., y: -74.9),
+            (x: -131., y: -74.3),
+            (x: -163., y: -77.5),
+            (x: 163., y: -77.4),
+            (x: 172., y: -71.7),
+            (x: 140., y: -65.9),
+            (x: 113., y: -65.7),
+            (x: 88., y: -66.6),
+            (x: 59., y: -66.9),
+            (x: 25., y: -69.8),
+            (x: -4., y: -70.0),
+            (x: -14., y: -71.0),
+            (x: -33., y: -77.3),
+            (x: -46., y: -77.9),
+            (x: -61., y: -74.7),
+        ]
+    }
+
+    #[test]
+    fn test_polygon_enclosing_pole() {
+        let polygon = antarctica();
+        assert_relative_eq!(
+            polygon.geodesic_area_signed(),
+            13662703680020.1,
+            epsilon = 1.
+        );
+        assert_relative_eq!(
+            polygon.geodesic_area_unsigned(),
+            13662703680020.1,
+            epsilon = 1.
+        );
+        assert_relative_eq!(polygon.geodesic_perimeter(), 16831067.893, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_reversed_polygon_enclosing_pole() {
+        let mut polygon = antarctica();
+        polygon.exterior_mut(|line_string| line_string.0.reverse());
+        assert_relative_eq!(
+            polygon.geodesic_area_signed(),
+            -13662703680020.1,
+            epsilon = 1.
+        );
+        assert_relative_eq!(
+            polygon.geodesic_area_unsigned(),
+            13662703680020.1,
+            epsilon = 1.
+        );
+        assert_relative_eq!(polygon.geodesic_perimeter(), 16831067.893, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn test_one_degree_square() {
+        // reference values from GeographicLib's `PolygonArea`
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+        ];
+        assert_relative_eq!(
+            polygon.geodesic_area_signed(),
+            12308778361.469,
+            epsilon = 1e-3
+        );
+        assert_relative_eq!(polygon.geodesic_perimeter(), 443770.917, epsilon = 1e-3);
+        // the spherical approximation differs noticeably at this scale
+        let approximation = polygon.chamberlain_duquette_unsigned_area();
+        assert!((approximation - polygon.geodesic_area_unsigned()).abs() > 1e6);
+    }
+
+    #[test]
+    fn test_polygon_spanning_antimeridian() {
+        // the same one degree square as above, shifted to straddle the antimeridian
+        let polygon = polygon![
+            (x: 179.5, y: 0.),
+            (x: -179.5, y: 0.),
+            (x: -179.5, y: 1.),
+            (x: 179.5, y: 1.),
+            (x: 179.5, y: 0.),
+        ];
+        assert_relative_eq!(
+            polygon.geodesic_area_signed(),
+            12308778361.469,
+            epsilon = 1e-3
+        );
+        assert_relative_eq!(polygon.geodesic_perimeter(), 443770.917, epsilon = 1e-3);
+
+        let mut reversed = polygon;
+        reversed.exterior_mut(|line_string| line_string.0.reverse());
+        assert_relative_eq!(
+            reversed.geodesic_area_signed(),
+            -12308778361.469,
+            epsilon = 1e-3
+        );
+    }
+
+    #[test]
+    fn test_rect_and_triangle() {
+        let rect = Rect::new((0., 0.), (1., 1.));
+        assert_relative_eq!(rect.geodesic_area_signed(), 12308778361.469, epsilon = 1e-3);
+        assert_relative_eq!(rect.geodesic_perimeter(), 443770.917, epsilon = 1e-3);
+
+        let triangle = Triangle::from([(0., 0.), (1., 0.), (0., 1.)]);
+        assert_relative_eq!(
+            triangle.geodesic_area_signed(),
+            triangle.to_polygon().geodesic_area_signed()
+        );
+        assert!(triangle.geodesic_area_signed() > 0.);
+        // the two halves of the square either side of its diagonal differ slightly on the
+        // ellipsoid, but together make up the whole
+        let other_half = Triangle::from([(1., 0.), (1., 1.), (0., 1.)]);
+        assert_relative_eq!(
+            triangle.geodesic_area_signed() + other_half.geodesic_area_signed(),
+            rect.geodesic_area_signed(),
+            epsilon = 1e-3
+        );
+    }
+
+    #[test]
+    fn test_polygon_with_interior() {
+        let exterior = polygon![
+            (x: 0., y: 0.),
+            (x: 1., y: 0.),
+            (x: 1., y: 1.),
+            (x: 0., y: 1.),
+            (x: 0., y: 0.),
+        ];
+        let interior = polygon![
+            (x: 0.25, y: 0.25),
+            (x: 0.75, y: 0.25),
+            (x: 0.75, y: 0.75),
+            (x: 0.25, y: 0.75),
+            (x: 0.25, y: 0.25),
+        ];
+        let polygon = Polygon::new(
+            exterior.exterior().clone(),
+            vec![interior.exterior().clone()],
+        );
+        assert_relative_eq!(
+            polygon.geodesic_area_signed(),
+            exterior.geodesic_area_signed() - interior.geodesic_area_signed(),
+            epsilon = 1e-3
+        );
+        assert_relative_eq!(
+            polygon.geodesic_perimeter(),
+            exterior.geodesic_perimeter() + interior.geodesic_perimeter(),
+            epsilon = 1e-3
+        );
+
+        // the winding of the interior doesn't matter
+        let mut reversed_interior = interior.exterior().clone();
+        reversed_interior.0.reverse();
+        let polygon = Polygon::new(exterior.exterior().clone(), vec![reversed_interior]);
+        assert_relative_eq!(
+            polygon.geodesic_area_signed(),
+            exterior.geodesic_area_signed() - interior.geodesic_area_signed(),
+            epsilon = 1e-3
+        );
+    }
+
+    #[test]
+    fn test_multi_polygon() {
+        let polygon = antarctica();
+        let multi_polygon = MultiPolygon::new(vec![polygon.clone(), polygon]);
+        assert_relative_eq!(
+            multi_polygon.geodesic_area_unsigned(),
+            2. * 13662703680020.1,
+            epsilon = 1.
+        );
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let line_string = LineString::from(vec![(0., 0.), (1., 1.)]);
+        let polygon = Polygon::new(line_string.clone(), vec![]);
+        assert_eq!(polygon.geodesic_area_signed(), 0.);
+        assert_eq!(polygon.geodesic_perimeter(), 0.);
+        assert_eq!(line_string.geodesic_area_unsigned(), 0.);
+    }
+}
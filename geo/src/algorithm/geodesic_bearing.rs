@@ -0,0 +1,178 @@
+use crate::{CoordNum, Point};
+use geographiclib_rs::{Geodesic, InverseGeodesic};
+
+/// Returns the initial bearing, or forward azimuth, of the geodesic from one point to another on
+/// an ellipsoidal model of the earth.
+///
+/// This uses the geodesic methods given by [Karney (2013)], and so is consistent with
+/// [`GeodesicDistance`](crate::GeodesicDistance). Unlike [`Bearing`](crate::Bearing), which works
+/// on a sphere, it matches the `azi1` output of GeographicLib.
+///
+/// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
+pub trait GeodesicBearing<T: CoordNum> {
+    /// Returns the initial bearing from `self` to `point` along the geodesic between them.
+    ///
+    /// # Units
+    ///
+    /// - return value: degrees, between -180 and 180, where zero degrees is north and 90 degrees
+    ///   is east, the same convention as [`Bearing`](crate::Bearing)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use approx::assert_relative_eq;
+    /// use geo::GeodesicBearing;
+    /// use geo::point;
+    ///
+    /// // JFK airport
+    /// let p_1 = point!(x: -73.78, y: 40.64);
+    /// // Singapore Changi airport
+    /// let p_2 = point!(x: 103.99, y: 1.36);
+    ///
+    /// // the shortest route is almost due north, over the pole
+    /// let bearing = p_1.geodesic_bearing(p_2);
+    /// assert_relative_eq!(bearing, 3.3, epsilon = 0.05);
+    /// ```
+    fn geodesic_bearing(&self, point: Point<T>) -> T;
+
+    /// Returns the initial bearing from `self` to `point`, and the distance between them, from a
+    /// single geodesic calculation.
+    ///
+    /// # Units
+    ///
+    /// - return value: (degrees, meters), where the bearing is as for
+    ///   [`geodesic_bearing`](Self::geodesic_bearing)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use approx::assert_relative_eq;
+    /// use geo::{GeodesicBearing, GeodesicDistance};
+    /// use geo::point;
+    ///
+    /// let p_1 = point!(x: -73.78, y: 40.64);
+    /// let p_2 = point!(x: 103.99, y: 1.36);
+    ///
+    /// let (bearing, distance) = p_1.geodesic_bearing_distance(p_2);
+    /// assert_eq!(bearing, p_1.geodesic_bearing(p_2));
+    /// assert_eq!(distance, p_1.geodesic_distance(&p_2));
+    /// ```
+    fn geodesic_bearing_distance(&self, point: Point<T>) -> (T, T);
+}
+
+impl GeodesicBearing<f64> for Point {
+    fn geodesic_bearing(&self, point: Point) -> f64 {
+        self.geodesic_bearing_distance(point).0
+    }
+
+    fn geodesic_bearing_distance(&self, point: Point) -> (f64, f64) {
+        let (distance, azi1, _azi2, _a12) =
+            Geodesic::wgs84().inverse(self.y(), self.x(), point.y(), point.x());
+        (azi1, distance)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, Bearing, GeodesicDistance};
+    use geographiclib_rs::DirectGeodesic;
+
+    #[test]
+    fn cardinal_directions() {
+        let origin = point!(x: 0., y: 0.);
+        assert_relative_eq!(origin.geodesic_bearing(point!(x: 0., y: 1.)), 0.);
+        assert_relative_eq!(origin.geodesic_bearing(point!(x: 1., y: 0.)), 90.);
+        assert_relative_eq!(origin.geodesic_bearing(point!(x: 0., y: -1.)), 180.);
+        assert_relative_eq!(origin.geodesic_bearing(point!(x: -1., y: 0.)), -90.);
+    }
+
+    #[test]
+    fn matches_direct_problem() {
+        // travelling from a point along the bearing for the distance reaches the other point
+        let p_1 = point!(x: 9.177789688110352, y: 48.776781529534965);
+        let p_2 = point!(x: 9.274410083250379, y: 48.84033282787534);
+        let (bearing, distance) = p_1.geodesic_bearing_distance(p_2);
+        let (lat, lon) = Geodesic::wgs84().direct(p_1.y(), p_1.x(), bearing, distance);
+        assert_relative_eq!(point!(x: lon, y: lat), p_2, epsilon = 1e-9);
+        assert_relative_eq!(distance, p_1.geodesic_distance(&p_2));
+
+        // on the ellipsoid, the bearing differs slightly from the one on a sphere
+        let spherical = p_1.bearing(p_2);
+        assert!((bearing - spherical).abs() > 1e-6);
+        assert_relative_eq!(bearing, spherical, epsilon = 0.5);
+    }
+
+    #[test]
+    fn near_the_poles() {
+        // between two points near the north pole, on opposite meridians, the geodesic goes
+        // over the pole
+        let p_1 = point!(x: 10., y: 89.9);
+        let p_2 = point!(x: -170., y: 89.9);
+        let (bearing, distance) = p_1.geodesic_bearing_distance(p_2);
+        assert_relative_eq!(bearing, 0., epsilon = 1e-9);
+        // the meridian distance between latitude 89.9 and the pole, twice
+        assert_relative_eq!(distance, 2. * 11_169.6, epsilon = 1.);
+
+        // from the south pole, every direction is north
+        let south_pole = point!(x: 0., y: -90.);
+        let bearing = south_pole.geodesic_bearing(point!(x: 45., y: -80.));
+        assert_relative_eq!(bearing, 45., epsilon = 1e-9);
+    }
+
+    #[test]
+    fn across_the_antimeridian() {
+        let p_1 = point!(x: 179.5, y: 10.);
+        let p_2 = point!(x: -179.5, y: 10.);
+        let (bearing, distance) = p_1.geodesic_bearing_distance(p_2);
+        // slightly north of east, rather than west the long way around
+        assert!(bearing > 89.9 && bearing < 90.);
+        assert!(distance < 110_000.);
+
+        let (reverse_bearing, reverse_distance) = p_2.geodesic_bearing_distance(p_1);
+        assert_relative_eq!(reverse_bearing, -bearing, epsilon = 1e-9);
+        assert_relative_eq!(reverse_distance, distance, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn same_convention_as_bearing() {
+        // bearings in every quadrant, including due south and west, agree with the spherical
+        // bearing to within the difference between the sphere and the ellipsoid
+        let origin = point!(x: 20., y: 30.);
+        for (dx, dy) in [
+            (0., 1.),
+            (1., 1.),
+            (1., 0.),
+            (1., -1.),
+            (0., -1.),
+            (-1., -1.),
+            (-1., 0.),
+            (-1., 1.),
+        ] {
+            let other = point!(x: 20. + dx, y: 30. + dy);
+            let geodesic = origin.geodesic_bearing(other);
+            let spherical = origin.bearing(other);
+            assert!((-180. ..=180.).contains(&geodesic));
+            let difference = (geodesic - spherical + 540.) % 360. - 180.;
+            assert!(difference.abs() < 0.5, "{} vs {}", geodesic, spherical);
+        }
+    }
+
+    #[test]
+    fn nearly_antipodal() {
+        // the geodesic between nearly antipodal points on the equator heads far from the
+        // equator, unlike the great circle along it
+        let p_1 = point!(x: 0., y: 0.);
+        let p_2 = point!(x: 179.5, y: 0.5);
+        let (bearing, distance) = p_1.geodesic_bearing_distance(p_2);
+        assert!(bearing.abs() < 90.);
+        let (lat, lon) = Geodesic::wgs84().direct(p_1.y(), p_1.x(), bearing, distance);
+        assert_relative_eq!(point!(x: lon, y: lat), p_2, epsilon = 1e-9);
+        assert_relative_eq!(distance, p_1.geodesic_distance(&p_2));
+
+        // and it's the shortest route, so the same length in both directions
+        let (reverse_bearing, reverse_distance) = p_2.geodesic_bearing_distance(p_1);
+        assert!((-180. ..=180.).contains(&reverse_bearing));
+        assert_relative_eq!(reverse_distance, distance, epsilon = 1e-6);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{CoordNum, Point};
+use geographiclib_rs::{DirectGeodesic, Geodesic};
+
+/// Returns a new Point using the distance to the existing Point and a bearing for the direction,
+/// on an ellipsoidal model of the earth.
+///
+/// This solves the direct geodesic problem on the WGS84 ellipsoid, using the methods given by
+/// [Karney (2013)], and so is the inverse of [`GeodesicDistance`](crate::GeodesicDistance) and
+/// [`GeodesicBearing`](crate::GeodesicBearing). It is more accurate than
+/// [`HaversineDestination`](crate::HaversineDestination), which works on a sphere.
+///
+/// [Karney (2013)]:  https://arxiv.org/pdf/1109.4448.pdf
+pub trait GeodesicDestination<T: CoordNum> {
+    /// Returns a new Point using distance to the existing Point and a bearing for the direction
+    ///
+    /// # Units
+    ///
+    /// - `bearing`: degrees, zero degrees is north and 90 degrees is east
+    /// - `distance`: meters
+    /// - return value: a Point whose longitude is between -180 and 180 degrees
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use approx::assert_relative_eq;
+    /// use geo::{GeodesicDestination, GeodesicDistance};
+    /// use geo::Point;
+    ///
+    /// let p_1 = Point::new(9.177789688110352, 48.776781529534965);
+    /// let p_2 = p_1.geodesic_destination(45., 10000.);
+    /// assert_relative_eq!(p_2, Point::new(9.27411867078536, 48.8403266058781), epsilon = 1.0e-6);
+    /// assert_relative_eq!(p_1.geodesic_distance(&p_2), 10000., epsilon = 1.0e-6);
+    /// ```
+    fn geodesic_destination(&self, bearing: T, distance: T) -> Point<T>;
+}
+
+impl GeodesicDestination<f64> for Point<f64> {
+    fn geodesic_destination(&self, bearing: f64, distance: f64) -> Point<f64> {
+        let (lat, lon) = Geodesic::wgs84().direct(self.y(), self.x(), bearing, distance);
+        Point::new(lon, lat)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{GeodesicBearing, GeodesicDistance};
+
+    /// Checks that the distance and bearing from `start` back to its destination are the ones it
+    /// was found with.
+    fn assert_round_trip(start: Point, bearing: f64, distance: f64) {
+        let end = start.geodesic_destination(bearing, distance);
+        let (bearing_back, distance_back) = start.geodesic_bearing_distance(end);
+        // to sub-millimeter
+        assert_relative_eq!(distance_back, distance, epsilon = 1.0e-4);
+        assert_relative_eq!(start.geodesic_distance(&end), distance, epsilon = 1.0e-4);
+        assert_relative_eq!(bearing_back, bearing, epsilon = 1.0e-6);
+    }
+
+    #[test]
+    fn returns_a_new_point() {
+        let p_1 = Point::new(9.177789688110352, 48.776781529534965);
+        let p_2 = p_1.geodesic_destination(45., 10000.);
+        assert_relative_eq!(
+            p_2,
+            Point::new(9.27411867078536, 48.8403266058781),
+            epsilon = 1.0e-6
+        );
+        assert_round_trip(p_1, 45., 10000.);
+    }
+
+    #[test]
+    fn round_trips() {
+        let starts = [
+            Point::new(0., 0.),
+            Point::new(-73.78, 40.64),
+            Point::new(103.99, 1.36),
+            Point::new(151.21, -33.87),
+        ];
+        for start in starts {
+            for bearing in [-135., -90., -30., 0., 10., 90., 179.] {
+                for distance in [1., 1000., 100_000., 5_000_000.] {
+                    assert_round_trip(start, bearing, distance);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn bearing_zero_is_north() {
+        let p_1 = Point::new(9.177789688110352, 48.776781529534965);
+        let p_2 = p_1.geodesic_destination(0., 1000.);
+        assert_relative_eq!(p_1.x(), p_2.x(), epsilon = 1.0e-9);
+        assert!(p_2.y() > p_1.y());
+
+        // a meridian quadrant, from the equator to the north pole, is about 10,001,966m long
+        let pole = Point::new(0., 0.).geodesic_destination(0., 10_001_965.729);
+        assert_relative_eq!(pole.y(), 90., epsilon = 1.0e-6);
+    }
+
+    #[test]
+    fn near_the_poles() {
+        assert_round_trip(Point::new(30., 89.9), 90., 20_000.);
+        assert_round_trip(Point::new(-120., -89.5), 45., 50_000.);
+
+        // heading north past the pole comes down the other side
+        let start = Point::new(10., 89.9);
+        let end = start.geodesic_destination(0., 22_000.);
+        assert_relative_eq!(end.x(), -170., epsilon = 1.0e-6);
+        assert!(end.y() < 90. && end.y() > 89.9);
+        assert_relative_eq!(start.geodesic_distance(&end), 22_000., epsilon = 1.0e-4);
+    }
+
+    #[test]
+    fn across_the_antimeridian() {
+        let start = Point::new(179.9, -16.5);
+        let end = start.geodesic_destination(90., 50_000.);
+        assert!(end.x() < -179.);
+        assert_round_trip(start, 90., 50_000.);
+
+        let start = Point::new(-179.95, 52.);
+        let end = start.geodesic_destination(-90., 20_000.);
+        assert!(end.x() > 179.);
+        assert_round_trip(start, -90., 20_000.);
+    }
+}
@@ -28,6 +28,30 @@ pub trait GeodesicIntermediate<T: CoordFloat> {
     /// ```
 
     fn geodesic_intermediate(&self, other: &Point<T>, f: T) -> Point<T>;
+
+    /// Returns the Points along the geodesic between two existing points, evenly spaced so that
+    /// none are more than `max_dist` meters apart, including the existing points if
+    /// `include_ends` is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// #
+    /// use geo::{GeodesicDistance, GeodesicIntermediate};
+    /// use geo::Point;
+    ///
+    /// let p1 = Point::new(10.0, 20.0);
+    /// let p2 = Point::new(125.0, 25.0);
+    /// // about 11,800 km apart, so split into 12 pieces
+    /// let route = p1.geodesic_intermediate_fill(&p2, 1_000_000.0, true);
+    /// assert_eq!(route.len(), 13);
+    /// assert_relative_eq!(
+    ///     route[0].geodesic_distance(&route[1]),
+    ///     p1.geodesic_distance(&p2) / 12.0,
+    ///     epsilon = 1.0e-6
+    /// );
+    /// ```
     fn geodesic_intermediate_fill(
         &self,
         other: &Point<T>,
@@ -66,16 +90,17 @@ impl GeodesicIntermediate<f64> for Point {
         }
 
         let number_of_points = (total_distance / max_dist).ceil();
-        let interval = 1.0 / number_of_points;
-
-        let mut current_step = interval;
         let mut points = if include_ends { vec![*self] } else { vec![] };
 
-        while current_step < 1.0 {
-            let (lat2, lon2) = g.direct(self.y(), self.x(), azi1, total_distance * current_step);
-            let point = Point::new(lon2, lat2);
-            points.push(point);
-            current_step += interval;
+        // The inverse problem is solved once, above, and each point is found from the start by
+        // the direct problem. Each fraction is found afresh, rather than by adding up steps, so
+        // that rounding error can't add or drop a point at the end.
+        let mut step = 1.0;
+        while step < number_of_points {
+            let distance = total_distance * (step / number_of_points);
+            let (lat2, lon2) = g.direct(self.y(), self.x(), azi1, distance);
+            points.push(Point::new(lon2, lat2));
+            step += 1.0;
         }
 
         if include_ends {
@@ -126,4 +151,42 @@ mod tests {
         let route = p1.geodesic_intermediate_fill(&p2, max_dist, include_ends);
         assert_eq!(route, vec![p1, i50, p2]);
     }
+
+    #[test]
+    fn fill_is_evenly_spaced() {
+        // a route 10,000 km long
+        let g = Geodesic::wgs84();
+        let p1 = Point::new(-40.0, 10.0);
+        let (lat2, lon2) = g.direct(p1.y(), p1.x(), 50.0, 10_000_000.0);
+        let p2 = Point::new(lon2, lat2);
+
+        let route = p1.geodesic_intermediate_fill(&p2, 100_000.0, true);
+        assert_eq!(route.len(), 101);
+        assert_eq!(route[0], p1);
+        assert_eq!(route[100], p2);
+        for pair in route.windows(2) {
+            let (distance, _, _, _) = g.inverse(pair[0].y(), pair[0].x(), pair[1].y(), pair[1].x());
+            assert_relative_eq!(distance, 100_000.0, epsilon = 3.0e-3);
+        }
+    }
+
+    #[test]
+    fn fill_has_no_extra_points() {
+        let p1 = Point::new(10.0, 20.0);
+        let p2 = Point::new(125.0, 25.0);
+        let g = Geodesic::wgs84();
+        let (total, _, _, _) = g.inverse(p1.y(), p1.x(), p2.y(), p2.x());
+
+        // adding up ten steps of 0.1 gives slightly less than 1
+        let route = p1.geodesic_intermediate_fill(&p2, total / 9.5, true);
+        assert_eq!(route.len(), 11);
+        let route = p1.geodesic_intermediate_fill(&p2, total / 9.5, false);
+        assert_eq!(route.len(), 9);
+
+        assert_eq!(
+            p1.geodesic_intermediate_fill(&p2, total, true),
+            vec![p1, p2]
+        );
+        assert!(p1.geodesic_intermediate_fill(&p2, total, false).is_empty());
+    }
 }
@@ -0,0 +1,281 @@
+use crate::{Coord, GeoFloat, MultiPoint, Point};
+
+/// Calculate robust centres of a set of points, which unlike the [`Centroid`](crate::Centroid)
+/// aren't dragged far off by a few outliers.
+///
+/// Together with [`OutlierDetection`](crate::OutlierDetection), this allows a centre to be
+/// estimated for noisy data.
+pub trait GeometricMedian<T>
+where
+    T: GeoFloat,
+{
+    /// The [geometric median](https://en.wikipedia.org/wiki/Geometric_median): the point which
+    /// minimises the sum of the Euclidean distances to all the points, along with the number
+    /// of iterations taken to find it.
+    ///
+    /// The median is found using Weiszfeld's algorithm, with the modification of Vardi and
+    /// Zhang (2000) for when an estimate lands on one of the points. Starting from the centroid,
+    /// each iteration moves the estimate closer to the median, stopping once it moves no further
+    /// than `tolerance`, or after `max_iterations` iterations, in which case the iteration count
+    /// is `max_iterations` and the estimate may not have converged.
+    ///
+    /// Returns `None` if there are no points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_relative_eq;
+    /// use geo::{point, Centroid, GeometricMedian, MultiPoint};
+    ///
+    /// let points = MultiPoint::new(vec![
+    ///     point!(x: 0., y: 0.),
+    ///     point!(x: 2., y: 0.),
+    ///     point!(x: 2., y: 2.),
+    ///     point!(x: 0., y: 2.),
+    ///     point!(x: 100., y: 1.),
+    /// ]);
+    ///
+    /// let (median, iterations) = points.geometric_median(1e-9, 1000).unwrap();
+    /// assert!(iterations < 1000);
+    /// // the outlier pulls the median just beyond the square, but the centroid far off it
+    /// assert!(median.x() < 3.);
+    /// assert_relative_eq!(median.y(), 1., epsilon = 1e-9);
+    /// assert_relative_eq!(points.centroid().unwrap().x(), 20.8);
+    /// ```
+    fn geometric_median(&self, tolerance: T, max_iterations: usize) -> Option<(Point<T>, usize)>;
+
+    /// The medoid: the one of the points which minimises the sum of the Euclidean distances to
+    /// all the others, choosing the first if there's a tie.
+    ///
+    /// This takes time in proportion to the square of the number of points. Returns `None` if
+    /// there are no points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, GeometricMedian};
+    ///
+    /// let points = vec![
+    ///     point!(x: 0., y: 0.),
+    ///     point!(x: 2., y: 0.),
+    ///     point!(x: 1., y: 1.),
+    ///     point!(x: 100., y: 1.),
+    /// ];
+    ///
+    /// assert_eq!(points.medoid(), Some(point!(x: 2., y: 0.)));
+    /// ```
+    fn medoid(&self) -> Option<Point<T>>;
+}
+
+impl<T> GeometricMedian<T> for MultiPoint<T>
+where
+    T: GeoFloat,
+{
+    fn geometric_median(&self, tolerance: T, max_iterations: usize) -> Option<(Point<T>, usize)> {
+        self.0.geometric_median(tolerance, max_iterations)
+    }
+
+    fn medoid(&self) -> Option<Point<T>> {
+        self.0.medoid()
+    }
+}
+
+impl<T> GeometricMedian<T> for [Point<T>]
+where
+    T: GeoFloat,
+{
+    fn geometric_median(&self, tolerance: T, max_iterations: usize) -> Option<(Point<T>, usize)> {
+        if self.is_empty() {
+            return None;
+        }
+        let count = T::from(self.len()).unwrap();
+        let mut median = self.iter().fold(Coord::zero(), |sum, point| sum + point.0) / count;
+        for iteration in 1..=max_iterations {
+            let next = weiszfeld_step(self, median);
+            let step = distance(next, median);
+            median = next;
+            if step <= tolerance {
+                return Some((median.into(), iteration));
+            }
+        }
+        Some((median.into(), max_iterations))
+    }
+
+    fn medoid(&self) -> Option<Point<T>> {
+        let total_distance = |median: &Point<T>| {
+            self.iter()
+                .fold(T::zero(), |sum, point| sum + distance(point.0, median.0))
+        };
+        self.iter()
+            .map(|point| (point, total_distance(point)))
+            .fold(
+                None,
+                |best: Option<(&Point<T>, T)>, (point, total)| match best {
+                    Some((_, best_total)) if best_total <= total => best,
+                    _ => Some((point, total)),
+                },
+            )
+            .map(|(point, _)| *point)
+    }
+}
+
+fn distance<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> T {
+    let diff = a - b;
+    diff.x.hypot(diff.y)
+}
+
+/// The next estimate of the median after `median`.
+fn weiszfeld_step<T: GeoFloat>(points: &[Point<T>], median: Coord<T>) -> Coord<T> {
+    let mut weighted_sum = Coord::zero();
+    let mut weight = T::zero();
+    // the sum of the unit vectors from `median` towards each point
+    let mut pull = Coord::zero();
+    let mut coincident = T::zero();
+    for point in points {
+        let d = distance(point.0, median);
+        if d.is_zero() {
+            coincident = coincident + T::one();
+            continue;
+        }
+        weighted_sum = weighted_sum + point.0 / d;
+        weight = weight + T::one() / d;
+        pull = pull + (point.0 - median) / d;
+    }
+    if weight.is_zero() {
+        // all the points are at `median`
+        return median;
+    }
+    let weiszfeld = weighted_sum / weight;
+    if coincident.is_zero() {
+        return weiszfeld;
+    }
+    // `median` is one of the points, which is the median itself unless the others pull harder
+    // than the points there
+    let pull = distance(pull, Coord::zero());
+    if pull <= coincident {
+        return median;
+    }
+    let ratio = coincident / pull;
+    weiszfeld * (T::one() - ratio) + median * ratio
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{point, Centroid};
+    use approx::assert_relative_eq;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn empty() {
+        let points: Vec<Point> = vec![];
+        assert_eq!(points.geometric_median(1e-9, 100), None);
+        assert_eq!(points.medoid(), None);
+    }
+
+    #[test]
+    fn single_point() {
+        let points = MultiPoint::new(vec![point!(x: 3., y: 4.)]);
+        assert_eq!(
+            points.geometric_median(1e-9, 100),
+            Some((point!(x: 3., y: 4.), 1))
+        );
+        assert_eq!(points.medoid(), Some(point!(x: 3., y: 4.)));
+    }
+
+    #[test]
+    fn symmetric() {
+        let points = [
+            point!(x: 0., y: 0.),
+            point!(x: 4., y: 0.),
+            point!(x: 4., y: 4.),
+            point!(x: 0., y: 4.),
+        ];
+        let (median, _) = points.geometric_median(1e-12, 100).unwrap();
+        assert_relative_eq!(median, point!(x: 2., y: 2.));
+    }
+
+    #[test]
+    fn median_on_a_point() {
+        // the median of points in a line is the middle one
+        let points = [
+            point!(x: 0., y: 0.),
+            point!(x: 1., y: 0.),
+            point!(x: 2., y: 0.),
+            point!(x: 3., y: 0.),
+            point!(x: 10., y: 0.),
+        ];
+        let (median, iterations) = points.geometric_median(1e-12, 1000).unwrap();
+        assert_relative_eq!(median, point!(x: 2., y: 0.), epsilon = 1e-9);
+        assert!(iterations < 1000);
+        assert_eq!(points.medoid(), Some(point!(x: 2., y: 0.)));
+
+        // a point is the median when the others pull on it no harder than the points there
+        let points = vec![
+            point!(x: 0., y: 0.),
+            point!(x: 0., y: 0.),
+            point!(x: 1., y: 0.),
+            point!(x: 0., y: 1.),
+        ];
+        assert_eq!(weiszfeld_step(&points, points[0].0), points[0].0);
+    }
+
+    #[test]
+    fn extreme_outlier() {
+        let mut points = vec![
+            point!(x: 1.0, y: 1.0),
+            point!(x: 1.2, y: 0.9),
+            point!(x: 0.8, y: 1.1),
+            point!(x: 1.1, y: 1.2),
+            point!(x: 0.9, y: 0.8),
+            point!(x: 1.0, y: 1.1),
+            point!(x: 1.1, y: 0.9),
+        ];
+        let (without_outlier, _) = points.geometric_median(1e-12, 1000).unwrap();
+        points.push(point!(x: 1000.0, y: -1000.0));
+        let multi_point = MultiPoint::new(points);
+
+        let (median, iterations) = multi_point.geometric_median(1e-12, 1000).unwrap();
+        assert!(iterations < 1000);
+        // the median barely moves, though the centroid is dragged far away
+        assert!(distance(median.0, without_outlier.0) < 0.1);
+        assert!(multi_point.centroid().unwrap().x() > 100.);
+        let medoid = multi_point.medoid().unwrap();
+        assert!(distance(medoid.0, without_outlier.0) < 0.2);
+    }
+
+    #[test]
+    fn max_iterations() {
+        let points = [
+            point!(x: 0., y: 0.),
+            point!(x: 10., y: 0.),
+            point!(x: 0., y: 3.),
+            point!(x: 100., y: 50.),
+        ];
+        let (_, iterations) = points.geometric_median(0., 5).unwrap();
+        assert_eq!(iterations, 5);
+        let (centroid, iterations) = points.geometric_median(1e-9, 0).unwrap();
+        assert_eq!(iterations, 0);
+        assert_relative_eq!(centroid, point!(x: 27.5, y: 13.25));
+    }
+
+    #[test]
+    fn minimises_total_distance() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut next = move || rng.gen_range(0..1000) as f64 / 10.;
+        let points: Vec<Point> = (0..30).map(|_| point!(x: next(), y: next())).collect();
+        let total = |median: Coord| {
+            points
+                .iter()
+                .map(|point| distance(point.0, median))
+                .sum::<f64>()
+        };
+        let (median, _) = points.geometric_median(1e-10, 10_000).unwrap();
+        let best = total(median.0);
+        for offset in [(1e-3, 0.), (-1e-3, 0.), (0., 1e-3), (0., -1e-3)] {
+            assert!(best <= total(median.0 + offset.into()));
+        }
+        assert!(best <= total(points.medoid().unwrap().0));
+    }
+}
@@ -0,0 +1,441 @@
+use crate::coords_iter::CoordsIter;
+use crate::euclidean_distance::EuclideanDistance;
+use crate::{
+    Coord, GeoFloat, Geometry, GeometryCollection, Line, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+use num_traits::Bounded;
+
+/// Determine the similarity between two geometries using the [Hausdorff distance].
+///
+/// The Hausdorff distance is the greatest of all the distances from a vertex of one geometry to
+/// the closest point on the other geometry, taken in both directions. Unlike the
+/// [`FrechetDistance`](crate::FrechetDistance) it ignores the ordering of the vertices, which
+/// makes it suitable for comparing geometries with very different vertex counts.
+///
+/// This is the discrete variant used by [JTS] and [GEOS]: distances are measured from the
+/// vertices of each geometry to the linework of the other. `Polygon`s are therefore compared by
+/// their exterior and interior rings rather than by their area.
+///
+/// The distance is undefined, and `None` is returned, if either geometry is empty.
+///
+/// [Hausdorff distance]: https://en.wikipedia.org/wiki/Hausdorff_distance
+/// [JTS]: https://locationtech.github.io/jts/javadoc/org/locationtech/jts/algorithm/distance/DiscreteHausdorffDistance.html
+/// [GEOS]: https://libgeos.org/doxygen/classgeos_1_1algorithm_1_1distance_1_1DiscreteHausdorffDistance.html
+pub trait HausdorffDistance<T, Rhs = Self> {
+    /// Determine the similarity between two geometries using the [Hausdorff distance].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::HausdorffDistance;
+    /// use geo::line_string;
+    ///
+    /// let line_string_a = line_string![
+    ///     (x: 130., y: 0.),
+    ///     (x: 0., y: 0.),
+    ///     (x: 0., y: 150.),
+    /// ];
+    ///
+    /// let line_string_b = line_string![
+    ///     (x: 10., y: 10.),
+    ///     (x: 10., y: 150.),
+    ///     (x: 130., y: 10.),
+    /// ];
+    ///
+    /// let distance = line_string_a.hausdorff_distance(&line_string_b);
+    ///
+    /// assert_eq!(Some(200f64.sqrt()), distance);
+    /// ```
+    ///
+    /// [Hausdorff distance]: https://en.wikipedia.org/wiki/Hausdorff_distance
+    fn hausdorff_distance(&self, rhs: &Rhs) -> Option<T>;
+
+    /// Determine the directed [Hausdorff distance] from `self` to `rhs`: the greatest distance
+    /// from a vertex of `self` to the closest point on `rhs`.
+    ///
+    /// Unlike [`hausdorff_distance`](Self::hausdorff_distance) this is not symmetric. It is zero
+    /// whenever every vertex of `self` lies on `rhs`, even if `rhs` extends far beyond `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::HausdorffDistance;
+    /// use geo::line_string;
+    ///
+    /// let short = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+    /// let long = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+    ///
+    /// assert_eq!(Some(0.), short.hausdorff_distance_directed(&long));
+    /// assert_eq!(Some(9.), long.hausdorff_distance_directed(&short));
+    /// assert_eq!(Some(9.), short.hausdorff_distance(&long));
+    /// ```
+    ///
+    /// [Hausdorff distance]: https://en.wikipedia.org/wiki/Hausdorff_distance
+    fn hausdorff_distance_directed(&self, rhs: &Rhs) -> Option<T>;
+}
+
+/// The segments a geometry's vertices are measured against when it is the target of a directed
+/// Hausdorff distance. Isolated points are represented by degenerate segments.
+trait Linework<T: GeoFloat> {
+    fn segments(&self) -> Vec<Line<T>>;
+}
+
+impl<T: GeoFloat> Linework<T> for Point<T> {
+    fn segments(&self) -> Vec<Line<T>> {
+        vec![Line::new(self.0, self.0)]
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for Line<T> {
+    fn segments(&self) -> Vec<Line<T>> {
+        vec![*self]
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for LineString<T> {
+    fn segments(&self) -> Vec<Line<T>> {
+        match self.0.as_slice() {
+            [coord] => vec![Line::new(*coord, *coord)],
+            _ => self.lines().collect(),
+        }
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for MultiPoint<T> {
+    fn segments(&self) -> Vec<Line<T>> {
+        self.iter().flat_map(Linework::segments).collect()
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for MultiLineString<T> {
+    fn segments(&self) -> Vec<Line<T>> {
+        self.iter().flat_map(Linework::segments).collect()
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for Polygon<T> {
+    fn segments(&self) -> Vec<Line<T>> {
+        std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .flat_map(Linework::segments)
+            .collect()
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for MultiPolygon<T> {
+    fn segments(&self) -> Vec<Line<T>> {
+        self.iter().flat_map(Linework::segments).collect()
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for Rect<T> {
+    fn segments(&self) -> Vec<Line<T>> {
+        self.to_lines().to_vec()
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for Triangle<T> {
+    fn segments(&self) -> Vec<Line<T>> {
+        self.to_lines().to_vec()
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for GeometryCollection<T> {
+    fn segments(&self) -> Vec<Line<T>> {
+        self.iter().flat_map(Linework::segments).collect()
+    }
+}
+
+impl<T: GeoFloat> Linework<T> for Geometry<T> {
+    crate::geometry_delegate_impl! {
+        fn segments(&self) -> Vec<Line<T>>;
+    }
+}
+
+/// The largest distance from a vertex of `from` to the closest point of `to`, or `None` if
+/// either is empty.
+fn directed_hausdorff_distance<'a, T, A, B>(from: &'a A, to: &B) -> Option<T>
+where
+    T: GeoFloat,
+    A: CoordsIter<'a, Scalar = T>,
+    B: Linework<T>,
+{
+    let segments = to.segments();
+    if segments.is_empty() {
+        return None;
+    }
+    from.coords_iter()
+        .map(|coord: Coord<T>| {
+            segments
+                .iter()
+                .map(|segment| coord.euclidean_distance(segment))
+                .fold(<T as Bounded>::max_value(), |accum, val| accum.min(val))
+        })
+        .reduce(|accum, val| accum.max(val))
+}
+
+macro_rules! impl_hausdorff_distance {
+    ($from:ident, [$($to:ident),*]) => {
+        $(
+            impl<T> HausdorffDistance<T, $to<T>> for $from<T>
+            where
+                T: GeoFloat,
+            {
+                fn hausdorff_distance(&self, rhs: &$to<T>) -> Option<T> {
+                    let forward = directed_hausdorff_distance(self, rhs)?;
+                    let backward = directed_hausdorff_distance(rhs, self)?;
+                    Some(forward.max(backward))
+                }
+
+                fn hausdorff_distance_directed(&self, rhs: &$to<T>) -> Option<T> {
+                    directed_hausdorff_distance(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_hausdorff_distance!(
+    Point,
+    [
+        Point,
+        MultiPoint,
+        LineString,
+        MultiLineString,
+        Polygon,
+        MultiPolygon,
+        Geometry
+    ]
+);
+impl_hausdorff_distance!(
+    MultiPoint,
+    [
+        Point,
+        MultiPoint,
+        LineString,
+        MultiLineString,
+        Polygon,
+        MultiPolygon,
+        Geometry
+    ]
+);
+impl_hausdorff_distance!(
+    LineString,
+    [
+        Point,
+        MultiPoint,
+        LineString,
+        MultiLineString,
+        Polygon,
+        MultiPolygon,
+        Geometry
+    ]
+);
+impl_hausdorff_distance!(
+    MultiLineString,
+    [
+        Point,
+        MultiPoint,
+        LineString,
+        MultiLineString,
+        Polygon,
+        MultiPolygon,
+        Geometry
+    ]
+);
+impl_hausdorff_distance!(
+    Polygon,
+    [
+        Point,
+        MultiPoint,
+        LineString,
+        MultiLineString,
+        Polygon,
+        MultiPolygon,
+        Geometry
+    ]
+);
+impl_hausdorff_distance!(
+    MultiPolygon,
+    [
+        Point,
+        MultiPoint,
+        LineString,
+        MultiLineString,
+        Polygon,
+        MultiPolygon,
+        Geometry
+    ]
+);
+impl_hausdorff_distance!(
+    Geometry,
+    [
+        Point,
+        MultiPoint,
+        LineString,
+        MultiLineString,
+        Polygon,
+        MultiPolygon,
+        Geometry
+    ]
+);
+
+#[cfg(test)]
+mod test {
+    use crate::HausdorffDistance;
+    use crate::{
+        line_string, point, polygon, Geometry, GeometryCollection, LineString, MultiLineString,
+        MultiPoint, MultiPolygon, Rect,
+    };
+
+    // The following expected values are taken from the JTS `DiscreteHausdorffDistanceTest` suite.
+
+    #[test]
+    fn test_line_segments() {
+        let a = line_string![(x: 0., y: 0.), (x: 2., y: 1.)];
+        let b = line_string![(x: 0., y: 0.), (x: 2., y: 0.)];
+        assert_relative_eq!(1., a.hausdorff_distance(&b).unwrap());
+    }
+
+    #[test]
+    fn test_line_segments_2() {
+        let a = line_string![(x: 0., y: 0.), (x: 2., y: 0.)];
+        let b = line_string![(x: 0., y: 1.), (x: 1., y: 2.), (x: 2., y: 1.)];
+        assert_relative_eq!(2., a.hausdorff_distance(&b).unwrap());
+    }
+
+    #[test]
+    fn test_lines_showing_discreteness_effect() {
+        let a = line_string![(x: 130., y: 0.), (x: 0., y: 0.), (x: 0., y: 150.)];
+        let b = line_string![(x: 10., y: 10.), (x: 10., y: 150.), (x: 130., y: 10.)];
+        assert_relative_eq!(14.142135623730951, a.hausdorff_distance(&b).unwrap());
+        assert_relative_eq!(14.142135623730951, b.hausdorff_distance(&a).unwrap());
+    }
+
+    #[test]
+    fn test_identical_linestrings() {
+        let a = line_string![(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.)];
+        assert_relative_eq!(0., a.hausdorff_distance(&a.clone()).unwrap());
+    }
+
+    #[test]
+    fn test_different_point_counts() {
+        // the same path, but sampled with a different number of vertices
+        let a = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        let b = line_string![
+            (x: 0., y: 0.),
+            (x: 2.5, y: 0.),
+            (x: 5., y: 0.),
+            (x: 7.5, y: 0.),
+            (x: 10., y: 0.),
+        ];
+        assert_relative_eq!(0., a.hausdorff_distance(&b).unwrap());
+    }
+
+    #[test]
+    fn test_point_to_polygon() {
+        // the distance is measured to the polygon's boundary, not its area
+        let poly = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let p = point!(x: 2., y: 1.);
+        // the polygon's vertex furthest from the point is (0, 4) or (4, 4)
+        assert_relative_eq!(13f64.sqrt(), p.hausdorff_distance(&poly).unwrap());
+        assert_relative_eq!(13f64.sqrt(), poly.hausdorff_distance(&p).unwrap());
+    }
+
+    #[test]
+    fn test_polygon_with_interior() {
+        let outer = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let with_hole = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+        );
+        // every vertex of the hole is 4 units away from the shared exterior
+        assert_relative_eq!(4., outer.hausdorff_distance(&with_hole).unwrap());
+    }
+
+    #[test]
+    fn test_multi_geometries() {
+        let a = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 0., y: 3.), (x: 1., y: 3.)],
+        ]);
+        let b = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+        assert_relative_eq!(3., a.hausdorff_distance(&b).unwrap());
+
+        let p = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let mp = MultiPolygon::new(vec![p.clone()]);
+        assert_relative_eq!(0., mp.hausdorff_distance(&p).unwrap());
+    }
+
+    #[test]
+    fn test_empty_geometries() {
+        let empty = LineString::<f64>::new(vec![]);
+        let a = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+        assert_eq!(None, empty.hausdorff_distance(&a));
+        assert_eq!(None, a.hausdorff_distance(&empty));
+        assert_eq!(None, empty.hausdorff_distance(&empty));
+        assert_eq!(None, empty.hausdorff_distance_directed(&a));
+        assert_eq!(None, a.hausdorff_distance_directed(&empty));
+
+        let empty_collection =
+            Geometry::GeometryCollection(GeometryCollection::<f64>::new_from(vec![]));
+        assert_eq!(None, empty_collection.hausdorff_distance(&a));
+    }
+
+    #[test]
+    fn test_directed() {
+        let a: LineString<f64> = line_string![(x: 0., y: 0.), (x: 2., y: 0.)];
+        let b = line_string![(x: 0., y: 1.), (x: 1., y: 2.), (x: 2., y: 1.)];
+        // every vertex of `a` is 1 away from `b`, but the apex of `b` is 2 away from `a`
+        assert_relative_eq!(1., a.hausdorff_distance_directed(&b).unwrap());
+        assert_relative_eq!(2., b.hausdorff_distance_directed(&a).unwrap());
+        assert_relative_eq!(
+            a.hausdorff_distance(&b).unwrap(),
+            a.hausdorff_distance_directed(&b)
+                .unwrap()
+                .max(b.hausdorff_distance_directed(&a).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_multi_point() {
+        let a = MultiPoint::new(vec![point!(x: 0., y: 0.), point!(x: 3., y: 4.)]);
+        let b = MultiPoint::new(vec![point!(x: 0., y: 0.)]);
+        assert_relative_eq!(0., b.hausdorff_distance_directed(&a).unwrap());
+        assert_relative_eq!(5., a.hausdorff_distance_directed(&b).unwrap());
+        assert_relative_eq!(5., a.hausdorff_distance(&b).unwrap());
+
+        // the points are measured against the linework, not just its vertices
+        let ls = line_string![(x: -1., y: 1.), (x: 4., y: 1.)];
+        assert_relative_eq!(3., a.hausdorff_distance_directed(&ls).unwrap());
+        // but the end (4, 1) of the line string is furthest from the points
+        assert_relative_eq!(10f64.sqrt(), a.hausdorff_distance(&ls).unwrap());
+    }
+
+    #[test]
+    fn test_vertex_order_is_ignored() {
+        let a = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let b = polygon![(x: 4., y: 4.), (x: 4., y: 0.), (x: 0., y: 0.), (x: 0., y: 4.)];
+        assert_relative_eq!(0., a.hausdorff_distance(&b).unwrap());
+    }
+
+    #[test]
+    fn test_geometry() {
+        let ls = line_string![(x: 0., y: 0.), (x: 2., y: 0.)];
+        let rect = Geometry::from(Rect::new((0., 0.), (2., 1.)));
+        // the vertices (0, 1) and (2, 1) of the rect are 1 away from the line string
+        assert_relative_eq!(1., rect.hausdorff_distance(&ls).unwrap());
+        assert_relative_eq!(0., ls.hausdorff_distance_directed(&rect).unwrap());
+
+        let collection = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            Geometry::from(point!(x: 0., y: 3.)),
+            Geometry::from(ls.clone()),
+        ]));
+        assert_relative_eq!(
+            3.,
+            collection.hausdorff_distance(&Geometry::from(ls)).unwrap()
+        );
+        assert_relative_eq!(0., collection.hausdorff_distance(&collection).unwrap());
+    }
+}
@@ -0,0 +1,386 @@
+use crate::{
+    Bearing, Closest, Coord, GeoFloat, Geometry, GeometryCollection, HaversineDestination,
+    HaversineDistance, Intersects, Line, LineString, MultiLineString, MultiPoint, MultiPolygon,
+    Point, Polygon, Rect, Triangle, MEAN_EARTH_RADIUS,
+};
+use num_traits::FromPrimitive;
+
+/// Find the closest `Point` between a given geometry and an input `Point`, on a sphere using the
+/// [haversine formula].
+///
+/// Unlike [`ClosestPoint`](crate::ClosestPoint), which works in the plane, this follows great
+/// circles, so it is suitable for longitude and latitude coordinates, in degrees, which are far
+/// apart. The closest point of each segment is found from the cross-track and along-track
+/// distances of the input point from its great circle. Segments may cross the antimeridian, and
+/// the returned longitudes are always between -180 and 180 degrees.
+///
+/// The result is `Closest::Intersection` if the input point is on the geometry, or for areal
+/// geometries, inside it in the plane. It is `Closest::Indeterminate` for empty geometries.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{point, Closest, HaversineClosestPoint, Line};
+///
+/// // along the equator, across the antimeridian
+/// let line = Line::new((170., 0.), (-170., 0.));
+/// let closest = line.haversine_closest_point(&point!(x: 179., y: 1.));
+///
+/// if let Closest::SinglePoint(closest) = closest {
+///     assert_relative_eq!(closest, point!(x: 179., y: 0.), epsilon = 1e-9);
+/// } else {
+///     panic!("expected a single point");
+/// }
+/// ```
+///
+/// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
+pub trait HaversineClosestPoint<T: GeoFloat> {
+    /// Find the closest point between `self` and `from`.
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T>;
+}
+
+/// Compare two `Closest`s relative to `from` by haversine distance, and return a copy of the best
+/// one.
+fn best_of_two<T>(left: Closest<T>, right: Closest<T>, from: Point<T>) -> Closest<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    let left_point = match left {
+        Closest::Indeterminate => return right,
+        Closest::Intersection(_) => return left,
+        Closest::SinglePoint(point) => point,
+    };
+    let right_point = match right {
+        Closest::Indeterminate => return left,
+        Closest::Intersection(_) => return right,
+        Closest::SinglePoint(point) => point,
+    };
+    if left_point.haversine_distance(&from) <= right_point.haversine_distance(&from) {
+        left
+    } else {
+        right
+    }
+}
+
+/// The best `Closest` of any of `geometries`, or `Closest::Indeterminate` if there are none.
+fn closest_of<'a, T, G, I>(geometries: I, from: Point<T>) -> Closest<T>
+where
+    T: GeoFloat + FromPrimitive,
+    G: HaversineClosestPoint<T> + 'a,
+    I: IntoIterator<Item = &'a G>,
+{
+    let mut best = Closest::Indeterminate;
+    for geometry in geometries {
+        best = best_of_two(geometry.haversine_closest_point(&from), best, from);
+        if matches!(best, Closest::Intersection(_)) {
+            // short circuit - nothing can be closer than an intersection
+            return best;
+        }
+    }
+    best
+}
+
+/// Wrap `longitude`, in degrees, to be between -180 and 180.
+fn wrap_longitude<T: GeoFloat + FromPrimitive>(longitude: T) -> T {
+    let half_turn = T::from(180).unwrap();
+    let turn = half_turn + half_turn;
+    let wrapped = (longitude + half_turn) % turn;
+    if wrapped < T::zero() {
+        wrapped + half_turn
+    } else {
+        wrapped - half_turn
+    }
+}
+
+/// The cross-track and along-track angular distances, in radians, of `point` from the great
+/// circle from `start` to `end`, which locate the foot of the perpendicular from the point to the
+/// great circle: the closest point of the great circle to it.
+///
+/// The cross-track angle is positive to the right of the great circle, looking from `start` to
+/// `end`, and the along-track angle is negative behind `start`.
+pub(crate) fn track_angles<T>(start: Point<T>, end: Point<T>, point: Point<T>) -> (T, T)
+where
+    T: GeoFloat + FromPrimitive,
+{
+    let radius = T::from(MEAN_EARTH_RADIUS).unwrap();
+    // the angular distance to the point, and the angle between the segment and the point, at
+    // its start
+    let to_point = start.haversine_distance(&point) / radius;
+    let angle = (start.bearing(point) - start.bearing(end)).to_radians();
+
+    let cross_track = (to_point.sin() * angle.sin()).asin();
+    let along_track = (to_point.sin() * angle.cos()).atan2(to_point.cos());
+    (cross_track, along_track)
+}
+
+impl<T> HaversineClosestPoint<T> for Point<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        if self == from {
+            Closest::Intersection(*self)
+        } else {
+            Closest::SinglePoint(*self)
+        }
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for Coord<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        Point::from(*self).haversine_closest_point(from)
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for Line<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        let (start, end) = (self.start_point(), self.end_point());
+        if start == end {
+            return start.haversine_closest_point(from);
+        }
+        if start == *from || end == *from {
+            return Closest::Intersection(*from);
+        }
+
+        let radius = T::from(MEAN_EARTH_RADIUS).unwrap();
+        let length = start.haversine_distance(&end) / radius;
+        let bearing = start.bearing(end);
+        let (cross_track, along_track) = track_angles(start, end, *from);
+        if along_track < T::zero() || along_track > length {
+            // beyond one of the ends, so the nearer of them is closest
+            return best_of_two(
+                Closest::SinglePoint(start),
+                Closest::SinglePoint(end),
+                *from,
+            );
+        }
+        if cross_track.abs() <= T::epsilon() {
+            return Closest::Intersection(*from);
+        }
+        let foot = start.haversine_destination(bearing, along_track * radius);
+        Closest::SinglePoint(Point::new(wrap_longitude(foot.x()), foot.y()))
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for LineString<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        let lines: Vec<Line<T>> = self.lines().collect();
+        closest_of(&lines, *from)
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for Polygon<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        if self.intersects(from) {
+            return Closest::Intersection(*from);
+        }
+        closest_of(
+            std::iter::once(self.exterior()).chain(self.interiors()),
+            *from,
+        )
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for Triangle<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        if self.intersects(from) {
+            return Closest::Intersection(*from);
+        }
+        closest_of(&self.to_lines(), *from)
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for Rect<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        if self.intersects(from) {
+            return Closest::Intersection(*from);
+        }
+        closest_of(&self.to_lines(), *from)
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for MultiPoint<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        closest_of(&self.0, *from)
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for MultiLineString<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        closest_of(&self.0, *from)
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for MultiPolygon<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        closest_of(&self.0, *from)
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for GeometryCollection<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T> {
+        closest_of(&self.0, *from)
+    }
+}
+
+impl<T> HaversineClosestPoint<T> for Geometry<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    crate::geometry_delegate_impl! {
+        fn haversine_closest_point(&self, from: &Point<T>) -> Closest<T>;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, ClosestPoint, HaversineIntermediate};
+
+    fn single_point(closest: Closest<f64>) -> Point<f64> {
+        match closest {
+            Closest::SinglePoint(point) => point,
+            _ => panic!("expected a single point, got {:?}", closest),
+        }
+    }
+
+    #[test]
+    fn point_on_meridian() {
+        let line = Line::new((0., 0.), (0., 50.));
+        let closest = single_point(line.haversine_closest_point(&point!(x: 1., y: 20.)));
+        assert_relative_eq!(closest.x(), 0., epsilon = 1e-9);
+        // a great circle from the point meets the meridian north of its latitude
+        assert!(closest.y() > 20. && closest.y() < 20.01);
+    }
+
+    #[test]
+    fn closest_point_is_closer_than_planar() {
+        // a segment between London and New York follows a great circle far north of the straight
+        // line between them
+        let line = Line::new((-0.1278, 51.5074), (-74.006, 40.7128));
+        let from = point!(x: -40., y: 55.);
+        let closest = single_point(line.haversine_closest_point(&from));
+        let distance = closest.haversine_distance(&from);
+
+        // no point on the segment is closer
+        for i in 0..=1000 {
+            let point = point!(x: -0.1278, y: 51.5074)
+                .haversine_intermediate(&point!(x: -74.006, y: 40.7128), i as f64 / 1000.);
+            assert!(distance <= point.haversine_distance(&from) + 1e-6);
+        }
+        let planar = match line.closest_point(&from) {
+            Closest::SinglePoint(point) => point,
+            _ => unreachable!(),
+        };
+        assert!(distance < planar.haversine_distance(&from));
+    }
+
+    #[test]
+    fn beyond_endpoints() {
+        let line = Line::new((0., 0.), (10., 0.));
+        assert_eq!(
+            line.haversine_closest_point(&point!(x: -5., y: 1.)),
+            Closest::SinglePoint(point!(x: 0., y: 0.))
+        );
+        assert_eq!(
+            line.haversine_closest_point(&point!(x: 15., y: -1.)),
+            Closest::SinglePoint(point!(x: 10., y: 0.))
+        );
+    }
+
+    #[test]
+    fn antimeridian() {
+        let line = Line::new((170., 10.), (-170., 10.));
+        let closest = single_point(line.haversine_closest_point(&point!(x: -179., y: 5.)));
+        assert!(closest.x() < -178. && closest.x() > -180.);
+        assert!(closest.y() > 10.);
+
+        // the other way around
+        let line = Line::new((-170., 10.), (170., 10.));
+        let reversed = single_point(line.haversine_closest_point(&point!(x: -179., y: 5.)));
+        assert_relative_eq!(closest, reversed, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn intersections() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        assert_eq!(
+            line_string.haversine_closest_point(&point!(x: 10., y: 0.)),
+            Closest::Intersection(point!(x: 10., y: 0.))
+        );
+        // on the equator, which is a great circle
+        assert_eq!(
+            line_string.haversine_closest_point(&point!(x: 5., y: 0.)),
+            Closest::Intersection(point!(x: 5., y: 0.))
+        );
+
+        let polygon = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        assert_eq!(
+            polygon.haversine_closest_point(&point!(x: 5., y: 5.)),
+            Closest::Intersection(point!(x: 5., y: 5.))
+        );
+    }
+
+    #[test]
+    fn multi_geometries() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let closest = single_point(line_string.haversine_closest_point(&point!(x: 12., y: 5.)));
+        assert_relative_eq!(closest.x(), 10., epsilon = 1e-9);
+
+        let polygon = polygon![(x: 20., y: 0.), (x: 30., y: 0.), (x: 30., y: 10.)];
+        let geometry = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            line_string.into(),
+            polygon.into(),
+            point!(x: 50., y: 50.).into(),
+        ]));
+        assert_eq!(
+            geometry.haversine_closest_point(&point!(x: 12., y: 5.)),
+            Closest::SinglePoint(closest)
+        );
+
+        let empty = MultiPolygon::<f64>::new(vec![]);
+        assert_eq!(
+            empty.haversine_closest_point(&point!(x: 0., y: 0.)),
+            Closest::Indeterminate
+        );
+    }
+
+    #[test]
+    fn wraps_longitudes() {
+        assert_eq!(wrap_longitude(190.), -170.);
+        assert_eq!(wrap_longitude(-190.), 170.);
+        assert_eq!(wrap_longitude(45.), 45.);
+        assert_eq!(wrap_longitude(540.), -180.);
+    }
+}
@@ -68,15 +68,14 @@ where
         }
 
         let number_of_points = (total_distance / max_dist).ceil();
-        let interval = T::one() / number_of_points;
-
-        let mut current_step = interval;
         let mut points = if include_ends { vec![*self] } else { vec![] };
 
-        while current_step < T::one() {
-            let point = get_point(&params, current_step);
-            points.push(point);
-            current_step = current_step + interval;
+        // each fraction is found afresh, rather than by adding up steps, so that rounding error
+        // can't add or drop a point at the end
+        let mut step = T::one();
+        while step < number_of_points {
+            points.push(get_point(&params, step / number_of_points));
+            step = step + T::one();
         }
 
         if include_ends {
@@ -240,4 +239,16 @@ mod test {
         let route = p1.haversine_intermediate_fill(&p2, max_dist, include_ends);
         assert_eq!(route, vec![p1, i25, i50, i75, p2]);
     }
+
+    #[test]
+    fn should_not_add_extra_points_test() {
+        use crate::HaversineDistance;
+        let p1 = Point::new(10.0, 20.0);
+        let p2 = Point::new(125.0, 25.0);
+        let total = p1.haversine_distance(&p2);
+        // adding up ten steps of 0.1 gives slightly less than 1
+        let route = p1.haversine_intermediate_fill(&p2, total / 9.5, true);
+        assert_eq!(route.len(), 11);
+        assert_eq!(route[10], p2);
+    }
 }
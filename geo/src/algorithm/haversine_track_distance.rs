@@ -0,0 +1,272 @@
+use crate::algorithm::haversine_closest_point::track_angles;
+use crate::{GeoFloat, HaversineDistance, Line, LineString, Point, MEAN_EARTH_RADIUS};
+use num_traits::FromPrimitive;
+
+/// Measure how far a `Point` is from a path, and how far along the path it is, on a sphere using
+/// the [haversine formula].
+///
+/// This is the spherical analogue of locating a point on a line in the plane, for paths such as a
+/// flight leg whose coordinates are longitude and latitude, in degrees. The closest point on the
+/// path itself, clamped to its ends, is given by
+/// [`HaversineClosestPoint`](crate::HaversineClosestPoint).
+///
+/// *Note*: this implementation uses a mean earth radius of 6371.088 km, based on the [recommendation of
+/// the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf)
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{point, Closest, HaversineClosestPoint, HaversineTrackDistance, Line};
+///
+/// // a flight leg along the equator
+/// let leg = Line::<f64>::new((0., 0.), (10., 0.));
+/// let fix = point!(x: 4., y: 1.);
+///
+/// // a degree of arc is about 111 km
+/// assert_relative_eq!(leg.cross_track_distance(&fix).unwrap(), -111_195., epsilon = 1.);
+/// assert_relative_eq!(leg.along_track_distance(&fix).unwrap(), 444_780., epsilon = 1.);
+///
+/// let closest = leg.haversine_closest_point(&fix);
+/// assert!(matches!(closest, Closest::SinglePoint(p) if (p.x() - 4.).abs() < 1e-9));
+/// ```
+///
+/// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
+pub trait HaversineTrackDistance<T: GeoFloat> {
+    /// The distance from `point` to the great circle of the nearest segment of the path, in
+    /// meters.
+    ///
+    /// This is the length of the perpendicular from the point to the great circle, which extends
+    /// beyond the ends of the segment, so it's no more than the distance to the segment itself.
+    /// It's positive if the point is to the right of the path, looking along it, and negative if
+    /// it's to the left.
+    ///
+    /// Returns `None` if the path is empty, or has no length.
+    fn cross_track_distance(&self, point: &Point<T>) -> Option<T>;
+
+    /// The distance along the path, in meters, from its start to the point on it closest to
+    /// `point`.
+    ///
+    /// This is between zero and the length of the path: a point beyond one of its ends is
+    /// measured to that end.
+    ///
+    /// Returns `None` if the path is empty, or has no length.
+    fn along_track_distance(&self, point: &Point<T>) -> Option<T>;
+}
+
+impl<T> HaversineTrackDistance<T> for Line<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn cross_track_distance(&self, point: &Point<T>) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+        let (cross_track, _) = track_angles(self.start_point(), self.end_point(), *point);
+        Some(cross_track * T::from(MEAN_EARTH_RADIUS).unwrap())
+    }
+
+    fn along_track_distance(&self, point: &Point<T>) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+        let (_, along_track) = track_angles(self.start_point(), self.end_point(), *point);
+        let along_track = along_track * T::from(MEAN_EARTH_RADIUS).unwrap();
+        let length = self.start_point().haversine_distance(&self.end_point());
+        Some(along_track.max(T::zero()).min(length))
+    }
+}
+
+impl<T> HaversineTrackDistance<T> for LineString<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn cross_track_distance(&self, point: &Point<T>) -> Option<T> {
+        let (segment, _) = nearest_segment(self, point)?;
+        segment.cross_track_distance(point)
+    }
+
+    fn along_track_distance(&self, point: &Point<T>) -> Option<T> {
+        let (segment, before) = nearest_segment(self, point)?;
+        Some(before + segment.along_track_distance(point)?)
+    }
+}
+
+/// The segment of `line_string` nearest to `point`, with the length of the line string before it,
+/// ignoring segments with no length.
+fn nearest_segment<T>(line_string: &LineString<T>, point: &Point<T>) -> Option<(Line<T>, T)>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    let mut nearest: Option<(Line<T>, T, T)> = None;
+    let mut before = T::zero();
+    for segment in line_string.lines() {
+        let length = segment
+            .start_point()
+            .haversine_distance(&segment.end_point());
+        if length == T::zero() {
+            continue;
+        }
+        // the distance to the closest point of the segment, clamped to its ends
+        let along_track = segment.along_track_distance(point)?;
+        let closest = if along_track == T::zero() {
+            segment.start_point()
+        } else if along_track == length {
+            segment.end_point()
+        } else {
+            let bearing = crate::Bearing::bearing(&segment.start_point(), segment.end_point());
+            crate::HaversineDestination::haversine_destination(
+                &segment.start_point(),
+                bearing,
+                along_track,
+            )
+        };
+        let distance = closest.haversine_distance(point);
+        if nearest.map_or(true, |(_, _, nearest_distance)| distance < nearest_distance) {
+            nearest = Some((segment, before, distance));
+        }
+        before = before + length;
+    }
+    nearest.map(|(segment, before, _)| (segment, before))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, Closest, HaversineClosestPoint, HaversineLength};
+
+    #[test]
+    fn line() {
+        let line = Line::new((0., 0.), (10., 0.));
+        let one_degree = point!(x: 0., y: 0.).haversine_distance(&point!(x: 1., y: 0.));
+
+        // to the left, looking east
+        let left = point!(x: 3., y: 2.);
+        assert_relative_eq!(
+            line.cross_track_distance(&left).unwrap(),
+            -2. * one_degree,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            line.along_track_distance(&left).unwrap(),
+            // the perpendicular meets the equator at the same longitude
+            3. * one_degree,
+            epsilon = 1e-6
+        );
+
+        let right = point!(x: 3., y: -2.);
+        assert_relative_eq!(
+            line.cross_track_distance(&right).unwrap(),
+            2. * one_degree,
+            epsilon = 1e-6
+        );
+
+        // on the line
+        let on = point!(x: 5., y: 0.);
+        assert_relative_eq!(line.cross_track_distance(&on).unwrap(), 0., epsilon = 1e-6);
+        assert_relative_eq!(
+            line.along_track_distance(&on).unwrap(),
+            5. * one_degree,
+            epsilon = 1e-6
+        );
+
+        assert_eq!(
+            Line::new((1., 1.), (1., 1.)).cross_track_distance(&on),
+            None
+        );
+    }
+
+    #[test]
+    fn closest_to_an_endpoint() {
+        let line = Line::new((0., 0.), (10., 0.));
+        let length = line.start_point().haversine_distance(&line.end_point());
+
+        // beyond the end
+        let beyond = point!(x: 12., y: 1.);
+        assert_eq!(line.along_track_distance(&beyond), Some(length));
+        assert_eq!(
+            line.haversine_closest_point(&beyond),
+            Closest::SinglePoint(line.end_point())
+        );
+        // the cross-track distance is still to the great circle, so less than to the end
+        let cross_track = line.cross_track_distance(&beyond).unwrap();
+        assert!(cross_track < 0.);
+        assert!(-cross_track < beyond.haversine_distance(&line.end_point()));
+
+        // behind the start
+        let behind = point!(x: -3., y: -1.);
+        assert_eq!(line.along_track_distance(&behind), Some(0.));
+        assert_eq!(
+            line.haversine_closest_point(&behind),
+            Closest::SinglePoint(line.start_point())
+        );
+        assert!(line.cross_track_distance(&behind).unwrap() > 0.);
+    }
+
+    #[test]
+    fn line_string() {
+        // east along the equator, then north
+        let path = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let one_degree = point!(x: 0., y: 0.).haversine_distance(&point!(x: 1., y: 0.));
+
+        let near_first = point!(x: 4., y: -0.5);
+        assert_relative_eq!(
+            path.along_track_distance(&near_first).unwrap(),
+            4. * one_degree,
+            epsilon = 1e-6
+        );
+        assert!(path.cross_track_distance(&near_first).unwrap() > 0.);
+
+        // west of the second leg, so to its left
+        let near_second = point!(x: 9.5, y: 6.);
+        assert_relative_eq!(
+            path.along_track_distance(&near_second).unwrap(),
+            // the perpendicular meets a meridian slightly poleward of the point's latitude
+            16. * one_degree,
+            max_relative = 1e-4
+        );
+        assert!(path.cross_track_distance(&near_second).unwrap() < 0.);
+
+        // beyond the end of the path
+        let beyond = point!(x: 10., y: 12.);
+        assert_relative_eq!(
+            path.along_track_distance(&beyond).unwrap(),
+            path.haversine_length(),
+            epsilon = 1e-6
+        );
+
+        // repeated points are skipped
+        let repeated = line_string![(x: 0., y: 0.), (x: 0., y: 0.), (x: 10., y: 0.)];
+        assert_relative_eq!(
+            repeated.along_track_distance(&near_first).unwrap(),
+            4. * one_degree,
+            epsilon = 1e-6
+        );
+
+        assert_eq!(
+            LineString::<f64>::new(vec![]).along_track_distance(&beyond),
+            None
+        );
+        assert_eq!(
+            line_string![(x: 1., y: 1.)].cross_track_distance(&beyond),
+            None
+        );
+    }
+
+    #[test]
+    fn across_the_antimeridian() {
+        let line = Line::new((170., 0.), (-170., 0.));
+        let one_degree = point!(x: 0., y: 0.).haversine_distance(&point!(x: 1., y: 0.));
+        let fix = point!(x: 179., y: 1.);
+        assert_relative_eq!(
+            line.along_track_distance(&fix).unwrap(),
+            9. * one_degree,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(
+            line.cross_track_distance(&fix).unwrap(),
+            -one_degree,
+            epsilon = 1e-6
+        );
+    }
+}
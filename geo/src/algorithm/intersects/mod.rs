@@ -133,6 +133,8 @@ mod test {
         coord, line_string, polygon, Geometry, Line, LineString, MultiLineString, MultiPoint,
         MultiPolygon, Point, Polygon, Rect,
     };
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
 
     /// Tests: intersection LineString and LineString
     #[test]
@@ -419,6 +421,49 @@ mod test {
             .to_polygon()
             .intersects(&bounding_rect_sm.to_polygon()));
     }
+    #[test]
+    fn rect_intersection_consistent_with_relate_test() {
+        use crate::Relate;
+
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut random = move |max: f64| {
+            // multiples of 1/8, so some vertices lie exactly on the rect
+            rng.gen_range(0..max as u64 * 8) as f64 / 8.
+        };
+        let mut intersecting = 0;
+        for _ in 0..1000 {
+            // star shaped, with a hole of the same shape half the size
+            let (x, y) = (random(10.), random(10.));
+            let radii: Vec<f64> = (0..6).map(|_| 1. + random(4.)).collect();
+            let ring = |scale: f64| {
+                LineString::from_iter(radii.iter().enumerate().map(|(i, r)| {
+                    let angle = i as f64 * std::f64::consts::PI / 3.;
+                    coord! { x: x + scale * r * angle.cos(), y: y + scale * r * angle.sin() }
+                }))
+            };
+            let polygon = Polygon::new(ring(1.), vec![ring(0.5)]);
+
+            let min = coord! { x: random(10.), y: random(10.) };
+            let rect = Rect::new(min, coord! { x: min.x + random(8.), y: min.y + random(8.) });
+
+            let expected = rect.relate(&polygon).is_intersects();
+            assert_eq!(expected, rect.to_polygon().intersects(&polygon));
+            assert_eq!(expected, rect.intersects(&polygon), "{rect:?} {polygon:?}");
+            assert_eq!(expected, polygon.intersects(&rect), "{rect:?} {polygon:?}");
+            intersecting += expected as usize;
+
+            let exterior = polygon.exterior();
+            let expected = rect.relate(exterior).is_intersects();
+            assert_eq!(expected, rect.intersects(exterior), "{rect:?} {exterior:?}");
+            assert_eq!(
+                expected,
+                exterior.intersects(&rect),
+                "{rect:?} {exterior:?}"
+            );
+        }
+        assert!(intersecting > 300, "only {intersecting} intersecting");
+    }
+
     #[test]
     fn point_intersects_line_test() {
         let p0 = Point::new(2., 4.);
@@ -35,16 +35,6 @@ symmetric_intersects_impl!(Line<T>, Polygon<T>);
 symmetric_intersects_impl!(Polygon<T>, LineString<T>);
 symmetric_intersects_impl!(Polygon<T>, MultiLineString<T>);
 
-impl<T> Intersects<Rect<T>> for Polygon<T>
-where
-    T: GeoNum,
-{
-    fn intersects(&self, rect: &Rect<T>) -> bool {
-        self.intersects(&rect.to_polygon())
-    }
-}
-symmetric_intersects_impl!(Rect<T>, Polygon<T>);
-
 impl<T> Intersects<Polygon<T>> for Polygon<T>
 where
     T: GeoNum,
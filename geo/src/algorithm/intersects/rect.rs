@@ -41,6 +41,15 @@ where
     fn intersects(&self, rhs: &Line<T>) -> bool {
         let lt = self.min();
         let rb = self.max();
+        // Most lines of a large geometry are well away from the
+        // rect, so check their bounding boxes first.
+        if (rhs.start.x < lt.x && rhs.end.x < lt.x)
+            || (rhs.start.x > rb.x && rhs.end.x > rb.x)
+            || (rhs.start.y < lt.y && rhs.end.y < lt.y)
+            || (rhs.start.y > rb.y && rhs.end.y > rb.y)
+        {
+            return false;
+        }
         let lb = Coord::from((lt.x, rb.y));
         let rt = Coord::from((rb.x, lt.y));
         // If either rhs.{start,end} lies inside Rect, then true
@@ -53,3 +62,38 @@ where
     }
 }
 symmetric_intersects_impl!(Line<T>, Rect<T>);
+
+// Same result as Polygon<T>: Intersects<Polygon<T>> on the rect's
+// polygon, but without the allocation, and returning early where
+// the rect contains the polygon's bounding box.
+impl<T> Intersects<Polygon<T>> for Rect<T>
+where
+    T: GeoNum,
+{
+    fn intersects(&self, rhs: &Polygon<T>) -> bool {
+        let exterior = rhs.exterior();
+        let bbox = match exterior.bounding_rect() {
+            Some(bbox) if exterior.0.len() >= 2 => bbox,
+            _ => return false,
+        };
+        if !self.intersects(&bbox) {
+            return false;
+        }
+        // The rect contains the exterior's coords
+        if self.intersects(&bbox.min()) && self.intersects(&bbox.max()) {
+            return true;
+        }
+        // The boundaries cross, or touch
+        if exterior
+            .lines()
+            .chain(rhs.interiors().iter().flat_map(|ring| ring.lines()))
+            .any(|line| self.intersects(&line))
+        {
+            return true;
+        }
+        // Otherwise, the rect is inside the polygon or not at all,
+        // as it'd contain the exterior if the polygon were inside it.
+        rhs.intersects(&self.min())
+    }
+}
+symmetric_intersects_impl!(Polygon<T>, Rect<T>);
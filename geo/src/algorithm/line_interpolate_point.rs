@@ -106,6 +106,146 @@ where
     }
 }
 
+/// Interpolate many points along a line at once.
+///
+/// Each point is the same as that given by [`LineInterpolatePoint`] for the corresponding
+/// fraction, but the line is walked only once for all of them, rather than once per point.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{LineString, point};
+/// use geo::LineInterpolatePoints;
+///
+/// let linestring: LineString = vec![
+///     [-1.0, 0.0],
+///     [0.0, 0.0],
+///     [0.0, 1.0]
+/// ].into();
+///
+/// assert_eq!(
+///     linestring.line_interpolate_points(&[0.75, 0.25, f64::NAN]),
+///     vec![Some(point!(x: 0.0, y: 0.5)), Some(point!(x: -0.5, y: 0.0)), None],
+/// );
+/// assert_eq!(
+///     linestring.line_points_every(0.75),
+///     vec![point!(x: -1.0, y: 0.0), point!(x: -0.25, y: 0.0), point!(x: 0.0, y: 0.5)],
+/// );
+/// ```
+pub trait LineInterpolatePoints<F: CoordFloat> {
+    /// Returns the point at each of the given fractions along the line, as
+    /// [`line_interpolate_point`](LineInterpolatePoint::line_interpolate_point) would, in the
+    /// same order as `fractions`.
+    ///
+    /// The fractions may be in any order, though the line is walked fastest when they're sorted.
+    fn line_interpolate_points(&self, fractions: &[F]) -> Vec<Option<Point<F>>>;
+
+    /// Returns the points at every multiple of `spacing` along the line, measured from its start.
+    ///
+    /// The first point is the start of the line. The end of the line is only included if its
+    /// length is a multiple of `spacing`. If any coordinates of the line are not finite, returns
+    /// an empty `Vec`.
+    ///
+    /// # Panics
+    ///
+    /// If `spacing` isn't greater than zero.
+    fn line_points_every(&self, spacing: F) -> Vec<Point<F>>;
+}
+
+impl<T> LineInterpolatePoints<T> for Line<T>
+where
+    T: CoordFloat + AddAssign + std::fmt::Debug,
+    Line<T>: EuclideanLength<T>,
+    LineString<T>: EuclideanLength<T>,
+{
+    fn line_interpolate_points(&self, fractions: &[T]) -> Vec<Option<Point<T>>> {
+        fractions
+            .iter()
+            .map(|fraction| self.line_interpolate_point(*fraction))
+            .collect()
+    }
+
+    fn line_points_every(&self, spacing: T) -> Vec<Point<T>> {
+        LineString::from(*self).line_points_every(spacing)
+    }
+}
+
+impl<T> LineInterpolatePoints<T> for LineString<T>
+where
+    T: CoordFloat + AddAssign + std::fmt::Debug,
+    Line<T>: EuclideanLength<T>,
+    LineString<T>: EuclideanLength<T>,
+{
+    fn line_interpolate_points(&self, fractions: &[T]) -> Vec<Option<Point<T>>> {
+        // fractions are clamped as in `line_interpolate_point`, and NaNs give `None`
+        let mut order: Vec<_> = fractions
+            .iter()
+            .enumerate()
+            .filter(|(_, fraction)| !fraction.is_nan())
+            .map(|(index, fraction)| (index, fraction.max(T::zero()).min(T::one())))
+            .collect();
+        order.sort_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap());
+
+        let total_length = self.euclidean_length();
+        let points = interpolate_sorted(
+            self,
+            order.iter().map(|(_, fraction)| total_length * *fraction),
+        );
+        let mut result = vec![None; fractions.len()];
+        for ((index, _), point) in order.iter().zip(points) {
+            result[*index] = point;
+        }
+        result
+    }
+
+    fn line_points_every(&self, spacing: T) -> Vec<Point<T>> {
+        assert!(spacing > T::zero());
+        let total_length = self.euclidean_length();
+        if !total_length.is_finite() {
+            return vec![];
+        }
+        let lengths = (0..)
+            .map(|step| spacing * T::from(step).unwrap())
+            .take_while(|length| *length <= total_length);
+        interpolate_sorted(self, lengths)
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+/// The points at each of the ascending `lengths` along `line_string`, found by walking its
+/// segments once in the same way as `LineString::line_interpolate_point`.
+fn interpolate_sorted<T>(
+    line_string: &LineString<T>,
+    lengths: impl Iterator<Item = T>,
+) -> Vec<Option<Point<T>>>
+where
+    T: CoordFloat + AddAssign,
+    Line<T>: EuclideanLength<T>,
+{
+    let mut segments = line_string.lines().map(|segment| {
+        let length = segment.euclidean_length();
+        (segment, length)
+    });
+    let mut segment = segments.next();
+    let mut cum_length = T::zero();
+    lengths
+        .map(|fractional_length| {
+            while let Some((line, length)) = segment {
+                if cum_length + length >= fractional_length {
+                    let segment_fraction = (fractional_length - cum_length) / length;
+                    return line.line_interpolate_point(segment_fraction);
+                }
+                cum_length += length;
+                segment = segments.next();
+            }
+            // as in `line_interpolate_point`, the length is NaN or there are no more segments
+            None
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
 
@@ -113,6 +253,8 @@ mod test {
     use crate::{coord, point};
     use crate::{ClosestPoint, LineLocatePoint};
     use num_traits::Float;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
 
     #[test]
     fn test_line_interpolate_point_line() {
@@ -294,4 +436,114 @@ mod test {
             _ => panic!("The closest point should be a SinglePoint"), // example chosen to not be an intersection
         };
     }
+
+    #[test]
+    fn test_line_interpolate_points_matches_single_calls() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut next = move || rng.gen::<f64>();
+
+        let mut coords: Vec<_> = (0..50).map(|_| [next() * 100., next() * 100.]).collect();
+        // a repeated coordinate, giving a segment with no length
+        coords.insert(10, coords[10]);
+        let linestring: LineString = coords.into();
+        let mut fractions: Vec<f64> = (0..200).map(|_| next() * 1.4 - 0.2).collect();
+        fractions.extend([
+            0.,
+            1.,
+            Float::nan(),
+            Float::infinity(),
+            Float::neg_infinity(),
+        ]);
+
+        let expected: Vec<_> = fractions
+            .iter()
+            .map(|fraction| linestring.line_interpolate_point(*fraction))
+            .collect();
+        assert_eq!(linestring.line_interpolate_points(&fractions), expected);
+
+        let mut sorted = fractions.clone();
+        sorted.retain(|fraction| !fraction.is_nan());
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let expected: Vec<_> = sorted
+            .iter()
+            .map(|fraction| linestring.line_interpolate_point(*fraction))
+            .collect();
+        assert_eq!(linestring.line_interpolate_points(&sorted), expected);
+
+        let line = Line::new(coord! { x: -1.0, y: 0.0 }, coord! { x: 1.0, y: 0.0 });
+        assert_eq!(
+            line.line_interpolate_points(&[0.75, Float::nan(), -1.0]),
+            vec![
+                Some(point!(x: 0.5, y: 0.0)),
+                None,
+                Some(point!(x: -1.0, y: 0.0))
+            ]
+        );
+
+        // linestrings with nans, and without any lines
+        let linestring: LineString = vec![[-1.0, 0.0], [0.0, Float::nan()], [0.0, 1.0]].into();
+        assert_eq!(
+            linestring.line_interpolate_points(&[0.0, 0.5, 1.0]),
+            vec![None, None, None]
+        );
+        let linestring: LineString = vec![[-1.0, 0.0]].into();
+        assert_eq!(linestring.line_interpolate_points(&[0.5]), vec![None]);
+        assert!(linestring.line_interpolate_points(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_line_points_every() {
+        let linestring: LineString = vec![[0.0, 0.0], [3.0, 0.0], [3.0, 2.0]].into();
+        assert_eq!(
+            linestring.line_points_every(2.0),
+            vec![
+                point!(x: 0.0, y: 0.0),
+                point!(x: 2.0, y: 0.0),
+                point!(x: 3.0, y: 1.0)
+            ]
+        );
+        // the length is a multiple of the spacing, so the end is included
+        assert_eq!(
+            linestring.line_points_every(2.5),
+            vec![
+                point!(x: 0.0, y: 0.0),
+                point!(x: 2.5, y: 0.0),
+                point!(x: 3.0, y: 2.0)
+            ]
+        );
+        // a spacing longer than the line gives just the start
+        assert_eq!(
+            linestring.line_points_every(10.0),
+            vec![point!(x: 0.0, y: 0.0)]
+        );
+
+        let points = linestring.line_points_every(0.1);
+        assert_eq!(points.len(), 51);
+        for (point, expected) in points.iter().zip(
+            (0..51)
+                .map(|step| linestring.line_interpolate_point(f64::from(step) * 0.1 / 5.0))
+                .map(Option::unwrap),
+        ) {
+            assert_relative_eq!(*point, expected, epsilon = 1e-12);
+        }
+
+        let line = Line::new(coord! { x: 0.0, y: 0.0 }, coord! { x: 0.0, y: 3.0 });
+        assert_eq!(
+            line.line_points_every(2.0),
+            vec![point!(x: 0.0, y: 0.0), point!(x: 0.0, y: 2.0)]
+        );
+
+        let linestring: LineString = vec![[-1.0, 0.0], [0.0, Float::infinity()]].into();
+        assert!(linestring.line_points_every(1.0).is_empty());
+        assert!(LineString::<f64>::new(vec![])
+            .line_points_every(1.0)
+            .is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_line_points_every_zero_spacing() {
+        let linestring: LineString = vec![[0.0, 0.0], [3.0, 0.0]].into();
+        linestring.line_points_every(0.0);
+    }
 }
@@ -1,11 +1,12 @@
-use crate::{Coord, GeoFloat, Line};
+use crate::kernels::Kernel;
+use crate::{Coord, GeoFloat, GeoNum, Line};
 use geo_types::coord;
 
 use crate::BoundingRect;
 use crate::Intersects;
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
-pub enum LineIntersection<F: GeoFloat> {
+pub enum LineIntersection<F: GeoNum> {
     /// Lines intersect in a single point
     SinglePoint {
         intersection: Coord<F>,
@@ -29,7 +30,7 @@ pub enum LineIntersection<F: GeoFloat> {
     Collinear { intersection: Line<F> },
 }
 
-impl<F: GeoFloat> LineIntersection<F> {
+impl<F: GeoNum> LineIntersection<F> {
     pub fn is_proper(&self) -> bool {
         match self {
             Self::Collinear { .. } => false,
@@ -73,14 +74,100 @@ impl<F: GeoFloat> LineIntersection<F> {
 pub fn line_intersection<F>(p: Line<F>, q: Line<F>) -> Option<LineIntersection<F>>
 where
     F: GeoFloat,
+{
+    use crate::kernels::RobustKernel;
+    intersection_by::<_, RobustKernel>(p, q, proper_intersection)
+}
+
+/// How two [`Lines`](Line) intersect, as returned by [`segment_intersection`].
+///
+/// Unlike [`LineIntersection`], this tells segments which only touch, such as where one ends on
+/// the other, apart from those which cross.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum SegmentIntersection<F: GeoNum> {
+    /// The segments don't intersect.
+    None,
+    /// The segments meet in a single point, which is an endpoint of one or both of them.
+    Touch(Coord<F>),
+    /// The segments cross at a single point in the interior of both.
+    ///
+    /// As for a proper [`LineIntersection::SinglePoint`], the computed point may be rounded onto
+    /// an endpoint.
+    Cross(Coord<F>),
+    /// The segments overlap along a line segment.
+    Collinear(Line<F>),
+}
+
+/// Returns how two [`Lines`](Line) intersect, telling those which only touch from those which
+/// cross.
+///
+/// This is classified, as [`line_intersection`] is, with exact orientation predicates, so
+/// segments which nearly overlap or nearly touch are never misclassified due to floating point
+/// rounding. Only the point where segments cross is computed approximately.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, Line};
+/// use geo::line_intersection::{segment_intersection, SegmentIntersection};
+///
+/// let line_1 = Line::new(coord! { x: 0.0, y: 0.0 }, coord! { x: 5.0, y: 5.0 });
+/// let line_2 = Line::new(coord! { x: 0.0, y: 5.0 }, coord! { x: 5.0, y: 0.0 });
+/// assert_eq!(
+///     segment_intersection(line_1, line_2),
+///     SegmentIntersection::Cross(coord! { x: 2.5, y: 2.5 })
+/// );
+///
+/// let line_2 = Line::new(coord! { x: 2.0, y: 2.0 }, coord! { x: 5.0, y: 0.0 });
+/// assert_eq!(
+///     segment_intersection(line_1, line_2),
+///     SegmentIntersection::Touch(coord! { x: 2.0, y: 2.0 })
+/// );
+///
+/// let line_2 = Line::new(coord! { x: 6.0, y: 6.0 }, coord! { x: 3.0, y: 3.0 });
+/// assert_eq!(
+///     segment_intersection(line_1, line_2),
+///     SegmentIntersection::Collinear(Line::new(coord! { x: 3.0, y: 3.0 }, coord! { x: 5.0, y: 5.0 }))
+/// );
+/// ```
+pub fn segment_intersection<F>(p: Line<F>, q: Line<F>) -> SegmentIntersection<F>
+where
+    F: GeoFloat,
+{
+    match line_intersection(p, q) {
+        None => SegmentIntersection::None,
+        Some(LineIntersection::SinglePoint {
+            intersection,
+            is_proper: true,
+        }) => SegmentIntersection::Cross(intersection),
+        Some(LineIntersection::SinglePoint { intersection, .. }) => {
+            SegmentIntersection::Touch(intersection)
+        }
+        Some(LineIntersection::Collinear { intersection }) => {
+            SegmentIntersection::Collinear(intersection)
+        }
+    }
+}
+
+/// The intersection of `p` and `q`, as by [`line_intersection`], with orientations from the
+/// kernel `K`, and using `proper_intersection` to compute the intersection of lines which cross
+/// in their interiors.
+pub(crate) fn intersection_by<F, K>(
+    p: Line<F>,
+    q: Line<F>,
+    proper_intersection: impl FnOnce(Line<F>, Line<F>) -> Coord<F>,
+) -> Option<LineIntersection<F>>
+where
+    F: GeoNum,
+    K: Kernel<F>,
 {
     if !p.bounding_rect().intersects(&q.bounding_rect()) {
         return None;
     }
 
-    use crate::kernels::{Kernel, Orientation::*, RobustKernel};
-    let p_q1 = RobustKernel::orient2d(p.start, p.end, q.start);
-    let p_q2 = RobustKernel::orient2d(p.start, p.end, q.end);
+    use crate::kernels::Orientation::*;
+    let p_q1 = K::orient2d(p.start, p.end, q.start);
+    let p_q2 = K::orient2d(p.start, p.end, q.end);
     if matches!(
         (p_q1, p_q2),
         (Clockwise, Clockwise) | (CounterClockwise, CounterClockwise)
@@ -88,8 +175,8 @@ where
         return None;
     }
 
-    let q_p1 = RobustKernel::orient2d(q.start, q.end, p.start);
-    let q_p2 = RobustKernel::orient2d(q.start, q.end, p.end);
+    let q_p1 = K::orient2d(q.start, q.end, p.start);
+    let q_p2 = K::orient2d(q.start, q.end, p.end);
     if matches!(
         (q_p1, q_p2),
         (Clockwise, Clockwise) | (CounterClockwise, CounterClockwise)
@@ -158,12 +245,12 @@ where
     }
 }
 
-fn collinear_intersection<F: GeoFloat>(p: Line<F>, q: Line<F>) -> Option<LineIntersection<F>> {
-    fn collinear<F: GeoFloat>(intersection: Line<F>) -> LineIntersection<F> {
+fn collinear_intersection<F: GeoNum>(p: Line<F>, q: Line<F>) -> Option<LineIntersection<F>> {
+    fn collinear<F: GeoNum>(intersection: Line<F>) -> LineIntersection<F> {
         LineIntersection::Collinear { intersection }
     }
 
-    fn improper<F: GeoFloat>(intersection: Coord<F>) -> LineIntersection<F> {
+    fn improper<F: GeoNum>(intersection: Coord<F>) -> LineIntersection<F> {
         LineIntersection::SinglePoint {
             intersection,
             is_proper: false,
@@ -602,4 +689,45 @@ mod test {
         };
         assert_eq!(actual, Some(expected));
     }
+
+    #[test]
+    fn segment_intersection_touch_or_cross() {
+        let line = Line::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 0. });
+        let touch = |other| segment_intersection(line, other);
+
+        // ends meeting, and an end on the interior of the other
+        assert_eq!(
+            touch(Line::new(coord! { x: 4., y: 0. }, coord! { x: 5., y: 3. })),
+            SegmentIntersection::Touch(coord! { x: 4., y: 0. })
+        );
+        assert_eq!(
+            touch(Line::new(coord! { x: 1., y: 3. }, coord! { x: 1., y: 0. })),
+            SegmentIntersection::Touch(coord! { x: 1., y: 0. })
+        );
+        // collinear, meeting only end to end
+        assert_eq!(
+            touch(Line::new(coord! { x: 4., y: 0. }, coord! { x: 6., y: 0. })),
+            SegmentIntersection::Touch(coord! { x: 4., y: 0. })
+        );
+        assert_eq!(
+            touch(Line::new(coord! { x: 1., y: -1. }, coord! { x: 3., y: 1. })),
+            SegmentIntersection::Cross(coord! { x: 2., y: 0. })
+        );
+        assert_eq!(
+            touch(Line::new(coord! { x: 5., y: -1. }, coord! { x: 5., y: 1. })),
+            SegmentIntersection::None
+        );
+    }
+
+    #[test]
+    fn segment_intersection_near_collinear() {
+        // (0.9, 2.1) is very slightly off the line through (0, 0) and (0.3, 0.7), though their
+        // cross product in floating point arithmetic is zero
+        let line_1 = Line::new(coord! { x: 0., y: 0. }, coord! { x: 0.3, y: 0.7 });
+        let line_2 = Line::new(coord! { x: 0., y: 0. }, coord! { x: 0.9, y: 2.1 });
+        assert_eq!(
+            segment_intersection(line_1, line_2),
+            SegmentIntersection::Touch(coord! { x: 0., y: 0. })
+        );
+    }
 }
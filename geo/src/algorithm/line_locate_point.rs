@@ -106,6 +106,109 @@ where
     }
 }
 
+/// Where the closest point on a line to a given point lies, as found by
+/// [`LineLocatePointFull`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineLocation<T> {
+    /// The fraction of the line's total length before the closest point, as returned by
+    /// [`LineLocatePoint::line_locate_point`]
+    pub fraction: T,
+    /// The length of the line before the closest point
+    pub distance: T,
+    /// The index of the segment on which the closest point lies
+    pub segment_index: usize,
+}
+
+/// Locates the closest point on the line to the given point, returning the length of the line
+/// before it and the index of its segment as well as its fraction of the total length.
+///
+/// This is equivalent to calling [`LineLocatePoint::line_locate_point`] and converting the
+/// fraction to a length, but without walking the line a second time.
+///
+/// If the point is equally close to more than one segment, the earliest of them is used. If
+/// the line has zero length, the location is the start of its first segment.
+///
+/// Returns `None` if the line has no segments, or if either the point's coordinates or any
+/// coordinates of the line are not finite.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{LineString, point};
+/// use geo::{LineLocatePointFull, LineLocation};
+///
+/// let linestring: LineString = vec![
+///     [-1.0, 0.0],
+///     [0.0, 0.0],
+///     [0.0, 1.0]
+/// ].into();
+///
+/// assert_eq!(
+///     linestring.line_locate_point_full(&point!(x: 0.5, y: 0.5)),
+///     Some(LineLocation { fraction: 0.75, distance: 1.5, segment_index: 1 })
+/// );
+/// ```
+pub trait LineLocatePointFull<T: CoordFloat> {
+    fn line_locate_point_full(&self, p: &Point<T>) -> Option<LineLocation<T>>;
+}
+
+impl<T> LineLocatePointFull<T> for Line<T>
+where
+    T: CoordFloat,
+    Line<T>: EuclideanLength<T>,
+{
+    fn line_locate_point_full(&self, p: &Point<T>) -> Option<LineLocation<T>> {
+        let fraction = self.line_locate_point(p)?;
+        Some(LineLocation {
+            fraction,
+            distance: fraction * self.euclidean_length(),
+            segment_index: 0,
+        })
+    }
+}
+
+impl<T> LineLocatePointFull<T> for LineString<T>
+where
+    T: CoordFloat + AddAssign,
+    Line<T>: EuclideanDistance<T, Point<T>> + EuclideanLength<T>,
+    LineString<T>: EuclideanLength<T>,
+{
+    fn line_locate_point_full(&self, p: &Point<T>) -> Option<LineLocation<T>> {
+        // this follows `line_locate_point`, so that the fractions are the same
+        if self.0.len() < 2 {
+            return None;
+        }
+        let total_length = (*self).euclidean_length();
+        let mut location = LineLocation {
+            fraction: T::zero(),
+            distance: T::zero(),
+            segment_index: 0,
+        };
+        if total_length == T::zero() {
+            return Some(location);
+        }
+        let mut cum_length = T::zero();
+        let mut closest_dist_to_point = T::infinity();
+        for (segment_index, segment) in self.lines().enumerate() {
+            let segment_distance_to_point = segment.euclidean_distance(p);
+            let segment_length = segment.euclidean_length();
+            let segment_fraction = segment.line_locate_point(p)?;
+            // only a strictly closer segment replaces the earlier one
+            if segment_distance_to_point < closest_dist_to_point {
+                closest_dist_to_point = segment_distance_to_point;
+                let distance = cum_length + segment_fraction * segment_length;
+                location = LineLocation {
+                    fraction: distance / total_length,
+                    distance,
+                    segment_index,
+                };
+            }
+            cum_length += segment_length;
+        }
+        Some(location)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -262,4 +365,89 @@ mod test {
         let pt = point!(x: 2.0, y: 2.0);
         assert_eq!(line.line_locate_point(&pt), None);
     }
+
+    #[test]
+    fn test_line_locate_point_full() {
+        let linestring: LineString = vec![[-1.0, 0.0], [0.5, 1.0], [1.0, 2.0], [3.0, 2.0]].into();
+        let total_length = linestring.euclidean_length();
+        for point in [
+            point!(x: 0.7, y: 0.7),
+            point!(x: -2.0, y: 0.0),
+            point!(x: 2.0, y: 3.0),
+            point!(x: 4.0, y: 2.5),
+        ] {
+            let location = linestring.line_locate_point_full(&point).unwrap();
+            assert_eq!(
+                Some(location.fraction),
+                linestring.line_locate_point(&point)
+            );
+            assert_relative_eq!(location.distance, location.fraction * total_length);
+            let segment = linestring.lines().nth(location.segment_index).unwrap();
+            assert_relative_eq!(
+                segment.euclidean_distance(&point),
+                linestring.euclidean_distance(&point)
+            );
+        }
+
+        let line = Line::new(coord! { x: -1.0, y: 0.0 }, coord! { x: 1.0, y: 0.0 });
+        assert_eq!(
+            line.line_locate_point_full(&point!(x: 0.5, y: 3.0)),
+            Some(LineLocation {
+                fraction: 0.75,
+                distance: 1.5,
+                segment_index: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_line_locate_point_full_equidistant() {
+        // (1, 1) is 1 from both segments, at 1 and at 3 along the line
+        let linestring: LineString = vec![[0.0, 0.0], [2.0, 0.0], [2.0, 2.0]].into();
+        assert_eq!(
+            linestring.line_locate_point_full(&point!(x: 1.0, y: 1.0)),
+            Some(LineLocation {
+                fraction: 0.25,
+                distance: 1.0,
+                segment_index: 0
+            })
+        );
+        // reversed, the earlier of the two segments is the other one
+        let reversed: LineString = vec![[2.0, 2.0], [2.0, 0.0], [0.0, 0.0]].into();
+        assert_eq!(
+            reversed.line_locate_point_full(&point!(x: 1.0, y: 1.0)),
+            Some(LineLocation {
+                fraction: 0.25,
+                distance: 1.0,
+                segment_index: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_line_locate_point_full_degenerate() {
+        let point = point!(x: 1.0, y: 1.0);
+        let empty: LineString = LineString::new(vec![]);
+        assert_eq!(empty.line_locate_point_full(&point), None);
+        let single: LineString = vec![[0.0, 0.0]].into();
+        assert_eq!(single.line_locate_point_full(&point), None);
+
+        let repeated: LineString = vec![[0.0, 0.0], [0.0, 0.0]].into();
+        assert_eq!(
+            repeated.line_locate_point_full(&point),
+            Some(LineLocation {
+                fraction: 0.0,
+                distance: 0.0,
+                segment_index: 0
+            })
+        );
+
+        let linestring: LineString = vec![[-1.0, 0.0], [0.0, Float::nan()], [0.0, 1.0]].into();
+        assert_eq!(linestring.line_locate_point_full(&point), None);
+        let linestring: LineString = vec![[-1.0, 0.0], [0.0, 0.0]].into();
+        assert_eq!(
+            linestring.line_locate_point_full(&point!(x: Float::nan(), y: 0.0)),
+            None
+        );
+    }
 }
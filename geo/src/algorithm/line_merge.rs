@@ -0,0 +1,236 @@
+use std::ops::Range;
+
+use crate::utils::lex_cmp;
+use crate::{Coord, CoordNum, LineString, MultiLineString};
+
+/// Merge `LineString`s which meet end to end into maximal paths.
+///
+/// Wherever the ends of exactly two `LineString`s meet, they are joined into one, reversing
+/// pieces as needed. Where three or more ends meet, the paths are split. Pieces forming a
+/// closed loop, which meets no other `LineString`, are merged into a single closed
+/// `LineString`.
+///
+/// The ends must be exactly equal to meet, without any tolerance. If the ends may be slightly
+/// apart, such as after floating point operations, snap the coordinates to a grid first, for
+/// example with [`MapCoords`](crate::MapCoords).
+///
+/// Each merged path follows the direction of the first of its pieces in the input, and the
+/// paths are in the order of their first pieces. `LineString`s with fewer than two coordinates
+/// are left out. The coordinates must not be `NaN`.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, LineMerge, MultiLineString};
+///
+/// let pieces = MultiLineString::new(vec![
+///     line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+///     line_string![(x: 2., y: 1.), (x: 1., y: 0.)],
+///     line_string![(x: 2., y: 1.), (x: 3., y: 1.)],
+/// ]);
+///
+/// assert_eq!(
+///     pieces.line_merge(),
+///     MultiLineString::new(vec![line_string![
+///         (x: 0., y: 0.),
+///         (x: 1., y: 0.),
+///         (x: 2., y: 1.),
+///         (x: 3., y: 1.),
+///     ]])
+/// );
+/// ```
+pub trait LineMerge<T: CoordNum> {
+    /// Merge the `LineString`s of `self` which meet end to end.
+    fn line_merge(&self) -> MultiLineString<T>;
+}
+
+impl<T: CoordNum> LineMerge<T> for MultiLineString<T> {
+    fn line_merge(&self) -> MultiLineString<T> {
+        let edges: Vec<&LineString<T>> = self.0.iter().filter(|ls| ls.0.len() >= 2).collect();
+        let graph = Graph::new(&edges);
+        let mut visited = vec![false; edges.len()];
+
+        let mut merged = vec![];
+        for (edge, line_string) in edges.iter().enumerate() {
+            if visited[edge] {
+                continue;
+            }
+            visited[edge] = true;
+            // walk away from both ends, then join the backward path, reversed, to the rest
+            let mut forward = line_string.0.clone();
+            graph.walk(2 * edge + 1, &mut visited, &mut forward);
+            let mut coords = vec![];
+            graph.walk(2 * edge, &mut visited, &mut coords);
+            coords.reverse();
+            coords.append(&mut forward);
+            merged.push(LineString::new(coords));
+        }
+        MultiLineString::new(merged)
+    }
+}
+
+/// The ends of the edges, grouped by their coordinates into nodes.
+///
+/// The ends of edge `i` are numbered `2 * i`, for its first coordinate, and `2 * i + 1`, for its
+/// last.
+struct Graph<'a, T: CoordNum> {
+    edges: &'a [&'a LineString<T>],
+    /// The ends, sorted by their coordinates.
+    ends: Vec<usize>,
+    /// The range of `ends` at the node of each end.
+    nodes: Vec<Range<usize>>,
+}
+
+impl<'a, T: CoordNum> Graph<'a, T> {
+    fn new(edges: &'a [&'a LineString<T>]) -> Self {
+        let coord = |end: usize| end_coord(edges, end);
+        let mut ends: Vec<usize> = (0..2 * edges.len()).collect();
+        ends.sort_by(|&a, &b| lex_cmp(&coord(a), &coord(b)));
+
+        let mut nodes = vec![0..0; ends.len()];
+        let mut start = 0;
+        while start < ends.len() {
+            let node_coord = coord(ends[start]);
+            let len = ends[start..]
+                .iter()
+                .take_while(|&&end| coord(end) == node_coord)
+                .count();
+            for &end in &ends[start..start + len] {
+                nodes[end] = start..start + len;
+            }
+            start += len;
+        }
+        Graph { edges, ends, nodes }
+    }
+
+    /// Walk away from `end` through the nodes joining exactly two ends, marking the edges on the
+    /// way as `visited`, and appending their coordinates, except those at `end`, to `coords`.
+    fn walk(&self, mut end: usize, visited: &mut [bool], coords: &mut Vec<Coord<T>>) {
+        loop {
+            let node = &self.ends[self.nodes[end].clone()];
+            let next = match node {
+                [a, b] if *a == end => *b,
+                [a, b] if *b == end => *a,
+                _ => return,
+            };
+            let edge = next / 2;
+            if visited[edge] {
+                return;
+            }
+            visited[edge] = true;
+            let edge_coords = &self.edges[edge].0;
+            if next % 2 == 0 {
+                coords.extend(edge_coords.iter().skip(1));
+            } else {
+                coords.extend(edge_coords.iter().rev().skip(1));
+            }
+            end = next ^ 1;
+        }
+    }
+}
+
+fn end_coord<T: CoordNum>(edges: &[&LineString<T>], end: usize) -> Coord<T> {
+    let coords = &edges[end / 2].0;
+    if end % 2 == 0 {
+        coords[0]
+    } else {
+        coords[coords.len() - 1]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::line_string;
+
+    fn merge(line_strings: Vec<LineString<i32>>) -> Vec<LineString<i32>> {
+        MultiLineString::new(line_strings).line_merge().0
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(merge(vec![]), vec![]);
+        assert_eq!(
+            merge(vec![line_string![], line_string![(x: 0, y: 0)]]),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn reverses_pieces() {
+        let merged = merge(vec![
+            line_string![(x: 1, y: 0), (x: 2, y: 0)],
+            line_string![(x: 1, y: 0), (x: 0, y: 0)],
+            line_string![(x: 3, y: 0), (x: 2, y: 0)],
+            line_string![(x: -1, y: 0), (x: 0, y: 0)],
+        ]);
+        assert_eq!(
+            merged,
+            vec![line_string![
+                (x: -1, y: 0),
+                (x: 0, y: 0),
+                (x: 1, y: 0),
+                (x: 2, y: 0),
+                (x: 3, y: 0),
+            ]]
+        );
+    }
+
+    #[test]
+    fn splits_at_junctions() {
+        // a T, with a tail on one arm
+        let merged = merge(vec![
+            line_string![(x: 0, y: 0), (x: 1, y: 0)],
+            line_string![(x: 1, y: 0), (x: 2, y: 0)],
+            line_string![(x: 2, y: 0), (x: 3, y: 0)],
+            line_string![(x: 1, y: 1), (x: 1, y: 0)],
+        ]);
+        assert_eq!(
+            merged,
+            vec![
+                line_string![(x: 0, y: 0), (x: 1, y: 0)],
+                line_string![(x: 1, y: 0), (x: 2, y: 0), (x: 3, y: 0)],
+                line_string![(x: 1, y: 1), (x: 1, y: 0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn closed_loops() {
+        let merged = merge(vec![
+            line_string![(x: 0, y: 0), (x: 1, y: 0)],
+            line_string![(x: 1, y: 1), (x: 0, y: 0)],
+            line_string![(x: 1, y: 0), (x: 1, y: 1)],
+            // an already closed loop
+            line_string![(x: 5, y: 5), (x: 6, y: 5), (x: 5, y: 6), (x: 5, y: 5)],
+        ]);
+        assert_eq!(
+            merged,
+            vec![
+                line_string![(x: 0, y: 0), (x: 1, y: 0), (x: 1, y: 1), (x: 0, y: 0)],
+                line_string![(x: 5, y: 5), (x: 6, y: 5), (x: 5, y: 6), (x: 5, y: 5)],
+            ]
+        );
+        assert!(merged.iter().all(LineString::is_closed));
+    }
+
+    #[test]
+    fn loop_at_junction() {
+        // a loop through a node shared with another line isn't merged with it
+        let merged = merge(vec![
+            line_string![(x: 0, y: 0), (x: 1, y: 0), (x: 0, y: 1), (x: 0, y: 0)],
+            line_string![(x: -1, y: 0), (x: 0, y: 0)],
+        ]);
+        assert_eq!(merged.len(), 2);
+    }
+
+    #[test]
+    fn exact_equality() {
+        let merged = MultiLineString::new(vec![
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 1. + f64::EPSILON, y: 0.), (x: 2., y: 0.)],
+        ])
+        .line_merge();
+        assert_eq!(merged.0.len(), 2);
+    }
+}
@@ -0,0 +1,212 @@
+use num_traits::FromPrimitive;
+
+use crate::algorithm::line_split::split_line_string;
+use crate::{
+    CoordFloat, EuclideanLength, HaversineIntermediate, HaversineLength, LineString,
+    MultiLineString,
+};
+
+/// Split a `LineString` into `n` pieces of equal euclidean length.
+///
+/// Where a cut falls part way along a segment, a new coordinate is interpolated there, which ends
+/// one piece and begins the next. Every coordinate of the `LineString` is kept, in the piece it
+/// falls in, and one which a cut falls on (within rounding error) ends one piece and begins the
+/// next. Because of rounding, the lengths of the pieces may differ from each other by a tiny
+/// amount.
+///
+/// Returns `None` if `n` is zero, or the `LineString` has no length, or any of its coordinates are
+/// not finite.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, LineStringSegmentize};
+///
+/// let route = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 5.)];
+///
+/// let pieces = route.line_segmentize(3).unwrap();
+/// assert_eq!(
+///     pieces.0,
+///     vec![
+///         line_string![(x: 0., y: 0.), (x: 5., y: 0.)],
+///         line_string![(x: 5., y: 0.), (x: 10., y: 0.)],
+///         line_string![(x: 10., y: 0.), (x: 10., y: 5.)],
+///     ]
+/// );
+/// ```
+pub trait LineStringSegmentize<T: CoordFloat> {
+    fn line_segmentize(&self, n: usize) -> Option<MultiLineString<T>>;
+}
+
+/// Split a `LineString` into `n` pieces of equal length along great circles, using the
+/// [haversine formula].
+///
+/// This is the same as [`LineStringSegmentize`], except that the pieces have equal
+/// [`HaversineLength`](crate::HaversineLength), and new coordinates lie on the great circle
+/// between the coordinates either side of them. Coordinates are expected to be longitude and
+/// latitude, in degrees.
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use geo::{line_string, HaversineLength, HaversineLineStringSegmentize};
+///
+/// // from New York City to London
+/// let route = line_string![(x: -74.006, y: 40.7128), (x: -0.1278, y: 51.5074)];
+///
+/// let pieces = route.line_segmentize_haversine(5).unwrap();
+/// for piece in &pieces {
+///     assert_relative_eq!(
+///         piece.haversine_length(),
+///         route.haversine_length() / 5.,
+///         epsilon = 1e-6
+///     );
+/// }
+/// ```
+///
+/// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
+pub trait HaversineLineStringSegmentize<T: CoordFloat> {
+    fn line_segmentize_haversine(&self, n: usize) -> Option<MultiLineString<T>>;
+}
+
+impl<T: CoordFloat> LineStringSegmentize<T> for LineString<T> {
+    fn line_segmentize(&self, n: usize) -> Option<MultiLineString<T>> {
+        let fractions = equal_fractions(n)?;
+        let pieces = split_line_string(
+            self,
+            &fractions,
+            |line| line.euclidean_length(),
+            |line, ratio| line.start + line.delta() * ratio,
+        )?;
+        non_degenerate(pieces)
+    }
+}
+
+impl<T> HaversineLineStringSegmentize<T> for LineString<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn line_segmentize_haversine(&self, n: usize) -> Option<MultiLineString<T>> {
+        let fractions = equal_fractions(n)?;
+        let pieces = split_line_string(
+            self,
+            &fractions,
+            |line| line.haversine_length(),
+            |line, ratio| {
+                line.start_point()
+                    .haversine_intermediate(&line.end_point(), ratio)
+                    .0
+            },
+        )?;
+        non_degenerate(pieces)
+    }
+}
+
+/// The fractions which cut a line into `n` equal pieces.
+fn equal_fractions<T: CoordFloat>(n: usize) -> Option<Vec<T>> {
+    if n == 0 {
+        return None;
+    }
+    let n_float = T::from(n)?;
+    (1..n).map(|i| Some(T::from(i)? / n_float)).collect()
+}
+
+/// The pieces, unless the line was too short to cut into them.
+fn non_degenerate<T: CoordFloat>(pieces: Vec<LineString<T>>) -> Option<MultiLineString<T>> {
+    if pieces.iter().any(|piece| piece.0.is_empty()) {
+        return None;
+    }
+    Some(MultiLineString::new(pieces))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, HaversineDistance, Point};
+
+    #[test]
+    fn pieces_have_equal_length() {
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 0.3, y: 1.7),
+            (x: 2.9, y: 1.1),
+            (x: 3., y: 5.),
+            (x: 2.1, y: 4.4),
+        ];
+        let total = line_string.euclidean_length();
+        for n in 1..20 {
+            let pieces = line_string.line_segmentize(n).unwrap();
+            assert_eq!(pieces.0.len(), n);
+            for piece in &pieces {
+                assert_relative_eq!(piece.euclidean_length(), total / n as f64, epsilon = 1e-9);
+            }
+            // consecutive pieces share their ends exactly
+            for pair in pieces.0.windows(2) {
+                assert_eq!(pair[0].0.last(), pair[1].0.first());
+            }
+            // and every original coordinate is kept
+            for coord in &line_string.0 {
+                assert!(pieces.iter().any(|piece| piece.0.contains(coord)));
+            }
+            assert_eq!(pieces.0[0].0[0], line_string.0[0]);
+            assert_eq!(pieces.0[n - 1].0.last(), line_string.0.last());
+        }
+    }
+
+    #[test]
+    fn cuts_on_vertices() {
+        let line_string =
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 2., y: 1.)];
+        let pieces = line_string.line_segmentize(3).unwrap();
+        assert_eq!(
+            pieces.0,
+            vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+                line_string![(x: 1., y: 0.), (x: 1., y: 1.)],
+                line_string![(x: 1., y: 1.), (x: 2., y: 1.)],
+            ]
+        );
+        assert_eq!(line_string.line_segmentize(1).unwrap().0, vec![line_string]);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+        assert_eq!(line_string.line_segmentize(0), None);
+        assert_eq!(LineString::<f64>::new(vec![]).line_segmentize(2), None);
+        assert_eq!(line_string![(x: 1., y: 1.)].line_segmentize(2), None);
+        assert_eq!(
+            line_string![(x: 1., y: 1.), (x: 1., y: 1.)].line_segmentize(2),
+            None
+        );
+        assert_eq!(
+            line_string![(x: 1., y: 1.), (x: f64::NAN, y: 1.)].line_segmentize(2),
+            None
+        );
+    }
+
+    #[test]
+    fn haversine_pieces_have_equal_length() {
+        let line_string = line_string![(x: -60., y: 60.), (x: 0., y: 65.), (x: 60., y: 60.)];
+        let total = line_string.haversine_length();
+        let pieces = line_string.line_segmentize_haversine(7).unwrap();
+        assert_eq!(pieces.0.len(), 7);
+        for piece in &pieces {
+            assert_relative_eq!(piece.haversine_length(), total / 7., epsilon = 1e-6);
+        }
+        for pair in pieces.0.windows(2) {
+            assert_eq!(pair[0].0.last(), pair[1].0.first());
+        }
+
+        // new coordinates are on the great circle, north of the line in lon/lat
+        let (start, end) = (Point::from(line_string.0[0]), Point::from(line_string.0[1]));
+        let cut: Point = pieces.0[1].0[0].into();
+        assert_relative_eq!(
+            start.haversine_distance(&cut) + cut.haversine_distance(&end),
+            start.haversine_distance(&end),
+            epsilon = 1e-6
+        );
+        assert!(cut.y() > 65. + 5. * cut.x() / 60.);
+    }
+}
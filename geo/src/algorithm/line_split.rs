@@ -0,0 +1,378 @@
+use std::iter::Sum;
+use std::ops::AddAssign;
+
+use crate::{
+    Coord, CoordFloat, EuclideanLength, GeoFloat, Line, LineLocatePoint, LineString, Point,
+};
+
+/// Split a `LineString` into parts, at fractions of its length or at the points nearest to
+/// other points.
+///
+/// A fraction is of the total euclidean length, so `0.5` splits a `LineString` into two parts of
+/// equal length. Fractions outside of `0.0..=1.0` are clamped into it.
+///
+/// Each part ends where the next one begins, on the same coordinate. When a split lands on an
+/// existing vertex, or within rounding error of one, that vertex ends one part and begins the
+/// next, rather than a new coordinate being interpolated next to it. If that vertex is repeated,
+/// the repeats are dropped, rather than leaving a part which begins or ends with a segment of no
+/// length.
+///
+/// A part between two splits at the same location has no length, and is returned as an empty
+/// `LineString`, with no coordinates. In particular, splitting at `0.0` gives an empty first part
+/// and an unchanged second part, and splitting at `1.0` gives an unchanged first part and an
+/// empty second part.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, LineSplit};
+///
+/// let route = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+///
+/// let (first, second) = route.split_at_fraction(0.25).unwrap();
+/// assert_eq!(first, line_string![(x: 0., y: 0.), (x: 5., y: 0.)]);
+/// assert_eq!(second, line_string![(x: 5., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)]);
+///
+/// // a GPS fix just off the route, next to its corner
+/// let (first, second) = route.split_at_point(&point!(x: 11., y: -1.)).unwrap();
+/// assert_eq!(first, line_string![(x: 0., y: 0.), (x: 10., y: 0.)]);
+/// assert_eq!(second, line_string![(x: 10., y: 0.), (x: 10., y: 10.)]);
+///
+/// let parts = route.split_at_multiple(&[0.25, 0.5, 1.0]).unwrap();
+/// assert_eq!(parts.len(), 4);
+/// assert!(parts[3].0.is_empty());
+/// ```
+pub trait LineSplit<T: GeoFloat> {
+    /// Split at `fraction` of the length, into the parts before and after it.
+    ///
+    /// Returns `None` if `fraction` is NaN, if there are fewer than two coordinates, or if any
+    /// coordinate is not finite.
+    fn split_at_fraction(&self, fraction: T) -> Option<(LineString<T>, LineString<T>)>;
+
+    /// Split at the location nearest to `point`, as found by
+    /// [`LineLocatePoint`](crate::LineLocatePoint), into the parts before and after it.
+    ///
+    /// Returns `None` if `point` is not finite, or as for
+    /// [`split_at_fraction`](Self::split_at_fraction).
+    fn split_at_point(&self, point: &Point<T>) -> Option<(LineString<T>, LineString<T>)>;
+
+    /// Split at each of `fractions` in a single pass, into `fractions.len() + 1` parts.
+    ///
+    /// Returns `None` if `fractions` are not sorted in ascending order, or as for
+    /// [`split_at_fraction`](Self::split_at_fraction).
+    fn split_at_multiple(&self, fractions: &[T]) -> Option<Vec<LineString<T>>>;
+}
+
+impl<T> LineSplit<T> for LineString<T>
+where
+    T: GeoFloat + AddAssign + Sum,
+{
+    fn split_at_fraction(&self, fraction: T) -> Option<(LineString<T>, LineString<T>)> {
+        let mut parts = self.split_at_multiple(&[fraction])?.into_iter();
+        Some((parts.next()?, parts.next()?))
+    }
+
+    fn split_at_point(&self, point: &Point<T>) -> Option<(LineString<T>, LineString<T>)> {
+        if !(point.x().is_finite() && point.y().is_finite()) {
+            return None;
+        }
+        self.split_at_fraction(self.line_locate_point(point)?)
+    }
+
+    fn split_at_multiple(&self, fractions: &[T]) -> Option<Vec<LineString<T>>> {
+        split_line_string(
+            self,
+            fractions,
+            |line| line.euclidean_length(),
+            |line, ratio| line.start + line.delta() * ratio,
+        )
+    }
+}
+
+/// Split `line_string` at each of the sorted `fractions` of its length, measuring each segment
+/// with `length` and finding the point a ratio of the way along a segment with `interpolate`.
+pub(crate) fn split_line_string<T: CoordFloat>(
+    line_string: &LineString<T>,
+    fractions: &[T],
+    length: impl Fn(Line<T>) -> T,
+    interpolate: impl Fn(Line<T>, T) -> Coord<T>,
+) -> Option<Vec<LineString<T>>> {
+    if line_string.0.len() < 2 || fractions.iter().any(|fraction| fraction.is_nan()) {
+        return None;
+    }
+    if fractions.windows(2).any(|pair| pair[0] > pair[1]) {
+        return None;
+    }
+
+    // the distance along the line of each coordinate
+    let mut distances = Vec::with_capacity(line_string.0.len());
+    let mut distance = T::zero();
+    distances.push(distance);
+    for line in line_string.lines() {
+        distance = distance + length(line);
+        distances.push(distance);
+    }
+    let total = distance;
+    if !total.is_finite() {
+        return None;
+    }
+    let tolerance = total * T::epsilon() * T::from(4).unwrap();
+
+    let mut parts = Vec::with_capacity(fractions.len() + 1);
+    let mut start = SplitLocation::vertex(line_string, &distances, 0);
+    // the splits are in order, so each is looked for from the segment of the last
+    let mut segment = 0;
+    for &fraction in fractions {
+        let target = total * fraction.max(T::zero()).min(T::one());
+        while segment + 2 < distances.len() && distances[segment + 1] < target {
+            segment += 1;
+        }
+        let end = SplitLocation::on_segment(
+            line_string,
+            &distances,
+            segment,
+            target,
+            tolerance,
+            &interpolate,
+        );
+        parts.push(part_between(line_string, &start, &end));
+        start = end;
+    }
+    let end = SplitLocation::vertex(line_string, &distances, line_string.0.len() - 1);
+    parts.push(part_between(line_string, &start, &end));
+    Some(parts)
+}
+
+/// Where a `LineString` is split: either on its vertex at `index`, or on the segment which begins
+/// at that vertex.
+struct SplitLocation<T: CoordFloat> {
+    index: usize,
+    /// The index of the vertex after which the next part continues, which is after `index` if
+    /// the vertex there is repeated.
+    resume: usize,
+    coord: Coord<T>,
+    on_vertex: bool,
+    distance: T,
+}
+
+impl<T: CoordFloat> SplitLocation<T> {
+    fn vertex(line_string: &LineString<T>, distances: &[T], index: usize) -> Self {
+        SplitLocation {
+            index,
+            resume: index,
+            coord: line_string.0[index],
+            on_vertex: true,
+            distance: distances[index],
+        }
+    }
+
+    /// A split on the vertex at `index`. If the vertex is repeated, the part before the split
+    /// ends at its first copy and the part after begins at its last, so that neither part begins
+    /// or ends with a segment of no length.
+    fn split_vertex(line_string: &LineString<T>, distances: &[T], index: usize) -> Self {
+        let coords = &line_string.0;
+        let coord = coords[index];
+        let first = coords[..index]
+            .iter()
+            .rposition(|c| *c != coord)
+            .map_or(0, |before| before + 1);
+        let last = coords[index..]
+            .iter()
+            .position(|c| *c != coord)
+            .map_or(coords.len() - 1, |after| index + after - 1);
+        SplitLocation {
+            index: first,
+            resume: last,
+            ..Self::vertex(line_string, distances, index)
+        }
+    }
+
+    /// The location `distance` along the line, which is on the segment beginning at `segment`,
+    /// snapped to either end of it if it's within `tolerance`.
+    fn on_segment(
+        line_string: &LineString<T>,
+        distances: &[T],
+        segment: usize,
+        distance: T,
+        tolerance: T,
+        interpolate: impl Fn(Line<T>, T) -> Coord<T>,
+    ) -> Self {
+        let (from, to) = (distances[segment], distances[segment + 1]);
+        if distance - from <= tolerance {
+            return Self::split_vertex(line_string, distances, segment);
+        }
+        if to - distance <= tolerance {
+            return Self::split_vertex(line_string, distances, segment + 1);
+        }
+        let line = Line::new(line_string.0[segment], line_string.0[segment + 1]);
+        let ratio = (distance - from) / (to - from);
+        SplitLocation {
+            index: segment,
+            resume: segment,
+            coord: interpolate(line, ratio),
+            on_vertex: false,
+            distance,
+        }
+    }
+}
+
+/// The part of `line_string` from `start` to `end`, which is empty if they're at the same place.
+fn part_between<T: CoordFloat>(
+    line_string: &LineString<T>,
+    start: &SplitLocation<T>,
+    end: &SplitLocation<T>,
+) -> LineString<T> {
+    if end.distance <= start.distance {
+        return LineString::new(vec![]);
+    }
+    let mut coords = Vec::with_capacity(end.index - start.resume + 2);
+    coords.push(start.coord);
+    coords.extend_from_slice(&line_string.0[start.resume + 1..=end.index]);
+    if !end.on_vertex {
+        coords.push(end.coord);
+    }
+    LineString::new(coords)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point};
+
+    #[test]
+    fn split_at_fraction() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+        let (first, second) = line_string.split_at_fraction(0.75).unwrap();
+        assert_eq!(
+            first,
+            line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.)]
+        );
+        assert_eq!(second, line_string![(x: 4., y: 2.), (x: 4., y: 4.)]);
+
+        // out of range fractions are clamped
+        let (first, second) = line_string.split_at_fraction(-1.).unwrap();
+        assert_eq!(first, LineString::new(vec![]));
+        assert_eq!(second, line_string);
+        let (first, second) = line_string.split_at_fraction(2.).unwrap();
+        assert_eq!(first, line_string);
+        assert_eq!(second, LineString::new(vec![]));
+
+        assert_eq!(line_string.split_at_fraction(f64::NAN), None);
+        assert_eq!(LineString::new(vec![]).split_at_fraction(0.5), None);
+        assert_eq!(line_string![(x: 1., y: 1.)].split_at_fraction(0.5), None);
+    }
+
+    #[test]
+    fn split_on_vertices() {
+        // lengths which aren't exact in binary, so the fractions are rounded
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 0.1, y: 0.),
+            (x: 0.1, y: 0.7),
+            (x: 0.3, y: 0.7),
+            (x: 0.3, y: 0.9),
+        ];
+        for (index, vertex) in line_string.points().enumerate() {
+            let (first, second) = line_string.split_at_point(&vertex).unwrap();
+            if index == 0 {
+                assert!(first.0.is_empty());
+            } else {
+                assert_eq!(first.0, line_string.0[..=index]);
+            }
+            if index == line_string.0.len() - 1 {
+                assert!(second.0.is_empty());
+            } else {
+                assert_eq!(second.0, line_string.0[index..]);
+            }
+        }
+    }
+
+    #[test]
+    fn split_at_point() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let (first, second) = line_string.split_at_point(&point!(x: 3., y: 2.)).unwrap();
+        assert_eq!(first, line_string![(x: 0., y: 0.), (x: 3., y: 0.)]);
+        assert_eq!(
+            second,
+            line_string![(x: 3., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)]
+        );
+        assert_eq!(
+            line_string.split_at_point(&point!(x: f64::NAN, y: 0.)),
+            None
+        );
+    }
+
+    #[test]
+    fn split_at_multiple() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let parts = line_string
+            .split_at_multiple(&[0., 0.25, 0.5, 0.5, 0.75])
+            .unwrap();
+        assert_eq!(
+            parts,
+            vec![
+                LineString::new(vec![]),
+                line_string![(x: 0., y: 0.), (x: 5., y: 0.)],
+                line_string![(x: 5., y: 0.), (x: 10., y: 0.)],
+                LineString::new(vec![]),
+                line_string![(x: 10., y: 0.), (x: 10., y: 5.)],
+                line_string![(x: 10., y: 5.), (x: 10., y: 10.)],
+            ]
+        );
+
+        // the same as splitting one at a time
+        let (first, rest) = line_string.split_at_fraction(0.3).unwrap();
+        let (second, third) = rest.split_at_fraction(4. / 7.).unwrap();
+        let parts = line_string.split_at_multiple(&[0.3, 0.7]).unwrap();
+        assert_eq!(parts[0], first);
+        assert_relative_eq!(parts[1], second);
+        assert_relative_eq!(parts[2], third);
+
+        assert_eq!(
+            line_string.split_at_multiple(&[]),
+            Some(vec![line_string.clone()])
+        );
+        assert_eq!(line_string.split_at_multiple(&[0.5, 0.25]), None);
+    }
+
+    #[test]
+    fn split_on_repeated_vertex() {
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 5., y: 0.),
+            (x: 5., y: 0.),
+            (x: 5., y: 0.),
+            (x: 10., y: 0.),
+        ];
+        let expected = (
+            line_string![(x: 0., y: 0.), (x: 5., y: 0.)],
+            line_string![(x: 5., y: 0.), (x: 10., y: 0.)],
+        );
+        assert_eq!(
+            line_string.split_at_point(&point!(x: 5., y: 1.)),
+            Some(expected.clone())
+        );
+        assert_eq!(line_string.split_at_fraction(0.5), Some(expected));
+
+        // repeats at the ends of the line aren't split, so they're kept
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 0.),
+        ];
+        let (first, second) = line_string.split_at_fraction(0.5).unwrap();
+        assert_eq!(
+            first,
+            line_string![(x: 0., y: 0.), (x: 0., y: 0.), (x: 5., y: 0.)]
+        );
+        assert_eq!(
+            second,
+            line_string![(x: 5., y: 0.), (x: 10., y: 0.), (x: 10., y: 0.)]
+        );
+        assert_eq!(
+            line_string.split_at_multiple(&[]),
+            Some(vec![line_string.clone()])
+        );
+    }
+}
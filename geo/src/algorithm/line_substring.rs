@@ -0,0 +1,272 @@
+use num_traits::FromPrimitive;
+
+use crate::{
+    Coord, CoordFloat, EuclideanLength, HaversineIntermediate, HaversineLength, Line,
+    LineInterpolatePoint, LineString,
+};
+
+/// Returns the part of a `LineString` between two fractions of its euclidean length.
+///
+/// The ends of the substring are interpolated with
+/// [`LineInterpolatePoint`](crate::LineInterpolatePoint), unless they fall exactly on existing
+/// coordinates, and the coordinates of the `LineString` between them are kept.
+///
+/// Fractions below `0.0` or above `1.0` are clamped to them. Returns `None` if either fraction
+/// is NaN, if `start` is greater than `end`, if the `LineString` is empty, or if any of its
+/// coordinates are not finite.
+///
+/// If `start` and `end` are the same, or the `LineString` has no length, the substring is the
+/// single coordinate at that location, repeated, so that it's still a line.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, LineSubstring};
+///
+/// let route = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+///
+/// assert_eq!(
+///     route.line_substring(0.3, 0.7),
+///     Some(line_string![(x: 6., y: 0.), (x: 10., y: 0.), (x: 10., y: 4.)]),
+/// );
+/// assert_eq!(route.line_substring(-1., 2.), Some(route.clone()));
+/// assert_eq!(route.line_substring(0.7, 0.3), None);
+/// ```
+pub trait LineSubstring<T: CoordFloat> {
+    fn line_substring(&self, start: T, end: T) -> Option<LineString<T>>;
+}
+
+/// Returns the part of a `LineString` between two fractions of its length along great circles,
+/// using the [haversine formula].
+///
+/// This is the same as [`LineSubstring`], except that the fractions are of the
+/// [`HaversineLength`](crate::HaversineLength), and the new ends lie on the great circle between
+/// the coordinates either side of them. Coordinates are expected to be longitude and latitude, in
+/// degrees.
+///
+/// # Examples
+///
+/// ```
+/// # use approx::assert_relative_eq;
+/// use geo::{line_string, HaversineLength, HaversineLineSubstring};
+///
+/// // from New York City, via London, to Paris
+/// let route = line_string![
+///     (x: -74.006, y: 40.7128),
+///     (x: -0.1278, y: 51.5074),
+///     (x: 2.3522, y: 48.8566),
+/// ];
+///
+/// let middle = route.haversine_line_substring(0.25, 0.75).unwrap();
+/// assert_relative_eq!(
+///     middle.haversine_length(),
+///     route.haversine_length() / 2.,
+///     epsilon = 1e-6
+/// );
+/// ```
+///
+/// [haversine formula]: https://en.wikipedia.org/wiki/Haversine_formula
+pub trait HaversineLineSubstring<T: CoordFloat> {
+    fn haversine_line_substring(&self, start: T, end: T) -> Option<LineString<T>>;
+}
+
+impl<T> LineSubstring<T> for LineString<T>
+where
+    T: CoordFloat,
+{
+    fn line_substring(&self, start: T, end: T) -> Option<LineString<T>> {
+        substring(
+            self,
+            start,
+            end,
+            |line| line.euclidean_length(),
+            |line, fraction| line.line_interpolate_point(fraction).map(|point| point.0),
+        )
+    }
+}
+
+impl<T> HaversineLineSubstring<T> for LineString<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn haversine_line_substring(&self, start: T, end: T) -> Option<LineString<T>> {
+        substring(
+            self,
+            start,
+            end,
+            |line| line.haversine_length(),
+            |line, fraction| {
+                let point = line
+                    .start_point()
+                    .haversine_intermediate(&line.end_point(), fraction);
+                Some(point.0)
+            },
+        )
+    }
+}
+
+/// The part of `line_string` between `start` and `end`, measuring each segment with `length`
+/// and finding the point a fraction of the way along a segment with `interpolate`.
+fn substring<T: CoordFloat>(
+    line_string: &LineString<T>,
+    start: T,
+    end: T,
+    length: impl Fn(Line<T>) -> T,
+    interpolate: impl Fn(Line<T>, T) -> Option<Coord<T>>,
+) -> Option<LineString<T>> {
+    let first = *line_string.0.first()?;
+    if start.is_nan() || end.is_nan() {
+        return None;
+    }
+    let clamp = |fraction: T| fraction.max(T::zero()).min(T::one());
+    let (start, end) = (clamp(start), clamp(end));
+    if start > end {
+        return None;
+    }
+
+    let lengths: Vec<T> = line_string.lines().map(&length).collect();
+    let total = lengths
+        .iter()
+        .fold(T::zero(), |total, &length| total + length);
+    if !total.is_finite() {
+        return None;
+    }
+    if total == T::zero() {
+        return Some(LineString::new(vec![first, first]));
+    }
+    let (start, end) = (total * start, total * end);
+
+    // the point `distance` along `line`
+    let point_on = |line: Line<T>, line_length: T, distance: T| {
+        let fraction = if line_length == T::zero() {
+            T::zero()
+        } else {
+            (distance / line_length).min(T::one())
+        };
+        if fraction == T::zero() {
+            Some(line.start)
+        } else if fraction == T::one() {
+            Some(line.end)
+        } else {
+            interpolate(line, fraction)
+        }
+    };
+
+    let mut coords: Vec<Coord<T>> = vec![];
+    let mut from = T::zero();
+    for (line, &line_length) in line_string.lines().zip(&lengths) {
+        let to = from + line_length;
+        if coords.is_empty() && start <= to {
+            coords.push(point_on(line, line_length, start - from)?);
+        }
+        if !coords.is_empty() {
+            let coord = if end <= to {
+                point_on(line, line_length, end - from)?
+            } else {
+                line.end
+            };
+            if coords.last() != Some(&coord) {
+                coords.push(coord);
+            }
+            if end <= to {
+                break;
+            }
+        }
+        from = to;
+    }
+
+    if coords.len() == 1 {
+        coords.push(coords[0]);
+    }
+    Some(LineString::new(coords))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, HaversineDistance, Point};
+
+    #[test]
+    fn line_substring() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+        assert_eq!(
+            line_string.line_substring(0.25, 0.75),
+            Some(line_string![(x: 2., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.)])
+        );
+        // within a single segment
+        assert_eq!(
+            line_string.line_substring(0.125, 0.375),
+            Some(line_string![(x: 1., y: 0.), (x: 3., y: 0.)])
+        );
+        // starting and ending on vertices
+        assert_eq!(
+            line_string.line_substring(0.5, 1.),
+            Some(line_string![(x: 4., y: 0.), (x: 4., y: 4.)])
+        );
+        assert_eq!(
+            line_string.line_substring(0., 0.5),
+            Some(line_string![(x: 0., y: 0.), (x: 4., y: 0.)])
+        );
+    }
+
+    #[test]
+    fn degenerate_substrings() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+        assert_eq!(
+            line_string.line_substring(0.5, 0.5),
+            Some(line_string![(x: 4., y: 0.), (x: 4., y: 0.)])
+        );
+        assert_eq!(
+            line_string.line_substring(-2., -1.),
+            Some(line_string![(x: 0., y: 0.), (x: 0., y: 0.)])
+        );
+        assert_eq!(line_string.line_substring(0.6, 0.4), None);
+        assert_eq!(line_string.line_substring(f64::NAN, 0.4), None);
+
+        assert_eq!(LineString::<f64>::new(vec![]).line_substring(0., 1.), None);
+        assert_eq!(
+            line_string![(x: 1., y: 1.)].line_substring(0., 1.),
+            Some(line_string![(x: 1., y: 1.), (x: 1., y: 1.)])
+        );
+        assert_eq!(
+            line_string![(x: 0., y: 0.), (x: f64::INFINITY, y: 0.)].line_substring(0., 1.),
+            None
+        );
+
+        // repeated coordinates are kept, but not duplicated at the ends
+        let repeated = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 0.), (x: 4., y: 0.)];
+        assert_eq!(
+            repeated.line_substring(0.25, 0.75),
+            Some(line_string![(x: 1., y: 0.), (x: 2., y: 0.), (x: 3., y: 0.)])
+        );
+    }
+
+    #[test]
+    fn haversine_line_substring() {
+        // along the equator, where the great circles are also straight lines in lon/lat
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 20., y: 0.)];
+        let substring = line_string.haversine_line_substring(0.25, 0.75).unwrap();
+        assert_eq!(substring.0.len(), 3);
+        assert_relative_eq!(substring.0[0], Coord { x: 5., y: 0. }, epsilon = 1e-9);
+        assert_eq!(substring.0[1], Coord { x: 10., y: 0. });
+        assert_relative_eq!(substring.0[2], Coord { x: 15., y: 0. }, epsilon = 1e-9);
+
+        // at high latitudes the great circle bends towards the pole, and its fractions differ
+        // from those of the euclidean length
+        let line_string = line_string![(x: -60., y: 60.), (x: 60., y: 60.)];
+        let substring = line_string.haversine_line_substring(0., 0.5).unwrap();
+        let end = Point::from(substring.0[1]);
+        assert_relative_eq!(end.x(), 0., epsilon = 1e-9);
+        assert!(end.y() > 70.);
+        let (start, finish) = (Point::from(line_string.0[0]), Point::from(line_string.0[1]));
+        assert_relative_eq!(
+            start.haversine_distance(&end),
+            end.haversine_distance(&finish),
+            epsilon = 1e-6
+        );
+        assert_eq!(
+            line_string.line_substring(0., 0.5).unwrap().0[1],
+            Coord { x: 0., y: 60. }
+        );
+    }
+}
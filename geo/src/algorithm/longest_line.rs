@@ -0,0 +1,173 @@
+use crate::convex_hull::quick_hull;
+use crate::coords_iter::CoordsIter;
+use crate::{Coord, EuclideanDistance, GeoFloat, Line};
+
+/// Find the longest line between two geometries, like [PostGIS's `ST_LongestLine`][postgis]:
+/// the line between the points of each which are furthest apart.
+///
+/// Unlike the [minimum distance](crate::EuclideanDistance), the greatest distance between two
+/// geometries is always between vertices of their convex hulls, so only those are compared.
+/// This takes time proportional to the product of the sizes of the hulls, after building them.
+///
+/// The line runs from a vertex of `self` to a vertex of `rhs`. If either geometry is empty, there
+/// is no line, and `None` is returned.
+///
+/// [postgis]: https://postgis.net/docs/ST_LongestLine.html
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, point, polygon, Line, LongestLine};
+///
+/// let zone = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 3.), (x: 0., y: 3.)];
+/// let antenna = point!(x: 12., y: 9.);
+///
+/// assert_eq!(zone.max_distance(&antenna), Some(15.));
+/// assert_eq!(
+///     zone.longest_line(&antenna),
+///     Some(Line::new(coord! { x: 0., y: 0. }, coord! { x: 12., y: 9. }))
+/// );
+/// ```
+pub trait LongestLine<T: GeoFloat, Rhs = Self> {
+    /// The line from a point of `self` to the point of `rhs` furthest from it, or `None` if
+    /// either is empty.
+    fn longest_line(&self, rhs: &Rhs) -> Option<Line<T>>;
+
+    /// The greatest distance between a point of `self` and a point of `rhs`, or `None` if
+    /// either is empty.
+    fn max_distance(&self, rhs: &Rhs) -> Option<T>;
+}
+
+impl<T, G, Rhs> LongestLine<T, Rhs> for G
+where
+    T: GeoFloat,
+    G: for<'a> CoordsIter<'a, Scalar = T>,
+    Rhs: for<'a> CoordsIter<'a, Scalar = T>,
+{
+    fn longest_line(&self, rhs: &Rhs) -> Option<Line<T>> {
+        let hull = hull_vertices(self);
+        let rhs_hull = hull_vertices(rhs);
+
+        let mut best: Option<(T, Line<T>)> = None;
+        for &start in &hull {
+            for &end in &rhs_hull {
+                let distance = start.euclidean_distance(&end);
+                if best.map_or(true, |(max, _)| distance > max) {
+                    best = Some((distance, Line::new(start, end)));
+                }
+            }
+        }
+        best.map(|(_, line)| line)
+    }
+
+    fn max_distance(&self, rhs: &Rhs) -> Option<T> {
+        self.longest_line(rhs)
+            .map(|line| line.start.euclidean_distance(&line.end))
+    }
+}
+
+/// The vertices of the convex hull of `geometry`, without the closing one.
+fn hull_vertices<'a, T, G>(geometry: &'a G) -> Vec<Coord<T>>
+where
+    T: GeoFloat,
+    G: CoordsIter<'a, Scalar = T>,
+{
+    let mut coords: Vec<Coord<T>> = geometry.coords_iter().collect();
+    if coords.is_empty() {
+        return coords;
+    }
+    let mut hull = quick_hull(&mut coords).0;
+    hull.pop();
+    hull
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coord, line_string, point, polygon, Geometry, LineString, MultiPoint, Polygon};
+    use approx::assert_relative_eq;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn empty() {
+        let empty = LineString::<f64>::new(vec![]);
+        let point = point!(x: 1., y: 1.);
+        assert_eq!(empty.longest_line(&point), None);
+        assert_eq!(point.longest_line(&empty), None);
+        assert_eq!(empty.max_distance(&empty), None);
+    }
+
+    #[test]
+    fn points() {
+        let a = point!(x: 1., y: 1.);
+        let b = point!(x: 4., y: 5.);
+        assert_eq!(a.max_distance(&a), Some(0.));
+        assert_eq!(a.max_distance(&b), Some(5.));
+        assert_eq!(
+            b.longest_line(&a),
+            Some(Line::new(coord! { x: 4., y: 5. }, coord! { x: 1., y: 1. }))
+        );
+    }
+
+    #[test]
+    fn collinear() {
+        // the hull of collinear points is just the two ends
+        let line_string = line_string![(x: 1., y: 0.), (x: 3., y: 0.), (x: 2., y: 0.)];
+        let point = point!(x: 0., y: 0.);
+        assert_eq!(
+            line_string.longest_line(&point),
+            Some(Line::new(coord! { x: 3., y: 0. }, coord! { x: 0., y: 0. }))
+        );
+    }
+
+    #[test]
+    fn overlapping_polygons() {
+        // the furthest points are opposite corners, across both squares
+        let a = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let b = polygon![(x: 1., y: 1.), (x: 4., y: 1.), (x: 4., y: 5.), (x: 1., y: 5.)];
+        assert_eq!(
+            a.longest_line(&b),
+            Some(Line::new(coord! { x: 0., y: 0. }, coord! { x: 4., y: 5. }))
+        );
+        assert_relative_eq!(a.max_distance(&b).unwrap(), 41f64.sqrt());
+        assert_eq!(
+            b.longest_line(&Geometry::from(a)),
+            Some(Line::new(coord! { x: 4., y: 5. }, coord! { x: 0., y: 0. }))
+        );
+    }
+
+    #[test]
+    fn matches_exhaustive_search() {
+        let mut rng = StdRng::seed_from_u64(46);
+        let mut random = move || rng.gen::<f64>() * 100.;
+        for _ in 0..50 {
+            // star-shaped polygons, most of whose vertices aren't on their hulls
+            let mut polygon = || {
+                let center = coord! { x: random(), y: random() };
+                let mut angles: Vec<f64> = (0..20).map(|_| random()).collect();
+                angles.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                let ring = angles.into_iter().map(|angle| {
+                    let (sin, cos) = (angle * std::f64::consts::PI / 50.).sin_cos();
+                    let radius = random() / 2.;
+                    (center.x + radius * cos, center.y + radius * sin)
+                });
+                Polygon::new(LineString::from_iter(ring), vec![])
+            };
+            let (a, b) = (polygon(), polygon());
+            let points: MultiPoint<f64> = b.exterior().points().collect();
+
+            let mut expected = 0f64;
+            for start in a.exterior() {
+                for end in b.exterior() {
+                    expected = expected.max(start.euclidean_distance(end));
+                }
+            }
+            let line = a.longest_line(&points).unwrap();
+            assert_eq!(a.max_distance(&b), Some(expected));
+            assert_eq!(line.start.euclidean_distance(&line.end), expected);
+            assert!(a.exterior().0.contains(&line.start));
+            assert!(b.exterior().0.contains(&line.end));
+        }
+    }
+}
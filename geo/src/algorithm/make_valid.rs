@@ -0,0 +1,214 @@
+use crate::bool_ops::float_boolean_op;
+use crate::{Coord, GeoFloat, LineString, MultiPolygon, OpType, Polygon};
+
+/// Repair an invalid polygonal geometry, so that it is [valid](crate::IsValid).
+///
+/// The rings of each polygon are noded, by splitting them wherever they intersect, and
+/// re-assembled into valid polygons using the [`BooleanOps`] machinery. A location is inside a
+/// repaired polygon if it is inside an odd number of its rings, so the two halves of a "bow tie"
+/// are both kept, while a hole which sticks out of its exterior ring becomes a separate polygon.
+/// The polygons of a `MultiPolygon` are then merged, so a location is inside the result if it is
+/// inside any of them.
+///
+/// The result may have more polygons than the input, and its winding order is unspecified.
+/// Coordinates which are NaN or infinite are dropped, and parts which have no area, such as
+/// spikes and collapsed rings, are removed. An input which has no area at all is repaired to an
+/// empty `MultiPolygon`.
+///
+/// Since the polygons are noded with floating point arithmetic, the repair relies on
+/// [`BooleanOps`] to be robust, which it may not be for pathological inputs with very many
+/// overlapping collinear segments.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, IsValid, MakeValid};
+///
+/// // a "bow tie", whose exterior ring crosses itself
+/// let bow_tie = polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+/// assert!(!bow_tie.is_valid());
+///
+/// let repaired = bow_tie.make_valid();
+///
+/// assert!(repaired.is_valid());
+/// assert_eq!(repaired.0.len(), 2);
+/// assert_eq!(repaired.unsigned_area(), 2.);
+/// ```
+pub trait MakeValid<T: GeoFloat> {
+    fn make_valid(&self) -> MultiPolygon<T>;
+}
+
+impl<T: GeoFloat> MakeValid<T> for Polygon<T> {
+    fn make_valid(&self) -> MultiPolygon<T> {
+        // `BooleanOps` decides which regions are inside by the parity of the rings crossed to
+        // reach them, regardless of whether the rings are valid
+        float_boolean_op(&[finite(self)], &[], OpType::Union)
+    }
+}
+
+impl<T: GeoFloat> MakeValid<T> for MultiPolygon<T> {
+    fn make_valid(&self) -> MultiPolygon<T> {
+        let repaired: Vec<MultiPolygon<T>> =
+            self.iter().map(|polygon| polygon.make_valid()).collect();
+        union_all(&repaired)
+    }
+}
+
+/// `polygon` without any non-finite coordinates.
+fn finite<T: GeoFloat>(polygon: &Polygon<T>) -> Polygon<T> {
+    let finite_ring = |ring: &LineString<T>| {
+        let coords: Vec<Coord<T>> = ring
+            .0
+            .iter()
+            .copied()
+            .filter(|coord| coord.x.is_finite() && coord.y.is_finite())
+            .collect();
+        LineString::new(coords)
+    };
+    Polygon::new(
+        finite_ring(polygon.exterior()),
+        polygon.interiors().iter().map(finite_ring).collect(),
+    )
+}
+
+/// The union of all of the valid `multi_polygons`, merged in pairs so that each coordinate is
+/// only processed `O(log n)` times.
+fn union_all<T: GeoFloat>(multi_polygons: &[MultiPolygon<T>]) -> MultiPolygon<T> {
+    match multi_polygons {
+        [] => MultiPolygon::new(vec![]),
+        [multi_polygon] => multi_polygon.clone(),
+        _ => {
+            let (left, right) = multi_polygons.split_at(multi_polygons.len() / 2);
+            float_boolean_op(&union_all(left).0, &union_all(right).0, OpType::Union)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Area, IsValid};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn assert_repaired(multi_polygon: &MultiPolygon<f64>, area: f64) {
+        assert_eq!(multi_polygon.explain_invalidity(), None);
+        assert_relative_eq!(multi_polygon.unsigned_area(), area, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn valid_polygon_is_unchanged() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)]],
+        );
+        let repaired = polygon.make_valid();
+        assert_repaired(&repaired, 99.);
+        assert_eq!(repaired.0.len(), 1);
+        assert_eq!(repaired.0[0].interiors().len(), 1);
+    }
+
+    #[test]
+    fn bow_ties() {
+        let bow_tie = polygon![(x: 0., y: 0.), (x: 4., y: 4.), (x: 4., y: 0.), (x: 0., y: 4.)];
+        let repaired = bow_tie.make_valid();
+        assert_repaired(&repaired, 8.);
+        assert_eq!(repaired.0.len(), 2);
+
+        // a ring which crosses itself several times
+        let zig_zag = polygon![
+            (x: 0., y: 0.),
+            (x: 6., y: 2.),
+            (x: 6., y: 0.),
+            (x: 0., y: 2.),
+            (x: 3., y: 3.),
+        ];
+        assert_eq!(zig_zag.make_valid().explain_invalidity(), None);
+    }
+
+    #[test]
+    fn spikes_and_collapses() {
+        let spike = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 2., y: 4.),
+            (x: 2., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        let repaired = spike.make_valid();
+        assert_repaired(&repaired, 4.);
+        assert_eq!(repaired.0[0].exterior().0.len(), 5);
+
+        let line = polygon![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 2.)];
+        assert_eq!(line.make_valid(), MultiPolygon::new(vec![]));
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert_eq!(empty.make_valid(), MultiPolygon::new(vec![]));
+    }
+
+    #[test]
+    fn invalid_holes() {
+        // a hole which sticks out of the exterior ring
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+            interiors: [[(x: 2., y: 1.), (x: 6., y: 1.), (x: 6., y: 3.), (x: 2., y: 3.)]],
+        );
+        let repaired = polygon.make_valid();
+        // the overlap is a hole, and the rest of the hole is a new polygon
+        assert_repaired(&repaired, 16. - 4. + 4.);
+        assert_eq!(repaired.0.len(), 2);
+
+        // nested holes
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 9., y: 1.), (x: 9., y: 9.), (x: 1., y: 9.)],
+                [(x: 2., y: 2.), (x: 3., y: 2.), (x: 3., y: 3.), (x: 2., y: 3.)],
+            ],
+        );
+        assert_repaired(&polygon.make_valid(), 100. - 64. + 1.);
+    }
+
+    #[test]
+    fn overlapping_multi_polygon() {
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+            polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)],
+            // sharing an edge with the first
+            polygon![(x: 0., y: 0.), (x: 0., y: 2.), (x: -1., y: 1.)],
+            // inside the first
+            polygon![(x: 0.5, y: 0.5), (x: 0.8, y: 0.5), (x: 0.8, y: 0.8)],
+            // separate
+            polygon![(x: 10., y: 10.), (x: 11., y: 10.), (x: 11., y: 11.)],
+        ]);
+        assert!(!multi_polygon.is_valid());
+        let repaired = multi_polygon.make_valid();
+        assert_repaired(&repaired, 7. + 1. + 0.5);
+        assert_eq!(repaired.0.len(), 2);
+    }
+
+    #[test]
+    fn non_finite_coords() {
+        let polygon = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: f64::NAN, y: 1.),
+            (x: 2., y: 2.),
+            (x: 0., y: 2.),
+        ];
+        assert_repaired(&polygon.make_valid(), 4.);
+    }
+
+    #[test]
+    fn random_self_intersections() {
+        let mut rng = StdRng::seed_from_u64(17);
+        let mut next = move || rng.gen::<f64>() * 10.;
+
+        for count in 3..40 {
+            let exterior: LineString = (0..count).map(|_| (next(), next())).collect();
+            let interior: LineString = (0..count / 2 + 3).map(|_| (next(), next())).collect();
+            let polygon = Polygon::new(exterior, vec![interior]);
+            assert_eq!(polygon.make_valid().explain_invalidity(), None);
+        }
+    }
+}
@@ -26,6 +26,8 @@
 
 pub use modern::*;
 mod modern {
+    use std::{error, fmt};
+
     pub(crate) use crate::geometry::*;
     pub(crate) use crate::CoordNum;
 
@@ -695,6 +697,265 @@ mod modern {
             Ok(())
         }
     }
+
+    /// Map a fallible function over all the coordinates in a geometry, like
+    /// [`MapCoords::try_map_coords`], but report which coordinate the function failed on.
+    pub trait TryMapCoordsWithPosition<T: CoordNum, NT>: MapCoords<T, NT> {
+        /// Map a fallible function over all the coordinates in a geometry, returning a `Result`.
+        ///
+        /// Upon encountering an `Err` from the function, this immediately returns a
+        /// [`MapCoordsError`] carrying the error along with the coordinate it was returned for
+        /// and that coordinate's [`position`](MapCoordsError::position) in the geometry.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use geo::{polygon, Coord, MultiPolygon, TryMapCoordsWithPosition};
+        ///
+        /// // Web Mercator is undefined near the poles
+        /// let to_web_mercator = |Coord { x, y }: Coord| -> Result<Coord, &str> {
+        ///     if y.abs() > 85.06 {
+        ///         return Err("latitude out of range");
+        ///     }
+        ///     Ok(Coord { x: x.to_radians(), y: (y.to_radians() / 2. + std::f64::consts::FRAC_PI_4).tan().ln() })
+        /// };
+        ///
+        /// let islands = MultiPolygon::new(vec![
+        ///     polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+        ///     polygon![(x: 0., y: 80.), (x: 1., y: 80.), (x: 1., y: 89.)],
+        /// ]);
+        ///
+        /// let error = islands.try_map_coords_with_position(to_web_mercator).unwrap_err();
+        /// assert_eq!(error.coord, Coord { x: 1., y: 89. });
+        /// // the third coordinate of the exterior of the second polygon
+        /// assert_eq!(error.position, vec![1, 0, 2]);
+        /// assert_eq!(error.source, "latitude out of range");
+        /// ```
+        fn try_map_coords_with_position<E>(
+            &self,
+            func: impl Fn(Coord<T>) -> Result<Coord<NT>, E> + Copy,
+        ) -> Result<Self::Output, MapCoordsError<T, E>>
+        where
+            NT: CoordNum;
+    }
+
+    /// The error returned by [`TryMapCoordsWithPosition::try_map_coords_with_position`], when the
+    /// function failed to map a coordinate.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct MapCoordsError<T: CoordNum, E> {
+        /// The coordinate which the function failed to map.
+        pub coord: Coord<T>,
+        /// The indices which lead to the coordinate, from the outermost part of the geometry in.
+        ///
+        /// Each level of nesting adds an index: of the geometry in a `GeometryCollection`, the
+        /// polygon in a `MultiPolygon`, the line string in a `MultiLineString`, or the point in a
+        /// `MultiPoint`; of the ring in a `Polygon`, where `0` is the exterior and the interiors
+        /// follow; and of the coordinate in a `LineString`, `Line` (the start is `0`), `Rect`
+        /// (the minimum is `0`) or `Triangle`. A `Point` adds no index of its own.
+        pub position: Vec<usize>,
+        /// The error returned by the function.
+        pub source: E,
+    }
+
+    impl<T: CoordNum, E> MapCoordsError<T, E> {
+        fn new(coord: Coord<T>, source: E) -> Self {
+            MapCoordsError {
+                coord,
+                position: vec![],
+                source,
+            }
+        }
+
+        /// The same error, at `index` in the part of the geometry containing this one.
+        fn within(mut self, index: usize) -> Self {
+            self.position.insert(0, index);
+            self
+        }
+    }
+
+    impl<T: CoordNum, E: fmt::Display> fmt::Display for MapCoordsError<T, E> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                f,
+                "failed to map coordinate ({:?}, {:?}) at position {:?}: {}",
+                self.coord.x, self.coord.y, self.position, self.source
+            )
+        }
+    }
+
+    impl<T: CoordNum, E: error::Error + 'static> error::Error for MapCoordsError<T, E> {
+        fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+            Some(&self.source)
+        }
+    }
+
+    /// Map each coordinate in order, with its index as its position.
+    fn try_map_each<T: CoordNum, NT: CoordNum, E>(
+        coords: &[Coord<T>],
+        func: impl Fn(Coord<T>) -> Result<Coord<NT>, E>,
+    ) -> Result<Vec<Coord<NT>>, MapCoordsError<T, E>> {
+        coords
+            .iter()
+            .enumerate()
+            .map(|(index, &coord)| {
+                func(coord).map_err(|source| MapCoordsError::new(coord, source).within(index))
+            })
+            .collect()
+    }
+
+    /// Map each of `parts` in order, with its index prepended to the position of any error.
+    fn try_map_parts<'a, G, T, NT, E>(
+        parts: impl IntoIterator<Item = &'a G>,
+        func: impl Fn(Coord<T>) -> Result<Coord<NT>, E> + Copy,
+    ) -> Result<Vec<G::Output>, MapCoordsError<T, E>>
+    where
+        G: TryMapCoordsWithPosition<T, NT> + 'a,
+        T: CoordNum,
+        NT: CoordNum,
+    {
+        parts
+            .into_iter()
+            .enumerate()
+            .map(|(index, part)| {
+                part.try_map_coords_with_position(func)
+                    .map_err(|error| error.within(index))
+            })
+            .collect()
+    }
+
+    impl<T: CoordNum, NT: CoordNum> TryMapCoordsWithPosition<T, NT> for Point<T> {
+        fn try_map_coords_with_position<E>(
+            &self,
+            func: impl Fn(Coord<T>) -> Result<Coord<NT>, E> + Copy,
+        ) -> Result<Self::Output, MapCoordsError<T, E>> {
+            func(self.0)
+                .map(Point)
+                .map_err(|source| MapCoordsError::new(self.0, source))
+        }
+    }
+
+    impl<T: CoordNum, NT: CoordNum> TryMapCoordsWithPosition<T, NT> for Line<T> {
+        fn try_map_coords_with_position<E>(
+            &self,
+            func: impl Fn(Coord<T>) -> Result<Coord<NT>, E> + Copy,
+        ) -> Result<Self::Output, MapCoordsError<T, E>> {
+            let coords = try_map_each(&[self.start, self.end], func)?;
+            Ok(Line::new(coords[0], coords[1]))
+        }
+    }
+
+    impl<T: CoordNum, NT: CoordNum> TryMapCoordsWithPosition<T, NT> for LineString<T> {
+        fn try_map_coords_with_position<E>(
+            &self,
+            func: impl Fn(Coord<T>) -> Result<Coord<NT>, E> + Copy,
+        ) -> Result<Self::Output, MapCoordsError<T, E>> {
+            Ok(LineString::new(try_map_each(&self.0, func)?))
+        }
+    }
+
+    impl<T: CoordNum, NT: CoordNum> TryMapCoordsWithPosition<T, NT> for Polygon<T> {
+        fn try_map_coords_with_position<E>(
+            &self,
+            func: impl Fn(Coord<T>) -> Result<Coord<NT>, E> + Copy,
+        ) -> Result<Self::Output, MapCoordsError<T, E>> {
+            let mut rings = try_map_parts(
+                std::iter::once(self.exterior()).chain(self.interiors()),
+                func,
+            )?;
+            let exterior = rings.remove(0);
+            Ok(Polygon::new(exterior, rings))
+        }
+    }
+
+    impl<T: CoordNum, NT: CoordNum> TryMapCoordsWithPosition<T, NT> for MultiPoint<T> {
+        fn try_map_coords_with_position<E>(
+            &self,
+            func: impl Fn(Coord<T>) -> Result<Coord<NT>, E> + Copy,
+        ) -> Result<Self::Output, MapCoordsError<T, E>> {
+            Ok(MultiPoint::new(try_map_parts(&self.0, func)?))
+        }
+    }
+
+    impl<T: CoordNum, NT: CoordNum> TryMapCoordsWithPosition<T, NT> for MultiLineString<T> {
+        fn try_map_coords_with_position<E>(
+            &self,
+            func: impl Fn(Coord<T>) -> Result<Coord<NT>, E> + Copy,
+        ) -> Result<Self::Output, MapCoordsError<T, E>> {
+            Ok(MultiLineString::new(try_map_parts(&self.0, func)?))
+        }
+    }
+
+    impl<T: CoordNum, NT: CoordNum> TryMapCoordsWithPosition<T, NT> for MultiPolygon<T> {
+        fn try_map_coords_with_position<E>(
+            &self,
+            func: impl Fn(Coord<T>) -> Result<Coord<NT>, E> + Copy,
+        ) -> Result<Self::Output, MapCoordsError<T, E>> {
+            Ok(MultiPolygon::new(try_map_parts(&self.0, func)?))
+        }
+    }
+
+    impl<T: CoordNum, NT: CoordNum> TryMapCoordsWithPosition<T, NT> for Geometry<T> {
+        fn try_map_coords_with_position<E>(
+            &self,
+            func: impl Fn(Coord<T>) -> Result<Coord<NT>, E> + Copy,
+        ) -> Result<Self::Output, MapCoordsError<T, E>> {
+            Ok(match *self {
+                Geometry::Point(ref x) => Geometry::Point(x.try_map_coords_with_position(func)?),
+                Geometry::Line(ref x) => Geometry::Line(x.try_map_coords_with_position(func)?),
+                Geometry::LineString(ref x) => {
+                    Geometry::LineString(x.try_map_coords_with_position(func)?)
+                }
+                Geometry::Polygon(ref x) => {
+                    Geometry::Polygon(x.try_map_coords_with_position(func)?)
+                }
+                Geometry::MultiPoint(ref x) => {
+                    Geometry::MultiPoint(x.try_map_coords_with_position(func)?)
+                }
+                Geometry::MultiLineString(ref x) => {
+                    Geometry::MultiLineString(x.try_map_coords_with_position(func)?)
+                }
+                Geometry::MultiPolygon(ref x) => {
+                    Geometry::MultiPolygon(x.try_map_coords_with_position(func)?)
+                }
+                Geometry::GeometryCollection(ref x) => {
+                    Geometry::GeometryCollection(x.try_map_coords_with_position(func)?)
+                }
+                Geometry::Rect(ref x) => Geometry::Rect(x.try_map_coords_with_position(func)?),
+                Geometry::Triangle(ref x) => {
+                    Geometry::Triangle(x.try_map_coords_with_position(func)?)
+                }
+            })
+        }
+    }
+
+    impl<T: CoordNum, NT: CoordNum> TryMapCoordsWithPosition<T, NT> for GeometryCollection<T> {
+        fn try_map_coords_with_position<E>(
+            &self,
+            func: impl Fn(Coord<T>) -> Result<Coord<NT>, E> + Copy,
+        ) -> Result<Self::Output, MapCoordsError<T, E>> {
+            Ok(GeometryCollection::new_from(try_map_parts(&self.0, func)?))
+        }
+    }
+
+    impl<T: CoordNum, NT: CoordNum> TryMapCoordsWithPosition<T, NT> for Rect<T> {
+        fn try_map_coords_with_position<E>(
+            &self,
+            func: impl Fn(Coord<T>) -> Result<Coord<NT>, E> + Copy,
+        ) -> Result<Self::Output, MapCoordsError<T, E>> {
+            let coords = try_map_each(&[self.min(), self.max()], func)?;
+            Ok(Rect::new(coords[0], coords[1]))
+        }
+    }
+
+    impl<T: CoordNum, NT: CoordNum> TryMapCoordsWithPosition<T, NT> for Triangle<T> {
+        fn try_map_coords_with_position<E>(
+            &self,
+            func: impl Fn(Coord<T>) -> Result<Coord<NT>, E> + Copy,
+        ) -> Result<Self::Output, MapCoordsError<T, E>> {
+            let coords = try_map_each(&[self.0, self.1, self.2], func)?;
+            Ok(Triangle::new(coords[0], coords[1], coords[2]))
+        }
+    }
 }
 pub use deprecated::*;
 pub(crate) mod deprecated {
@@ -899,7 +1160,7 @@ pub(crate) mod deprecated {
 
 #[cfg(test)]
 mod test {
-    use super::{MapCoords, MapCoordsInPlace};
+    use super::{MapCoords, MapCoordsError, MapCoordsInPlace, TryMapCoordsWithPosition};
     use crate::{
         coord, polygon, Coord, Geometry, GeometryCollection, Line, LineString, MultiLineString,
         MultiPoint, MultiPolygon, Point, Polygon, Rect,
@@ -1205,6 +1466,85 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_fallible_with_position() {
+        // Web Mercator, which is undefined near the poles
+        let project = |Coord { x, y }: Coord| -> Result<Coord, &'static str> {
+            if y.abs() > 85.06 {
+                return Err("latitude out of range");
+            }
+            Ok(Coord {
+                x: x.to_radians(),
+                y: (y.to_radians() / 2. + std::f64::consts::FRAC_PI_4)
+                    .tan()
+                    .ln(),
+            })
+        };
+
+        let svalbard = polygon![
+            exterior: [(x: 10., y: 76.), (x: 30., y: 76.), (x: 30., y: 81.), (x: 10., y: 81.)],
+            interiors: [
+                [(x: 15., y: 77.), (x: 20., y: 77.), (x: 20., y: 78.)],
+                // a ring which strays too close to the pole
+                [(x: 21., y: 79.), (x: 25., y: 79.), (x: 25., y: 88.)],
+            ],
+        ];
+        let collection = GeometryCollection::new_from(vec![
+            Geometry::Point(Point::new(0., 0.)),
+            Geometry::MultiPolygon(MultiPolygon::new(vec![
+                polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+                svalbard.clone(),
+            ])),
+        ]);
+
+        let error = collection
+            .try_map_coords_with_position(project)
+            .unwrap_err();
+        assert_eq!(
+            error,
+            MapCoordsError {
+                coord: coord! { x: 25., y: 88. },
+                position: vec![1, 1, 2, 2],
+                source: "latitude out of range",
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "failed to map coordinate (25.0, 88.0) at position [1, 1, 2, 2]: latitude out of range"
+        );
+        assert_eq!(
+            svalbard
+                .try_map_coords_with_position(project)
+                .unwrap_err()
+                .position,
+            vec![2, 2]
+        );
+
+        // the first failure is reported
+        let line_string = LineString::from(vec![(0., 0.), (0., 89.), (0., -89.)]);
+        let error = line_string
+            .try_map_coords_with_position(project)
+            .unwrap_err();
+        assert_eq!(error.coord, coord! { x: 0., y: 89. });
+        assert_eq!(error.position, vec![1]);
+
+        let error = Point::new(0., -89.)
+            .try_map_coords_with_position(project)
+            .unwrap_err();
+        assert!(error.position.is_empty());
+        let error = Rect::new((0., 0.), (1., 89.))
+            .try_map_coords_with_position(project)
+            .unwrap_err();
+        assert_eq!(error.position, vec![1]);
+
+        // and without failures the results are the same as `try_map_coords`
+        let good = MultiPolygon::new(vec![Polygon::new(svalbard.exterior().clone(), vec![])]);
+        assert_eq!(
+            good.try_map_coords_with_position(project).unwrap(),
+            good.try_map_coords(project).unwrap()
+        );
+    }
+
     #[test]
     fn rect_map_invert_coords() {
         let rect = Rect::new(coord! { x: 0., y: 0. }, coord! { x: 1., y: 1. });
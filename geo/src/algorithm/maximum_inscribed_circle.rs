@@ -0,0 +1,325 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::{
+    BoundingRect, Centroid, Coord, EuclideanDistance, GeoFloat, Intersects, MultiPolygon, Point,
+    Polygon, Rect,
+};
+
+/// Find the pole of inaccessibility of a polygon: the point inside it which is farthest from its
+/// boundary, i.e. the center of its largest inscribed circle. Unlike the
+/// [`Centroid`](crate::Centroid) or an [`InteriorPoint`](crate::InteriorPoint), this is well
+/// inside even a crescent or ring shaped polygon, which makes it a good position for a label.
+///
+/// This uses the [polylabel] algorithm: the bounding rectangle is covered with square cells,
+/// which are repeatedly split into quarters, most promising first, until none of them can contain
+/// a point more than `tolerance` farther from the boundary than the best one found so far.
+/// Interior rings are part of the boundary, so the circle never overlaps a hole.
+///
+/// Returns the center of the circle and its radius. The radius of the largest inscribed circle is
+/// at most `tolerance` more than the returned one. The search always terminates, but a tolerance
+/// which is very small relative to the size of the polygon is only respected up to floating point
+/// precision, and a negative tolerance is treated as zero.
+///
+/// For a `MultiPolygon`, the circle is the largest one inscribed in any of its polygons. A
+/// geometry with no area has a circle of radius zero, which is centered on the origin if the
+/// geometry is empty.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{polygon, MaximumInscribedCircle};
+///
+/// // an L shape, with a thicker vertical arm
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 2.),
+///     (x: 4., y: 2.),
+///     (x: 4., y: 10.),
+///     (x: 0., y: 10.),
+/// ];
+///
+/// let (center, radius) = polygon.maximum_inscribed_circle(0.01);
+///
+/// assert_relative_eq!(radius, 2., epsilon = 0.01);
+/// assert_relative_eq!(center.x(), 2., epsilon = 0.1);
+/// ```
+///
+/// [polylabel]: https://github.com/mapbox/polylabel
+pub trait MaximumInscribedCircle<T: GeoFloat> {
+    fn maximum_inscribed_circle(&self, tolerance: T) -> (Point<T>, T);
+}
+
+impl<T: GeoFloat> MaximumInscribedCircle<T> for Polygon<T> {
+    fn maximum_inscribed_circle(&self, tolerance: T) -> (Point<T>, T) {
+        polylabel(self, tolerance)
+    }
+}
+
+impl<T: GeoFloat> MaximumInscribedCircle<T> for MultiPolygon<T> {
+    fn maximum_inscribed_circle(&self, tolerance: T) -> (Point<T>, T) {
+        polylabel(self, tolerance)
+    }
+}
+
+/// The geometries whose pole of inaccessibility can be found.
+trait Inaccessibility<T: GeoFloat>: BoundingRect<T, Output = Option<Rect<T>>> {
+    /// The distance from `coord` to the boundary, which is negative outside of the geometry.
+    fn signed_distance(&self, coord: Coord<T>) -> T;
+
+    fn first_coord(&self) -> Option<Coord<T>>;
+
+    fn centroid_coord(&self) -> Option<Coord<T>>;
+}
+
+impl<T: GeoFloat> Inaccessibility<T> for Polygon<T> {
+    fn signed_distance(&self, coord: Coord<T>) -> T {
+        let point = Point::from(coord);
+        let distance = std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .map(|ring| point.euclidean_distance(ring))
+            .fold(T::infinity(), |accum, val| accum.min(val));
+        if self.intersects(&coord) {
+            distance
+        } else {
+            -distance
+        }
+    }
+
+    fn first_coord(&self) -> Option<Coord<T>> {
+        self.exterior().0.first().copied()
+    }
+
+    fn centroid_coord(&self) -> Option<Coord<T>> {
+        self.centroid().map(|centroid| centroid.0)
+    }
+}
+
+impl<T: GeoFloat> Inaccessibility<T> for MultiPolygon<T> {
+    fn signed_distance(&self, coord: Coord<T>) -> T {
+        // inside one of the polygons if any, or else the least far outside of them
+        self.iter()
+            .map(|polygon| polygon.signed_distance(coord))
+            .fold(T::neg_infinity(), |accum, val| accum.max(val))
+    }
+
+    fn first_coord(&self) -> Option<Coord<T>> {
+        self.iter().find_map(|polygon| polygon.first_coord())
+    }
+
+    fn centroid_coord(&self) -> Option<Coord<T>> {
+        self.centroid().map(|centroid| centroid.0)
+    }
+}
+
+/// A square cell of the search.
+struct Cell<T: GeoFloat> {
+    center: Coord<T>,
+    /// Half of the side of the cell
+    half_size: T,
+    /// The signed distance from the center to the boundary
+    distance: T,
+    /// The largest distance to the boundary of any point in the cell
+    potential: T,
+}
+
+impl<T: GeoFloat> Cell<T> {
+    fn new<G: Inaccessibility<T>>(geometry: &G, center: Coord<T>, half_size: T) -> Self {
+        let distance = geometry.signed_distance(center);
+        Cell {
+            center,
+            half_size,
+            distance,
+            potential: distance + half_size * T::from(std::f64::consts::SQRT_2).unwrap(),
+        }
+    }
+}
+
+// These impls give us a max-heap of the most promising cells
+impl<T: GeoFloat> Ord for Cell<T> {
+    fn cmp(&self, other: &Cell<T>) -> Ordering {
+        self.potential.partial_cmp(&other.potential).unwrap()
+    }
+}
+
+impl<T: GeoFloat> PartialOrd for Cell<T> {
+    fn partial_cmp(&self, other: &Cell<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: GeoFloat> Eq for Cell<T> {}
+
+impl<T: GeoFloat> PartialEq for Cell<T> {
+    fn eq(&self, other: &Cell<T>) -> bool {
+        self.potential == other.potential
+    }
+}
+
+fn polylabel<T, G>(geometry: &G, tolerance: T) -> (Point<T>, T)
+where
+    T: GeoFloat,
+    G: Inaccessibility<T>,
+{
+    let (bounds, first) = match (geometry.bounding_rect(), geometry.first_coord()) {
+        (Some(bounds), Some(first)) => (bounds, first),
+        _ => return (Point::new(T::zero(), T::zero()), T::zero()),
+    };
+    let cell_size = bounds.width().min(bounds.height());
+    if cell_size == T::zero() {
+        return (first.into(), T::zero());
+    }
+    // a negative tolerance would split every cell down to the smallest size
+    let tolerance = tolerance.max(T::zero());
+    let two = T::one() + T::one();
+    // Cells smaller than this can't be told apart
+    let min_half_size = bounds.width().max(bounds.height()) * T::epsilon();
+
+    let half_size = cell_size / two;
+    let mut cells = BinaryHeap::new();
+    let mut x = bounds.min().x;
+    while x < bounds.max().x {
+        let mut y = bounds.min().y;
+        while y < bounds.max().y {
+            let center = Coord {
+                x: x + half_size,
+                y: y + half_size,
+            };
+            cells.push(Cell::new(geometry, center, half_size));
+            y = y + cell_size;
+        }
+        x = x + cell_size;
+    }
+
+    // Start from the centroid, which is often a good guess for simple shapes
+    let mut best = (bounds.center(), geometry.signed_distance(bounds.center()));
+    if let Some(centroid) = geometry.centroid_coord() {
+        let distance = geometry.signed_distance(centroid);
+        if distance > best.1 {
+            best = (centroid, distance);
+        }
+    }
+
+    while let Some(cell) = cells.pop() {
+        if cell.distance > best.1 {
+            best = (cell.center, cell.distance);
+        }
+        if cell.potential - best.1 <= tolerance || cell.half_size <= min_half_size {
+            continue;
+        }
+        let half_size = cell.half_size / two;
+        for (dx, dy) in [(-1, -1), (1, -1), (-1, 1), (1, 1)] {
+            let center = Coord {
+                x: cell.center.x + half_size * T::from(dx).unwrap(),
+                y: cell.center.y + half_size * T::from(dy).unwrap(),
+            };
+            cells.push(Cell::new(geometry, center, half_size));
+        }
+    }
+
+    (best.0.into(), best.1.max(T::zero()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{polygon, Contains, InteriorPoint, LineString};
+
+    #[test]
+    fn square() {
+        let square = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let (center, radius) = square.maximum_inscribed_circle(0.001);
+        assert_relative_eq!(center, Point::new(5., 5.), epsilon = 0.01);
+        assert_relative_eq!(radius, 5., epsilon = 0.001);
+    }
+
+    #[test]
+    fn large_hole() {
+        // a thick band above the hole, and thin ones elsewhere
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 1., y: 1.), (x: 9., y: 1.), (x: 9., y: 6.5), (x: 1., y: 6.5)]],
+        );
+        // the centroid is in the hole
+        let centroid = polygon.centroid().unwrap();
+        assert!(!polygon.contains(&centroid));
+
+        let tolerance = 0.001;
+        let (center, radius) = polygon.maximum_inscribed_circle(tolerance);
+        assert!(polygon.contains(&center));
+        assert_relative_eq!(radius, 1.75, epsilon = tolerance);
+        assert_relative_eq!(center.y(), 8.25, epsilon = 0.01);
+        assert_relative_eq!(polygon.signed_distance(center.0), radius);
+
+        // the interior point is inside the polygon, but in one of the thin bands
+        let interior_point = polygon.interior_point().unwrap();
+        assert!(polygon.contains(&interior_point));
+        assert_relative_eq!(polygon.signed_distance(interior_point.0), 0.5);
+    }
+
+    #[test]
+    fn crescent() {
+        let crescent = polygon![
+            (x: 0., y: 0.),
+            (x: 4., y: 2.),
+            (x: 6., y: 6.),
+            (x: 4., y: 10.),
+            (x: 0., y: 12.),
+            (x: 3., y: 8.),
+            (x: 3., y: 4.),
+        ];
+        let (center, radius) = crescent.maximum_inscribed_circle(0.001);
+        assert!(crescent.contains(&center));
+        assert!(radius > 0.);
+        // the centroid hugs the inner edge
+        let centroid = crescent.centroid().unwrap();
+        assert!(crescent.signed_distance(centroid.0) < radius / 2.);
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)],
+            polygon![(x: 10., y: 0.), (x: 16., y: 0.), (x: 16., y: 6.), (x: 10., y: 6.)],
+        ]);
+        let (center, radius) = multi_polygon.maximum_inscribed_circle(0.001);
+        assert_relative_eq!(center, Point::new(13., 3.), epsilon = 0.01);
+        assert_relative_eq!(radius, 3., epsilon = 0.001);
+    }
+
+    #[test]
+    fn degenerate() {
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert_eq!(
+            empty.maximum_inscribed_circle(0.1),
+            (Point::new(0., 0.), 0.)
+        );
+
+        let line = polygon![(x: 1., y: 1.), (x: 2., y: 2.), (x: 3., y: 3.)];
+        assert_eq!(line.maximum_inscribed_circle(0.1).1, 0.);
+        let point = polygon![(x: 1., y: 1.), (x: 1., y: 1.), (x: 1., y: 1.)];
+        assert_eq!(
+            point.maximum_inscribed_circle(0.1),
+            (Point::new(1., 1.), 0.)
+        );
+    }
+
+    #[test]
+    fn zero_tolerance_terminates() {
+        let triangle = polygon![(x: 0., y: 0.), (x: 3., y: 0.), (x: 0., y: 4.)];
+        let (_, radius) = triangle.maximum_inscribed_circle(0.);
+        // the inradius of a 3-4-5 triangle
+        assert_relative_eq!(radius, 1., epsilon = 1e-9);
+    }
+
+    #[test]
+    fn negative_tolerance() {
+        let triangle = polygon![(x: 0., y: 0.), (x: 3., y: 0.), (x: 0., y: 4.)];
+        assert_eq!(
+            triangle.maximum_inscribed_circle(-1.),
+            triangle.maximum_inscribed_circle(0.)
+        );
+    }
+}
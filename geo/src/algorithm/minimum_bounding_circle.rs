@@ -0,0 +1,241 @@
+use crate::kernels::{Kernel, Orientation};
+use crate::{Coord, CoordNum, CoordsIter, GeoFloat};
+
+/// Calculate the smallest circle which contains every coordinate of a geometry.
+///
+/// This uses [Welzl's algorithm] with the move-to-front heuristic, which takes expected linear
+/// time for most inputs.
+///
+/// Returns `None` for an empty geometry. The circle of a single coordinate has a radius of zero.
+///
+/// # Examples
+///
+/// ```
+/// use geo::minimum_bounding_circle::{Circle, MinimumBoundingCircle};
+/// use geo::{coord, polygon};
+///
+/// let triangle = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 2., y: 1.)];
+///
+/// let circle = triangle.minimum_bounding_circle().unwrap();
+///
+/// assert_eq!(circle, Circle { center: coord! { x: 2., y: 0. }, radius: 2. });
+/// ```
+///
+/// [Welzl's algorithm]: https://en.wikipedia.org/wiki/Smallest-circle_problem#Welzl's_algorithm
+pub trait MinimumBoundingCircle<'a, T: GeoFloat> {
+    fn minimum_bounding_circle(&'a self) -> Option<Circle<T>>;
+}
+
+/// A circle, as returned by [`MinimumBoundingCircle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Circle<T: CoordNum> {
+    pub center: Coord<T>,
+    pub radius: T,
+}
+
+impl<'a, T, G> MinimumBoundingCircle<'a, T> for G
+where
+    G: CoordsIter<'a, Scalar = T>,
+    T: GeoFloat,
+{
+    fn minimum_bounding_circle(&'a self) -> Option<Circle<T>> {
+        let mut coords: Vec<Coord<T>> = self.coords_iter().collect();
+        let mut circle = Circle::from_one(*coords.first()?);
+
+        for i in 1..coords.len() {
+            if circle.contains(coords[i]) {
+                continue;
+            }
+            // `coords[i]` must be on the boundary of the circle of `coords[..=i]`
+            circle = Circle::from_one(coords[i]);
+            for j in 0..i {
+                if circle.contains(coords[j]) {
+                    continue;
+                }
+                // ... and so must `coords[j]` be, for the circle of `coords[..=j]` and `coords[i]`
+                circle = Circle::from_two(coords[i], coords[j]);
+                for k in 0..j {
+                    if !circle.contains(coords[k]) {
+                        circle = Circle::from_three(coords[i], coords[j], coords[k]);
+                    }
+                }
+            }
+            // Coordinates which were outside of the circle are likely to be on the boundary of
+            // the final one, so checking them first avoids most of the recomputation.
+            coords[..=i].rotate_right(1);
+        }
+
+        Some(circle)
+    }
+}
+
+impl<T: GeoFloat> Circle<T> {
+    fn from_one(coord: Coord<T>) -> Self {
+        Circle {
+            center: coord,
+            radius: T::zero(),
+        }
+    }
+
+    /// The smallest circle through `a` and `b`.
+    fn from_two(a: Coord<T>, b: Coord<T>) -> Self {
+        let two = T::one() + T::one();
+        let center = (a + b) / two;
+        Circle {
+            center,
+            radius: distance(center, a).max(distance(center, b)),
+        }
+    }
+
+    /// The circle through `a`, `b`, and `c`, or the smallest circle containing them if they are
+    /// collinear.
+    fn from_three(a: Coord<T>, b: Coord<T>, c: Coord<T>) -> Self {
+        if T::Ker::orient2d(a, b, c) == Orientation::Collinear {
+            // the coordinates furthest apart span the other one
+            let (ab, bc, ca) = (distance(a, b), distance(b, c), distance(c, a));
+            return if ab >= bc && ab >= ca {
+                Circle::from_two(a, b)
+            } else if bc >= ca {
+                Circle::from_two(b, c)
+            } else {
+                Circle::from_two(c, a)
+            };
+        }
+
+        let two = T::one() + T::one();
+        let (b_offset, c_offset) = (b - a, c - a);
+        let b_squared = b_offset.x * b_offset.x + b_offset.y * b_offset.y;
+        let c_squared = c_offset.x * c_offset.x + c_offset.y * c_offset.y;
+        let denominator = two * (b_offset.x * c_offset.y - b_offset.y * c_offset.x);
+        let center = a + Coord {
+            x: (c_offset.y * b_squared - b_offset.y * c_squared) / denominator,
+            y: (b_offset.x * c_squared - c_offset.x * b_squared) / denominator,
+        };
+        Circle {
+            center,
+            radius: distance(center, a)
+                .max(distance(center, b))
+                .max(distance(center, c)),
+        }
+    }
+
+    /// Whether `coord` is in the circle, allowing for rounding errors in its computation.
+    fn contains(&self, coord: Coord<T>) -> bool {
+        let four = T::from(4).unwrap();
+        distance(self.center, coord) <= self.radius * (T::one() + four * T::epsilon())
+    }
+}
+
+fn distance<T: GeoFloat>(a: Coord<T>, b: Coord<T>) -> T {
+    let delta = b - a;
+    delta.x.hypot(delta.y)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point, polygon, MultiPoint, Point};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn empty() {
+        let points = MultiPoint::<f64>::new(vec![]);
+        assert_eq!(points.minimum_bounding_circle(), None);
+    }
+
+    #[test]
+    fn one_point() {
+        let circle = point!(x: 1., y: 2.).minimum_bounding_circle().unwrap();
+        assert_eq!(circle.center, coord! { x: 1., y: 2. });
+        assert_eq!(circle.radius, 0.);
+
+        // repeated points are the same as a single point
+        let points = MultiPoint::new(vec![point!(x: 1., y: 2.); 3]);
+        assert_eq!(points.minimum_bounding_circle(), Some(circle));
+    }
+
+    #[test]
+    fn two_points() {
+        let line = line_string![(x: 0., y: 0.), (x: 6., y: 8.)];
+        let circle = line.minimum_bounding_circle().unwrap();
+        assert_eq!(circle.center, coord! { x: 3., y: 4. });
+        assert_relative_eq!(circle.radius, 5.);
+    }
+
+    #[test]
+    fn collinear_points() {
+        let line = line_string![
+            (x: 2., y: 2.),
+            (x: 0., y: 0.),
+            (x: 3., y: 3.),
+            (x: -1., y: -1.),
+            (x: 1., y: 1.),
+        ];
+        let circle = line.minimum_bounding_circle().unwrap();
+        assert_relative_eq!(circle.center, coord! { x: 1., y: 1. });
+        assert_relative_eq!(circle.radius, 8f64.sqrt());
+    }
+
+    #[test]
+    fn triangles() {
+        // an acute triangle is bounded by its circumcircle
+        let acute = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 2., y: 3.)];
+        let circle = acute.minimum_bounding_circle().unwrap();
+        assert_relative_eq!(circle.center, coord! { x: 2., y: 5. / 6. });
+        assert_relative_eq!(circle.radius, 13. / 6.);
+
+        // but an obtuse one by the circle through the ends of its longest side
+        let obtuse = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 1., y: 1.)];
+        let circle = obtuse.minimum_bounding_circle().unwrap();
+        assert_relative_eq!(circle.center, coord! { x: 2., y: 0. });
+        assert_relative_eq!(circle.radius, 2.);
+    }
+
+    #[test]
+    fn square() {
+        let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let circle = square.minimum_bounding_circle().unwrap();
+        assert_relative_eq!(circle.center, coord! { x: 1., y: 1. });
+        assert_relative_eq!(circle.radius, 2f64.sqrt());
+    }
+
+    #[test]
+    fn random_points_are_contained() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut next = move || rng.gen::<f64>();
+
+        for count in 1..40 {
+            let points: Vec<Point> = (0..count)
+                .map(|_| point!(x: next() * 100., y: next() * 50.))
+                .collect();
+            let points = MultiPoint::new(points);
+            let circle = points.minimum_bounding_circle().unwrap();
+            for point in &points {
+                assert!(distance(circle.center, point.0) <= circle.radius + 1e-9);
+            }
+
+            // no circle through two or three of the points, which contains all of them, is smaller
+            let coords: Vec<_> = points.coords_iter().collect();
+            let contains_all = |c: &Circle<f64>| {
+                coords
+                    .iter()
+                    .all(|&p| distance(c.center, p) <= c.radius + 1e-9)
+            };
+            for (i, &a) in coords.iter().enumerate() {
+                for (j, &b) in coords.iter().enumerate().skip(i + 1) {
+                    let candidate = Circle::from_two(a, b);
+                    if contains_all(&candidate) {
+                        assert!(circle.radius <= candidate.radius + 1e-9);
+                    }
+                    for &c in &coords[j + 1..] {
+                        let candidate = Circle::from_three(a, b, c);
+                        if contains_all(&candidate) {
+                            assert!(circle.radius <= candidate.radius + 1e-9);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{
+    Closest, ClosestPoint, Coord, CoordsIter, EuclideanDistance, EuclideanLength, GeoFloat, Line,
+    LineString, MultiPolygon, Polygon,
+};
+
+/// Find the minimum clearance of a geometry, like [JTS's `MinimumClearance`][jts]: the smallest
+/// distance by which moving a vertex can make the geometry invalid, or collapse a part of it.
+///
+/// This is the smallest distance from a vertex to a segment it isn't an end of, or between two
+/// distinct vertices, such as the ends of the shortest segment. Vertices at exactly the same
+/// coordinates, like the ends of a closed ring, are ignored. Geometries whose clearance is
+/// small, relative to their precision, are liable to become invalid when their coordinates are
+/// rounded or transformed.
+///
+/// Geometries with fewer than three coordinates have no clearance, and return `None`.
+///
+/// [jts]: https://locationtech.github.io/jts/javadoc/org/locationtech/jts/precision/MinimumClearance.html
+///
+/// # Examples
+///
+/// ```
+/// use geo::{coord, polygon, Line, MinimumClearance};
+///
+/// // a square, with a notch cut almost through it
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 10.),
+///     (x: 5.6, y: 10.),
+///     (x: 5., y: 1.),
+///     (x: 4.4, y: 10.),
+///     (x: 0., y: 10.),
+/// ];
+///
+/// assert_eq!(polygon.minimum_clearance(), Some(1.));
+/// assert_eq!(
+///     polygon.minimum_clearance_line(),
+///     Some(Line::new(coord! { x: 5., y: 1. }, coord! { x: 5., y: 0. }))
+/// );
+/// ```
+pub trait MinimumClearance<T: GeoFloat> {
+    /// The minimum clearance of `self`, or `None` if it has fewer than three coordinates.
+    fn minimum_clearance(&self) -> Option<T>;
+
+    /// The line realizing the minimum clearance of `self`, from a vertex to the nearest point
+    /// of a segment or vertex, or `None` if it has fewer than three coordinates.
+    fn minimum_clearance_line(&self) -> Option<Line<T>>;
+}
+
+impl<T: GeoFloat> MinimumClearance<T> for LineString<T> {
+    fn minimum_clearance(&self) -> Option<T> {
+        self.minimum_clearance_line()
+            .map(|line| line.euclidean_length())
+    }
+
+    fn minimum_clearance_line(&self) -> Option<Line<T>> {
+        if self.coords_count() < 3 {
+            return None;
+        }
+        minimum_clearance_line(self.lines().collect())
+    }
+}
+
+impl<T: GeoFloat> MinimumClearance<T> for Polygon<T> {
+    fn minimum_clearance(&self) -> Option<T> {
+        self.minimum_clearance_line()
+            .map(|line| line.euclidean_length())
+    }
+
+    fn minimum_clearance_line(&self) -> Option<Line<T>> {
+        if self.coords_count() < 3 {
+            return None;
+        }
+        minimum_clearance_line(rings(self).flat_map(|ring| ring.lines()).collect())
+    }
+}
+
+impl<T: GeoFloat> MinimumClearance<T> for MultiPolygon<T> {
+    fn minimum_clearance(&self) -> Option<T> {
+        self.minimum_clearance_line()
+            .map(|line| line.euclidean_length())
+    }
+
+    fn minimum_clearance_line(&self) -> Option<Line<T>> {
+        if self.coords_count() < 3 {
+            return None;
+        }
+        minimum_clearance_line(
+            self.iter()
+                .flat_map(rings)
+                .flat_map(|ring| ring.lines())
+                .collect(),
+        )
+    }
+}
+
+fn rings<T: GeoFloat>(polygon: &Polygon<T>) -> impl Iterator<Item = &LineString<T>> {
+    std::iter::once(polygon.exterior()).chain(polygon.interiors())
+}
+
+/// The shortest line from a vertex of `segments` to a segment it isn't an end of, or to another
+/// vertex.
+fn minimum_clearance_line<T: GeoFloat>(mut segments: Vec<Line<T>>) -> Option<Line<T>> {
+    let min_x = |line: &Line<T>| line.start.x.min(line.end.x);
+    let max_x = |line: &Line<T>| line.start.x.max(line.end.x);
+    segments.sort_by(|a, b| min_x(a).partial_cmp(&min_x(b)).unwrap());
+    let vertices: Vec<Coord<T>> = segments.iter().flat_map(|s| [s.start, s.end]).collect();
+
+    let mut best: Option<(T, Line<T>)> = None;
+    for &vertex in &vertices {
+        for segment in &segments {
+            let bound = best.map_or(T::infinity(), |(distance, _)| distance);
+            // the segments are sorted by their left ends, so none further on can be closer
+            if min_x(segment) > vertex.x + bound {
+                break;
+            }
+            if max_x(segment) < vertex.x - bound
+                || segment.start.y.min(segment.end.y) > vertex.y + bound
+                || segment.start.y.max(segment.end.y) < vertex.y - bound
+            {
+                continue;
+            }
+
+            let nearest = if vertex == segment.start || vertex == segment.end {
+                // the other end, unless the segment has no length
+                let other = if vertex == segment.start {
+                    segment.end
+                } else {
+                    segment.start
+                };
+                if other == vertex {
+                    continue;
+                }
+                other
+            } else {
+                if vertex.euclidean_distance(segment) >= bound {
+                    continue;
+                }
+                match segment.closest_point(&vertex.into()) {
+                    Closest::Intersection(point) | Closest::SinglePoint(point) => point.0,
+                    Closest::Indeterminate => continue,
+                }
+            };
+            let distance = vertex.euclidean_distance(&nearest);
+            if distance < bound {
+                best = Some((distance, Line::new(vertex, nearest)));
+            }
+        }
+    }
+    best.map(|(_, line)| line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coord, line_string, polygon};
+    use approx::assert_relative_eq;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn too_few_coords() {
+        assert_eq!(line_string![].minimum_clearance(), None::<f64>);
+        assert_eq!(
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.)].minimum_clearance(),
+            None
+        );
+        assert_eq!(
+            Polygon::<f64>::new(line_string![], vec![]).minimum_clearance(),
+            None
+        );
+    }
+
+    #[test]
+    fn line_string_clearance() {
+        // the last vertex is nearer the first segment than the shortest segment is long
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 2.),
+            (x: 4., y: 0.5),
+        ];
+        assert_eq!(line_string.minimum_clearance(), Some(0.5));
+        assert_eq!(
+            line_string.minimum_clearance_line(),
+            Some(Line::new(coord! { x: 4., y: 0.5 }, coord! { x: 4., y: 0. }))
+        );
+
+        // the shortest segment
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 0.25)];
+        assert_eq!(line_string.minimum_clearance(), Some(0.25));
+    }
+
+    #[test]
+    fn repeated_vertices() {
+        // a closed ring, with a repeated vertex
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 0.),
+            (x: 4., y: 3.),
+            (x: 0., y: 0.),
+        ];
+        assert_relative_eq!(line_string.minimum_clearance().unwrap(), 2.4);
+    }
+
+    #[test]
+    fn polygon_with_hole() {
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 9.75, y: 5.), (x: 2., y: 8.)]],
+        ];
+        assert_eq!(polygon.minimum_clearance(), Some(0.25));
+        assert_eq!(
+            polygon.minimum_clearance_line(),
+            Some(Line::new(
+                coord! { x: 9.75, y: 5. },
+                coord! { x: 10., y: 5. }
+            ))
+        );
+    }
+
+    #[test]
+    fn multi_polygon() {
+        // a square, and a triangle pointing at it
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            polygon![(x: 10.5, y: 3.), (x: 15., y: -2.), (x: 15., y: 8.)],
+        ]);
+        assert_eq!(multi_polygon.minimum_clearance(), Some(0.5));
+        assert_eq!(
+            multi_polygon.minimum_clearance_line(),
+            Some(Line::new(
+                coord! { x: 10.5, y: 3. },
+                coord! { x: 10., y: 3. }
+            ))
+        );
+    }
+
+    #[test]
+    fn pruning_matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(9);
+        let mut random = move || rng.gen::<f64>() * 100.;
+        for _ in 0..50 {
+            let line_string = LineString::from_iter((0..30).map(|_| (random(), random())));
+            let lines: Vec<Line<f64>> = line_string.lines().collect();
+            let mut expected = f64::INFINITY;
+            for vertex in line_string.coords() {
+                for line in &lines {
+                    let distance = if vertex == &line.start {
+                        vertex.euclidean_distance(&line.end)
+                    } else if vertex == &line.end {
+                        vertex.euclidean_distance(&line.start)
+                    } else {
+                        vertex.euclidean_distance(line)
+                    };
+                    expected = expected.min(distance);
+                }
+            }
+            assert_relative_eq!(
+                line_string.minimum_clearance().unwrap(),
+                expected,
+                epsilon = 1e-9
+            );
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::{ConvexHull, Coord, GeoFloat, LineString, Polygon};
+
+/// Return the minimum bounding rectangle of a geometry, i.e. the rectangle of smallest area which
+/// contains it, allowing any orientation.
+///
+/// One side of this rectangle is always collinear with an edge of the [convex
+/// hull](ConvexHull), so the [rotating calipers] method is used to check the rectangle of
+/// each edge in turn, which takes linear time in the number of vertices of the hull.
+///
+/// The rectangle is a `Polygon` whose exterior is a closed, counter-clockwise ring of five
+/// coordinates. For a geometry with no area, it is degenerate: a single point, or a line
+/// segment traced back and forth. An empty geometry has no rectangle.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_relative_eq;
+/// use geo::{line_string, Area, BoundingRect, MinimumRotatedRect};
+///
+/// // a diagonal strip
+/// let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 10.), (x: 11., y: 9.)];
+///
+/// let rect = line_string.minimum_rotated_rect().unwrap();
+///
+/// assert_eq!(rect.exterior().0.len(), 5);
+/// assert_relative_eq!(rect.unsigned_area(), 20., epsilon = 1e-9);
+/// assert_eq!(line_string.bounding_rect().unwrap().unsigned_area(), 110.);
+/// ```
+///
+/// [rotating calipers]: https://en.wikipedia.org/wiki/Rotating_calipers
+pub trait MinimumRotatedRect<'a, T> {
+    type Scalar: GeoFloat;
+    fn minimum_rotated_rect(&'a self) -> Option<Polygon<Self::Scalar>>;
+}
+
+impl<'a, T, G> MinimumRotatedRect<'a, T> for G
+where
+    T: GeoFloat,
+    G: ConvexHull<'a, T, Scalar = T>,
+{
+    type Scalar = T;
+
+    fn minimum_rotated_rect(&'a self) -> Option<Polygon<T>> {
+        let hull = self.convex_hull();
+        let mut coords = hull.exterior().0.clone();
+        coords.dedup();
+        if coords.len() > 1 && coords.first() == coords.last() {
+            coords.pop();
+        }
+
+        let dot = |a: Coord<T>, b: Coord<T>| a.x * b.x + a.y * b.y;
+        let n = coords.len();
+        match n {
+            0 => return None,
+            1 => return Some(Polygon::new(LineString::new(vec![coords[0]; 5]), vec![])),
+            _ => {}
+        }
+
+        let mut best: Option<(T, [Coord<T>; 4])> = None;
+        // The vertices furthest along, above, and behind the current edge, which only ever move
+        // forwards around the (counter-clockwise) hull
+        let (mut ahead, mut above, mut behind) = (1, 1, 1);
+        for i in 0..n {
+            let origin = coords[i];
+            let edge = coords[(i + 1) % n] - origin;
+            let length = edge.x.hypot(edge.y);
+            let u = edge / length;
+            // the hull is on the left of each of its edges
+            let v = Coord { x: -u.y, y: u.x };
+
+            let along = |j: usize| dot(coords[j % n] - origin, u);
+            let across = |j: usize| dot(coords[j % n] - origin, v);
+            if i == 0 {
+                ahead = 1;
+            }
+            while along(ahead + 1) > along(ahead) {
+                ahead = (ahead + 1) % n;
+            }
+            if i == 0 {
+                above = ahead;
+            }
+            while across(above + 1) > across(above) {
+                above = (above + 1) % n;
+            }
+            if i == 0 {
+                behind = above;
+            }
+            while along(behind + 1) < along(behind) {
+                behind = (behind + 1) % n;
+            }
+
+            let (min_u, max_u, max_v) = (along(behind), along(ahead), across(above));
+            let area = (max_u - min_u) * max_v;
+            if best.map_or(true, |(best_area, _)| area < best_area) {
+                let corners = [
+                    origin + u * min_u,
+                    origin + u * max_u,
+                    origin + u * max_u + v * max_v,
+                    origin + u * min_u + v * max_v,
+                ];
+                best = Some((area, corners));
+            }
+        }
+
+        let (_, corners) = best?;
+        // A degenerate rectangle may already look closed, so close it explicitly
+        let mut exterior = corners.to_vec();
+        exterior.push(corners[0]);
+        Some(Polygon::new(LineString::new(exterior), vec![]))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        line_string, point, polygon, Area, BoundingRect, CoordsIter, EuclideanDistance, MultiPoint,
+        Point,
+    };
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn assert_contains_all<'a, G>(rect: &Polygon, geometry: &'a G)
+    where
+        G: CoordsIter<'a, Scalar = f64>,
+    {
+        for coord in geometry.coords_iter() {
+            assert!(Point::from(coord).euclidean_distance(rect) < 1e-9);
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let empty = MultiPoint::<f64>::new(vec![]);
+        assert_eq!(empty.minimum_rotated_rect(), None);
+    }
+
+    #[test]
+    fn degenerate() {
+        let rect = point!(x: 1., y: 2.).minimum_rotated_rect().unwrap();
+        assert_eq!(rect.exterior().0, vec![point!(x: 1., y: 2.).0; 5]);
+
+        let line = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 3., y: 3.)];
+        let rect = line.minimum_rotated_rect().unwrap();
+        assert_eq!(rect.exterior().0.len(), 5);
+        assert_relative_eq!(rect.unsigned_area(), 0.);
+        assert_contains_all(&rect, &line);
+    }
+
+    #[test]
+    fn rotated_rectangle() {
+        let rectangle = polygon![(x: 0., y: 0.), (x: 4., y: 4.), (x: 3., y: 5.), (x: -1., y: 1.)];
+        let rect = rectangle.minimum_rotated_rect().unwrap();
+        assert_eq!(rect.exterior().0.len(), 5);
+        assert!(rect.exterior().is_closed());
+        assert_relative_eq!(
+            rect.unsigned_area(),
+            rectangle.unsigned_area(),
+            epsilon = 1e-9
+        );
+        assert!(rect.signed_area() > 0.);
+        for coord in rect.exterior().coords_iter() {
+            assert!(rectangle
+                .exterior()
+                .0
+                .iter()
+                .any(|c| { Point::from(*c).euclidean_distance(&Point::from(coord)) < 1e-9 }));
+        }
+    }
+
+    #[test]
+    fn axis_aligned() {
+        let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+        let rect = square.minimum_rotated_rect().unwrap();
+        assert_relative_eq!(rect.unsigned_area(), 4.);
+        assert_contains_all(&rect, &square);
+    }
+
+    #[test]
+    fn random_points() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut next = move || rng.gen::<f64>();
+
+        for count in 1..50 {
+            // points in a rotated, elongated strip
+            let points: MultiPoint = (0..count)
+                .map(|_| {
+                    let (along, across) = (next() * 100., next() * 10.);
+                    point!(x: along * 0.8 - across * 0.6, y: along * 0.6 + across * 0.8)
+                })
+                .collect();
+            let rect = points.minimum_rotated_rect().unwrap();
+            assert_contains_all(&rect, &points);
+            let bounding_rect = points.bounding_rect().unwrap();
+            assert!(rect.unsigned_area() <= bounding_rect.unsigned_area() + 1e-9);
+            assert!(rect.unsigned_area() <= 1000. + 1e-9);
+        }
+    }
+}
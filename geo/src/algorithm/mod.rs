@@ -12,15 +12,19 @@ pub use bearing::Bearing;
 
 /// Boolean Ops such as union, xor, difference;
 pub mod bool_ops;
-pub use bool_ops::{BooleanOps, OpType};
+pub use bool_ops::{unary_union, BooleanOps, BooleanOpsError, GeometryBooleanOps, OpType};
 
 /// Calculate the bounding rectangle of a `Geometry`.
 pub mod bounding_rect;
-pub use bounding_rect::BoundingRect;
+pub use bounding_rect::{BoundingRect, BoundingRectAccumulator};
+
+/// Grow or shrink a `Geometry` by a distance.
+pub mod buffer;
+pub use buffer::{Buffer, BufferStyle, CapStyle, JoinStyle};
 
 /// Calculate the centroid of a `Geometry`.
 pub mod centroid;
-pub use centroid::Centroid;
+pub use centroid::{Centroid, WeightedCentroid};
 
 /// Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` using Chaikins algorithm.
 pub mod chaikin_smoothing;
@@ -30,10 +34,17 @@ pub use chaikin_smoothing::ChaikinSmoothing;
 pub mod chamberlain_duquette_area;
 pub use chamberlain_duquette_area::ChamberlainDuquetteArea;
 
+/// Clip a `Geometry` to an axis-aligned `Rect`.
+pub mod clip;
+pub use clip::RectClip;
+
 /// Calculate the closest `Point` between a `Geometry` and an input `Point`.
 pub mod closest_point;
 pub use closest_point::ClosestPoint;
 
+/// Divide a group of points into clusters, using k-means or DBSCAN.
+pub mod clustering;
+
 /// Calculate the concave hull of a `Geometry`.
 pub mod concave_hull;
 pub use concave_hull::ConcaveHull;
@@ -42,13 +53,25 @@ pub use concave_hull::ConcaveHull;
 pub mod contains;
 pub use contains::Contains;
 
+/// Determine whether `Geometry` `B` lies in the interior of `Geometry` `A`, not touching its boundary.
+pub mod contains_properly;
+pub use contains_properly::ContainsProperly;
+
+/// Determine whether `Geometry` `A` covers `Geometry` `B`, with no point of `B` outside `A`.
+pub mod covers;
+pub use covers::Covers;
+
+/// Determine whether `Geometry` `A` is covered by `Geometry` `B`.
+pub mod covered_by;
+pub use covered_by::CoveredBy;
+
 /// Convert the type of a geometry’s coordinate value.
 pub mod convert;
 pub use convert::{Convert, TryConvert};
 
 /// Calculate the convex hull of a `Geometry`.
 pub mod convex_hull;
-pub use convex_hull::ConvexHull;
+pub use convex_hull::{ConvexHull, ConvexHullBuilder, ConvexHullIndices};
 
 /// Determine whether a `Coord` lies inside, outside, or on the boundary of a geometry.
 pub mod coordinate_position;
@@ -58,17 +81,33 @@ pub use coordinate_position::CoordinatePosition;
 pub mod coords_iter;
 pub use coords_iter::CoordsIter;
 
+/// Determine whether a geometry crosses another, their interiors meeting in fewer dimensions.
+pub mod crosses;
+pub use crosses::Crosses;
+
 /// Densify linear geometry components
 pub mod densify;
 pub use densify::Densify;
 
+/// Densify linear geometry components along geodesics on an ellipsoidal model of the earth
+pub mod densify_geodesic;
+pub use densify_geodesic::DensifyGeodesic;
+
+/// Densify linear geometry components along great circles
+pub mod densify_haversine;
+pub use densify_haversine::DensifyHaversine;
+
 /// Dimensionality of a geometry and its boundary, based on OGC-SFA.
 pub mod dimensions;
 pub use dimensions::HasDimensions;
 
+/// Determine whether two geometries are topologically equal: they cover the same points.
+pub mod equals;
+pub use equals::Equals;
+
 /// Calculate the minimum Euclidean distance between two `Geometries`.
 pub mod euclidean_distance;
-pub use euclidean_distance::EuclideanDistance;
+pub use euclidean_distance::{EuclideanDistance, SignedEuclideanDistance};
 
 /// Calculate the length of a planar line between two `Geometries`.
 pub mod euclidean_length;
@@ -82,6 +121,18 @@ pub use extremes::Extremes;
 pub mod frechet_distance;
 pub use frechet_distance::FrechetDistance;
 
+/// Calculate the initial bearing of the geodesic between two `Point`s on an ellipsoidal model of the earth.
+pub mod geodesic_bearing;
+pub use geodesic_bearing::GeodesicBearing;
+
+/// Calculate the geodesic area and perimeter of a `Geometry` on an ellipsoidal model of the earth.
+pub mod geodesic_area;
+pub use geodesic_area::GeodesicArea;
+
+/// Calculate a destination `Point`, given a distance and a bearing, on an ellipsoidal model of the earth.
+pub mod geodesic_destination;
+pub use geodesic_destination::GeodesicDestination;
+
 /// Calculate the Geodesic distance between two `Point`s.
 pub mod geodesic_distance;
 pub use geodesic_distance::GeodesicDistance;
@@ -94,10 +145,26 @@ pub use geodesic_intermediate::GeodesicIntermediate;
 pub mod geodesic_length;
 pub use geodesic_length::GeodesicLength;
 
+/// Calculate the geometric median or medoid of a group of points, as centres robust to outliers.
+pub mod geometric_median;
+pub use geometric_median::GeometricMedian;
+
+/// Calculate the Hausdorff distance between two `Geometries`.
+pub mod hausdorff_distance;
+pub use hausdorff_distance::HausdorffDistance;
+
+/// Calculate the closest `Point` on a geometry to a `Point`, using the haversine formula.
+pub mod haversine_closest_point;
+pub use haversine_closest_point::HaversineClosestPoint;
+
 /// Calculate a destination `Point`, given a distance and a bearing.
 pub mod haversine_destination;
 pub use haversine_destination::HaversineDestination;
 
+/// Calculate the cross-track and along-track distances of a `Point` from a path, on a sphere.
+pub mod haversine_track_distance;
+pub use haversine_track_distance::HaversineTrackDistance;
+
 /// Calculate the Haversine distance between two `Geometries`.
 pub mod haversine_distance;
 pub use haversine_distance::HaversineDistance;
@@ -128,30 +195,98 @@ pub use k_nearest_concave_hull::KNearestConcaveHull;
 
 /// Interpolate a point along a `Line` or `LineString`.
 pub mod line_interpolate_point;
-pub use line_interpolate_point::LineInterpolatePoint;
+pub use line_interpolate_point::{LineInterpolatePoint, LineInterpolatePoints};
 
 /// Computes the intersection of two Lines.
 pub mod line_intersection;
-pub use line_intersection::LineIntersection;
+pub use line_intersection::{LineIntersection, SegmentIntersection};
 
 /// Locate a point along a `Line` or `LineString`.
 pub mod line_locate_point;
-pub use line_locate_point::LineLocatePoint;
+pub use line_locate_point::{LineLocatePoint, LineLocatePointFull, LineLocation};
+
+/// Merge `LineString`s which meet end to end into maximal paths.
+pub mod line_merge;
+pub use line_merge::LineMerge;
+
+/// Split a `LineString` into pieces of equal length.
+pub mod line_segmentize;
+pub use line_segmentize::{HaversineLineStringSegmentize, LineStringSegmentize};
+
+/// Split a `LineString` at fractions of its length, or at the locations nearest to `Point`s.
+pub mod line_split;
+pub use line_split::LineSplit;
+
+/// Extract the part of a `LineString` between two fractions of its length.
+pub mod line_substring;
+pub use line_substring::{HaversineLineSubstring, LineSubstring};
 
 /// Iterate over the lines in a geometry.
 pub mod lines_iter;
 pub use lines_iter::LinesIter;
 
+/// Find the longest line, and greatest distance, between two geometries.
+pub mod longest_line;
+pub use longest_line::LongestLine;
+
+/// Repair an invalid `Polygon` or `MultiPolygon`.
+pub mod make_valid;
+pub use make_valid::MakeValid;
+
 /// Apply a function to all `Coord`s of a `Geometry`.
 pub mod map_coords;
-pub use map_coords::{MapCoords, MapCoordsInPlace};
+pub use map_coords::{MapCoords, MapCoordsError, MapCoordsInPlace, TryMapCoordsWithPosition};
 #[allow(deprecated)]
 pub use map_coords::{MapCoordsInplace, TryMapCoords, TryMapCoordsInplace};
 
+/// Calculate the largest circle inscribed in a `Polygon` or `MultiPolygon`.
+pub mod maximum_inscribed_circle;
+pub use maximum_inscribed_circle::MaximumInscribedCircle;
+
+/// Calculate the smallest circle containing a `Geometry`.
+pub mod minimum_bounding_circle;
+pub use minimum_bounding_circle::MinimumBoundingCircle;
+
+/// Calculate the minimum clearance of a geometry, the smallest move of a vertex which can make it invalid.
+pub mod minimum_clearance;
+pub use minimum_clearance::MinimumClearance;
+
+/// Calculate the minimum rotated rectangle of a `Geometry`.
+pub mod minimum_rotated_rect;
+pub use minimum_rotated_rect::MinimumRotatedRect;
+
+/// Find the geometries of a collection nearest to a `Point`.
+pub mod nearest_neighbor;
+pub use nearest_neighbor::{HaversineNearestNeighbor, NearestNeighbor};
+
+/// Find the nearest points of two geometries.
+pub mod nearest_points;
+pub use nearest_points::NearestPoints;
+
+/// Split a set of lines wherever they meet.
+pub mod node_lines;
+pub use node_lines::node_lines;
+
 /// Orient a `Polygon`'s exterior and interior rings.
 pub mod orient;
 pub use orient::Orient;
 
+/// Determine whether two geometries of the same dimension partly overlap.
+pub mod overlaps;
+pub use overlaps::Overlaps;
+
+/// Split polygons into pieces along lines.
+pub mod polygon_split;
+pub use polygon_split::PolygonSplit;
+
+/// Build the polygons enclosed by a set of lines.
+pub mod polygonize;
+pub use polygonize::{polygonize, Polygonized};
+
+/// Index a `Geometry` once, to speed up testing many other geometries against it.
+pub mod prepared;
+pub use prepared::PreparedGeometry;
+
 /// Coordinate projections and transformations using the current stable version of [PROJ](http://proj.org).
 #[cfg(feature = "use-proj")]
 pub mod proj;
@@ -164,6 +299,22 @@ pub use relate::Relate;
 pub mod remove_repeated_points;
 pub use remove_repeated_points::RemoveRepeatedPoints;
 
+/// Calculate the constant bearing of the rhumb line between two `Point`s.
+pub mod rhumb_bearing;
+pub use rhumb_bearing::RhumbBearing;
+
+/// Calculate a destination `Point`, given a distance and a constant bearing along a rhumb line.
+pub mod rhumb_destination;
+pub use rhumb_destination::RhumbDestination;
+
+/// Calculate a new `Point` lying on a rhumb line between two `Point`s.
+pub mod rhumb_intermediate;
+pub use rhumb_intermediate::RhumbIntermediate;
+
+/// Calculate the distance between two `Point`s along a rhumb line.
+pub mod rhumb_distance;
+pub use rhumb_distance::RhumbDistance;
+
 /// Rotate a `Geometry` by an angle given in degrees.
 pub mod rotate;
 pub use rotate::Rotate;
@@ -171,6 +322,10 @@ pub use rotate::Rotate;
 #[allow(deprecated)]
 pub use rotate::RotatePoint;
 
+/// Find the paths shared by two `LineString`s.
+pub mod shared_paths;
+pub use shared_paths::SharedPaths;
+
 /// Scale a `Geometry` up or down by a factor
 pub mod scale;
 pub use scale::Scale;
@@ -181,26 +336,54 @@ pub use skew::Skew;
 
 /// Composable affine operations such as rotate, scale, skew, and translate
 pub mod affine_ops;
-pub use affine_ops::{AffineOps, AffineTransform};
+pub use affine_ops::{AffineDecomposition, AffineOps, AffineTransform};
 
 /// Simplify `Geometries` using the Ramer-Douglas-Peucker algorithm.
 pub mod simplify;
-pub use simplify::{Simplify, SimplifyIdx};
+pub use simplify::{Simplify, SimplifyIdx, SimplifyPreserve, SimplifyWithPins};
 
 /// Simplify `Geometries` using the Visvalingam-Whyatt algorithm. Includes a topology-preserving variant.
 pub mod simplify_vw;
 pub use simplify_vw::{SimplifyVw, SimplifyVwIdx, SimplifyVwPreserve};
 
+/// Snap the vertices of a geometry to those of another.
+pub mod snap;
+pub use snap::{snap, Snap};
+
+/// Reduce the precision of a geometry, by rounding its coordinates to a grid.
+pub mod snap_to_grid;
+pub use snap_to_grid::SnapToGrid;
+
+/// Build a spatial index of `Geometries`, to find those near a `Point` or in a `Rect`.
+pub mod spatial_index;
+pub use spatial_index::SpatialIndex;
+
 /// Transform a geometry using PROJ.
 #[cfg(feature = "use-proj")]
 pub mod transform;
 #[cfg(feature = "use-proj")]
 pub use transform::Transform;
 
+/// Determine whether two geometries touch, meeting only at their boundaries.
+pub mod touches;
+pub use touches::Touches;
+
 /// Translate a `Geometry` along the given offsets.
 pub mod translate;
 pub use translate::Translate;
 
+/// Triangulate a set of points using the Delaunay triangulation.
+pub mod triangulate_delaunay;
+pub use triangulate_delaunay::TriangulateDelaunay;
+
+/// Triangulate polygons using an ear clipping algorithm.
+pub mod triangulate_earcut;
+pub use triangulate_earcut::TriangulateEarcut;
+
+/// Check whether a geometry is valid, and why not.
+pub mod validation;
+pub use validation::{InvalidityReason, IsValid};
+
 /// Calculate the Vincenty distance between two `Point`s.
 pub mod vincenty_distance;
 pub use vincenty_distance::VincentyDistance;
@@ -209,6 +392,10 @@ pub use vincenty_distance::VincentyDistance;
 pub mod vincenty_length;
 pub use vincenty_length::VincentyLength;
 
+/// Calculate the Voronoi diagram of a set of points.
+pub mod voronoi;
+pub use voronoi::{Voronoi, VoronoiError};
+
 /// Calculate and work with the winding order of `Linestring`s.
 pub mod winding_order;
 pub use winding_order::Winding;
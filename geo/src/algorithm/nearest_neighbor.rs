@@ -0,0 +1,231 @@
+use std::cmp::Ordering;
+
+use num_traits::FromPrimitive;
+
+use crate::{
+    EuclideanDistance, GeoFloat, HaversineDistance, LineString, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon,
+};
+
+/// Find the geometries of a collection nearest to a `Point`, by Euclidean distance.
+///
+/// Ties are broken by the order of the collection, the first geometry being the nearest.
+/// Each query scans the whole collection once, so to find the neighbours of many points among
+/// many geometries, an [R*-tree](rstar::RTree) may be faster.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, NearestNeighbor};
+///
+/// let roads = vec![
+///     line_string![(x: 0., y: 0.), (x: 10., y: 0.)],
+///     line_string![(x: 0., y: 5.), (x: 10., y: 5.)],
+///     line_string![(x: 0., y: 20.), (x: 10., y: 20.)],
+/// ];
+/// let query = point!(x: 5., y: 4.);
+///
+/// assert_eq!(roads.nearest(&query), Some(&roads[1]));
+/// assert_eq!(roads.k_nearest(&query, 2), vec![&roads[1], &roads[0]]);
+/// ```
+pub trait NearestNeighbor<T: GeoFloat, G> {
+    /// The nearest geometry to `query`, or `None` if there are no geometries.
+    fn nearest(&self, query: &Point<T>) -> Option<&G>;
+
+    /// The `k` nearest geometries to `query`, nearest first, or all of them if there are fewer
+    /// than `k`.
+    fn k_nearest(&self, query: &Point<T>, k: usize) -> Vec<&G>;
+}
+
+impl<T, G> NearestNeighbor<T, G> for [G]
+where
+    T: GeoFloat,
+    G: EuclideanDistance<T, Point<T>>,
+{
+    fn nearest(&self, query: &Point<T>) -> Option<&G> {
+        nearest_by(self, |geometry| geometry.euclidean_distance(query))
+    }
+
+    fn k_nearest(&self, query: &Point<T>, k: usize) -> Vec<&G> {
+        k_nearest_by(self, k, |geometry| geometry.euclidean_distance(query))
+    }
+}
+
+macro_rules! nearest_neighbor_impl {
+    ($multi:ident, $single:ident) => {
+        impl<T: GeoFloat> NearestNeighbor<T, $single<T>> for $multi<T> {
+            fn nearest(&self, query: &Point<T>) -> Option<&$single<T>> {
+                self.0.nearest(query)
+            }
+
+            fn k_nearest(&self, query: &Point<T>, k: usize) -> Vec<&$single<T>> {
+                self.0.k_nearest(query, k)
+            }
+        }
+    };
+}
+
+nearest_neighbor_impl!(MultiPoint, Point);
+nearest_neighbor_impl!(MultiLineString, LineString);
+nearest_neighbor_impl!(MultiPolygon, Polygon);
+
+/// Find the longitude/latitude points of a collection nearest to a `Point`, by
+/// [haversine](HaversineDistance) distance.
+///
+/// This is otherwise the same as [`NearestNeighbor`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, HaversineNearestNeighbor, NearestNeighbor};
+///
+/// let stations = vec![point!(x: 20., y: 80.), point!(x: 0., y: 75.)];
+/// let query = point!(x: 0., y: 80.);
+///
+/// // 20 degrees of longitude are shorter than 5 of latitude, this far north
+/// assert_eq!(stations.nearest_haversine(&query), Some(&stations[0]));
+/// assert_eq!(stations.nearest(&query), Some(&stations[1]));
+/// ```
+pub trait HaversineNearestNeighbor<T: GeoFloat> {
+    /// The nearest point to `query`, or `None` if there are no points.
+    fn nearest_haversine(&self, query: &Point<T>) -> Option<&Point<T>>;
+
+    /// The `k` nearest points to `query`, nearest first, or all of them if there are fewer than
+    /// `k`.
+    fn k_nearest_haversine(&self, query: &Point<T>, k: usize) -> Vec<&Point<T>>;
+}
+
+impl<T> HaversineNearestNeighbor<T> for [Point<T>]
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn nearest_haversine(&self, query: &Point<T>) -> Option<&Point<T>> {
+        nearest_by(self, |point| point.haversine_distance(query))
+    }
+
+    fn k_nearest_haversine(&self, query: &Point<T>, k: usize) -> Vec<&Point<T>> {
+        k_nearest_by(self, k, |point| point.haversine_distance(query))
+    }
+}
+
+impl<T> HaversineNearestNeighbor<T> for MultiPoint<T>
+where
+    T: GeoFloat + FromPrimitive,
+{
+    fn nearest_haversine(&self, query: &Point<T>) -> Option<&Point<T>> {
+        self.0.nearest_haversine(query)
+    }
+
+    fn k_nearest_haversine(&self, query: &Point<T>, k: usize) -> Vec<&Point<T>> {
+        self.0.k_nearest_haversine(query, k)
+    }
+}
+
+/// Order by distance, then by index, so that ties go to the first geometry.
+fn compare<T: GeoFloat>(a: &(T, usize), b: &(T, usize)) -> Ordering {
+    a.0.partial_cmp(&b.0)
+        .unwrap_or(Ordering::Equal)
+        .then(a.1.cmp(&b.1))
+}
+
+fn nearest_by<G, T: GeoFloat>(geometries: &[G], distance: impl Fn(&G) -> T) -> Option<&G> {
+    geometries
+        .iter()
+        .enumerate()
+        .map(|(idx, geometry)| (distance(geometry), idx))
+        .min_by(compare)
+        .map(|(_, idx)| &geometries[idx])
+}
+
+fn k_nearest_by<G, T: GeoFloat>(geometries: &[G], k: usize, distance: impl Fn(&G) -> T) -> Vec<&G> {
+    let mut distances: Vec<(T, usize)> = geometries
+        .iter()
+        .enumerate()
+        .map(|(idx, geometry)| (distance(geometry), idx))
+        .collect();
+    if k == 0 {
+        return vec![];
+    }
+    if k < distances.len() {
+        distances.select_nth_unstable_by(k - 1, compare);
+        distances.truncate(k);
+    }
+    distances.sort_unstable_by(compare);
+    distances
+        .into_iter()
+        .map(|(_, idx)| &geometries[idx])
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon};
+
+    #[test]
+    fn empty() {
+        let points: Vec<Point> = vec![];
+        let query = point!(x: 0., y: 0.);
+        assert_eq!(points.nearest(&query), None);
+        assert!(points.k_nearest(&query, 3).is_empty());
+        assert_eq!(points.nearest_haversine(&query), None);
+        assert!(MultiPolygon::<f64>::new(vec![])
+            .k_nearest(&query, 1)
+            .is_empty());
+    }
+
+    #[test]
+    fn ties() {
+        let points = MultiPoint::new(vec![
+            point!(x: 2., y: 0.),
+            point!(x: 0., y: 1.),
+            point!(x: 1., y: 0.),
+            point!(x: -1., y: 0.),
+            point!(x: 0., y: -1.),
+        ]);
+        let query = point!(x: 0., y: 0.);
+
+        // the four points at a distance of 1 are in the order of the collection
+        assert_eq!(points.nearest(&query), Some(&points.0[1]));
+        assert_eq!(
+            points.k_nearest(&query, 3),
+            vec![&points.0[1], &points.0[2], &points.0[3]]
+        );
+        assert_eq!(
+            points.k_nearest(&query, 10),
+            vec![
+                &points.0[1],
+                &points.0[2],
+                &points.0[3],
+                &points.0[4],
+                &points.0[0]
+            ]
+        );
+        assert!(points.k_nearest(&query, 0).is_empty());
+        assert_eq!(
+            points.k_nearest_haversine(&query, 2),
+            vec![&points.0[1], &points.0[2]]
+        );
+    }
+
+    #[test]
+    fn polygons() {
+        let polygons = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            polygon![(x: 20., y: 0.), (x: 30., y: 0.), (x: 30., y: 10.)],
+        ]);
+        // inside the first polygon
+        assert_eq!(
+            polygons.nearest(&point!(x: 9., y: 5.)),
+            Some(&polygons.0[0])
+        );
+        assert_eq!(
+            polygons.nearest(&point!(x: 25., y: 1.)),
+            Some(&polygons.0[1])
+        );
+        assert_eq!(
+            polygons.k_nearest(&point!(x: 17.5, y: 2.), 2),
+            vec![&polygons.0[1], &polygons.0[0]]
+        );
+    }
+}
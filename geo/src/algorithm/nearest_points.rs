@@ -0,0 +1,376 @@
+use crate::line_intersection::{line_intersection, LineIntersection};
+use crate::{
+    Closest, ClosestPoint, Coord, EuclideanDistance, GeoFloat, Geometry, GeometryCollection,
+    Intersects, Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect,
+    Triangle,
+};
+use rstar::{RTree, RTreeNum};
+
+/// Find the nearest points of two geometries, like [GEOS's `nearestPoints`][geos]: a point of
+/// each, the distance between which is the [`EuclideanDistance`] between the geometries.
+///
+/// If the geometries intersect, both points are the same point, where they intersect. A
+/// geometry lying inside a `Polygon` intersects it, as for [`Intersects`], so this is also the
+/// case when one is entirely inside the other. Otherwise, each vertex of either geometry is
+/// compared with the nearest segments of the other, found with an [R*-tree], so the points are
+/// a vertex of one and the point nearest it on a segment of the other.
+///
+/// The distance between the points is that given by [`EuclideanDistance`], up to floating
+/// point rounding. If either geometry is empty, there are no nearest points, and `None` is
+/// returned.
+///
+/// [geos]: https://libgeos.org/doxygen/classgeos_1_1operation_1_1distance_1_1DistanceOp.html
+/// [R*-tree]: rstar::RTree
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, NearestPoints};
+///
+/// let a = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let b = polygon![(x: 4., y: 1.), (x: 6., y: 0.), (x: 6., y: 2.)];
+///
+/// assert_eq!(
+///     a.nearest_points(&b),
+///     Some((point!(x: 2., y: 1.), point!(x: 4., y: 1.)))
+/// );
+///
+/// // a polygon inside another intersects it, at its vertex
+/// let c = polygon![(x: 1., y: 1.), (x: 1.5, y: 1.), (x: 1.5, y: 1.5)];
+/// assert_eq!(
+///     a.nearest_points(&c),
+///     Some((point!(x: 1., y: 1.), point!(x: 1., y: 1.)))
+/// );
+/// ```
+pub trait NearestPoints<T: GeoFloat, Rhs = Self> {
+    /// The nearest point of `self` to `rhs`, and the nearest point of `rhs` to `self`, or
+    /// `None` if either is empty.
+    fn nearest_points(&self, rhs: &Rhs) -> Option<(Point<T>, Point<T>)>;
+}
+
+/// The parts of a geometry its nearest points are found among.
+trait Parts<T: GeoFloat> {
+    /// The segments of the geometry. Isolated points are represented by degenerate segments.
+    fn segments(&self) -> Vec<Line<T>>;
+
+    /// A vertex of each of the connected parts of the geometry.
+    fn components(&self) -> Vec<Coord<T>>;
+}
+
+impl<T: GeoFloat> Parts<T> for Point<T> {
+    fn segments(&self) -> Vec<Line<T>> {
+        vec![Line::new(self.0, self.0)]
+    }
+
+    fn components(&self) -> Vec<Coord<T>> {
+        vec![self.0]
+    }
+}
+
+impl<T: GeoFloat> Parts<T> for Line<T> {
+    fn segments(&self) -> Vec<Line<T>> {
+        vec![*self]
+    }
+
+    fn components(&self) -> Vec<Coord<T>> {
+        vec![self.start]
+    }
+}
+
+impl<T: GeoFloat> Parts<T> for LineString<T> {
+    fn segments(&self) -> Vec<Line<T>> {
+        match self.0.as_slice() {
+            [coord] => vec![Line::new(*coord, *coord)],
+            _ => self.lines().collect(),
+        }
+    }
+
+    fn components(&self) -> Vec<Coord<T>> {
+        self.0.first().copied().into_iter().collect()
+    }
+}
+
+impl<T: GeoFloat> Parts<T> for Polygon<T> {
+    fn segments(&self) -> Vec<Line<T>> {
+        std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .flat_map(Parts::segments)
+            .collect()
+    }
+
+    fn components(&self) -> Vec<Coord<T>> {
+        self.exterior().components()
+    }
+}
+
+impl<T: GeoFloat> Parts<T> for Rect<T> {
+    fn segments(&self) -> Vec<Line<T>> {
+        self.to_lines().to_vec()
+    }
+
+    fn components(&self) -> Vec<Coord<T>> {
+        vec![self.min()]
+    }
+}
+
+impl<T: GeoFloat> Parts<T> for Triangle<T> {
+    fn segments(&self) -> Vec<Line<T>> {
+        self.to_lines().to_vec()
+    }
+
+    fn components(&self) -> Vec<Coord<T>> {
+        vec![self.0]
+    }
+}
+
+macro_rules! impl_parts_for_collection {
+    ($($type:ident),*) => {
+        $(
+            impl<T: GeoFloat> Parts<T> for $type<T> {
+                fn segments(&self) -> Vec<Line<T>> {
+                    self.iter().flat_map(Parts::segments).collect()
+                }
+
+                fn components(&self) -> Vec<Coord<T>> {
+                    self.iter().flat_map(Parts::components).collect()
+                }
+            }
+        )*
+    };
+}
+
+impl_parts_for_collection!(
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection
+);
+
+impl<T: GeoFloat> Parts<T> for Geometry<T> {
+    crate::geometry_delegate_impl! {
+        fn segments(&self) -> Vec<Line<T>>;
+        fn components(&self) -> Vec<Coord<T>>;
+    }
+}
+
+/// The nearest points of `a` and `b`.
+fn nearest_points<T, A, B>(a: &A, b: &B) -> Option<(Point<T>, Point<T>)>
+where
+    T: GeoFloat + RTreeNum,
+    A: Parts<T> + Intersects<Coord<T>>,
+    B: Parts<T> + Intersects<Coord<T>>,
+{
+    let (segments_a, segments_b) = (a.segments(), b.segments());
+    if segments_a.is_empty() || segments_b.is_empty() {
+        return None;
+    }
+    let tree_a = RTree::bulk_load(segments_a);
+    let tree_b = RTree::bulk_load(segments_b);
+
+    // where the boundaries meet
+    for (line_a, line_b) in tree_a.intersection_candidates_with_other_tree(&tree_b) {
+        if let Some(coord) = meeting_point(*line_a, *line_b) {
+            return Some((coord.into(), coord.into()));
+        }
+    }
+
+    // the boundaries don't meet, so a part of one is either entirely inside the other, or not
+    if let Some(coord) = b.components().into_iter().find(|coord| a.intersects(coord)) {
+        return Some((coord.into(), coord.into()));
+    }
+    if let Some(coord) = a.components().into_iter().find(|coord| b.intersects(coord)) {
+        return Some((coord.into(), coord.into()));
+    }
+
+    // the geometries are apart, so one of the nearest points is a vertex
+    let nearest_to = |tree: &RTree<Line<T>>, vertex: Coord<T>| {
+        let line = tree.nearest_neighbor(&vertex.into()).unwrap();
+        let nearest = match line.closest_point(&vertex.into()) {
+            Closest::Intersection(point) | Closest::SinglePoint(point) => point.0,
+            Closest::Indeterminate => line.start,
+        };
+        (nearest.euclidean_distance(&vertex), nearest)
+    };
+    let vertices = |tree: &RTree<Line<T>>| {
+        tree.iter()
+            .flat_map(|line| [line.start, line.end])
+            .collect::<Vec<_>>()
+    };
+    let from_b = vertices(&tree_b).into_iter().map(|vertex| {
+        let (distance, nearest) = nearest_to(&tree_a, vertex);
+        (distance, nearest, vertex)
+    });
+    let from_a = vertices(&tree_a).into_iter().map(|vertex| {
+        let (distance, nearest) = nearest_to(&tree_b, vertex);
+        (distance, vertex, nearest)
+    });
+    from_b
+        .chain(from_a)
+        .min_by(|(a, _, _), (b, _, _)| a.partial_cmp(b).unwrap())
+        .map(|(_, a, b)| (a.into(), b.into()))
+}
+
+/// A point where two segments meet, either of which may be degenerate.
+fn meeting_point<T: GeoFloat>(a: Line<T>, b: Line<T>) -> Option<Coord<T>> {
+    if a.start == a.end {
+        return b.intersects(&a.start).then_some(a.start);
+    }
+    if b.start == b.end {
+        return a.intersects(&b.start).then_some(b.start);
+    }
+    match line_intersection(a, b)? {
+        LineIntersection::SinglePoint { intersection, .. } => Some(intersection),
+        LineIntersection::Collinear { intersection } => Some(intersection.start),
+    }
+}
+
+macro_rules! impl_nearest_points {
+    ([$($from:ident),*], $to:tt) => {
+        $(
+            impl_nearest_points!(@from $from, $to);
+        )*
+    };
+    (@from $from:ident, [$($to:ident),*]) => {
+        $(
+            impl<T> NearestPoints<T, $to<T>> for $from<T>
+            where
+                T: GeoFloat + RTreeNum,
+            {
+                fn nearest_points(&self, rhs: &$to<T>) -> Option<(Point<T>, Point<T>)> {
+                    nearest_points(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_nearest_points!(
+    [
+        Point,
+        MultiPoint,
+        Line,
+        LineString,
+        MultiLineString,
+        Polygon,
+        MultiPolygon,
+        Rect,
+        Triangle,
+        Geometry
+    ],
+    [
+        Point,
+        MultiPoint,
+        Line,
+        LineString,
+        MultiLineString,
+        Polygon,
+        MultiPolygon,
+        Rect,
+        Triangle,
+        Geometry
+    ]
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{line_string, point, polygon};
+    use approx::assert_relative_eq;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn empty() {
+        let empty = LineString::<f64>::new(vec![]);
+        assert_eq!(empty.nearest_points(&point!(x: 0., y: 0.)), None);
+        assert_eq!(point!(x: 0., y: 0.).nearest_points(&empty), None);
+        assert_eq!(MultiPoint::<f64>::new(vec![]).nearest_points(&empty), None);
+    }
+
+    #[test]
+    fn points() {
+        let a = point!(x: 0., y: 0.);
+        let b = MultiPoint::from(vec![(5., 5.), (3., 4.), (-4., -4.)]);
+        assert_eq!(a.nearest_points(&b), Some((a, point!(x: 3., y: 4.))));
+        assert_eq!(a.nearest_points(&a), Some((a, a)));
+    }
+
+    #[test]
+    fn point_on_line_string() {
+        let point = point!(x: 1., y: 0.5);
+        let line_string = line_string![(x: 0., y: 0.), (x: 2., y: 1.), (x: 3., y: 0.)];
+        assert_eq!(line_string.nearest_points(&point), Some((point, point)));
+    }
+
+    #[test]
+    fn crossing_lines() {
+        let a = line_string![(x: 0., y: 0.), (x: 2., y: 2.)];
+        let b = Line::new((0., 2.), (2., 0.));
+        assert_eq!(
+            a.nearest_points(&b),
+            Some((point!(x: 1., y: 1.), point!(x: 1., y: 1.)))
+        );
+    }
+
+    #[test]
+    fn line_inside_hole() {
+        // the line is inside the hole, so nearest the hole's boundary
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        ];
+        let line = Line::new((4., 4.), (7., 5.));
+        assert_eq!(
+            polygon.nearest_points(&line),
+            Some((point!(x: 8., y: 5.), point!(x: 7., y: 5.)))
+        );
+
+        // inside the polygon, outside the hole
+        let line = Line::new((1., 1.), (1., 9.));
+        assert_eq!(
+            line.nearest_points(&polygon),
+            Some((point!(x: 1., y: 1.), point!(x: 1., y: 1.)))
+        );
+    }
+
+    #[test]
+    fn geometries() {
+        let rect = Geometry::from(Rect::new((0., 0.), (1., 1.)));
+        let triangle = Triangle::from([(3., 0.), (4., 3.), (2., 3.)]);
+        let (a, b) = rect.nearest_points(&triangle).unwrap();
+        assert_eq!(a, point!(x: 1., y: 1.));
+        assert_relative_eq!(b, point!(x: 2.5, y: 1.5));
+    }
+
+    #[test]
+    fn matches_euclidean_distance() {
+        let mut rng = StdRng::seed_from_u64(47);
+        let mut random = move || rng.gen::<f64>() * 100.;
+        for _ in 0..100 {
+            // star-shaped polygons, which may overlap, or be apart
+            let mut polygon = || {
+                let (x, y) = (random(), random());
+                let mut angles: Vec<f64> = (0..10).map(|_| random()).collect();
+                angles.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                let ring = angles.into_iter().map(|angle| {
+                    let (sin, cos) = (angle * std::f64::consts::PI / 50.).sin_cos();
+                    let radius = random() / 4. + 1.;
+                    (x + radius * cos, y + radius * sin)
+                });
+                Polygon::new(LineString::from_iter(ring), vec![])
+            };
+            let (a, b) = (polygon(), polygon());
+            let (point_a, point_b) = a.nearest_points(&b).unwrap();
+            assert_relative_eq!(
+                point_a.euclidean_distance(&point_b),
+                a.euclidean_distance(&b),
+                epsilon = 1e-9
+            );
+            assert!(a.euclidean_distance(&point_a) < 1e-9);
+            assert!(b.euclidean_distance(&point_b) < 1e-9);
+            if a.intersects(&b) {
+                assert_eq!(point_a, point_b);
+            }
+        }
+    }
+}
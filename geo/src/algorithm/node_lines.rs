@@ -0,0 +1,208 @@
+use crate::sweep::{CrossingsIter, SweepPoint};
+use crate::{GeoFloat, Line, LineString, MultiLineString};
+
+/// Node a set of lines, splitting them wherever they meet, like
+/// [PostGIS's `ST_Node`][postgis].
+///
+/// Every point where the lines cross or touch becomes a vertex shared by the pieces meeting
+/// there, so that no two pieces of the result intersect except at their ends. Where lines
+/// overlap, the overlapping part is returned only once. This is what [`polygonize`] expects of
+/// its input.
+///
+/// The result has one two-point `LineString` for each piece, with the pieces ordered, and each
+/// directed, from left to right and then bottom to top, rather than following the input. Use
+/// [`LineMerge`] to join the pieces back into longer paths. Segments of zero length are left
+/// out. The coordinates must be finite.
+///
+/// The intersections are computed with floating point arithmetic, so the new vertices are
+/// rounded, and may lie slightly off the lines they split.
+///
+/// [postgis]: https://postgis.net/docs/ST_Node.html
+/// [`polygonize`]: crate::polygonize
+/// [`LineMerge`]: crate::LineMerge
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, node_lines, MultiLineString};
+///
+/// let noded = node_lines(vec![
+///     line_string![(x: 0., y: 0.), (x: 2., y: 2.)],
+///     line_string![(x: 0., y: 2.), (x: 2., y: 0.)],
+///     // overlaps the end of the first line
+///     line_string![(x: 1.5, y: 1.5), (x: 2., y: 2.)],
+/// ]);
+///
+/// assert_eq!(
+///     noded,
+///     MultiLineString::new(vec![
+///         line_string![(x: 0., y: 0.), (x: 1., y: 1.)],
+///         line_string![(x: 0., y: 2.), (x: 1., y: 1.)],
+///         line_string![(x: 1., y: 1.), (x: 1.5, y: 1.5)],
+///         line_string![(x: 1., y: 1.), (x: 2., y: 0.)],
+///         line_string![(x: 1.5, y: 1.5), (x: 2., y: 2.)],
+///     ])
+/// );
+/// ```
+pub fn node_lines<T, L>(lines: impl IntoIterator<Item = L>) -> MultiLineString<T>
+where
+    T: GeoFloat,
+    L: Into<LineString<T>>,
+{
+    let segments: Vec<Line<T>> = lines
+        .into_iter()
+        .flat_map(|line_string| line_string.into().lines().collect::<Vec<_>>())
+        .filter(|line| line.start != line.end)
+        .collect();
+
+    // each piece ending at a point yielded by the sweep contains no other point it yields
+    let mut pieces: Vec<(SweepPoint<T>, SweepPoint<T>)> = vec![];
+    let mut iter = CrossingsIter::from_iter(segments);
+    while iter.next().is_some() {
+        pieces.extend(
+            iter.intersections()
+                .iter()
+                .filter(|crossing| !crossing.at_left && crossing.line.is_line())
+                .map(|crossing| crossing.line.end_points()),
+        );
+    }
+
+    // overlapping segments are split into the same pieces
+    pieces.sort_unstable();
+    pieces.dedup();
+
+    MultiLineString::new(
+        pieces
+            .into_iter()
+            .map(|(left, right)| LineString::new(vec![*left, *right]))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line_intersection::{line_intersection, LineIntersection};
+    use crate::{coord, line_string, polygonize, Area};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// Asserts that pieces only meet at their ends, and are distinct.
+    fn assert_noded(noded: &MultiLineString<f64>) {
+        let lines: Vec<Line<f64>> = noded.0.iter().flat_map(|ls| ls.lines()).collect();
+        assert_eq!(lines.len(), noded.0.len());
+        for (i, p) in lines.iter().enumerate() {
+            assert_ne!(p.start, p.end);
+            for q in &lines[i + 1..] {
+                match line_intersection(*p, *q) {
+                    None => {}
+                    Some(LineIntersection::SinglePoint { intersection, .. }) => {
+                        assert!(
+                            [p.start, p.end].contains(&intersection)
+                                && [q.start, q.end].contains(&intersection),
+                            "{p:?} and {q:?} meet at {intersection:?}, not at their ends"
+                        );
+                    }
+                    Some(LineIntersection::Collinear { .. }) => {
+                        panic!("{p:?} and {q:?} overlap")
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn crossing_lines() {
+        let noded = node_lines(vec![
+            line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)],
+            line_string![(x: 2., y: -1.), (x: 2., y: 1.)],
+            line_string![(x: 3., y: 2.), (x: 5., y: 2.)],
+        ]);
+        assert_noded(&noded);
+        assert_eq!(noded.0.len(), 8);
+    }
+
+    #[test]
+    fn touching_lines() {
+        // the end of the second line splits the first
+        let noded = node_lines(vec![
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+            line_string![(x: 1., y: 0.), (x: 1., y: 1.)],
+        ]);
+        assert_eq!(
+            noded,
+            MultiLineString::new(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+                line_string![(x: 1., y: 0.), (x: 1., y: 1.)],
+                line_string![(x: 1., y: 0.), (x: 2., y: 0.)],
+            ])
+        );
+    }
+
+    #[test]
+    fn overlapping_lines() {
+        let noded = node_lines(vec![
+            line_string![(x: 0., y: 0.), (x: 3., y: 0.)],
+            line_string![(x: 2., y: 0.), (x: 1., y: 0.)],
+            line_string![(x: 0., y: 0.), (x: 3., y: 0.)],
+        ]);
+        assert_eq!(
+            noded,
+            MultiLineString::new(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+                line_string![(x: 1., y: 0.), (x: 2., y: 0.)],
+                line_string![(x: 2., y: 0.), (x: 3., y: 0.)],
+            ])
+        );
+    }
+
+    #[test]
+    fn degenerate_lines() {
+        let noded = node_lines(vec![
+            line_string![(x: 1., y: 1.), (x: 1., y: 1.)],
+            line_string![(x: 0., y: 0.)],
+            line_string![],
+        ]);
+        assert!(noded.0.is_empty());
+    }
+
+    #[test]
+    fn polygonize_noded() {
+        // two overlapping squares: their intersection, and the two differences
+        let noded = node_lines(vec![
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.), (x: 0., y: 0.)],
+            line_string![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.), (x: 1., y: 1.)],
+        ]);
+        assert_noded(&noded);
+        let polygonized = polygonize(noded);
+        assert_eq!(polygonized.polygons.0.len(), 3);
+        assert_eq!(polygonized.polygons.unsigned_area(), 7.);
+    }
+
+    #[test]
+    fn random_lines() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut random = move |max: f64| {
+            // multiples of 1/64, so some lines overlap exactly
+            rng.gen_range(0..max as u64 * 64) as f64 / 64.
+        };
+        for _ in 0..20 {
+            let lines: Vec<LineString<f64>> = (0..20)
+                .map(|i| {
+                    let mut coords = vec![coord! { x: random(10.), y: random(10.) }];
+                    for _ in 0..3 {
+                        let last = coords[coords.len() - 1];
+                        coords.push(if i % 4 == 0 {
+                            // axis aligned, to overlap with each other
+                            coord! { x: last.x, y: random(10.) }
+                        } else {
+                            coord! { x: random(10.), y: random(10.) }
+                        });
+                    }
+                    LineString::new(coords)
+                })
+                .collect();
+            assert_noded(&node_lines(lines));
+        }
+    }
+}
@@ -63,6 +63,48 @@ pub trait Orient {
     /// assert_eq!(expected, oriented);
     /// ```
     fn orient(&self, orientation: Direction) -> Self;
+
+    /// Orients a Polygon's exterior ring according to convention, leaving its interior rings as
+    /// they are
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::orient::{Direction, Orient};
+    /// use geo::polygon;
+    /// use geo::winding_order::{Winding, WindingOrder};
+    ///
+    /// let polygon = polygon![
+    ///     exterior: [(x: 0.0, y: 0.0), (x: 0.0, y: 4.0), (x: 4.0, y: 4.0), (x: 4.0, y: 0.0)],
+    ///     interiors: [[(x: 1.0, y: 1.0), (x: 2.0, y: 1.0), (x: 2.0, y: 2.0)]],
+    /// ];
+    ///
+    /// let oriented = polygon.orient_exterior_only(Direction::Default);
+    /// assert_eq!(oriented.exterior().winding_order(), Some(WindingOrder::CounterClockwise));
+    /// assert_eq!(oriented.interiors(), polygon.interiors());
+    /// ```
+    fn orient_exterior_only(&self, orientation: Direction) -> Self;
+
+    /// Orients a Polygon's interior rings according to convention, leaving its exterior ring as
+    /// it is
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::orient::{Direction, Orient};
+    /// use geo::polygon;
+    /// use geo::winding_order::{Winding, WindingOrder};
+    ///
+    /// let polygon = polygon![
+    ///     exterior: [(x: 0.0, y: 0.0), (x: 0.0, y: 4.0), (x: 4.0, y: 4.0), (x: 4.0, y: 0.0)],
+    ///     interiors: [[(x: 1.0, y: 1.0), (x: 2.0, y: 1.0), (x: 2.0, y: 2.0)]],
+    /// ];
+    ///
+    /// let oriented = polygon.orient_interiors_only(Direction::Default);
+    /// assert_eq!(oriented.exterior(), polygon.exterior());
+    /// assert_eq!(oriented.interiors()[0].winding_order(), Some(WindingOrder::Clockwise));
+    /// ```
+    fn orient_interiors_only(&self, orientation: Direction) -> Self;
 }
 
 impl<T> Orient for Polygon<T>
@@ -72,6 +114,14 @@ where
     fn orient(&self, direction: Direction) -> Polygon<T> {
         orient(self, direction)
     }
+
+    fn orient_exterior_only(&self, direction: Direction) -> Polygon<T> {
+        orient_rings(self, Some(direction), None)
+    }
+
+    fn orient_interiors_only(&self, direction: Direction) -> Polygon<T> {
+        orient_rings(self, None, Some(direction))
+    }
 }
 
 impl<T> Orient for MultiPolygon<T>
@@ -81,6 +131,22 @@ where
     fn orient(&self, direction: Direction) -> MultiPolygon<T> {
         MultiPolygon::new(self.iter().map(|poly| poly.orient(direction)).collect())
     }
+
+    fn orient_exterior_only(&self, direction: Direction) -> MultiPolygon<T> {
+        MultiPolygon::new(
+            self.iter()
+                .map(|poly| poly.orient_exterior_only(direction))
+                .collect(),
+        )
+    }
+
+    fn orient_interiors_only(&self, direction: Direction) -> MultiPolygon<T> {
+        MultiPolygon::new(
+            self.iter()
+                .map(|poly| poly.orient_interiors_only(direction))
+                .collect(),
+        )
+    }
 }
 
 /// By default, a properly-oriented Polygon has its outer ring oriented counter-clockwise,
@@ -101,21 +167,40 @@ fn orient<T>(poly: &Polygon<T>, direction: Direction) -> Polygon<T>
 where
     T: GeoNum,
 {
-    let interiors = poly
-        .interiors()
-        .iter()
-        .map(|l| {
-            l.clone_to_winding_order(match direction {
-                Direction::Default => WindingOrder::Clockwise,
-                Direction::Reversed => WindingOrder::CounterClockwise,
+    orient_rings(poly, Some(direction), Some(direction))
+}
+
+// orient the exterior and interior rings of a Polygon according to convention,
+// leaving those whose direction is `None` as they are
+fn orient_rings<T>(
+    poly: &Polygon<T>,
+    exterior: Option<Direction>,
+    interiors: Option<Direction>,
+) -> Polygon<T>
+where
+    T: GeoNum,
+{
+    let interiors = match interiors {
+        Some(direction) => poly
+            .interiors()
+            .iter()
+            .map(|l| {
+                l.clone_to_winding_order(match direction {
+                    Direction::Default => WindingOrder::Clockwise,
+                    Direction::Reversed => WindingOrder::CounterClockwise,
+                })
             })
-        })
-        .collect();
+            .collect(),
+        None => poly.interiors().to_vec(),
+    };
 
-    let ext_ring = poly.exterior().clone_to_winding_order(match direction {
-        Direction::Default => WindingOrder::CounterClockwise,
-        Direction::Reversed => WindingOrder::Clockwise,
-    });
+    let ext_ring = match exterior {
+        Some(direction) => poly.exterior().clone_to_winding_order(match direction {
+            Direction::Default => WindingOrder::CounterClockwise,
+            Direction::Reversed => WindingOrder::Clockwise,
+        }),
+        None => poly.exterior().clone(),
+    };
 
     Polygon::new(ext_ring, interiors)
 }
@@ -145,4 +230,69 @@ mod test {
         assert_eq!(oriented.exterior().0, oriented_ext_ls.0);
         assert_eq!(oriented.interiors()[0].0, oriented_int_ls.0);
     }
+
+    #[test]
+    fn test_orient_rings_separately() {
+        use crate::polygon;
+        // a clockwise exterior, with one clockwise and one counter-clockwise hole
+        let poly = polygon![
+            exterior: [(x: 0.0, y: 0.0), (x: 0.0, y: 10.0), (x: 10.0, y: 10.0), (x: 10.0, y: 0.0)],
+            interiors: [
+                [(x: 1.0, y: 1.0), (x: 1.0, y: 2.0), (x: 2.0, y: 2.0), (x: 2.0, y: 1.0)],
+                [(x: 5.0, y: 5.0), (x: 6.0, y: 5.0), (x: 6.0, y: 6.0), (x: 5.0, y: 6.0)],
+            ],
+        ];
+        let windings = |poly: &Polygon<f64>| {
+            (
+                poly.exterior().winding_order(),
+                poly.interiors()
+                    .iter()
+                    .map(|ring| ring.winding_order())
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let cw = Some(WindingOrder::Clockwise);
+        let ccw = Some(WindingOrder::CounterClockwise);
+        assert_eq!(windings(&poly), (cw, vec![cw, ccw]));
+
+        assert_eq!(
+            windings(&poly.orient_exterior_only(Direction::Default)),
+            (ccw, vec![cw, ccw])
+        );
+        assert_eq!(
+            windings(&poly.orient_exterior_only(Direction::Reversed)),
+            (cw, vec![cw, ccw])
+        );
+        assert_eq!(
+            windings(&poly.orient_interiors_only(Direction::Default)),
+            (cw, vec![cw, cw])
+        );
+        assert_eq!(
+            windings(&poly.orient_interiors_only(Direction::Reversed)),
+            (cw, vec![ccw, ccw])
+        );
+        assert_eq!(
+            windings(&poly.orient(Direction::Default)),
+            (ccw, vec![cw, cw])
+        );
+        assert_eq!(
+            windings(&poly.orient(Direction::Reversed)),
+            (cw, vec![ccw, ccw])
+        );
+
+        // rings which are left alone are unchanged, not just in the same direction
+        assert_eq!(
+            poly.orient_exterior_only(Direction::Default).interiors(),
+            poly.interiors()
+        );
+        assert_eq!(
+            poly.orient_interiors_only(Direction::Default).exterior(),
+            poly.exterior()
+        );
+
+        let multi = MultiPolygon::new(vec![poly.clone(), poly]);
+        for poly in &multi.orient_interiors_only(Direction::Default) {
+            assert_eq!(windings(poly), (cw, vec![cw, cw]));
+        }
+    }
 }
@@ -1,7 +1,9 @@
 use std::iter::Sum;
 use std::ops::RangeInclusive;
 
-use crate::{GeoFloat, MultiPoint, Point};
+use crate::{GeoFloat, HaversineDistance, MultiPoint, Point};
+
+use num_traits::FromPrimitive;
 
 use rstar::primitives::GeomWithData;
 use rstar::RTree;
@@ -108,6 +110,72 @@ where
     ///```
     fn outliers(&self, k_neighbours: usize) -> Vec<T>;
 
+    /// The continuous LOF score of each point, in input order, leaving any thresholding to the
+    /// caller. These are the scores returned by [`OutlierDetection::outliers`], so if
+    /// `k_neighbours` >= points in the set, or `k_neighbours` < 1, every score is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, OutlierDetection};
+    ///
+    /// let v = vec![
+    ///     point!(x: 0.0, y: 0.0),
+    ///     point!(x: 0.0, y: 1.0),
+    ///     point!(x: 3.0, y: 0.0),
+    ///     point!(x: 1.0, y: 1.0),
+    /// ];
+    ///
+    /// let scores = v.outlier_scores(2);
+    /// let outliers: Vec<usize> = (0..v.len()).filter(|&i| scores[i] > 1.5).collect();
+    /// assert_eq!(outliers, vec![2]);
+    ///
+    /// // too few points for the neighbour count
+    /// assert_eq!(v.outlier_scores(4), vec![1.0; 4]);
+    /// ```
+    fn outlier_scores(&self, k_neighbours: usize) -> Vec<T> {
+        self.outliers(k_neighbours)
+    }
+
+    /// The LOF algorithm for longitude/latitude points, in degrees, with neighbours found and
+    /// distances measured along great circles, by the [haversine
+    /// formula](crate::HaversineDistance), rather than in the plane. This is what's wanted for
+    /// e.g. GPS fixes spread over more than a small area, as a degree of longitude is shorter the
+    /// further it is from the equator.
+    ///
+    /// Otherwise this is the same as [`OutlierDetection::outliers`], though the scores differ
+    /// even for nearby points, as this uses distances rather than squared distances.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{point, OutlierDetection};
+    ///
+    /// // far from the equator, the degrees of longitude between these points are much shorter
+    /// // than the degrees of latitude to the last
+    /// let v = vec![
+    ///     point!(x: 0.0, y: 70.0),
+    ///     point!(x: 10.0, y: 70.0),
+    ///     point!(x: 20.0, y: 70.0),
+    ///     point!(x: 30.0, y: 70.0),
+    ///     point!(x: 40.0, y: 70.0),
+    ///     point!(x: 20.0, y: 60.0),
+    /// ];
+    ///
+    /// // in the plane, the ends of the row are the likeliest outliers
+    /// let planar = v.outliers(3);
+    /// assert!(planar[5] < planar[0]);
+    /// // but on the sphere, it's the last point
+    /// let haversine = v.outliers_haversine(3);
+    /// assert!(haversine[5] > haversine[0]);
+    /// ```
+    fn outliers_haversine(&self, k_neighbours: usize) -> Vec<T>
+    where
+        T: FromPrimitive + Sum,
+    {
+        haversine_lof(self.prepared_detector().points, k_neighbours)
+    }
+
     /// Create a prepared outlier detector allowing multiple runs to retain the spatial index in use.
     /// A [`PreparedDetector`] can efficiently recompute outliers with different `k_neigbhours` values.
     fn prepared_detector(&self) -> PreparedDetector<T>;
@@ -192,6 +260,11 @@ where
     pub fn outliers(&self, kneighbours: usize) -> Vec<T> {
         lof(self.points, &self.tree, kneighbours)
     }
+
+    /// See [`OutlierDetection::outlier_scores`] for usage
+    pub fn outlier_scores(&self, kneighbours: usize) -> Vec<T> {
+        self.outliers(kneighbours)
+    }
 }
 
 fn lof<T>(
@@ -202,7 +275,6 @@ fn lof<T>(
 where
     T: GeoFloat + Sum,
 {
-    debug_assert!(kneighbours > 0);
     if points.len() <= kneighbours || kneighbours < 1 {
         // no point in trying to run the algorithm in this case
         return points.iter().map(|_| T::one()).collect();
@@ -212,9 +284,56 @@ where
         .map(|point| {
             tree.nearest_neighbor_iter_with_distance_2(point)
                 .take(kneighbours)
+                .map(|(neighbour, distance)| (neighbour.data, distance))
                 .collect()
         })
         .collect::<Vec<Vec<_>>>();
+    local_outlier_factors(&knn_dists)
+}
+
+/// [`lof`], with neighbours and distances on the sphere rather than the plane.
+fn haversine_lof<T>(points: &[Point<T>], kneighbours: usize) -> Vec<T>
+where
+    T: GeoFloat + FromPrimitive + Sum,
+{
+    if points.len() <= kneighbours || kneighbours < 1 {
+        return points.iter().map(|_| T::one()).collect();
+    }
+    // the straight line distance between points on the unit sphere increases with the
+    // great-circle distance between them, so their nearest neighbours are the same
+    let unit_vectors: Vec<GeomWithData<[T; 3], usize>> = points
+        .iter()
+        .enumerate()
+        .map(|(idx, point)| GeomWithData::new(unit_vector(point), idx))
+        .collect();
+    let tree = RTree::bulk_load(unit_vectors);
+    let knn_dists = points
+        .iter()
+        .map(|point| {
+            tree.nearest_neighbor_iter(&unit_vector(point))
+                .take(kneighbours)
+                .map(|neighbour| {
+                    let distance = point.haversine_distance(&points[neighbour.data]);
+                    (neighbour.data, distance)
+                })
+                .collect()
+        })
+        .collect::<Vec<Vec<_>>>();
+    local_outlier_factors(&knn_dists)
+}
+
+/// The position of a longitude/latitude point on the unit sphere.
+fn unit_vector<T: GeoFloat>(point: &Point<T>) -> [T; 3] {
+    let (lon, lat) = (point.x().to_radians(), point.y().to_radians());
+    [lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin()]
+}
+
+/// The LOF of each point, given the indices of and distances to its `k` nearest neighbours, in
+/// order of distance.
+fn local_outlier_factors<T>(knn_dists: &[Vec<(usize, T)>]) -> Vec<T>
+where
+    T: GeoFloat + Sum,
+{
     // calculate LRD (local reachability density) of each point
     // LRD is the estimated distance at which a point can be found by its neighbours:
     // count(neighbour_set) / sum(max(point.kTh_dist, point.dist2(other point)) for all points in neighbour_set)
@@ -252,7 +371,7 @@ where
             // sum neighbour set LRD scores
             let lrd_scores: T = neighbours
                 .iter()
-                .map(|(neighbour, _)| local_reachability_densities[neighbour.data])
+                .map(|(neighbour, _)| local_reachability_densities[*neighbour])
                 .sum();
             // sum neighbour set reachDistance
             let sum_rd: T = neighbours
@@ -273,6 +392,13 @@ where
         pd.outliers(k_neighbours)
     }
 
+    fn outliers_haversine(&self, k_neighbours: usize) -> Vec<T>
+    where
+        T: FromPrimitive,
+    {
+        haversine_lof(&self.0, k_neighbours)
+    }
+
     fn prepared_detector(&self) -> PreparedDetector<T> {
         PreparedDetector::new(&self.0)
     }
@@ -307,6 +433,13 @@ where
         pd.outliers(k_neighbours)
     }
 
+    fn outliers_haversine(&self, k_neighbours: usize) -> Vec<T>
+    where
+        T: FromPrimitive,
+    {
+        haversine_lof(self, k_neighbours)
+    }
+
     fn prepared_detector(&self) -> PreparedDetector<T> {
         PreparedDetector::new(self)
     }
@@ -443,4 +576,85 @@ mod tests {
         // different neighbour sizes give different scores
         assert_ne!(s1[2], s2[2]);
     }
+    #[test]
+    fn outlier_scores() {
+        let v = vec![
+            Point::new(0.0, 0.0),
+            Point::new(0.0, 1.0),
+            Point::new(3.0, 0.0),
+            Point::new(1.0, 1.0),
+        ];
+        assert_eq!(v.outlier_scores(3), v.outliers(3));
+        assert_eq!(
+            v.prepared_detector().outlier_scores(2),
+            MultiPoint::new(v.clone()).outlier_scores(2)
+        );
+        // too few points, or no neighbours
+        assert_eq!(v.outlier_scores(4), vec![1.0; 4]);
+        assert_eq!(v.outlier_scores(0), vec![1.0; 4]);
+        assert_eq!(v[..1].outlier_scores(1), vec![1.0]);
+        assert!(v[..0].outlier_scores(1).is_empty());
+    }
+    #[test]
+    fn haversine_outliers() {
+        let argmax = |scores: Vec<f64>| {
+            (0..scores.len())
+                .max_by(|&a, &b| scores[a].partial_cmp(&scores[b]).unwrap())
+                .unwrap()
+        };
+        // a column of points along a meridian near the pole, and one off to the side by tens of
+        // degrees of longitude, which is very near on the sphere
+        let mut v: Vec<Point> = (0..8)
+            .map(|i| point!(x: 0.0, y: 50.0 + 5.0 * i as f64))
+            .collect();
+        v.push(point!(x: 40.0, y: 86.0));
+        assert_eq!(argmax(v.outliers(3)), 8);
+        let haversine = v.outliers_haversine(3);
+        assert!(haversine[8] < 1.5, "{haversine:?}");
+        assert_ne!(argmax(haversine), 8);
+
+        // near the equator, the metrics agree
+        let mut v: Vec<Point> = (0..8)
+            .map(|i| point!(x: 0.1 * (i % 4) as f64, y: 0.1 * (i / 4) as f64))
+            .collect();
+        v.push(point!(x: 0.15, y: 1.0));
+        assert_eq!(argmax(v.outliers(3)), 8);
+        assert_eq!(argmax(v.outliers_haversine(3)), 8);
+        assert_eq!(
+            MultiPoint::new(v.clone()).outliers_haversine(3),
+            v.outliers_haversine(3)
+        );
+        assert_eq!(v[..3].outliers_haversine(3), vec![1.0; 3]);
+    }
+
+    #[test]
+    fn haversine_default_method() {
+        // an implementor which doesn't provide `outliers_haversine`
+        struct Points(Vec<Point>);
+        impl OutlierDetection<f64> for Points {
+            fn outliers(&self, k_neighbours: usize) -> Vec<f64> {
+                self.0.outliers(k_neighbours)
+            }
+            fn prepared_detector(&self) -> PreparedDetector<'_, f64> {
+                self.0.prepared_detector()
+            }
+            fn generate_ensemble(&self, bounds: RangeInclusive<usize>) -> Vec<Vec<f64>> {
+                self.0.generate_ensemble(bounds)
+            }
+            fn ensemble_min(&self, bounds: RangeInclusive<usize>) -> Vec<f64> {
+                self.0.ensemble_min(bounds)
+            }
+            fn ensemble_max(&self, bounds: RangeInclusive<usize>) -> Vec<f64> {
+                self.0.ensemble_max(bounds)
+            }
+        }
+
+        let v: Vec<Point> = (0..10)
+            .map(|i| point!(x: (i % 4) as f64, y: 60.0 + (i / 4) as f64))
+            .collect();
+        assert_eq!(
+            Points(v.clone()).outliers_haversine(3),
+            v.outliers_haversine(3)
+        );
+    }
 }
@@ -0,0 +1,139 @@
+use crate::dimensions::HasDimensions;
+use crate::{BoundingRect, GeoFloat, Intersects, Rect, Relate};
+
+/// Tests if two geometries overlap: they have the same dimension, their interiors meet in that
+/// dimension, and neither contains the other.
+///
+/// Following the [OGC Simple Features] definition, the [DE-9IM] intersection matrix for
+/// (Self, Rhs) is `[T*T***T**]` for points or areas, and `[1*T***T**]` for lines. Geometries
+/// of different dimensions never overlap, and neither do:
+/// - identical geometries, as neither has any interior outside the other
+/// - geometries where one contains the other
+/// - areas which only share (part of) an edge, or lines which only cross, as their interiors
+///   don't meet in as many dimensions as they have
+///
+/// This is the same as [`IntersectionMatrix::is_overlaps`] on the result of [`Relate`], but
+/// answers quickly when the dimensions differ, or the bounding rectangles are disjoint, without
+/// computing the whole matrix.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Overlaps};
+///
+/// let field = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+/// let wood = polygon![(x: 5., y: 5.), (x: 15., y: 5.), (x: 15., y: 15.), (x: 5., y: 15.)];
+/// let pond = polygon![(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)];
+/// let meadow = polygon![(x: 10., y: 0.), (x: 20., y: 0.), (x: 20., y: 5.), (x: 10., y: 5.)];
+///
+/// assert!(field.overlaps(&wood));
+/// // within the field
+/// assert!(!field.overlaps(&pond));
+/// // sharing only part of an edge
+/// assert!(!field.overlaps(&meadow));
+/// assert!(!field.overlaps(&field));
+/// ```
+///
+/// [OGC Simple Features]: https://www.ogc.org/standards/sfa
+/// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+/// [`IntersectionMatrix::is_overlaps`]: crate::relate::IntersectionMatrix::is_overlaps
+pub trait Overlaps<F, Rhs> {
+    fn overlaps(&self, other: &Rhs) -> bool;
+}
+
+impl<F, G1, G2> Overlaps<F, G2> for G1
+where
+    F: GeoFloat,
+    G1: Relate<F, G2> + BoundingRect<F> + HasDimensions,
+    G2: BoundingRect<F> + HasDimensions,
+{
+    fn overlaps(&self, other: &G2) -> bool {
+        if self.is_empty() || self.dimensions() != other.dimensions() {
+            return false;
+        }
+        let rects: (Option<Rect<F>>, Option<Rect<F>>) =
+            (self.bounding_rect().into(), other.bounding_rect().into());
+        match rects {
+            (Some(a), Some(b)) if a.intersects(&b) => self.relate(other).is_overlaps(),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Geometry, MultiPoint, MultiPolygon, Polygon};
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon {
+        polygon![
+            (x: x, y: y),
+            (x: x + size, y: y),
+            (x: x + size, y: y + size),
+            (x: x, y: y + size),
+        ]
+    }
+
+    #[test]
+    fn areas() {
+        let parcel = square(0., 0., 10.);
+        assert!(parcel.overlaps(&square(5., 5., 10.)));
+        assert!(square(5., 5., 10.).overlaps(&parcel));
+
+        // identical, containing or contained
+        assert!(!parcel.overlaps(&parcel));
+        assert!(!parcel.overlaps(&square(2., 2., 2.)));
+        assert!(!square(2., 2., 2.).overlaps(&parcel));
+        // sharing an edge, or a corner
+        assert!(!parcel.overlaps(&square(10., 0., 10.)));
+        assert!(!parcel.overlaps(&square(10., 10., 10.)));
+        // disjoint
+        assert!(!parcel.overlaps(&square(20., 20., 10.)));
+
+        let parcels = MultiPolygon::new(vec![square(0., 0., 10.), square(20., 0., 10.)]);
+        assert!(parcels.overlaps(&square(5., 0., 10.)));
+        assert!(!parcels.overlaps(&square(10., 0., 10.)));
+    }
+
+    #[test]
+    fn lines() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        // sharing a stretch, each going further
+        assert!(line_string.overlaps(&line_string![(x: 5., y: 0.), (x: 15., y: 0.)]));
+        assert!(
+            line_string.overlaps(&line_string![(x: 5., y: 5.), (x: 5., y: 0.), (x: 15., y: 0.)])
+        );
+        // within the other, crossing, or end to end
+        assert!(!line_string.overlaps(&line_string![(x: 2., y: 0.), (x: 8., y: 0.)]));
+        assert!(!line_string.overlaps(&line_string![(x: 5., y: -5.), (x: 5., y: 5.)]));
+        assert!(!line_string.overlaps(&line_string![(x: 10., y: 0.), (x: 20., y: 0.)]));
+        assert!(!line_string.overlaps(&line_string));
+    }
+
+    #[test]
+    fn points() {
+        let points = MultiPoint::new(vec![point!(x: 0., y: 0.), point!(x: 1., y: 1.)]);
+        assert!(points.overlaps(&MultiPoint::new(vec![
+            point!(x: 1., y: 1.),
+            point!(x: 2., y: 2.)
+        ])));
+        assert!(!points.overlaps(&MultiPoint::new(vec![point!(x: 1., y: 1.)])));
+        assert!(!points.overlaps(&points));
+        assert!(!point!(x: 1., y: 1.).overlaps(&point!(x: 1., y: 1.)));
+    }
+
+    #[test]
+    fn mismatched_dimensions() {
+        let parcel = square(0., 0., 10.);
+        assert!(!parcel.overlaps(&line_string![(x: 5., y: 5.), (x: 15., y: 5.)]));
+        assert!(!line_string![(x: 5., y: 5.), (x: 15., y: 5.)].overlaps(&parcel));
+        assert!(!parcel.overlaps(&MultiPoint::new(vec![
+            point!(x: 5., y: 5.),
+            point!(x: 15., y: 5.)
+        ])));
+
+        let a = Geometry::from(parcel);
+        assert!(a.overlaps(&Geometry::from(square(5., 5., 10.))));
+        assert!(!a.overlaps(&Geometry::from(point!(x: 5., y: 5.))));
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{
+    node_lines, polygonize, Contains, GeoFloat, InteriorPoint, LineString, MultiLineString,
+    MultiPolygon, Polygon,
+};
+
+/// Split polygons into pieces, cutting them along lines.
+///
+/// Unlike [`BooleanOps::difference`](crate::BooleanOps::difference), which removes an area,
+/// this cuts along lines, which have no area, so the pieces together cover the whole of
+/// `self`. The lines are [noded](crate::node_lines) with the rings, and the pieces are the
+/// faces of the result which are inside `self`, with any holes they enclose.
+///
+/// A piece is only cut off where lines fully cross it, or form closed loops inside it. The
+/// parts of lines which end inside a polygon, or lie outside it, are ignored, so a polygon which
+/// isn't cut into at least two pieces is returned unchanged.
+///
+/// The lines are noded with floating point arithmetic, so the vertices where they cross the
+/// rings are rounded. The pieces are in no particular order, and neither is the winding order
+/// of their rings.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygon, Area, MultiLineString, PolygonSplit};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+///
+/// // a diagonal, across the square and beyond it
+/// let diagonal = line_string![(x: -1., y: -1.), (x: 3., y: 3.)];
+/// let pieces = square.split(&MultiLineString::new(vec![diagonal]));
+///
+/// assert_eq!(pieces.0.len(), 2);
+/// assert_eq!(pieces.0[0].unsigned_area(), 2.);
+/// assert_eq!(pieces.0[1].unsigned_area(), 2.);
+///
+/// // a line ending inside the square doesn't cut it
+/// let notch = line_string![(x: -1., y: 1.), (x: 1., y: 1.)];
+/// assert_eq!(
+///     square.split(&MultiLineString::new(vec![notch])),
+///     square.into()
+/// );
+/// ```
+pub trait PolygonSplit<T: GeoFloat> {
+    /// Split `self` into the pieces it's cut into by `lines`.
+    fn split(&self, lines: &MultiLineString<T>) -> MultiPolygon<T>;
+}
+
+impl<T: GeoFloat> PolygonSplit<T> for Polygon<T> {
+    fn split(&self, lines: &MultiLineString<T>) -> MultiPolygon<T> {
+        let rings = std::iter::once(self.exterior()).chain(self.interiors());
+        let noded = node_lines(rings.chain(lines).cloned().collect::<Vec<LineString<T>>>());
+
+        // each face is either entirely inside or outside of `self`
+        let pieces: Vec<Polygon<T>> = polygonize(noded)
+            .polygons
+            .into_iter()
+            .filter(|face| {
+                face.interior_point()
+                    .map_or(false, |point| self.contains(&point))
+            })
+            .collect();
+
+        if pieces.len() < 2 {
+            MultiPolygon::new(vec![self.clone()])
+        } else {
+            MultiPolygon::new(pieces)
+        }
+    }
+}
+
+impl<T: GeoFloat> PolygonSplit<T> for MultiPolygon<T> {
+    fn split(&self, lines: &MultiLineString<T>) -> MultiPolygon<T> {
+        MultiPolygon::new(
+            self.iter()
+                .flat_map(|polygon| polygon.split(lines))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{line_string, polygon, Area};
+
+    fn square() -> Polygon<f64> {
+        polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)]
+    }
+
+    #[test]
+    fn diagonal() {
+        let diagonal = line_string![(x: 0., y: 0.), (x: 2., y: 2.)];
+        let pieces = square().split(&MultiLineString::new(vec![diagonal]));
+        assert_eq!(pieces.0.len(), 2);
+        for piece in &pieces {
+            // a triangle
+            assert_eq!(piece.exterior().0.len(), 4);
+            assert_eq!(piece.unsigned_area(), 2.);
+            assert!(piece.interiors().is_empty());
+        }
+    }
+
+    #[test]
+    fn lines_not_crossing() {
+        let lines = MultiLineString::new(vec![
+            // ending inside
+            line_string![(x: 1., y: -1.), (x: 1., y: 1.)],
+            // outside
+            line_string![(x: 3., y: 0.), (x: 3., y: 3.), (x: 4., y: 0.), (x: 3., y: 0.)],
+            // along the boundary
+            line_string![(x: 0., y: 0.), (x: 2., y: 0.)],
+        ]);
+        assert_eq!(square().split(&lines), MultiPolygon::new(vec![square()]));
+    }
+
+    #[test]
+    fn crossing_lines() {
+        let lines = MultiLineString::new(vec![
+            line_string![(x: 1., y: -1.), (x: 1., y: 3.)],
+            line_string![(x: -1., y: 1.), (x: 3., y: 1.)],
+            line_string![(x: 1.5, y: -1.), (x: 1.5, y: 0.5)],
+        ]);
+        let pieces = square().split(&lines);
+        assert_eq!(pieces.0.len(), 4);
+        for piece in &pieces {
+            assert_eq!(piece.unsigned_area(), 1.);
+        }
+    }
+
+    #[test]
+    fn holes() {
+        // a square with a hole, cut through the hole, and around a loop in one half
+        let polygon = polygon![
+            exterior: [(x: 0., y: 0.), (x: 6., y: 0.), (x: 6., y: 6.), (x: 0., y: 6.)],
+            interiors: [[(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)]],
+        ];
+        let lines = MultiLineString::new(vec![
+            line_string![(x: 3., y: -1.), (x: 3., y: 7.)],
+            line_string![(x: 0.5, y: 0.5), (x: 1.5, y: 0.5), (x: 1.5, y: 1.5), (x: 0.5, y: 1.5), (x: 0.5, y: 0.5)],
+        ]);
+        let pieces = polygon.split(&lines);
+        assert_eq!(pieces.0.len(), 3);
+        assert_eq!(pieces.unsigned_area(), polygon.unsigned_area());
+        let mut areas: Vec<f64> = pieces.iter().map(|piece| piece.unsigned_area()).collect();
+        areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(areas, vec![1., 15., 16.]);
+        assert_eq!(
+            pieces
+                .iter()
+                .map(|piece| piece.interiors().len())
+                .sum::<usize>(),
+            1
+        );
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let multi_polygon = MultiPolygon::new(vec![
+            square(),
+            polygon![(x: 3., y: 0.), (x: 5., y: 0.), (x: 5., y: 2.), (x: 3., y: 2.)],
+        ]);
+        let lines = MultiLineString::new(vec![line_string![(x: -1., y: 1.), (x: 6., y: 1.)]]);
+        let pieces = multi_polygon.split(&lines);
+        assert_eq!(pieces.0.len(), 4);
+        assert_eq!(pieces.unsigned_area(), 8.);
+    }
+}
@@ -0,0 +1,469 @@
+use std::cmp::Ordering;
+
+use crate::algorithm::area::twice_signed_ring_area;
+use crate::kernels::{Kernel, Orientation};
+use crate::utils::{coord_pos_relative_to_ring, lex_cmp, CoordPos};
+use crate::winding_order::{Winding, WindingOrder};
+use crate::{
+    BoundingRect, Coord, GeoFloat, LineString, MultiLineString, MultiPolygon, Polygon, Rect,
+};
+
+/// The result of [`polygonize`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygonized<T: GeoFloat> {
+    /// The polygons enclosed by the lines.
+    pub polygons: MultiPolygon<T>,
+    /// The lines with an end not meeting any other line, and the lines which, once those are
+    /// removed, have such an end, and so on.
+    pub dangles: MultiLineString<T>,
+    /// The lines which are on the boundary of no polygon, though both their ends meet other
+    /// lines, such as a line joining two polygons.
+    pub cut_edges: MultiLineString<T>,
+}
+
+/// Build the polygons enclosed by a set of lines, like [PostGIS's `ST_Polygonize`][postgis].
+///
+/// The lines must be noded: they may only meet at their ends. The ends must be exactly equal to
+/// meet, without any tolerance. Use [`node_lines`](crate::node_lines) first to split lines which
+/// cross or overlap. Each face of the planar graph formed by the lines becomes a
+/// polygon, with the faces it encloses, which don't meet it, as holes. Any polygons within
+/// those holes are also returned.
+///
+/// Lines which can't be on the boundary of any polygon are returned as [dangles] and
+/// [cut edges]. Lines repeated in either direction are only used once, and lines with fewer
+/// than two distinct coordinates are left out. The coordinates must not be `NaN`.
+///
+/// [postgis]: https://postgis.net/docs/ST_Polygonize.html
+/// [dangles]: Polygonized::dangles
+/// [cut edges]: Polygonized::cut_edges
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, polygonize, Area, Line};
+///
+/// // a square, split by a line across it, with a spur off a corner
+/// let polygonized = polygonize(vec![
+///     line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.)],
+///     line_string![(x: 2., y: 2.), (x: 0., y: 2.), (x: 0., y: 0.)],
+///     Line::new((0., 0.), (2., 2.)).into(),
+///     Line::new((2., 2.), (3., 3.)).into(),
+/// ]);
+///
+/// assert_eq!(polygonized.polygons.0.len(), 2);
+/// assert_eq!(polygonized.polygons.unsigned_area(), 4.);
+/// assert_eq!(polygonized.dangles.0, vec![line_string![(x: 2., y: 2.), (x: 3., y: 3.)]]);
+/// assert!(polygonized.cut_edges.0.is_empty());
+/// ```
+pub fn polygonize<T, L>(lines: impl IntoIterator<Item = L>) -> Polygonized<T>
+where
+    T: GeoFloat,
+    L: Into<LineString<T>>,
+{
+    let mut graph = Graph::new(lines.into_iter().map(Into::into).collect());
+    let dangles = graph.remove_dangles();
+    let cut_edges = graph.remove_cut_edges();
+
+    let mut shells = vec![];
+    let mut holes = vec![];
+    for ring in graph.rings() {
+        // faces are traced anticlockwise, and the outlines of connected parts clockwise
+        match ring.winding_order() {
+            Some(WindingOrder::CounterClockwise) => shells.push(ring),
+            Some(WindingOrder::Clockwise) => holes.push(ring),
+            None => {}
+        }
+    }
+
+    Polygonized {
+        polygons: MultiPolygon::new(assign_holes(shells, holes)),
+        dangles: MultiLineString::new(graph.take_edges(&dangles)),
+        cut_edges: MultiLineString::new(graph.take_edges(&cut_edges)),
+    }
+}
+
+/// A planar graph, whose nodes are the ends of its edges.
+///
+/// Each edge `i` has two half-edges: `2 * i`, along the edge from its first coordinate, and
+/// `2 * i + 1`, back along it from its last.
+struct Graph<T: GeoFloat> {
+    edges: Vec<LineString<T>>,
+    live: Vec<bool>,
+    /// The half-edges, sorted by their origins, and then anticlockwise by their directions.
+    order: Vec<usize>,
+    /// The index of each half-edge in `order`, and the range of `order` with the same origin.
+    position: Vec<(usize, usize, usize)>,
+}
+
+impl<T: GeoFloat> Graph<T> {
+    fn new(mut edges: Vec<LineString<T>>) -> Self {
+        for edge in edges.iter_mut() {
+            edge.0.dedup();
+        }
+        edges.retain(|edge| edge.0.len() >= 2);
+        // repeated edges are the same either way round, so keep the first of those equal once
+        // directed the same way
+        let mut keys: Vec<(Vec<Coord<T>>, usize)> = edges
+            .iter()
+            .enumerate()
+            .map(|(i, edge)| {
+                let mut key = edge.0.clone();
+                if lex_cmp_coords(key.iter().rev(), &key) == Ordering::Less {
+                    key.reverse();
+                }
+                (key, i)
+            })
+            .collect();
+        keys.sort_by(|(a, i), (b, j)| lex_cmp_coords(a, b).then(i.cmp(j)));
+        keys.dedup_by(|(a, _), (b, _)| a == b);
+        let mut repeated = vec![true; edges.len()];
+        for (_, i) in keys {
+            repeated[i] = false;
+        }
+        let mut repeated = repeated.into_iter();
+        edges.retain(|_| !repeated.next().unwrap());
+
+        let mut graph = Graph {
+            live: vec![true; edges.len()],
+            order: (0..2 * edges.len()).collect(),
+            position: vec![(0, 0, 0); 2 * edges.len()],
+            edges,
+        };
+        let mut order = std::mem::take(&mut graph.order);
+        order.sort_by(|&a, &b| {
+            let (origin, a_towards) = graph.direction(a);
+            lex_cmp(&origin, &graph.direction(b).0)
+                .then_with(|| compare_directions(origin, a_towards, graph.direction(b).1))
+        });
+        let mut start = 0;
+        while start < order.len() {
+            let origin = graph.direction(order[start]).0;
+            let len = order[start..]
+                .iter()
+                .take_while(|&&half_edge| graph.direction(half_edge).0 == origin)
+                .count();
+            for (i, &half_edge) in order.iter().enumerate().skip(start).take(len) {
+                graph.position[half_edge] = (i, start, start + len);
+            }
+            start += len;
+        }
+        graph.order = order;
+        graph
+    }
+
+    /// The origin of a half-edge, and the next coordinate along it.
+    fn direction(&self, half_edge: usize) -> (Coord<T>, Coord<T>) {
+        let coords = &self.edges[half_edge / 2].0;
+        if half_edge % 2 == 0 {
+            (coords[0], coords[1])
+        } else {
+            (coords[coords.len() - 1], coords[coords.len() - 2])
+        }
+    }
+
+    /// The live half-edges from the origin of `half_edge`, including it, in anticlockwise order
+    /// from it.
+    fn around(&self, half_edge: usize) -> impl Iterator<Item = usize> + '_ {
+        let (i, start, end) = self.position[half_edge];
+        self.order[i..end]
+            .iter()
+            .chain(&self.order[start..i])
+            .copied()
+            .filter(move |&other| self.live[other / 2])
+    }
+
+    /// The next half-edge around the face to the left of `half_edge`, which is the last turning
+    /// anticlockwise from its end, so the first clockwise.
+    fn next(&self, half_edge: usize) -> usize {
+        self.around(half_edge ^ 1)
+            .last()
+            .expect("the twin of a live half-edge is live")
+    }
+
+    /// Remove the edges with an end of degree one, repeatedly, returning them.
+    fn remove_dangles(&mut self) -> Vec<usize> {
+        let mut dangles = vec![];
+        let mut candidates: Vec<usize> = (0..2 * self.edges.len()).collect();
+        while let Some(half_edge) = candidates.pop() {
+            if !self.live[half_edge / 2] || self.around(half_edge).nth(1).is_some() {
+                continue;
+            }
+            self.live[half_edge / 2] = false;
+            dangles.push(half_edge / 2);
+            // the other end may now be of degree one
+            candidates.extend(self.around(half_edge ^ 1));
+        }
+        dangles.sort_unstable();
+        dangles
+    }
+
+    /// Remove the edges with the same face on both sides, returning them.
+    fn remove_cut_edges(&mut self) -> Vec<usize> {
+        let faces = self.faces();
+        let cut_edges: Vec<usize> = (0..self.edges.len())
+            .filter(|&edge| self.live[edge] && faces[2 * edge] == faces[2 * edge + 1])
+            .collect();
+        for &edge in &cut_edges {
+            self.live[edge] = false;
+        }
+        cut_edges
+    }
+
+    /// The face to the left of each live half-edge, numbered in order.
+    fn faces(&self) -> Vec<Option<usize>> {
+        let mut faces = vec![None; 2 * self.edges.len()];
+        let mut count = 0;
+        for start in 0..faces.len() {
+            if !self.live[start / 2] || faces[start].is_some() {
+                continue;
+            }
+            let mut half_edge = start;
+            while faces[half_edge].is_none() {
+                faces[half_edge] = Some(count);
+                half_edge = self.next(half_edge);
+            }
+            count += 1;
+        }
+        faces
+    }
+
+    /// The rings around each face.
+    fn rings(&self) -> Vec<LineString<T>> {
+        let mut visited = vec![false; 2 * self.edges.len()];
+        let mut rings = vec![];
+        for start in 0..visited.len() {
+            if !self.live[start / 2] || visited[start] {
+                continue;
+            }
+            let mut coords = vec![self.direction(start).0];
+            let mut half_edge = start;
+            while !visited[half_edge] {
+                visited[half_edge] = true;
+                let edge = &self.edges[half_edge / 2].0;
+                if half_edge % 2 == 0 {
+                    coords.extend(edge.iter().skip(1));
+                } else {
+                    coords.extend(edge.iter().rev().skip(1));
+                }
+                half_edge = self.next(half_edge);
+            }
+            rings.push(LineString::new(coords));
+        }
+        rings
+    }
+
+    fn take_edges(&mut self, edges: &[usize]) -> Vec<LineString<T>> {
+        edges
+            .iter()
+            .map(|&edge| std::mem::replace(&mut self.edges[edge], LineString::new(vec![])))
+            .collect()
+    }
+}
+
+/// Make a polygon of each shell, with the holes it's the smallest shell around.
+///
+/// Holes around no shell are the outlines of connected parts of the graph not in any face.
+fn assign_holes<T: GeoFloat>(
+    shells: Vec<LineString<T>>,
+    holes: Vec<LineString<T>>,
+) -> Vec<Polygon<T>> {
+    let shells: Vec<(Rect<T>, T, LineString<T>)> = shells
+        .into_iter()
+        .filter_map(|shell| {
+            Some((
+                shell.bounding_rect()?,
+                twice_signed_ring_area(&shell),
+                shell,
+            ))
+        })
+        .collect();
+    let mut interiors = vec![vec![]; shells.len()];
+    for hole in holes {
+        // the parts of the graph don't meet, so any coordinate of the hole is inside its shell
+        let coord = hole.0[0];
+        let smallest = shells
+            .iter()
+            .enumerate()
+            .filter(|(_, (rect, _, shell))| {
+                rect.min().x <= coord.x
+                    && coord.x <= rect.max().x
+                    && rect.min().y <= coord.y
+                    && coord.y <= rect.max().y
+                    && coord_pos_relative_to_ring(coord, shell) == CoordPos::Inside
+            })
+            .min_by(|(_, (_, a, _)), (_, (_, b, _))| a.partial_cmp(b).unwrap());
+        if let Some((i, _)) = smallest {
+            interiors[i].push(hole);
+        }
+    }
+    shells
+        .into_iter()
+        .zip(interiors)
+        .map(|((_, _, shell), interiors)| Polygon::new(shell, interiors))
+        .collect()
+}
+
+/// Compare the directions from `origin` towards `a` and `b`, anticlockwise from the positive
+/// x axis.
+fn compare_directions<T: GeoFloat>(origin: Coord<T>, a: Coord<T>, b: Coord<T>) -> Ordering {
+    let quadrant = |towards: Coord<T>| {
+        let (x, y) = (towards.x - origin.x, towards.y - origin.y);
+        if x > T::zero() && y >= T::zero() {
+            0
+        } else if x <= T::zero() && y > T::zero() {
+            1
+        } else if x < T::zero() && y <= T::zero() {
+            2
+        } else {
+            3
+        }
+    };
+    quadrant(a)
+        .cmp(&quadrant(b))
+        .then_with(|| match T::Ker::orient2d(origin, a, b) {
+            Orientation::CounterClockwise => Ordering::Less,
+            Orientation::Clockwise => Ordering::Greater,
+            Orientation::Collinear => Ordering::Equal,
+        })
+}
+
+/// Compare sequences of coordinates lexicographically, by [`lex_cmp`].
+fn lex_cmp_coords<'a, T: GeoFloat + 'a>(
+    a: impl IntoIterator<Item = &'a Coord<T>>,
+    b: impl IntoIterator<Item = &'a Coord<T>>,
+) -> Ordering {
+    let mut b = b.into_iter();
+    for a in a {
+        match b.next().map(|b| lex_cmp(a, b)) {
+            None => return Ordering::Greater,
+            Some(Ordering::Equal) => {}
+            Some(ordering) => return ordering,
+        }
+    }
+    if b.next().is_some() {
+        Ordering::Less
+    } else {
+        Ordering::Equal
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, polygon, Area, Line};
+
+    fn unit_grid(n: i32) -> Vec<Line<f64>> {
+        let mut lines = vec![];
+        for i in 0..=n {
+            for j in 0..n {
+                let (i, j) = (i as f64, j as f64);
+                lines.push(Line::new((i, j), (i, j + 1.)));
+                lines.push(Line::new((j, i), (j + 1., i)));
+            }
+        }
+        lines
+    }
+
+    #[test]
+    fn grid() {
+        let polygonized = polygonize(unit_grid(3));
+        assert_eq!(polygonized.polygons.0.len(), 9);
+        for polygon in &polygonized.polygons {
+            assert_eq!(polygon.unsigned_area(), 1.);
+            assert!(polygon.interiors().is_empty());
+        }
+        assert!(polygonized.dangles.0.is_empty());
+        assert!(polygonized.cut_edges.0.is_empty());
+    }
+
+    #[test]
+    fn crossing_grid() {
+        // long lines crossing each other, overhanging the grid's cells
+        let mut lines = vec![];
+        for i in 0..=3 {
+            let i = i as f64;
+            lines.push(line_string![(x: i, y: -0.5), (x: i, y: 3.5)]);
+            lines.push(line_string![(x: -0.5, y: i), (x: 3.5, y: i)]);
+        }
+        let polygonized = polygonize(crate::node_lines(lines));
+        assert_eq!(polygonized.polygons.0.len(), 9);
+        for polygon in &polygonized.polygons {
+            assert_eq!(polygon.unsigned_area(), 1.);
+            assert_eq!(polygon.exterior().0.len(), 5);
+            assert!(polygon.interiors().is_empty());
+        }
+        assert_eq!(polygonized.dangles.0.len(), 16);
+        assert!(polygonized.cut_edges.0.is_empty());
+    }
+
+    #[test]
+    fn dangles() {
+        let mut lines = unit_grid(1);
+        // a spur of two lines, and a line meeting nothing
+        lines.push(Line::new((1., 1.), (2., 1.)));
+        lines.push(Line::new((2., 1.), (2., 2.)));
+        lines.push(Line::new((5., 5.), (6., 5.)));
+        let polygonized = polygonize(lines);
+        assert_eq!(polygonized.polygons.0.len(), 1);
+        assert_eq!(polygonized.polygons.unsigned_area(), 1.);
+        assert_eq!(polygonized.dangles.0.len(), 3);
+        assert!(polygonized
+            .dangles
+            .0
+            .contains(&line_string![(x: 1., y: 1.), (x: 2., y: 1.)]));
+        assert!(polygonized.cut_edges.0.is_empty());
+    }
+
+    #[test]
+    fn cut_edges() {
+        let polygonized = polygonize(vec![
+            line_string![(x: 1., y: 1.), (x: 0., y: 1.), (x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+            line_string![(x: 1., y: 1.), (x: 3., y: 3.)],
+            line_string![(x: 3., y: 3.), (x: 4., y: 3.), (x: 4., y: 4.), (x: 3., y: 4.), (x: 3., y: 3.)],
+        ]);
+        assert_eq!(polygonized.polygons.0.len(), 2);
+        assert!(polygonized.dangles.0.is_empty());
+        assert_eq!(
+            polygonized.cut_edges.0,
+            vec![line_string![(x: 1., y: 1.), (x: 3., y: 3.)]]
+        );
+    }
+
+    #[test]
+    fn holes() {
+        let outer = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.), (x: 0., y: 0.)];
+        // clockwise, to check the winding of the input doesn't matter
+        let inner = line_string![(x: 2., y: 2.), (x: 2., y: 4.), (x: 4., y: 4.), (x: 4., y: 2.), (x: 2., y: 2.)];
+        let polygonized = polygonize(vec![outer, inner.clone()]);
+        let mut polygons: Vec<Polygon<f64>> = polygonized.polygons.0;
+        polygons.sort_by(|a, b| a.unsigned_area().total_cmp(&b.unsigned_area()));
+        assert_eq!(polygons.len(), 2);
+        assert_eq!(polygons[0].unsigned_area(), 4.);
+        assert!(polygons[0].interiors().is_empty());
+        assert_eq!(polygons[1].unsigned_area(), 96.);
+        assert_eq!(polygons[1].interiors().len(), 1);
+        assert_eq!(polygons[1].interiors(), &[inner]);
+    }
+
+    #[test]
+    fn repeated_lines() {
+        let mut lines = unit_grid(1);
+        lines.extend(unit_grid(1));
+        lines.extend(
+            unit_grid(1)
+                .iter()
+                .map(|line| Line::new(line.end, line.start)),
+        );
+        // degenerate
+        lines.push(Line::new((0., 0.), (0., 0.)));
+        let polygonized = polygonize(lines);
+        assert_eq!(polygonized.polygons.0.len(), 1);
+        assert_eq!(
+            polygonized.polygons.0[0].unsigned_area(),
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)]
+                .unsigned_area()
+        );
+        assert!(polygonized.dangles.0.is_empty());
+        assert!(polygonized.cut_edges.0.is_empty());
+    }
+}
@@ -0,0 +1,861 @@
+use std::borrow::Cow;
+
+use rstar::primitives::GeomWithData;
+use rstar::{RTree, RTreeObject, AABB};
+
+use crate::coordinate_position::{CoordPos, CoordinatePosition};
+use crate::dimensions::Dimensions;
+use crate::kernels::{Kernel, Orientation};
+use crate::relate::IntersectionMatrix;
+use crate::{
+    BoundingRect, Contains, Coord, GeoFloat, Geometry, GeometryCollection, GeometryCow, Intersects,
+    Line, LineString, MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Relate,
+    Triangle,
+};
+
+/// A geometry with an index of its segments, to speed up testing many other geometries against
+/// it.
+///
+/// Preparing a geometry builds an [R-tree] of its edges once, so that [`Contains`],
+/// [`Intersects`] and [`Relate`] only need to look at the few edges near the geometry they're
+/// testing, rather than at every edge. This is similar to `PreparedGeometry` in [JTS]. The
+/// answers are the same as those for the unprepared geometry.
+///
+/// Testing whether a polygonal geometry (a `Polygon`, `MultiPolygon`, `Rect` or `Triangle`)
+/// contains, or relates to, a `Point` or `MultiPoint` only uses the index, as does finding the
+/// [`CoordinatePosition`] of a coordinate relative to it, and testing whether any geometry
+/// intersects another. Other tests use the index to give a quick answer
+/// where they can, such as when a vertex of the other geometry is outside the polygons, and
+/// otherwise fall back to [`Relate`].
+///
+/// The geometry can be borrowed, as in `PreparedGeometry::from(&polygon)`, or owned.
+///
+/// Preparing takes longer than a single test, so it's worth it when one complex geometry, such
+/// as a `Polygon` with many vertices, is tested many times.
+///
+/// [R-tree]: https://en.wikipedia.org/wiki/R-tree
+/// [JTS]: https://locationtech.github.io/jts/
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, polygon, Contains, Intersects, PreparedGeometry, Relate};
+///
+/// let polygon = polygon![
+///     (x: 0., y: 0.),
+///     (x: 10., y: 0.),
+///     (x: 10., y: 10.),
+///     (x: 5., y: 5.),
+///     (x: 0., y: 10.),
+/// ];
+/// let prepared = PreparedGeometry::from(&polygon);
+///
+/// for point in [point!(x: 2., y: 2.), point!(x: 5., y: 8.), point!(x: 10., y: 5.)] {
+///     assert_eq!(prepared.contains(&point), polygon.contains(&point));
+/// }
+///
+/// let line_string = line_string![(x: 5., y: 6.), (x: 5., y: 20.)];
+/// assert!(!prepared.intersects(&line_string));
+/// assert!(prepared.relate(&line_string).is_disjoint());
+/// ```
+#[derive(Clone, Debug)]
+pub struct PreparedGeometry<'a, T: GeoFloat> {
+    geometry: GeometryCow<'a, T>,
+    bounding_rect: Option<Rect<T>>,
+    /// Whether `geometry` is a `Polygon`, `MultiPolygon`, `Rect` or `Triangle`.
+    polygonal: bool,
+    points: RTree<Point<T>>,
+    /// The segments of all the lines and rings, with the polygon that each ring belongs to.
+    segments: RTree<GeomWithData<Line<T>, Option<usize>>>,
+    /// A coordinate on each point, line and polygon.
+    representatives: Vec<Coord<T>>,
+}
+
+impl<'a, T: GeoFloat> PreparedGeometry<'a, T> {
+    /// Prepare `geometry`, by indexing its segments.
+    ///
+    /// `geometry` may be borrowed, e.g. a `&Polygon`, or owned.
+    pub fn new(geometry: impl Into<Self>) -> Self {
+        geometry.into()
+    }
+
+    /// The position of each of `coords` relative to the geometry, the same as calling
+    /// [`coordinate_position`](CoordinatePosition::coordinate_position) on the unprepared
+    /// geometry for each of them.
+    ///
+    /// For polygonal geometries, this only uses the index, so classifying many coordinates
+    /// against one complex polygon is much faster than with the unprepared polygon.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::coordinate_position::CoordPos;
+    /// use geo::{coord, polygon, PreparedGeometry};
+    ///
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+    /// let prepared = PreparedGeometry::from(&polygon);
+    ///
+    /// let coords = [coord! { x: 2., y: 2. }, coord! { x: 4., y: 1. }, coord! { x: 5., y: 1. }];
+    /// assert_eq!(
+    ///     prepared.coordinate_positions(&coords),
+    ///     vec![CoordPos::Inside, CoordPos::OnBoundary, CoordPos::Outside]
+    /// );
+    /// ```
+    pub fn coordinate_positions(&self, coords: &[Coord<T>]) -> Vec<CoordPos> {
+        coords
+            .iter()
+            .map(|coord| self.coordinate_position(coord))
+            .collect()
+    }
+
+    fn prepare(geometry: GeometryCow<'a, T>) -> Self {
+        let mut points = vec![];
+        let mut segments = vec![];
+        let mut representatives = vec![];
+        let mut polygon_count = 0;
+        for part in parts(&geometry) {
+            if let Part::Point(coord) = part {
+                points.push(Point::from(coord));
+            }
+            let polygon = match part {
+                Part::Polygon(_) => {
+                    polygon_count += 1;
+                    Some(polygon_count - 1)
+                }
+                _ => None,
+            };
+            segments.extend(part.lines().map(|line| GeomWithData::new(line, polygon)));
+            representatives.extend(part.representative());
+        }
+
+        PreparedGeometry {
+            bounding_rect: geometry.bounding_rect(),
+            polygonal: matches!(
+                geometry,
+                GeometryCow::Polygon(_)
+                    | GeometryCow::MultiPolygon(_)
+                    | GeometryCow::Rect(_)
+                    | GeometryCow::Triangle(_)
+            ),
+            geometry,
+            points: RTree::bulk_load(points),
+            segments: RTree::bulk_load(segments),
+            representatives,
+        }
+    }
+
+    /// Whether the geometry contains `point`.
+    ///
+    /// This uses the index for polygonal geometries. Other geometries can't contain a point
+    /// unless it's on one of their edges, so they are tested directly once the point is found
+    /// to be within their bounding rect.
+    fn contains_point(&self, point: &Point<T>) -> bool {
+        if self.polygonal {
+            return self.area_position(point.0) == AreaPosition::Inside;
+        }
+        match self.bounding_rect {
+            Some(rect) if rect.intersects(point) => self.geometry.contains(point),
+            _ => false,
+        }
+    }
+
+    fn contains_geometry(&self, other: &GeometryCow<T>) -> bool {
+        // everything in `other` must be within the bounding rect
+        match (self.bounding_rect, other.bounding_rect()) {
+            (Some(rect), Some(other_rect)) if covers_rect(rect, other_rect) => {}
+            _ => return false,
+        }
+        if !self.polygonal {
+            return self.geometry.relate(other).is_contains();
+        }
+
+        let other_parts = parts(other);
+        let mut positions = other_parts
+            .iter()
+            .flat_map(|part| part.coords())
+            .map(|coord| self.area_position(coord));
+        match other {
+            // the points must all be in the polygons, with at least one in their interior
+            GeometryCow::Point(_) | GeometryCow::MultiPoint(_) => {
+                let mut inside = false;
+                for position in positions {
+                    match position {
+                        AreaPosition::Outside => return false,
+                        AreaPosition::Inside => inside = true,
+                        AreaPosition::Boundary => {}
+                    }
+                }
+                inside
+            }
+            _ => {
+                if positions.any(|position| position == AreaPosition::Outside) {
+                    return false;
+                }
+                self.geometry.relate(other).is_contains()
+            }
+        }
+    }
+
+    fn intersects_geometry(&self, other: &GeometryCow<T>) -> bool {
+        match (self.bounding_rect, other.bounding_rect()) {
+            (Some(rect), Some(other_rect)) if rect.intersects(&other_rect) => {}
+            _ => return false,
+        }
+
+        let other_parts = parts(other);
+        // any point or edge of `other` touching a point or edge of `self`
+        for part in &other_parts {
+            if let Part::Point(coord) = part {
+                if self.touches_coord(*coord) || self.area_position(*coord) != AreaPosition::Outside
+                {
+                    return true;
+                }
+            }
+            if part.lines().any(|line| self.touches_line(line)) {
+                return true;
+            }
+        }
+
+        // no edges cross, so each part of either geometry is either entirely inside the other or
+        // entirely outside it
+        for part in &other_parts {
+            if let Some(coord) = part.representative() {
+                if self.area_position(coord) != AreaPosition::Outside {
+                    return true;
+                }
+            }
+            if let Part::Polygon(polygon) = part {
+                let rect = match polygon.bounding_rect() {
+                    Some(rect) => rect,
+                    None => continue,
+                };
+                let inside = self
+                    .representatives
+                    .iter()
+                    .any(|coord| rect.intersects(coord) && polygon.intersects(coord));
+                if inside {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    fn relate_geometry(&self, other: &GeometryCow<T>) -> IntersectionMatrix {
+        let point = match other {
+            GeometryCow::Point(point) if self.polygonal && self.bounding_rect.is_some() => point,
+            _ => return self.geometry.relate(other),
+        };
+        // the point is in exactly one of the interior, boundary or exterior of the polygons
+        let mut matrix = IntersectionMatrix::empty();
+        let position = match self.area_position(point.0) {
+            AreaPosition::Inside => CoordPos::Inside,
+            AreaPosition::Boundary => CoordPos::OnBoundary,
+            AreaPosition::Outside => CoordPos::Outside,
+        };
+        matrix.set(position, CoordPos::Inside, Dimensions::ZeroDimensional);
+        matrix.set(
+            CoordPos::Inside,
+            CoordPos::Outside,
+            Dimensions::TwoDimensional,
+        );
+        matrix.set(
+            CoordPos::OnBoundary,
+            CoordPos::Outside,
+            Dimensions::OneDimensional,
+        );
+        matrix.set(
+            CoordPos::Outside,
+            CoordPos::Outside,
+            Dimensions::TwoDimensional,
+        );
+        matrix
+    }
+
+    /// Whether `coord` is on one of the points or edges.
+    fn touches_coord(&self, coord: Coord<T>) -> bool {
+        let point = Point::from(coord);
+        self.points.locate_at_point(&point).is_some()
+            || self
+                .segments
+                .locate_in_envelope_intersecting(&AABB::from_point(point))
+                .any(|segment| segment.geom().intersects(&coord))
+    }
+
+    /// Whether `line` crosses or touches one of the points or edges.
+    fn touches_line(&self, line: Line<T>) -> bool {
+        let envelope = line.envelope();
+        self.segments
+            .locate_in_envelope_intersecting(&envelope)
+            .any(|segment| segment.geom().intersects(&line))
+            || self
+                .points
+                .locate_in_envelope_intersecting(&envelope)
+                .any(|point| line.intersects(point))
+    }
+
+    /// Where `coord` is relative to the polygons.
+    fn area_position(&self, coord: Coord<T>) -> AreaPosition {
+        let polygons = self.polygon_positions(coord);
+        if polygons.iter().any(|(_, odd, boundary)| *odd && !boundary) {
+            AreaPosition::Inside
+        } else if polygons.iter().any(|(_, _, boundary)| *boundary) {
+            AreaPosition::Boundary
+        } else {
+            AreaPosition::Outside
+        }
+    }
+
+    /// For each polygon with an edge crossing the ray from `coord` towards positive `x`, or
+    /// through `coord`: the polygon, whether an odd number of its edges cross the ray, and
+    /// whether `coord` is on one of its edges.
+    fn polygon_positions(&self, coord: Coord<T>) -> Vec<(usize, bool, bool)> {
+        let max_x = match self.bounding_rect {
+            Some(rect) if rect.intersects(&coord) => rect.max().x,
+            _ => return vec![],
+        };
+        let ray = AABB::from_corners(Point::from(coord), Point::new(max_x, coord.y));
+
+        let mut polygons: Vec<(usize, bool, bool)> = vec![];
+        for segment in self.segments.locate_in_envelope_intersecting(&ray) {
+            let polygon = match segment.data {
+                Some(polygon) => polygon,
+                None => continue,
+            };
+            let index = match polygons.iter().position(|(p, _, _)| *p == polygon) {
+                Some(index) => index,
+                None => {
+                    polygons.push((polygon, false, false));
+                    polygons.len() - 1
+                }
+            };
+            let line = *segment.geom();
+            if line.intersects(&coord) {
+                polygons[index].2 = true;
+            } else if crosses_ray(line, coord) {
+                polygons[index].1 = !polygons[index].1;
+            }
+        }
+        polygons
+    }
+}
+
+impl<T: GeoFloat> Contains<Point<T>> for GeometryCow<'_, T> {
+    crate::geometry_cow_delegate_impl! {
+        fn contains(&self, point: &Point<T>) -> bool;
+    }
+}
+
+impl<'a, T: GeoFloat> Contains<Point<T>> for PreparedGeometry<'a, T> {
+    fn contains(&self, point: &Point<T>) -> bool {
+        self.contains_point(point)
+    }
+}
+
+impl<'a, T: GeoFloat> CoordinatePosition for PreparedGeometry<'a, T> {
+    type Scalar = T;
+
+    fn calculate_coordinate_position(
+        &self,
+        coord: &Coord<T>,
+        is_inside: &mut bool,
+        boundary_count: &mut usize,
+    ) {
+        if !self.polygonal {
+            if let Some(rect) = self.bounding_rect {
+                if rect.intersects(coord) {
+                    self.geometry
+                        .calculate_coordinate_position(coord, is_inside, boundary_count);
+                }
+            }
+            return;
+        }
+        // as for each polygon of the unprepared geometry
+        for (_, odd, boundary) in self.polygon_positions(*coord) {
+            if boundary {
+                *boundary_count += 1;
+            } else if odd {
+                *is_inside = true;
+            }
+        }
+    }
+}
+
+macro_rules! impl_prepared_geometry {
+    ($($type:ident),*) => {
+        $(
+            impl<'a, T: GeoFloat> From<&'a $type<T>> for PreparedGeometry<'a, T> {
+                fn from(geometry: &'a $type<T>) -> Self {
+                    Self::prepare(GeometryCow::from(geometry))
+                }
+            }
+
+            impl<'a, T: GeoFloat> From<$type<T>> for PreparedGeometry<'a, T> {
+                fn from(geometry: $type<T>) -> Self {
+                    Self::prepare(GeometryCow::$type(Cow::Owned(geometry)))
+                }
+            }
+
+            impl<'a, T: GeoFloat> Intersects<$type<T>> for PreparedGeometry<'a, T> {
+                fn intersects(&self, other: &$type<T>) -> bool {
+                    self.intersects_geometry(&GeometryCow::from(other))
+                }
+            }
+
+            impl<'a, T: GeoFloat> Relate<T, $type<T>> for PreparedGeometry<'a, T> {
+                fn relate(&self, other: &$type<T>) -> IntersectionMatrix {
+                    self.relate_geometry(&GeometryCow::from(other))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_prepared_geometry_contains {
+    ($($type:ident),*) => {
+        $(
+            impl<'a, T: GeoFloat> Contains<$type<T>> for PreparedGeometry<'a, T> {
+                fn contains(&self, other: &$type<T>) -> bool {
+                    self.contains_geometry(&GeometryCow::from(other))
+                }
+            }
+        )*
+    };
+}
+
+impl_prepared_geometry!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Rect,
+    Triangle
+);
+impl_prepared_geometry_contains!(
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+    GeometryCollection,
+    Rect,
+    Triangle
+);
+
+impl<'a, T: GeoFloat> From<&'a Geometry<T>> for PreparedGeometry<'a, T> {
+    fn from(geometry: &'a Geometry<T>) -> Self {
+        Self::prepare(GeometryCow::from(geometry))
+    }
+}
+
+impl<'a, T: GeoFloat> From<Geometry<T>> for PreparedGeometry<'a, T> {
+    fn from(geometry: Geometry<T>) -> Self {
+        Self::prepare(match geometry {
+            Geometry::Point(g) => GeometryCow::Point(Cow::Owned(g)),
+            Geometry::Line(g) => GeometryCow::Line(Cow::Owned(g)),
+            Geometry::LineString(g) => GeometryCow::LineString(Cow::Owned(g)),
+            Geometry::Polygon(g) => GeometryCow::Polygon(Cow::Owned(g)),
+            Geometry::MultiPoint(g) => GeometryCow::MultiPoint(Cow::Owned(g)),
+            Geometry::MultiLineString(g) => GeometryCow::MultiLineString(Cow::Owned(g)),
+            Geometry::MultiPolygon(g) => GeometryCow::MultiPolygon(Cow::Owned(g)),
+            Geometry::GeometryCollection(g) => GeometryCow::GeometryCollection(Cow::Owned(g)),
+            Geometry::Rect(g) => GeometryCow::Rect(Cow::Owned(g)),
+            Geometry::Triangle(g) => GeometryCow::Triangle(Cow::Owned(g)),
+        })
+    }
+}
+
+impl<'a, T: GeoFloat> Contains<Geometry<T>> for PreparedGeometry<'a, T> {
+    fn contains(&self, other: &Geometry<T>) -> bool {
+        match other {
+            Geometry::Point(point) => self.contains_point(point),
+            _ => self.contains_geometry(&GeometryCow::from(other)),
+        }
+    }
+}
+
+impl<'a, T: GeoFloat> Intersects<Geometry<T>> for PreparedGeometry<'a, T> {
+    fn intersects(&self, other: &Geometry<T>) -> bool {
+        self.intersects_geometry(&GeometryCow::from(other))
+    }
+}
+
+impl<'a, T: GeoFloat> Relate<T, Geometry<T>> for PreparedGeometry<'a, T> {
+    fn relate(&self, other: &Geometry<T>) -> IntersectionMatrix {
+        self.relate_geometry(&GeometryCow::from(other))
+    }
+}
+
+/// Whether `rect` covers `other`.
+fn covers_rect<T: GeoFloat>(rect: Rect<T>, other: Rect<T>) -> bool {
+    rect.min().x <= other.min().x
+        && rect.min().y <= other.min().y
+        && rect.max().x >= other.max().x
+        && rect.max().y >= other.max().y
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AreaPosition {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// Whether `line` crosses the ray from `coord` towards positive `x`, counting a vertex on the ray
+/// as above it, so that a ray through a vertex crosses exactly one of the edges which meet there.
+fn crosses_ray<T: GeoFloat>(line: Line<T>, coord: Coord<T>) -> bool {
+    let (start, end) = (line.start, line.end);
+    if (start.y > coord.y) == (end.y > coord.y) {
+        return false;
+    }
+    let orientation = T::Ker::orient2d(start, end, coord);
+    if end.y > start.y {
+        orientation == Orientation::CounterClockwise
+    } else {
+        orientation == Orientation::Clockwise
+    }
+}
+
+/// A single point, line, line string or polygon of a geometry.
+enum Part<'a, T: GeoFloat> {
+    Point(Coord<T>),
+    Line(Line<T>),
+    LineString(&'a LineString<T>),
+    Polygon(Cow<'a, Polygon<T>>),
+}
+
+impl<'a, T: GeoFloat> Part<'a, T> {
+    fn lines(&self) -> Box<dyn Iterator<Item = Line<T>> + '_> {
+        match self {
+            Part::Point(_) => Box::new(std::iter::empty()),
+            Part::Line(line) => Box::new(std::iter::once(*line)),
+            Part::LineString(line_string) => Box::new(line_string.lines()),
+            Part::Polygon(polygon) => Box::new(
+                std::iter::once(polygon.exterior())
+                    .chain(polygon.interiors())
+                    .flat_map(|ring| ring.lines()),
+            ),
+        }
+    }
+
+    /// The vertices of the part.
+    fn coords(&self) -> Box<dyn Iterator<Item = Coord<T>> + '_> {
+        match self {
+            Part::Point(coord) => Box::new(std::iter::once(*coord)),
+            Part::Line(line) => Box::new([line.start, line.end].into_iter()),
+            Part::LineString(line_string) => Box::new(line_string.coords().copied()),
+            Part::Polygon(polygon) => Box::new(
+                std::iter::once(polygon.exterior())
+                    .chain(polygon.interiors())
+                    .flat_map(|ring| ring.coords().copied()),
+            ),
+        }
+    }
+
+    /// A coordinate on the part, if it isn't empty.
+    fn representative(&self) -> Option<Coord<T>> {
+        match self {
+            Part::Point(coord) => Some(*coord),
+            Part::Line(line) => Some(line.start),
+            Part::LineString(line_string) => line_string.0.first().copied(),
+            Part::Polygon(polygon) => polygon.exterior().0.first().copied(),
+        }
+    }
+}
+
+fn parts<'a, T: GeoFloat>(geometry: &'a GeometryCow<'_, T>) -> Vec<Part<'a, T>> {
+    let mut parts = vec![];
+    match geometry {
+        GeometryCow::Point(point) => parts.push(Part::Point(point.0)),
+        GeometryCow::Line(line) => parts.push(Part::Line(**line)),
+        GeometryCow::LineString(line_string) => parts.push(Part::LineString(line_string)),
+        GeometryCow::Polygon(polygon) => parts.push(Part::Polygon(Cow::Borrowed(polygon))),
+        GeometryCow::MultiPoint(multi_point) => {
+            parts.extend(multi_point.iter().map(|point| Part::Point(point.0)))
+        }
+        GeometryCow::MultiLineString(multi_line_string) => {
+            parts.extend(multi_line_string.iter().map(Part::LineString))
+        }
+        GeometryCow::MultiPolygon(multi_polygon) => parts.extend(
+            multi_polygon
+                .iter()
+                .map(|polygon| Part::Polygon(Cow::Borrowed(polygon))),
+        ),
+        GeometryCow::Rect(rect) => parts.push(Part::Polygon(Cow::Owned(rect.to_polygon()))),
+        GeometryCow::Triangle(triangle) => {
+            parts.push(Part::Polygon(Cow::Owned(triangle.to_polygon())))
+        }
+        GeometryCow::GeometryCollection(collection) => {
+            for geometry in collection.iter() {
+                push_parts(geometry, &mut parts);
+            }
+        }
+    }
+    parts
+}
+
+fn push_parts<'a, T: GeoFloat>(geometry: &'a Geometry<T>, parts: &mut Vec<Part<'a, T>>) {
+    match geometry {
+        Geometry::Point(point) => parts.push(Part::Point(point.0)),
+        Geometry::Line(line) => parts.push(Part::Line(*line)),
+        Geometry::LineString(line_string) => parts.push(Part::LineString(line_string)),
+        Geometry::Polygon(polygon) => parts.push(Part::Polygon(Cow::Borrowed(polygon))),
+        Geometry::MultiPoint(multi_point) => {
+            parts.extend(multi_point.iter().map(|point| Part::Point(point.0)))
+        }
+        Geometry::MultiLineString(multi_line_string) => {
+            parts.extend(multi_line_string.iter().map(Part::LineString))
+        }
+        Geometry::MultiPolygon(multi_polygon) => parts.extend(
+            multi_polygon
+                .iter()
+                .map(|polygon| Part::Polygon(Cow::Borrowed(polygon))),
+        ),
+        Geometry::Rect(rect) => parts.push(Part::Polygon(Cow::Owned(rect.to_polygon()))),
+        Geometry::Triangle(triangle) => {
+            parts.push(Part::Polygon(Cow::Owned(triangle.to_polygon())))
+        }
+        Geometry::GeometryCollection(collection) => {
+            for geometry in collection {
+                push_parts(geometry, parts);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        line_string, point, polygon, GeometryCollection, MultiPoint, MultiPolygon, Translate,
+        Triangle,
+    };
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn assert_matches_unprepared(geometry: &Geometry<f64>, others: &[Geometry<f64>]) {
+        let prepared = PreparedGeometry::from(geometry);
+        for other in others {
+            assert_eq!(
+                prepared.intersects(other),
+                geometry.intersects(other),
+                "{:?} intersects {:?}",
+                geometry,
+                other
+            );
+            if !relate_supports(geometry) || !relate_supports(other) {
+                continue;
+            }
+            assert_eq!(
+                prepared.contains(other),
+                geometry.relate(other).is_contains(),
+                "{:?} contains {:?}",
+                geometry,
+                other
+            );
+            assert_eq!(
+                prepared.relate(other),
+                geometry.relate(other),
+                "{:?} relate {:?}",
+                geometry,
+                other
+            );
+            if let Geometry::Point(point) = other {
+                assert_eq!(
+                    prepared.contains(point),
+                    geometry.contains(point),
+                    "{:?} contains {:?}",
+                    geometry,
+                    point
+                );
+            }
+        }
+    }
+
+    /// The multi-polygon of the test geometries has overlapping polygons, which `Relate` doesn't
+    /// support.
+    fn relate_supports(geometry: &Geometry<f64>) -> bool {
+        !matches!(geometry, Geometry::MultiPolygon(_))
+    }
+
+    fn test_geometries() -> Vec<Geometry<f64>> {
+        let donut = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 8., y: 2.), (x: 8., y: 8.), (x: 2., y: 8.)]],
+        );
+        vec![
+            donut.clone().into(),
+            MultiPolygon::new(vec![
+                donut.clone(),
+                // overlapping the first
+                polygon![(x: 5., y: 5.), (x: 15., y: 5.), (x: 15., y: 15.)],
+                // inside its hole
+                polygon![(x: 4., y: 4.), (x: 6., y: 4.), (x: 5., y: 6.)],
+            ])
+            .into(),
+            Rect::new((1., 1.), (9., 3.)).into(),
+            Triangle::new((0., 0.).into(), (10., 0.).into(), (0., 10.).into()).into(),
+            line_string![(x: 0., y: 5.), (x: 5., y: 5.), (x: 5., y: 12.)].into(),
+            Line::new((3., 3.), (7., 7.)).into(),
+            point!(x: 5., y: 5.).into(),
+            MultiPoint::new(vec![point!(x: 1., y: 1.), point!(x: 9., y: 9.)]).into(),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                point!(x: 20., y: 20.).into(),
+                donut.translate(3., 3.).into(),
+            ])),
+        ]
+    }
+
+    #[test]
+    fn matches_unprepared() {
+        let geometries = test_geometries();
+        let mut others = geometries.clone();
+        for x in 0..=24 {
+            for y in 0..=24 {
+                others.push(point!(x: x as f64 / 2. - 1., y: y as f64 / 2. - 1.).into());
+            }
+        }
+        others.push(line_string![(x: 2.5, y: 2.5), (x: 7.5, y: 7.5)].into());
+        others.push(line_string![(x: -5., y: 5.), (x: 20., y: 5.)].into());
+        others.push(polygon![(x: 3., y: 3.), (x: 4., y: 3.), (x: 4., y: 4.)].into());
+        others.push(polygon![(x: -1., y: -1.), (x: 30., y: -1.), (x: 30., y: 30.)].into());
+        others.push(point!(x: 100., y: 100.).into());
+
+        for geometry in &geometries {
+            assert_matches_unprepared(geometry, &others);
+        }
+    }
+
+    #[test]
+    fn coordinate_positions_match_unprepared() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut next = move || rng.gen::<f64>();
+        // random coordinates, and a grid through many vertices and edges
+        let mut coords: Vec<Coord<f64>> = (0..2000)
+            .map(|_| Coord {
+                x: next() * 26. - 3.,
+                y: next() * 26. - 3.,
+            })
+            .collect();
+        for x in 0..=24 {
+            for y in 0..=24 {
+                coords.push(Coord {
+                    x: x as f64 / 2. - 1.,
+                    y: y as f64 / 2. - 1.,
+                });
+            }
+        }
+
+        for geometry in test_geometries() {
+            let prepared = PreparedGeometry::from(&geometry);
+            let expected: Vec<CoordPos> = coords
+                .iter()
+                .map(|coord| geometry.coordinate_position(coord))
+                .collect();
+            assert_eq!(
+                prepared.coordinate_positions(&coords),
+                expected,
+                "{geometry:?}"
+            );
+        }
+
+        let norway: Polygon<f64> = Polygon::new(geo_test_fixtures::norway_main(), vec![]);
+        let rect = norway.bounding_rect().unwrap();
+        let mut coords: Vec<Coord<f64>> = (0..2000)
+            .map(|_| Coord {
+                x: rect.min().x + rect.width() * next(),
+                y: rect.min().y + rect.height() * next(),
+            })
+            .collect();
+        coords.extend(norway.exterior().coords().step_by(5));
+        let positions = PreparedGeometry::from(&norway).coordinate_positions(&coords);
+        for (coord, position) in coords.iter().zip(&positions) {
+            assert_eq!(*position, norway.coordinate_position(coord), "{coord:?}");
+        }
+        // the test covers every outcome
+        for position in [CoordPos::Inside, CoordPos::OnBoundary, CoordPos::Outside] {
+            assert!(positions.contains(&position));
+        }
+    }
+
+    #[test]
+    fn borrowed_and_owned() {
+        let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+        let borrowed = PreparedGeometry::from(&polygon);
+        let owned = PreparedGeometry::new(polygon.clone());
+        let multi_polygon = MultiPolygon::new(vec![polygon.clone()]);
+        let from_multi_polygon = PreparedGeometry::from(&multi_polygon);
+        for prepared in [&borrowed, &owned, &from_multi_polygon] {
+            assert!(prepared.contains(&point!(x: 2., y: 2.)));
+            assert!(!prepared.contains(&point!(x: 4., y: 2.)));
+            assert!(prepared.intersects(&point!(x: 4., y: 2.)));
+            assert!(prepared.contains(&Rect::new((1., 1.), (3., 3.))));
+            assert!(!prepared.contains(&Rect::new((1., 1.), (5., 3.))));
+            assert!(prepared
+                .relate(&line_string![(x: 2., y: 2.), (x: 6., y: 2.)])
+                .is_crosses());
+        }
+    }
+
+    #[test]
+    fn empty_geometries() {
+        let empty: Geometry<f64> = Polygon::new(LineString::new(vec![]), vec![]).into();
+        let prepared = PreparedGeometry::new(empty.clone());
+        assert!(!prepared.contains(&point!(x: 0., y: 0.)));
+        assert!(!prepared.intersects(&point!(x: 0., y: 0.)));
+        assert_eq!(
+            prepared.relate(&point!(x: 0., y: 0.)),
+            empty.relate(&Geometry::from(point!(x: 0., y: 0.)))
+        );
+
+        let prepared = PreparedGeometry::new(Rect::new((0., 0.), (1., 1.)));
+        assert!(!prepared.intersects(&empty));
+        assert!(!prepared.contains(&empty));
+    }
+
+    #[test]
+    fn matches_unprepared_for_complex_polygon() {
+        let norway: Polygon<f64> = Polygon::new(geo_test_fixtures::norway_main(), vec![]);
+        let rect = norway.bounding_rect().unwrap();
+        let prepared = PreparedGeometry::from(&norway);
+
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut next = move || rng.gen::<f64>();
+        let mut random_coord = move || Coord {
+            x: rect.min().x + rect.width() * next(),
+            y: rect.min().y + rect.height() * next(),
+        };
+
+        let mut contained = 0;
+        for _ in 0..1000 {
+            let point = Point::from(random_coord());
+            let contains = prepared.contains(&point);
+            assert_eq!(contains, norway.contains(&point));
+            contained += contains as usize;
+        }
+        // the test covers both outcomes
+        assert!(contained > 50 && contained < 950);
+
+        // every vertex is on the boundary
+        for coord in norway.exterior().coords().step_by(7) {
+            assert!(!prepared.contains(&Point::from(*coord)));
+            assert!(prepared.intersects(&Point::from(*coord)));
+        }
+
+        for i in 0..200 {
+            let line = Line::new(random_coord(), random_coord());
+            assert_eq!(prepared.intersects(&line), norway.intersects(&line));
+            // the unprepared polygon is slow to relate
+            if i % 20 == 0 {
+                assert_eq!(prepared.contains(&line), norway.contains(&line));
+            }
+        }
+        let points: MultiPoint = (0..20).map(|_| Point::from(random_coord())).collect();
+        assert_eq!(prepared.contains(&points), norway.contains(&points));
+    }
+}
@@ -61,42 +61,60 @@ impl<T> std::ops::IndexMut<CoordPos> for LocationArray<T> {
     }
 }
 
+/// The error returned when a DE-9IM pattern, or the string form of an [`IntersectionMatrix`],
+/// is malformed.
 #[derive(Debug)]
-pub struct InvalidInputError {
+pub struct InvalidPatternError {
     message: String,
 }
 
-impl InvalidInputError {
+impl InvalidPatternError {
     fn new(message: String) -> Self {
         Self { message }
     }
 }
 
-impl std::error::Error for InvalidInputError {}
-impl std::fmt::Display for InvalidInputError {
+impl std::error::Error for InvalidPatternError {}
+impl std::fmt::Display for InvalidPatternError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "invalid input:  {}", self.message)
+        write!(f, "invalid DE-9IM pattern: {}", self.message)
     }
 }
 
 impl std::fmt::Debug for IntersectionMatrix {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        fn char_for_dim(dim: &Dimensions) -> &'static str {
+        write!(f, "IntersectionMatrix({})", self)
+    }
+}
+
+/// Writes the matrix in its canonical 9-character form, such as `"212101212"`, row by row with
+/// `F` for an empty entry.
+///
+/// ```
+/// use geo::{polygon, relate::Relate};
+///
+/// let a = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let b = polygon![(x: 1., y: 1.), (x: 3., y: 1.), (x: 3., y: 3.), (x: 1., y: 3.)];
+///
+/// assert_eq!(a.relate(&b).to_string(), "212101212");
+/// ```
+impl std::fmt::Display for IntersectionMatrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn char_for_dim(dim: &Dimensions) -> char {
             match dim {
-                Dimensions::Empty => "F",
-                Dimensions::ZeroDimensional => "0",
-                Dimensions::OneDimensional => "1",
-                Dimensions::TwoDimensional => "2",
+                Dimensions::Empty => 'F',
+                Dimensions::ZeroDimensional => '0',
+                Dimensions::OneDimensional => '1',
+                Dimensions::TwoDimensional => '2',
             }
         }
-        let text = self
+        let text: String = self
             .0
             .iter()
             .flat_map(|r| r.iter().map(char_for_dim))
-            .collect::<Vec<&str>>()
-            .join("");
+            .collect();
 
-        write!(f, "IntersectionMatrix({})", &text)
+        f.write_str(&text)
     }
 }
 
@@ -160,10 +178,10 @@ impl IntersectionMatrix {
     pub(crate) fn set_at_least_from_string(
         &mut self,
         dimensions: &str,
-    ) -> Result<(), InvalidInputError> {
+    ) -> Result<(), InvalidPatternError> {
         if dimensions.len() != 9 {
             let message = format!("Expected dimensions length 9, found: {}", dimensions.len());
-            return Err(InvalidInputError::new(message));
+            return Err(InvalidPatternError::new(message));
         }
 
         let mut chars = dimensions.chars();
@@ -176,7 +194,7 @@ impl IntersectionMatrix {
                     'F' => {}
                     other => {
                         let message = format!("expected '0', '1', '2', or 'F'. Found: {}", other);
-                        return Err(InvalidInputError::new(message));
+                        return Err(InvalidPatternError::new(message));
                     }
                 }
             }
@@ -220,6 +238,175 @@ impl IntersectionMatrix {
             && self.0[CoordPos::Outside][CoordPos::OnBoundary] == Dimensions::Empty
     }
 
+    /// Tests whether this matrix matches `[T**FF*FF*]`.
+    ///
+    /// returns `true` if the second geometry lies in the interior of the first, which unlike
+    /// [`is_contains`](Self::is_contains) excludes it touching the first's boundary.
+    pub fn is_contains_properly(&self) -> bool {
+        self.0[CoordPos::Inside][CoordPos::Inside] != Dimensions::Empty
+            && self.0[CoordPos::OnBoundary][CoordPos::Inside] == Dimensions::Empty
+            && self.0[CoordPos::OnBoundary][CoordPos::OnBoundary] == Dimensions::Empty
+            && self.0[CoordPos::Outside][CoordPos::Inside] == Dimensions::Empty
+            && self.0[CoordPos::Outside][CoordPos::OnBoundary] == Dimensions::Empty
+    }
+
+    /// Tests whether this matrix matches any of `[T*****FF*]`, `[*T****FF*]`, `[***T**FF*]` or
+    /// `[****T*FF*]`.
+    ///
+    /// returns `true` if every point of the second geometry is in the first, which unlike
+    /// [`is_contains`](Self::is_contains) includes the second geometry lying on the first's
+    /// boundary.
+    pub fn is_covers(&self) -> bool {
+        self.matches_any(&["T*****FF*", "*T****FF*", "***T**FF*", "****T*FF*"])
+    }
+
+    /// Tests whether this matrix matches any of `[T*F**F***]`, `[*TF**F***]`, `[**FT*F***]` or
+    /// `[**F*TF***]`.
+    ///
+    /// returns `true` if every point of the first geometry is in the second, which unlike
+    /// [`is_within`](Self::is_within) includes the first geometry lying on the second's boundary.
+    pub fn is_covered_by(&self) -> bool {
+        self.matches_any(&["T*F**F***", "*TF**F***", "**FT*F***", "**F*TF***"])
+    }
+
+    /// Tests whether the geometries have the same dimension, and this matrix matches
+    /// `[T*F**FFF*]`.
+    ///
+    /// returns `true` if the two geometries are topologically equal, i.e. they cover the same
+    /// points, even if their coordinates differ.
+    pub fn is_equal_topo(&self) -> bool {
+        self.dimensions_a() == self.dimensions_b() && self.matches_any(&["T*F**FFF*"])
+    }
+
+    /// Tests whether the geometries are not both zero dimensional, and this matrix matches any of
+    /// `[FT*******]`, `[F**T*****]` or `[F***T****]`.
+    ///
+    /// returns `true` if the two geometries meet, but only at their boundaries.
+    pub fn is_touches(&self) -> bool {
+        if self.dimensions_a() == Dimensions::ZeroDimensional
+            && self.dimensions_b() == Dimensions::ZeroDimensional
+        {
+            return false;
+        }
+        self.matches_any(&["FT*******", "F**T*****", "F***T****"])
+    }
+
+    /// Tests whether this matrix matches `[T*T******]` if the first geometry has a lower
+    /// dimension than the second, `[T*****T**]` if it has a higher dimension, or `[0********]`
+    /// if both are one dimensional.
+    ///
+    /// returns `true` if the interiors of the two geometries meet in a lower dimension than the
+    /// higher of theirs, and each has some interior outside the other. Points can't cross points,
+    /// and areas can't cross areas.
+    pub fn is_crosses(&self) -> bool {
+        let (a, b) = (self.dimensions_a(), self.dimensions_b());
+        if a == Dimensions::Empty || b == Dimensions::Empty {
+            return false;
+        }
+        if a < b {
+            self.matches_any(&["T*T******"])
+        } else if a > b {
+            self.matches_any(&["T*****T**"])
+        } else if a == Dimensions::OneDimensional {
+            self.matches_any(&["0********"])
+        } else {
+            false
+        }
+    }
+
+    /// Tests whether the geometries have the same dimension, and this matrix matches
+    /// `[1*T***T**]` if they're both one dimensional, or `[T*T***T**]` otherwise.
+    ///
+    /// returns `true` if the interiors of the two geometries meet in the same dimension as their
+    /// own, and each has some interior outside the other.
+    pub fn is_overlaps(&self) -> bool {
+        let (a, b) = (self.dimensions_a(), self.dimensions_b());
+        if a != b || a == Dimensions::Empty {
+            return false;
+        }
+        if a == Dimensions::OneDimensional {
+            self.matches_any(&["1*T***T**"])
+        } else {
+            self.matches_any(&["T*T***T**"])
+        }
+    }
+
+    /// Tests whether this matrix matches a DE-9IM pattern, such as `"T*F**F***"`.
+    ///
+    /// Each of the nine characters of the pattern is compared with the corresponding entry of
+    /// the matrix, and may be:
+    /// - `T`: the entry is not empty
+    /// - `F`: the entry is empty
+    /// - `*`: the entry can be anything
+    /// - `0`, `1` or `2`: the entry has exactly that dimension
+    ///
+    /// ```
+    /// use geo::{point, polygon, relate::Relate};
+    ///
+    /// let polygon = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)];
+    /// let point = point!(x: 4., y: 2.);
+    ///
+    /// let matrix = polygon.relate(&point);
+    /// // the point is on the polygon's boundary
+    /// assert!(matrix.matches("F**0*****").unwrap());
+    /// assert!(!matrix.matches("T********").unwrap());
+    /// assert!(matrix.matches("T*").is_err());
+    /// ```
+    pub fn matches(&self, pattern: &str) -> Result<bool, InvalidPatternError> {
+        let length = pattern.chars().count();
+        if length != 9 {
+            let message = format!("Expected pattern length 9, found: {}", length);
+            return Err(InvalidPatternError::new(message));
+        }
+
+        let mut chars = pattern.chars();
+        let mut matches = true;
+        for a in &[CoordPos::Inside, CoordPos::OnBoundary, CoordPos::Outside] {
+            for b in &[CoordPos::Inside, CoordPos::OnBoundary, CoordPos::Outside] {
+                let dimensions = self.0[*a][*b];
+                matches &= match chars.next().expect("already validated length is 9") {
+                    'T' => dimensions != Dimensions::Empty,
+                    'F' => dimensions == Dimensions::Empty,
+                    '*' => true,
+                    '0' => dimensions == Dimensions::ZeroDimensional,
+                    '1' => dimensions == Dimensions::OneDimensional,
+                    '2' => dimensions == Dimensions::TwoDimensional,
+                    other => {
+                        let message =
+                            format!("expected 'T', 'F', '*', '0', '1', or '2'. Found: {}", other);
+                        return Err(InvalidPatternError::new(message));
+                    }
+                };
+            }
+        }
+        Ok(matches)
+    }
+
+    /// Whether this matrix matches any of the (valid) `patterns`.
+    fn matches_any(&self, patterns: &[&str]) -> bool {
+        patterns
+            .iter()
+            .any(|pattern| self.matches(pattern).expect("predicate patterns are valid"))
+    }
+
+    /// The dimensions of the first geometry, which are those of its interior.
+    ///
+    /// The interior, boundary and exterior of the second geometry cover the plane, so the
+    /// interior of the first is the union of the first row of the matrix.
+    fn dimensions_a(&self) -> Dimensions {
+        let row = &self.0[CoordPos::Inside];
+        row.iter().copied().max().expect("rows are not empty")
+    }
+
+    /// The dimensions of the second geometry, which are those of its interior.
+    fn dimensions_b(&self) -> Dimensions {
+        [CoordPos::Inside, CoordPos::OnBoundary, CoordPos::Outside]
+            .iter()
+            .map(|position| self.0[*position][CoordPos::Inside])
+            .max()
+            .expect("columns are not empty")
+    }
+
     /// Directly accesses this matrix
     ///
     /// ```
@@ -263,11 +450,189 @@ impl IntersectionMatrix {
     }
 }
 
+/// Parses a matrix from its canonical 9-character form, such as `"212101212"`, as written by
+/// its [`Display`](std::fmt::Display) implementation.
+///
+/// Each entry must be `F`, `0`, `1` or `2`: unlike a pattern given to
+/// [`matches`](IntersectionMatrix::matches), a matrix can't have `T` or `*` entries.
+///
+/// ```
+/// use geo::{polygon, relate::{IntersectionMatrix, Relate}};
+///
+/// let a = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// let b = polygon![(x: 2., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 2., y: 2.)];
+///
+/// let expected: IntersectionMatrix = "FF2F11212".parse().unwrap();
+/// assert_eq!(a.relate(&b), expected);
+/// assert!("T*T******".parse::<IntersectionMatrix>().is_err());
+/// ```
 impl std::str::FromStr for IntersectionMatrix {
-    type Err = InvalidInputError;
+    type Err = InvalidPatternError;
     fn from_str(str: &str) -> Result<Self, Self::Err> {
         let mut im = IntersectionMatrix::empty();
         im.set_at_least_from_string(str)?;
         Ok(im)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algorithm::Relate;
+    use crate::{line_string, point, polygon, MultiPoint, Polygon};
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon<f64> {
+        polygon![
+            (x: x, y: y),
+            (x: x + size, y: y),
+            (x: x + size, y: y + size),
+            (x: x, y: y + size),
+        ]
+    }
+
+    #[test]
+    fn matches() {
+        let matrix: IntersectionMatrix = "212101212".parse().unwrap();
+        assert!(matrix.matches("212101212").unwrap());
+        assert!(matrix.matches("T*T***T**").unwrap());
+        assert!(matrix.matches("*********").unwrap());
+        assert!(!matrix.matches("F********").unwrap());
+        assert!(!matrix.matches("1********").unwrap());
+        assert!(matrix.matches("T*T*").is_err());
+        assert!(matrix.matches("X********").is_err());
+    }
+
+    #[test]
+    fn parse_and_display() {
+        for text in ["212101212", "FF2F11212", "0FFFFFFF2", "FFFFFFFFF"] {
+            let matrix: IntersectionMatrix = text.parse().unwrap();
+            assert_eq!(matrix.to_string(), text);
+            assert!(matrix.matches(text).unwrap());
+        }
+        assert_eq!(
+            format!("{:?}", IntersectionMatrix::empty()),
+            "IntersectionMatrix(FFFFFFFFF)"
+        );
+        let matrix = square(0., 0., 2.).relate(&square(1., 1., 2.));
+        assert_eq!(
+            matrix.to_string().parse::<IntersectionMatrix>().unwrap(),
+            matrix
+        );
+
+        assert!("21210121".parse::<IntersectionMatrix>().is_err());
+        assert!("2121012122".parse::<IntersectionMatrix>().is_err());
+        assert!("T12101212".parse::<IntersectionMatrix>().is_err());
+        assert!("*12101212".parse::<IntersectionMatrix>().is_err());
+    }
+
+    #[test]
+    fn touches() {
+        // polygons sharing an edge
+        let matrix = square(0., 0., 2.).relate(&square(2., 0., 2.));
+        assert!(matrix.is_touches());
+        assert!(!matrix.is_overlaps());
+        assert!(matrix.is_intersects());
+
+        // a line ending on a polygon's boundary
+        let line = line_string![(x: 2., y: 1.), (x: 5., y: 1.)];
+        let matrix = line.relate(&square(0., 0., 2.));
+        assert!(matrix.is_touches());
+        assert!(!matrix.is_crosses());
+
+        // points can't touch
+        let matrix = point!(x: 1., y: 1.).relate(&point!(x: 1., y: 1.));
+        assert!(!matrix.is_touches());
+
+        // a polygon with a point inside doesn't touch it
+        assert!(!square(0., 0., 2.)
+            .relate(&point!(x: 1., y: 1.))
+            .is_touches());
+    }
+
+    #[test]
+    fn crosses() {
+        // lines which cross in an X
+        let a = line_string![(x: 0., y: 0.), (x: 2., y: 2.)];
+        let b = line_string![(x: 0., y: 2.), (x: 2., y: 0.)];
+        assert!(a.relate(&b).is_crosses());
+
+        // a multipoint with points on and off a line
+        let multi_point = MultiPoint::from(vec![(1., 1.), (5., 0.)]);
+        assert!(multi_point.relate(&a).is_crosses());
+        assert!(a.relate(&multi_point).is_crosses());
+        let on_line = MultiPoint::from(vec![(1., 1.), (0.5, 0.5)]);
+        assert!(!on_line.relate(&a).is_crosses());
+
+        // a line passing through a polygon
+        let line = line_string![(x: -1., y: 1.), (x: 3., y: 1.)];
+        assert!(line.relate(&square(0., 0., 2.)).is_crosses());
+        assert!(square(0., 0., 2.).relate(&line).is_crosses());
+        let inside = line_string![(x: 0.5, y: 1.), (x: 1.5, y: 1.)];
+        assert!(!inside.relate(&square(0., 0., 2.)).is_crosses());
+
+        // overlapping lines don't cross
+        let c = line_string![(x: 1., y: 1.), (x: 3., y: 3.)];
+        assert!(!a.relate(&c).is_crosses());
+
+        // and polygons can't cross
+        assert!(!square(0., 0., 2.).relate(&square(1., 1., 2.)).is_crosses());
+    }
+
+    #[test]
+    fn overlaps() {
+        assert!(square(0., 0., 2.).relate(&square(1., 1., 2.)).is_overlaps());
+        assert!(!square(0., 0., 4.).relate(&square(1., 1., 2.)).is_overlaps());
+
+        // collinear lines which share a part
+        let a = line_string![(x: 0., y: 0.), (x: 2., y: 0.)];
+        let b = line_string![(x: 1., y: 0.), (x: 3., y: 0.)];
+        assert!(a.relate(&b).is_overlaps());
+
+        let a = MultiPoint::from(vec![(0., 0.), (1., 1.)]);
+        let b = MultiPoint::from(vec![(1., 1.), (2., 2.)]);
+        assert!(a.relate(&b).is_overlaps());
+
+        // geometries of different dimensions don't overlap
+        let line = line_string![(x: -1., y: 1.), (x: 3., y: 1.)];
+        assert!(!line.relate(&square(0., 0., 2.)).is_overlaps());
+    }
+
+    #[test]
+    fn equal_topo() {
+        // the same square, starting from a different vertex
+        let a = square(0., 0., 2.);
+        let b = polygon![(x: 2., y: 2.), (x: 0., y: 2.), (x: 0., y: 0.), (x: 2., y: 0.)];
+        assert!(a.relate(&b).is_equal_topo());
+
+        // a line, reversed and with an extra vertex
+        let a = line_string![(x: 0., y: 0.), (x: 2., y: 0.)];
+        let b = line_string![(x: 2., y: 0.), (x: 1., y: 0.), (x: 0., y: 0.)];
+        assert!(a.relate(&b).is_equal_topo());
+
+        assert!(!square(0., 0., 2.)
+            .relate(&square(0., 0., 3.))
+            .is_equal_topo());
+    }
+
+    #[test]
+    fn covers() {
+        let polygon = square(0., 0., 2.);
+        let boundary = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.), (x: 0., y: 0.)];
+        let matrix = polygon.relate(&boundary);
+        assert!(matrix.is_covers());
+        assert!(!matrix.is_contains());
+        let matrix = boundary.relate(&polygon);
+        assert!(matrix.is_covered_by());
+        assert!(!matrix.is_within());
+
+        let corner = point!(x: 2., y: 2.);
+        assert!(polygon.relate(&corner).is_covers());
+        assert!(corner.relate(&polygon).is_covered_by());
+        assert!(!polygon.relate(&point!(x: 3., y: 2.)).is_covers());
+
+        let inner = square(0.5, 0.5, 1.);
+        assert!(polygon.relate(&inner).is_covers());
+        assert!(inner.relate(&polygon).is_covered_by());
+        assert!(!inner.relate(&polygon).is_covers());
+    }
+}
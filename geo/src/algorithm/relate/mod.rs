@@ -1,8 +1,11 @@
 pub(crate) use edge_end_builder::EdgeEndBuilder;
-pub use geomgraph::intersection_matrix::IntersectionMatrix;
+pub use geomgraph::intersection_matrix::{IntersectionMatrix, InvalidPatternError};
 
+use crate::bool_ops::{try_clip_with, try_unary_union, BooleanOpsError};
 use crate::geometry::*;
-use crate::{GeoFloat, GeometryCow};
+use crate::sweep::FloatArith;
+use crate::{GeoFloat, GeometryCow, Intersects};
+use std::borrow::Cow;
 
 mod edge_end_builder;
 mod geomgraph;
@@ -57,11 +60,101 @@ pub trait Relate<F, T> {
 
 impl<F: GeoFloat> Relate<F, GeometryCow<'_, F>> for GeometryCow<'_, F> {
     fn relate(&self, other: &GeometryCow<F>) -> IntersectionMatrix {
-        let mut relate_computer = relate_operation::RelateOperation::new(self, other);
+        let a = normalize_collection(self);
+        let b = normalize_collection(other);
+        let mut relate_computer = relate_operation::RelateOperation::new(&a, &b);
         relate_computer.compute_intersection_matrix()
     }
 }
 
+/// A `GeometryCollection` is related as the union of its members. The geometry graph can't label
+/// the interiors of overlapping polygons, nor tell that the end points of a line inside a polygon
+/// aren't on the boundary of the union, so a collection with polygonal members is replaced by a
+/// flattened collection of:
+///
+/// - the union of its polygonal members, as a single `MultiPolygon`
+/// - the parts of its lines outside of that union
+/// - its points outside of that union
+///
+/// Empty and nested collections are otherwise related as the union of their members. If the
+/// union can't be computed, such as for non-finite coordinates, the collection is related as it
+/// is.
+fn normalize_collection<'a, 'b, F: GeoFloat>(
+    geometry: &'b GeometryCow<'a, F>,
+) -> Cow<'b, GeometryCow<'a, F>> {
+    let collection = match geometry {
+        GeometryCow::GeometryCollection(collection) => collection,
+        _ => return Cow::Borrowed(geometry),
+    };
+
+    let mut polygons = vec![];
+    let mut others = vec![];
+    flatten_collection(collection, &mut polygons, &mut others);
+    if polygons.is_empty() || (polygons.len() == 1 && others.is_empty()) {
+        return Cow::Borrowed(geometry);
+    }
+
+    match union_members(&polygons, others) {
+        Ok(members) => Cow::Owned(GeometryCow::GeometryCollection(Cow::Owned(
+            GeometryCollection::new_from(members),
+        ))),
+        Err(_) => Cow::Borrowed(geometry),
+    }
+}
+
+/// The union of `polygons`, and the parts of the lower dimensional `others` outside of it.
+fn union_members<F: GeoFloat>(
+    polygons: &[Polygon<F>],
+    others: Vec<Geometry<F>>,
+) -> Result<Vec<Geometry<F>>, BooleanOpsError<F>> {
+    let union = try_unary_union(polygons)?;
+    let mut lines = MultiLineString::new(vec![]);
+    let mut points = MultiPoint::new(vec![]);
+    for geometry in others {
+        match geometry {
+            Geometry::Point(point) => points.0.push(point),
+            Geometry::MultiPoint(multi_point) => points.0.extend(multi_point),
+            Geometry::Line(line) => lines.0.push(line.into()),
+            Geometry::LineString(line_string) => lines.0.push(line_string),
+            Geometry::MultiLineString(multi_line_string) => lines.0.extend(multi_line_string),
+            _ => unreachable!("polygonal and collection members are flattened"),
+        }
+    }
+
+    let lines = try_clip_with::<FloatArith, _>(&union.0, &lines, true)?;
+    points.0.retain(|point| !union.intersects(point));
+
+    let mut members = vec![Geometry::MultiPolygon(union)];
+    if !lines.0.is_empty() {
+        members.push(Geometry::MultiLineString(lines));
+    }
+    if !points.0.is_empty() {
+        members.push(Geometry::MultiPoint(points));
+    }
+    Ok(members)
+}
+
+fn flatten_collection<F: GeoFloat>(
+    collection: &GeometryCollection<F>,
+    polygons: &mut Vec<Polygon<F>>,
+    others: &mut Vec<Geometry<F>>,
+) {
+    for geometry in collection {
+        match geometry {
+            Geometry::Polygon(polygon) => polygons.push(polygon.clone()),
+            Geometry::MultiPolygon(multi_polygon) => {
+                polygons.extend(multi_polygon.0.iter().cloned())
+            }
+            Geometry::Rect(rect) => polygons.push(rect.to_polygon()),
+            Geometry::Triangle(triangle) => polygons.push(triangle.to_polygon()),
+            Geometry::GeometryCollection(collection) => {
+                flatten_collection(collection, polygons, others)
+            }
+            _ => others.push(geometry.clone()),
+        }
+    }
+}
+
 macro_rules! relate_impl {
     ($k:ty, $t:ty) => {
         relate_impl![($k, $t),];
@@ -114,3 +207,103 @@ macro_rules! cartesian_pairs_helper {
 // but I don't know that we want to make GeometryCow public (yet?).
 cartesian_pairs!(relate_impl, [Point<F>, Line<F>, LineString<F>, Polygon<F>, MultiPoint<F>, MultiLineString<F>, MultiPolygon<F>, Rect<F>, Triangle<F>, GeometryCollection<F>]);
 relate_impl!(Geometry<F>, Geometry<F>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, polygon};
+
+    fn collection(geometries: Vec<Geometry<f64>>) -> Geometry<f64> {
+        Geometry::GeometryCollection(GeometryCollection::new_from(geometries))
+    }
+
+    #[test]
+    fn collection_with_overlapping_polygons() {
+        let a = Geometry::from(Rect::new((0., 0.), (2., 2.)));
+        let b = Geometry::from(Rect::new((1., 1.), (3., 3.)));
+        let collection = collection(vec![a.clone(), b, point!(x: 5., y: 5.).into()]);
+
+        let matrix = Geometry::from(point!(x: 1.5, y: 1.5)).relate(&collection);
+        assert_eq!(matrix, "0FFFFF212".parse().unwrap());
+        let matrix = a.relate(&collection);
+        assert_eq!(matrix, "2FF11F212".parse().unwrap());
+        assert!(matrix.is_within());
+        assert!(collection.relate(&a).is_contains());
+    }
+
+    #[test]
+    fn nested_collection() {
+        let a = Geometry::from(Rect::new((0., 0.), (2., 2.)));
+        let b = Geometry::from(Rect::new((1., 1.), (3., 3.)));
+        let nested = collection(vec![
+            collection(vec![a.clone()]),
+            collection(vec![collection(vec![b.clone()]), collection(vec![])]),
+        ]);
+        let flat = collection(vec![a.clone(), b]);
+
+        assert_eq!(nested.relate(&flat), "2FFF1FFF2".parse().unwrap());
+        assert_eq!(
+            collection(vec![collection(vec![a.clone()])]).relate(&a),
+            "2FFF1FFF2".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn collection_with_covered_lines_and_points() {
+        let rect = Geometry::from(Rect::new((0., 0.), (4., 4.)));
+        let inside = Geometry::from(LineString::from(vec![(1., 1.), (2., 2.)]));
+        let covered = collection(vec![
+            rect.clone(),
+            inside.clone(),
+            point!(x: 3., y: 1.).into(),
+            point!(x: 4., y: 2.).into(),
+        ]);
+        // the line and points are covered by the rect, so its boundary is the rect's
+        assert_eq!(covered.relate(&rect), "2FFF1FFF2".parse().unwrap());
+        assert_eq!(
+            collection(vec![rect.clone(), inside]).relate(&rect),
+            "2FFF1FFF2".parse().unwrap()
+        );
+
+        // the parts outside of the rect are still related
+        let crossing = Geometry::from(LineString::from(vec![(2., 2.), (6., 2.)]));
+        let outside = Geometry::from(point!(x: 6., y: 6.));
+        let mixed = collection(vec![rect.clone(), crossing, outside.clone()]);
+        let matrix = mixed.relate(&rect);
+        assert!(matrix.is_contains());
+        assert!(!matrix.is_within());
+        assert!(mixed.relate(&outside).is_contains());
+        assert!(mixed
+            .relate(&Geometry::from(point!(x: 5., y: 2.)))
+            .is_contains());
+        assert!(!mixed
+            .relate(&Geometry::from(point!(x: 5., y: 3.)))
+            .is_intersects());
+    }
+
+    #[test]
+    fn collection_with_non_finite_coordinates() {
+        // the union fails, so the collection is related as it is, rather than panicking
+        let a = Geometry::from(Rect::new((0., 0.), (2., 2.)));
+        let infinite = Geometry::from(polygon![
+            (x: 1., y: 1.),
+            (x: f64::INFINITY, y: 1.),
+            (x: 3., y: 3.),
+        ]);
+        let collection = collection(vec![a, infinite]);
+        let far = Geometry::from(point!(x: -5., y: -5.));
+        assert!(!collection.relate(&far).is_intersects());
+    }
+
+    #[test]
+    fn empty_collection() {
+        let empty = collection(vec![]);
+        let nested_empty = collection(vec![collection(vec![])]);
+        let a = Geometry::from(Rect::new((0., 0.), (2., 2.)));
+
+        assert_eq!(empty.relate(&a), "FFFFFF212".parse().unwrap());
+        assert_eq!(a.relate(&empty), "FF2FF1FF2".parse().unwrap());
+        assert_eq!(empty.relate(&nested_empty), "FFFFFFFF2".parse().unwrap());
+        assert!(!empty.relate(&a).is_intersects());
+    }
+}
@@ -15,8 +15,8 @@ use std::rc::Rc;
 /// Computes an [`IntersectionMatrix`] describing the topological relationship between two
 /// Geometries.
 ///
-/// `RelateOperation` does not currently support [`GeometryCollection`]s with overlapping Polygons,
-/// and may provide surprising results in that case.
+/// `RelateOperation` does not support [`GeometryCollection`]s with overlapping Polygons, which
+/// [`Relate`](super::Relate) unions before relating.
 ///
 /// This implementation relies heavily on the functionality of [`GeometryGraph`].
 ///
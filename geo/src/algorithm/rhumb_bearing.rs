@@ -0,0 +1,91 @@
+use crate::algorithm::rhumb_distance::{
+    mercator_latitude_difference, shorter_longitude_difference,
+};
+use crate::{CoordFloat, Point};
+use num_traits::FromPrimitive;
+
+/// Returns the constant bearing of the [rhumb line] from one point to another.
+///
+/// A rhumb line crosses every meridian at the same angle, so this bearing can be followed all the
+/// way, unlike the initial bearing of a great circle given by [`Bearing`](crate::Bearing). Of the
+/// two ways around the earth, the rhumb line which crosses fewer degrees of longitude is taken,
+/// as for [`RhumbDistance`](crate::RhumbDistance).
+///
+/// [rhumb line]: https://en.wikipedia.org/wiki/Rhumb_line
+pub trait RhumbBearing<T: CoordFloat> {
+    /// Returns the bearing of the rhumb line to another Point in degrees, where North is 0° and
+    /// East is 90°.
+    ///
+    /// # Units
+    ///
+    /// - return value: degrees, between -180 and 180, the same convention as
+    ///   [`Bearing`](crate::Bearing)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use approx::assert_relative_eq;
+    /// use geo::{Bearing, RhumbBearing};
+    /// use geo::point;
+    ///
+    /// // New York City
+    /// let p_1 = point!(x: -74.006, y: 40.7128);
+    /// // London
+    /// let p_2 = point!(x: -0.1278, y: 51.5074);
+    ///
+    /// let bearing = p_1.rhumb_bearing(p_2);
+    /// assert_relative_eq!(bearing, 78.04, epsilon = 0.01);
+    /// // the great circle sets off further north, and curves back south
+    /// assert!(p_1.bearing(p_2) < bearing);
+    /// ```
+    fn rhumb_bearing(&self, point: Point<T>) -> T;
+}
+
+impl<T> RhumbBearing<T> for Point<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn rhumb_bearing(&self, point: Point<T>) -> T {
+        let delta_lambda = shorter_longitude_difference(self.x(), point.x()).to_radians();
+        // zero along a parallel, where the bearing is due east or west
+        let delta_psi = mercator_latitude_difference(self.y().to_radians(), point.y().to_radians());
+        delta_lambda.atan2(delta_psi).to_degrees()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::point;
+
+    #[test]
+    fn cardinal_directions() {
+        let origin = point!(x: 10., y: 40.);
+        assert_relative_eq!(origin.rhumb_bearing(point!(x: 10., y: 41.)), 0.);
+        assert_relative_eq!(origin.rhumb_bearing(point!(x: 11., y: 40.)), 90.);
+        assert_relative_eq!(origin.rhumb_bearing(point!(x: 10., y: 39.)), 180.);
+        assert_relative_eq!(origin.rhumb_bearing(point!(x: 9., y: 40.)), -90.);
+    }
+
+    #[test]
+    fn reverse_bearing() {
+        // unlike a great circle, the rhumb line back is in exactly the opposite direction
+        let start = point!(x: -20., y: -30.);
+        let end = point!(x: 40., y: 50.);
+        let bearing = start.rhumb_bearing(end);
+        assert!(bearing > 0. && bearing < 90.);
+        assert_relative_eq!(end.rhumb_bearing(start), bearing - 180., epsilon = 1e-9);
+    }
+
+    #[test]
+    fn across_the_antimeridian() {
+        let p_1 = point!(x: 179., y: 0.);
+        let p_2 = point!(x: -179., y: 0.);
+        assert_relative_eq!(p_1.rhumb_bearing(p_2), 90.);
+        assert_relative_eq!(p_2.rhumb_bearing(p_1), -90.);
+
+        let p_3 = point!(x: -179., y: 1.);
+        let bearing = p_1.rhumb_bearing(p_3);
+        assert!(bearing > 0. && bearing < 90.);
+    }
+}
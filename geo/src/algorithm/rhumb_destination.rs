@@ -0,0 +1,132 @@
+use crate::algorithm::rhumb_distance::{mercator_ratio, normalize_longitude};
+use crate::{CoordFloat, Point, MEAN_EARTH_RADIUS};
+use num_traits::FromPrimitive;
+
+/// Returns a new Point using the distance to the existing Point and a constant bearing along a
+/// [rhumb line].
+///
+/// This is the inverse of [`RhumbBearing`](crate::RhumbBearing) and
+/// [`RhumbDistance`](crate::RhumbDistance). A rhumb line which would pass over a pole continues
+/// down the other side of it.
+///
+/// *Note*: this implementation uses a mean earth radius of 6371.088 km, based on the [recommendation of
+/// the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf)
+///
+/// [rhumb line]: https://en.wikipedia.org/wiki/Rhumb_line
+pub trait RhumbDestination<T: CoordFloat> {
+    /// Returns a new Point using distance to the existing Point and a bearing for the direction
+    ///
+    /// # Units
+    ///
+    /// - `bearing`: degrees, zero degrees is north
+    /// - `distance`: meters
+    /// - return value: a Point whose longitude is between -180 and 180 degrees
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use approx::assert_relative_eq;
+    /// use geo::{RhumbBearing, RhumbDestination, RhumbDistance};
+    /// use geo::Point;
+    ///
+    /// let p_1 = Point::new(9.177789688110352, 48.776781529534965);
+    /// let p_2 = p_1.rhumb_destination(45., 10000.);
+    /// assert_relative_eq!(p_1.rhumb_bearing(p_2), 45., epsilon = 1.0e-9);
+    /// assert_relative_eq!(p_1.rhumb_distance(&p_2), 10000., epsilon = 1.0e-6);
+    /// ```
+    fn rhumb_destination(&self, bearing: T, distance: T) -> Point<T>;
+}
+
+impl<T> RhumbDestination<T> for Point<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn rhumb_destination(&self, bearing: T, distance: T) -> Point<T> {
+        let half_pi = T::from(std::f64::consts::FRAC_PI_2).unwrap();
+        let pi = half_pi + half_pi;
+
+        let delta = distance / T::from(MEAN_EARTH_RADIUS).unwrap();
+        let (sin_bearing, cos_bearing) = bearing.to_radians().sin_cos();
+        let phi1 = self.y().to_radians();
+        let mut phi2 = phi1 + delta * cos_bearing;
+        // over a pole, and down the other side
+        if phi2 > half_pi {
+            phi2 = pi - phi2;
+        } else if phi2 < -half_pi {
+            phi2 = -pi - phi2;
+        }
+
+        // well defined for a bearing due east or west, when the ratio is the cosine of the latitude
+        let q = mercator_ratio(phi1, phi2);
+        let lambda = self.x() + (delta * sin_bearing / q).to_degrees();
+
+        Point::new(normalize_longitude(lambda), phi2.to_degrees())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, RhumbBearing, RhumbDistance};
+
+    /// Checks that the bearing and distance from `start` to its destination are the ones it was
+    /// found with.
+    fn assert_round_trip(start: Point, bearing: f64, distance: f64) {
+        let end = start.rhumb_destination(bearing, distance);
+        assert!((-180. ..=180.).contains(&end.x()));
+        assert_relative_eq!(start.rhumb_distance(&end), distance, epsilon = 1e-6);
+        assert_relative_eq!(start.rhumb_bearing(end), bearing, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn round_trips() {
+        let starts = [
+            point!(x: 0., y: 0.),
+            point!(x: -73.78, y: 40.64),
+            point!(x: 151.21, y: -33.87),
+        ];
+        for start in starts {
+            for bearing in [-135., -90., -30., 0., 10., 90., 179.] {
+                for distance in [1., 1000., 100_000., 2_000_000.] {
+                    assert_round_trip(start, bearing, distance);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn due_east_and_west() {
+        // along a parallel, with no division by zero
+        let start = point!(x: 10., y: 60.);
+        let east = start.rhumb_destination(90., 100_000.);
+        assert_relative_eq!(east.y(), 60., epsilon = 1e-9);
+        assert!(east.x() > 11.);
+        let west = start.rhumb_destination(-90., 100_000.);
+        assert_relative_eq!(west.y(), 60., epsilon = 1e-9);
+        assert_relative_eq!(west.x(), 20. - east.x(), epsilon = 1e-9);
+        assert_relative_eq!(
+            start.rhumb_destination(270., 100_000.),
+            west,
+            epsilon = 1e-9
+        );
+        assert_round_trip(start, 90., 100_000.);
+    }
+
+    #[test]
+    fn across_the_antimeridian() {
+        let start = point!(x: 179.5, y: -16.5);
+        let end = start.rhumb_destination(80., 200_000.);
+        assert!(end.x() < -178. && end.x() > -179.);
+        assert_round_trip(start, 80., 200_000.);
+
+        let start = point!(x: -179.5, y: 52.);
+        let end = start.rhumb_destination(-100., 100_000.);
+        assert!(end.x() > 178.);
+        assert_round_trip(start, -100., 100_000.);
+
+        // all the way around the equator
+        let circumference = 2. * std::f64::consts::PI * MEAN_EARTH_RADIUS;
+        let around = point!(x: 0., y: 0.).rhumb_destination(90., circumference);
+        assert_relative_eq!(around.x(), 0., epsilon = 1e-6);
+    }
+}
@@ -0,0 +1,210 @@
+use crate::{CoordFloat, Point, MEAN_EARTH_RADIUS};
+use num_traits::FromPrimitive;
+
+/// Determine the distance between two points along a [rhumb line], or loxodrome, which crosses
+/// every meridian at the same angle.
+///
+/// A rhumb line can be followed on a constant compass bearing, which makes it the usual route in
+/// marine navigation, but it's longer than the great circle measured by
+/// [`HaversineDistance`](crate::HaversineDistance), except along the equator or a meridian. The
+/// difference grows with the distance and latitude. Of the two ways around the earth, the rhumb
+/// line which crosses fewer degrees of longitude is taken, across the antimeridian if need be.
+///
+/// *Note*: this implementation uses a mean earth radius of 6371.088 km, based on the [recommendation of
+/// the IUGG](ftp://athena.fsv.cvut.cz/ZFG/grs80-Moritz.pdf), as the haversine algorithms do.
+///
+/// [rhumb line]: https://en.wikipedia.org/wiki/Rhumb_line
+pub trait RhumbDistance<T, Rhs = Self> {
+    /// Determine the distance between two points along a rhumb line.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{HaversineDistance, RhumbDistance};
+    /// use geo::point;
+    ///
+    /// // New York City
+    /// let p1 = point!(x: -74.006f64, y: 40.7128f64);
+    ///
+    /// // London
+    /// let p2 = point!(x: -0.1278f64, y: 51.5074f64);
+    ///
+    /// let distance = p1.rhumb_distance(&p2);
+    ///
+    /// assert_eq!(
+    ///     5_794_129., // meters
+    ///     distance.round()
+    /// );
+    /// assert!(distance > p1.haversine_distance(&p2));
+    /// ```
+    fn rhumb_distance(&self, rhs: &Rhs) -> T;
+}
+
+impl<T> RhumbDistance<T, Point<T>> for Point<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn rhumb_distance(&self, rhs: &Point<T>) -> T {
+        let phi1 = self.y().to_radians();
+        let phi2 = rhs.y().to_radians();
+        let delta_phi = phi2 - phi1;
+        let delta_lambda = shorter_longitude_difference(self.x(), rhs.x()).to_radians();
+        let q = mercator_ratio(phi1, phi2);
+
+        let delta = (delta_phi * delta_phi + q * q * delta_lambda * delta_lambda).sqrt();
+        T::from(MEAN_EARTH_RADIUS).unwrap() * delta
+    }
+}
+
+/// The difference from longitude `from` to `to`, in degrees, the shorter way around, across the
+/// antimeridian if need be.
+pub(crate) fn shorter_longitude_difference<T: CoordFloat + FromPrimitive>(from: T, to: T) -> T {
+    let half_turn = T::from(180).unwrap();
+    let mut delta = to - from;
+    if delta > half_turn {
+        delta = delta - half_turn - half_turn;
+    } else if delta < -half_turn {
+        delta = delta + half_turn + half_turn;
+    }
+    delta
+}
+
+/// The difference in latitude on a Mercator projection, from `phi1` to `phi2`, in radians.
+pub(crate) fn mercator_latitude_difference<T: CoordFloat + FromPrimitive>(phi1: T, phi2: T) -> T {
+    let two = T::one() + T::one();
+    let quarter_pi = T::from(std::f64::consts::FRAC_PI_4).unwrap();
+    ((phi2 / two + quarter_pi).tan() / (phi1 / two + quarter_pi).tan()).ln()
+}
+
+/// The ratio of the difference in latitude, from `phi1` to `phi2` in radians, to that on a
+/// Mercator projection.
+///
+/// This is undefined for a line along a parallel, and loses precision near one, where it tends
+/// to the cosine of the latitude.
+pub(crate) fn mercator_ratio<T: CoordFloat + FromPrimitive>(phi1: T, phi2: T) -> T {
+    let delta_phi = phi2 - phi1;
+    if delta_phi.abs() > T::epsilon().sqrt() {
+        delta_phi / mercator_latitude_difference(phi1, phi2)
+    } else {
+        let two = T::one() + T::one();
+        ((phi1 + phi2) / two).cos()
+    }
+}
+
+/// The same longitude, between -180 and 180 degrees.
+pub(crate) fn normalize_longitude<T: CoordFloat + FromPrimitive>(lambda: T) -> T {
+    let half_turn = T::from(180).unwrap();
+    let turn = half_turn + half_turn;
+    if (-half_turn..=half_turn).contains(&lambda) {
+        return lambda;
+    }
+    let wrapped = (lambda + half_turn) % turn;
+    if wrapped < T::zero() {
+        wrapped + turn - half_turn
+    } else {
+        wrapped - half_turn
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::normalize_longitude;
+    use crate::{HaversineDistance, Point, RhumbDistance};
+
+    #[test]
+    fn along_the_equator_and_meridians() {
+        // these are great circles too
+        let a = Point::new(0., 0.);
+        let b = Point::new(1., 0.);
+        assert_relative_eq!(
+            a.rhumb_distance(&b),
+            a.haversine_distance(&b),
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(a.rhumb_distance(&b), 111195.0802335329_f64, epsilon = 1e-6);
+
+        let c = Point::new(12.5, -40.);
+        let d = Point::new(12.5, 65.);
+        assert_relative_eq!(
+            c.rhumb_distance(&d),
+            c.haversine_distance(&d),
+            epsilon = 1e-6
+        );
+    }
+
+    #[test]
+    fn along_a_parallel() {
+        let a = Point::new(-10., 60.);
+        let b = Point::new(10., 60.);
+        // the parallel at 60 degrees is half as long as the equator
+        assert_relative_eq!(
+            a.rhumb_distance(&b),
+            Point::new(0., 0.).rhumb_distance(&Point::new(10., 0.)),
+            epsilon = 1e-6
+        );
+        // and longer than the great circle, which bends towards the pole
+        assert!(a.rhumb_distance(&b) > a.haversine_distance(&b) + 1000.);
+
+        // nearly along it
+        let c = Point::new(10., 60. + 1e-10);
+        assert_relative_eq!(
+            a.rhumb_distance(&c),
+            a.rhumb_distance(&b),
+            max_relative = 1e-9
+        );
+    }
+
+    #[test]
+    fn short_distances() {
+        // over short distances, the rhumb line and great circle are almost the same
+        let dover = Point::new(1. + 20. / 60. + 17. / 3600., 51. + 7. / 60. + 32. / 3600.);
+        let calais = Point::new(1. + 51. / 60. + 9. / 3600., 50. + 57. / 60. + 48. / 3600.);
+        let distance = dover.rhumb_distance(&calais);
+        assert_relative_eq!(distance, 40_235., epsilon = 1.);
+        assert_relative_eq!(distance, dover.haversine_distance(&calais), epsilon = 0.1);
+        assert_relative_eq!(distance, calais.rhumb_distance(&dover), epsilon = 1e-6);
+
+        // including when nearly along a parallel
+        for offset in [1e-12, 1e-9, 1e-7, 1e-5] {
+            let a = Point::new(2., 50.);
+            let b = Point::new(2.01, 50. + offset);
+            assert_relative_eq!(
+                a.rhumb_distance(&b),
+                a.haversine_distance(&b),
+                max_relative = 1e-9
+            );
+        }
+    }
+
+    #[test]
+    fn across_the_antimeridian() {
+        let a = Point::new(179., 10.);
+        let b = Point::new(-179., 12.);
+        let c = Point::new(1., 10.);
+        let d = Point::new(3., 12.);
+        // the same as the same rhumb line elsewhere, not the long way around
+        assert_relative_eq!(a.rhumb_distance(&b), c.rhumb_distance(&d), epsilon = 1e-6);
+        assert_relative_eq!(b.rhumb_distance(&a), c.rhumb_distance(&d), epsilon = 1e-6);
+        assert!(a.rhumb_distance(&b) < 400_000.);
+    }
+
+    #[test]
+    fn same_point() {
+        let a = Point::new(42., -33.);
+        assert_eq!(a.rhumb_distance(&a), 0.);
+    }
+
+    #[test]
+    fn normalizes_longitude() {
+        assert_eq!(normalize_longitude(10.), 10.);
+        assert_eq!(normalize_longitude(180.), 180.);
+        assert_eq!(normalize_longitude(190.), -170.);
+        assert_eq!(normalize_longitude(-190.), 170.);
+        assert_eq!(normalize_longitude(900.), -180.);
+        assert_eq!(normalize_longitude(-540.), -180.);
+    }
+}
@@ -0,0 +1,249 @@
+use crate::algorithm::rhumb_distance::{
+    mercator_latitude_difference, normalize_longitude, shorter_longitude_difference,
+};
+use crate::{CoordFloat, Point, RhumbDistance};
+use num_traits::FromPrimitive;
+
+/// Returns a new Point along a [rhumb line] between two existing points.
+///
+/// On a Mercator projection, where a rhumb line is straight, the new points are on the straight
+/// line between the existing ones, and they're spaced by distance along the rhumb line, as
+/// measured by [`RhumbDistance`](crate::RhumbDistance). The rhumb line which crosses fewer degrees
+/// of longitude is taken, across the antimeridian if need be, and the longitudes of the new points
+/// are between -180 and 180 degrees.
+///
+/// [rhumb line]: https://en.wikipedia.org/wiki/Rhumb_line
+pub trait RhumbIntermediate<T: CoordFloat> {
+    /// Returns a new Point a fraction `f` of the way along the rhumb line between two existing
+    /// points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use approx::assert_relative_eq;
+    /// use geo::{RhumbDistance, RhumbIntermediate};
+    /// use geo::Point;
+    ///
+    /// let p1 = Point::new(10.0, 20.0);
+    /// let p2 = Point::new(125.0, 25.0);
+    /// let i20 = p1.rhumb_intermediate(&p2, 0.2);
+    /// assert_relative_eq!(i20, Point::new(32.7, 21.0), epsilon = 0.1);
+    /// assert_relative_eq!(
+    ///     p1.rhumb_distance(&i20),
+    ///     0.2 * p1.rhumb_distance(&p2),
+    ///     epsilon = 1e-6
+    /// );
+    /// ```
+    fn rhumb_intermediate(&self, other: &Point<T>, f: T) -> Point<T>;
+
+    /// Returns the Points along the rhumb line between two existing points, evenly spaced so that
+    /// none are more than `max_dist` meters apart, including the existing points if
+    /// `include_ends` is `true`.
+    fn rhumb_intermediate_fill(
+        &self,
+        other: &Point<T>,
+        max_dist: T,
+        include_ends: bool,
+    ) -> Vec<Point<T>>;
+}
+
+impl<T> RhumbIntermediate<T> for Point<T>
+where
+    T: CoordFloat + FromPrimitive,
+{
+    fn rhumb_intermediate(&self, other: &Point<T>, f: T) -> Point<T> {
+        RhumbParams::new(self, other).point(f)
+    }
+
+    fn rhumb_intermediate_fill(
+        &self,
+        other: &Point<T>,
+        max_dist: T,
+        include_ends: bool,
+    ) -> Vec<Point<T>> {
+        let params = RhumbParams::new(self, other);
+        let total_distance = self.rhumb_distance(other);
+
+        if total_distance <= max_dist {
+            return if include_ends {
+                vec![*self, *other]
+            } else {
+                vec![]
+            };
+        }
+
+        let number_of_points = (total_distance / max_dist).ceil();
+        let mut points = if include_ends { vec![*self] } else { vec![] };
+
+        // each fraction is found afresh, rather than by adding up steps, so that rounding error
+        // can't add or drop a point at the end
+        let mut step = T::one();
+        while step < number_of_points {
+            points.push(params.point(step / number_of_points));
+            step = step + T::one();
+        }
+
+        if include_ends {
+            points.push(*other);
+        }
+
+        points
+    }
+}
+
+/// The rhumb line between two points, in radians.
+struct RhumbParams<T: CoordFloat> {
+    phi1: T,
+    lambda1: T,
+    delta_phi: T,
+    delta_lambda: T,
+    /// The difference in latitude on a Mercator projection, or `None` if the line is so close to a
+    /// parallel that it's too imprecise to use.
+    delta_psi: Option<T>,
+}
+
+impl<T: CoordFloat + FromPrimitive> RhumbParams<T> {
+    fn new(p1: &Point<T>, p2: &Point<T>) -> Self {
+        let phi1 = p1.y().to_radians();
+        let phi2 = p2.y().to_radians();
+        let delta_phi = phi2 - phi1;
+        let delta_psi = if delta_phi.abs() > T::epsilon().sqrt() {
+            Some(mercator_latitude_difference(phi1, phi2))
+        } else {
+            None
+        };
+        RhumbParams {
+            phi1,
+            lambda1: p1.x().to_radians(),
+            delta_phi,
+            delta_lambda: shorter_longitude_difference(p1.x(), p2.x()).to_radians(),
+            delta_psi,
+        }
+    }
+
+    /// The point a fraction `f` of the way along the line.
+    fn point(&self, f: T) -> Point<T> {
+        // latitude changes in proportion to the distance along a rhumb line, and longitude in
+        // proportion to the latitude on a Mercator projection
+        let phi = self.phi1 + self.delta_phi * f;
+        let lambda_fraction = match self.delta_psi {
+            Some(delta_psi) => mercator_latitude_difference(self.phi1, phi) / delta_psi,
+            None => f,
+        };
+        let lambda = self.lambda1 + self.delta_lambda * lambda_fraction;
+        Point::new(normalize_longitude(lambda.to_degrees()), phi.to_degrees())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::point;
+
+    /// Project `point` onto a Mercator projection, unwrapping its longitude to be within 180
+    /// degrees of `reference`.
+    fn mercator(point: Point, reference: Point) -> (f64, f64) {
+        let x = reference.x() + shorter_longitude_difference(reference.x(), point.x());
+        let y = (std::f64::consts::FRAC_PI_4 + point.y().to_radians() / 2.)
+            .tan()
+            .ln();
+        (x.to_radians(), y)
+    }
+
+    /// Checks that `points` are on the straight line between `start` and `end` on a Mercator
+    /// projection.
+    fn assert_collinear_on_mercator(start: Point, end: Point, points: &[Point]) {
+        let (x1, y1) = mercator(start, start);
+        let (x2, y2) = mercator(end, start);
+        let length = (x2 - x1).hypot(y2 - y1);
+        for &point in points {
+            let (x, y) = mercator(point, start);
+            // the distance of the point from the line
+            let offset = ((x2 - x1) * (y - y1) - (y2 - y1) * (x - x1)) / length;
+            assert_relative_eq!(offset, 0., epsilon = 1e-12);
+            // and between its ends
+            let along = ((x2 - x1) * (x - x1) + (y2 - y1) * (y - y1)) / length;
+            assert!(along >= -1e-12 && along <= length + 1e-12);
+        }
+    }
+
+    #[test]
+    fn intermediate_points() {
+        let p1 = point!(x: -74.006, y: 40.7128);
+        let p2 = point!(x: -0.1278, y: 51.5074);
+        let total = p1.rhumb_distance(&p2);
+        assert_relative_eq!(p1.rhumb_intermediate(&p2, 0.), p1, epsilon = 1e-12);
+        assert_relative_eq!(p1.rhumb_intermediate(&p2, 1.), p2, epsilon = 1e-12);
+        for f in [0.1, 0.25, 0.5, 0.9] {
+            let point = p1.rhumb_intermediate(&p2, f);
+            assert_relative_eq!(p1.rhumb_distance(&point), f * total, epsilon = 1e-6);
+            assert_collinear_on_mercator(p1, p2, &[point]);
+        }
+    }
+
+    #[test]
+    fn along_a_parallel_and_a_meridian() {
+        let p1 = point!(x: 10., y: 60.);
+        let p2 = point!(x: 30., y: 60.);
+        assert_relative_eq!(
+            p1.rhumb_intermediate(&p2, 0.25),
+            point!(x: 15., y: 60.),
+            epsilon = 1e-12
+        );
+
+        let p3 = point!(x: 10., y: -20.);
+        assert_relative_eq!(
+            p1.rhumb_intermediate(&p3, 0.5),
+            point!(x: 10., y: 20.),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn fill() {
+        let p1 = point!(x: -20., y: -30.);
+        let p2 = point!(x: 40., y: 50.);
+        let total = p1.rhumb_distance(&p2);
+
+        let points = p1.rhumb_intermediate_fill(&p2, total / 9.5, true);
+        assert_eq!(points.len(), 11);
+        assert_eq!(points[0], p1);
+        assert_eq!(points[10], p2);
+        for pair in points.windows(2) {
+            assert_relative_eq!(
+                pair[0].rhumb_distance(&pair[1]),
+                total / 10.,
+                epsilon = 1e-6
+            );
+        }
+        assert_collinear_on_mercator(p1, p2, &points);
+
+        let points = p1.rhumb_intermediate_fill(&p2, total / 9.5, false);
+        assert_eq!(points.len(), 9);
+
+        assert_eq!(p1.rhumb_intermediate_fill(&p2, total, true), vec![p1, p2]);
+        assert!(p1.rhumb_intermediate_fill(&p2, total, false).is_empty());
+    }
+
+    #[test]
+    fn across_the_antimeridian() {
+        let p1 = point!(x: 170., y: -10.);
+        let p2 = point!(x: -170., y: 10.);
+        let points: Vec<Point> = p1.rhumb_intermediate_fill(&p2, 100_000., true);
+        assert!(points.len() > 20);
+        // every point is on the short way round, near the antimeridian, with a normalized
+        // longitude
+        for point in &points {
+            assert!(point.x().abs() >= 170. - 1e-9 && point.x().abs() <= 180.);
+        }
+        for pair in points.windows(2) {
+            assert!(pair[0].rhumb_distance(&pair[1]) <= 100_000. + 1e-6);
+        }
+        assert_collinear_on_mercator(p1, p2, &points);
+        assert_relative_eq!(
+            p1.rhumb_intermediate(&p2, 0.5),
+            point!(x: 180., y: 0.),
+            epsilon = 1e-9
+        );
+    }
+}
@@ -84,6 +84,27 @@ pub trait Rotate<T: CoordFloat> {
     ///     (x: 17.071067811865476, y: 7.0710678118654755)
     /// ]);
     /// ```
+    ///
+    /// To combine the rotation with other transformations before applying it, build it with
+    /// [`AffineTransform::rotate`] instead. As with matrix multiplication, `a.compose(&b)` applies
+    /// `b` first:
+    ///
+    /// ```
+    /// use geo::{point, AffineOps, AffineTransform, Rotate, Translate};
+    /// use approx::assert_relative_eq;
+    ///
+    /// let p = point!(x: 3.0, y: 1.0);
+    /// let pivot = point!(x: 2.0, y: 1.0);
+    ///
+    /// let transform =
+    ///     AffineTransform::translate(10.0, 0.0).compose(&AffineTransform::rotate(90.0, pivot));
+    ///
+    /// assert_relative_eq!(
+    ///     p.affine_transform(&transform),
+    ///     p.rotate_around_point(90.0, pivot).translate(10.0, 0.0),
+    /// );
+    /// assert_relative_eq!(p.affine_transform(&transform), point!(x: 12.0, y: 2.0));
+    /// ```
     #[must_use]
     fn rotate_around_point(&self, degrees: T, point: Point<T>) -> Self;
 
@@ -378,6 +399,34 @@ mod test {
         assert_eq!(rotated, Point::new(-10.506096654409877, 20.564971157455595));
     }
     #[test]
+    fn test_rotate_around_point_composes() {
+        use crate::{AffineOps, AffineTransform, Scale, Translate};
+
+        let poly = polygon![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 2.), (x: 0., y: 2.)];
+        let pivot = point!(x: 1., y: 3.);
+
+        // the transform passed to `compose` is applied first
+        let transform =
+            AffineTransform::translate(-2., 5.).compose(&AffineTransform::rotate(30., pivot));
+        assert_relative_eq!(
+            poly.affine_transform(&transform),
+            poly.rotate_around_point(30., pivot).translate(-2., 5.),
+            epsilon = 1e-12
+        );
+
+        // and in the other order, with a scale
+        let transform = AffineTransform::scale(2., 0.5, pivot)
+            .compose(&AffineTransform::rotate(30., pivot))
+            .compose(&AffineTransform::translate(-2., 5.));
+        assert_relative_eq!(
+            poly.affine_transform(&transform),
+            poly.translate(-2., 5.)
+                .rotate_around_point(30., pivot)
+                .scale_around_point(2., 0.5, pivot),
+            epsilon = 1e-12
+        );
+    }
+    #[test]
     fn test_rotate_line() {
         let line0 = Line::from([(0., 0.), (0., 2.)]);
         let line1 = Line::from([(1., 1.), (-1., 1.)]);
@@ -0,0 +1,208 @@
+use crate::line_intersection::{line_intersection, LineIntersection};
+use crate::{Coord, GeoFloat, Line, LineString, MultiLineString};
+
+/// Find the paths shared by two `LineString`s, like [PostGIS's `ST_SharedPaths`][postgis].
+///
+/// The shared paths are the parts where the two run on top of each other. They're returned
+/// as two `MultiLineString`s: first the paths along which both run the same way, then those
+/// along which they run opposite ways. The paths follow the direction of `self`, in the order
+/// they appear along it, and are merged wherever consecutive parts of `self` are shared.
+///
+/// The segments of the two must be exactly collinear to be shared, though they may overlap
+/// only in part, starting or ending anywhere along each other. Points where the two only
+/// cross or touch aren't shared paths. Each segment of `self` is compared with each of
+/// `other`, so this takes time proportional to the product of their sizes.
+///
+/// [postgis]: https://postgis.net/docs/ST_SharedPaths.html
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, MultiLineString, SharedPaths};
+///
+/// let road = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+/// let other = line_string![
+///     (x: 1., y: 0.),
+///     (x: 3., y: 0.),
+///     (x: 3., y: 2.),
+///     (x: 4., y: 2.),
+///     (x: 4., y: 1.),
+/// ];
+///
+/// let (same, opposite) = road.shared_paths(&other);
+/// assert_eq!(same, MultiLineString::new(vec![line_string![(x: 1., y: 0.), (x: 3., y: 0.)]]));
+/// assert_eq!(opposite, MultiLineString::new(vec![line_string![(x: 4., y: 1.), (x: 4., y: 2.)]]));
+/// ```
+pub trait SharedPaths<T: GeoFloat, Rhs = Self> {
+    /// Returns the paths shared with `other` running the same way, and those running
+    /// opposite ways.
+    fn shared_paths(&self, other: &Rhs) -> (MultiLineString<T>, MultiLineString<T>);
+}
+
+impl<T: GeoFloat> SharedPaths<T> for LineString<T> {
+    fn shared_paths(&self, other: &LineString<T>) -> (MultiLineString<T>, MultiLineString<T>) {
+        let mut same: Vec<Vec<Coord<T>>> = vec![];
+        let mut opposite: Vec<Vec<Coord<T>>> = vec![];
+        for line in self.lines() {
+            let direction = line.delta();
+            let along = |coord: Coord<T>| {
+                let offset = coord - line.start;
+                offset.x * direction.x + offset.y * direction.y
+            };
+
+            // the parts of this segment shared with each of `other`'s, directed along it
+            let mut shared: Vec<(Line<T>, bool)> = other
+                .lines()
+                .filter_map(|other_line| match line_intersection(line, other_line) {
+                    Some(LineIntersection::Collinear { intersection }) => {
+                        let other_direction = other_line.delta();
+                        let is_same = direction.x * other_direction.x
+                            + direction.y * other_direction.y
+                            > T::zero();
+                        let piece = if along(intersection.start) <= along(intersection.end) {
+                            intersection
+                        } else {
+                            Line::new(intersection.end, intersection.start)
+                        };
+                        Some((piece, is_same))
+                    }
+                    _ => None,
+                })
+                .collect();
+            shared.sort_by(|(a, _), (b, _)| along(a.start).partial_cmp(&along(b.start)).unwrap());
+
+            // how far along this segment the last path of each kind reaches
+            let (mut reached_same, mut reached_opposite) = (None, None);
+            for (piece, is_same) in shared {
+                let (paths, reached) = if is_same {
+                    (&mut same, &mut reached_same)
+                } else {
+                    (&mut opposite, &mut reached_opposite)
+                };
+                let (start, end) = (along(piece.start), along(piece.end));
+                // a part repeated by `other`
+                if reached.map_or(false, |reached| end <= reached) {
+                    continue;
+                }
+                match paths.last_mut() {
+                    Some(path)
+                        if reached.map_or(false, |reached| start <= reached)
+                            || path.last() == Some(&piece.start) =>
+                    {
+                        path.push(piece.end)
+                    }
+                    _ => paths.push(vec![piece.start, piece.end]),
+                }
+                *reached = Some(end);
+            }
+        }
+
+        let multi_line_string =
+            |paths: Vec<Vec<_>>| paths.into_iter().map(LineString::new).collect();
+        (multi_line_string(same), multi_line_string(opposite))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line_string;
+
+    #[test]
+    fn identical() {
+        let ls = line_string![(x: 0., y: 0.), (x: 1., y: 1.), (x: 2., y: 0.), (x: 4., y: 0.)];
+        let (same, opposite) = ls.shared_paths(&ls);
+        assert_eq!(same, MultiLineString::new(vec![ls.clone()]));
+        assert!(opposite.0.is_empty());
+
+        let mut reversed = ls.clone();
+        reversed.0.reverse();
+        let (same, opposite) = ls.shared_paths(&reversed);
+        assert!(same.0.is_empty());
+        assert_eq!(opposite, MultiLineString::new(vec![ls]));
+    }
+
+    #[test]
+    fn partial_overlaps() {
+        // `other` starts and ends partway along segments of `ls`, and
+        // its segments start and end partway along those of `ls`
+        let ls = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.)];
+        let other = line_string![
+            (x: 2., y: 0.),
+            (x: 5., y: 0.),
+            (x: 12., y: 0.),
+            (x: 10., y: 0.),
+            (x: 10., y: 3.),
+        ];
+        let (same, opposite) = ls.shared_paths(&other);
+        assert_eq!(
+            same,
+            MultiLineString::new(vec![line_string![
+                (x: 2., y: 0.),
+                (x: 5., y: 0.),
+                (x: 10., y: 0.),
+                (x: 10., y: 3.),
+            ]])
+        );
+        assert!(opposite.0.is_empty());
+    }
+
+    #[test]
+    fn same_and_opposite() {
+        let ls = line_string![(x: 0., y: 0.), (x: 6., y: 0.)];
+        let other = line_string![
+            (x: 1., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 1.),
+            (x: 5., y: 1.),
+            (x: 5., y: 0.),
+            (x: 3., y: 0.),
+        ];
+        let (same, opposite) = ls.shared_paths(&other);
+        assert_eq!(
+            same,
+            MultiLineString::new(vec![line_string![(x: 1., y: 0.), (x: 2., y: 0.)]])
+        );
+        assert_eq!(
+            opposite,
+            MultiLineString::new(vec![line_string![(x: 3., y: 0.), (x: 5., y: 0.)]])
+        );
+    }
+
+    #[test]
+    fn crossing_and_touching() {
+        let ls = line_string![(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.)];
+        let other = line_string![(x: 2., y: -1.), (x: 2., y: 1.), (x: 4., y: 0.)];
+        let (same, opposite) = ls.shared_paths(&other);
+        assert!(same.0.is_empty());
+        assert!(opposite.0.is_empty());
+    }
+
+    #[test]
+    fn separate_paths() {
+        let ls = line_string![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 4., y: 2.)];
+        let other = line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 3., y: 5.), (x: 3., y: 2.), (x: 4., y: 2.)];
+        let (same, opposite) = ls.shared_paths(&other);
+        assert_eq!(
+            same,
+            MultiLineString::new(vec![
+                line_string![(x: 0., y: 0.), (x: 1., y: 0.)],
+                line_string![(x: 3., y: 2.), (x: 4., y: 2.)],
+            ])
+        );
+        assert!(opposite.0.is_empty());
+    }
+
+    #[test]
+    fn repeated_parts() {
+        // `other` doubles back over itself
+        let ls = line_string![(x: 0., y: 0.), (x: 6., y: 0.)];
+        let other = line_string![(x: 1., y: 0.), (x: 5., y: 0.), (x: 4., y: 1.), (x: 2., y: 0.), (x: 4., y: 0.)];
+        let (same, opposite) = ls.shared_paths(&other);
+        assert_eq!(
+            same,
+            MultiLineString::new(vec![line_string![(x: 1., y: 0.), (x: 5., y: 0.)]])
+        );
+        assert!(opposite.0.is_empty());
+    }
+}
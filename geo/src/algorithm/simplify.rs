@@ -1,5 +1,7 @@
+use crate::coordinate_position::{coord_pos_relative_to_ring, CoordPos};
 use crate::{Coord, GeoFloat, Line, LineString, MultiLineString, MultiPolygon, Polygon};
-use crate::{CoordsIter, EuclideanDistance};
+use crate::{CoordsIter, EuclideanDistance, Intersects};
+use std::{error, fmt};
 
 const LINE_STRING_INITIAL_MIN: usize = 2;
 const POLYGON_INITIAL_MIN: usize = 4;
@@ -247,6 +249,179 @@ where
     }
 }
 
+/// Simplifies a geometry while retaining a set of _pinned_ vertices.
+///
+/// The geometry is split at each of the pinned vertices, and each of the resulting pieces is
+/// simplified independently using the [Ramer–Douglas–Peucker
+/// algorithm](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm), so the pinned
+/// vertices are always retained regardless of `epsilon`. The first and last vertices are always
+/// retained too.
+///
+/// An epsilon less than or equal to zero will return an unaltered version of the geometry.
+///
+/// # Errors
+///
+/// Returns a [`PinOutOfBoundsError`] if any of the pinned indices is not a vertex of the geometry.
+pub trait SimplifyWithPins<T, Epsilon = T> {
+    /// Returns the simplified representation of a geometry, retaining the vertices at the
+    /// `pinned` indices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::SimplifyWithPins;
+    /// use geo::line_string;
+    ///
+    /// let line_string = line_string![
+    ///     (x: 0.0, y: 0.0),
+    ///     (x: 5.0, y: 0.1),
+    ///     (x: 10.0, y: 0.0),
+    ///     (x: 15.0, y: 0.1),
+    ///     (x: 20.0, y: 0.0),
+    /// ];
+    ///
+    /// let simplified = line_string.simplify_with_pins(&1.0, &[3]).unwrap();
+    ///
+    /// let expected = line_string![
+    ///     (x: 0.0, y: 0.0),
+    ///     (x: 15.0, y: 0.1),
+    ///     (x: 20.0, y: 0.0),
+    /// ];
+    ///
+    /// assert_eq!(expected, simplified);
+    /// assert!(line_string.simplify_with_pins(&1.0, &[5]).is_err());
+    /// ```
+    fn simplify_with_pins(
+        &self,
+        epsilon: &T,
+        pinned: &[usize],
+    ) -> Result<Self, PinOutOfBoundsError>
+    where
+        T: GeoFloat,
+        Self: Sized;
+
+    /// Returns the simplified indices of a geometry, retaining the `pinned` indices. These are
+    /// the indices of the vertices returned by
+    /// [`simplify_with_pins`](Self::simplify_with_pins), in the same way as
+    /// [`SimplifyIdx`] relates to [`Simplify`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::SimplifyWithPins;
+    /// use geo::line_string;
+    ///
+    /// let line_string = line_string![
+    ///     (x: 0.0, y: 0.0),
+    ///     (x: 5.0, y: 0.1),
+    ///     (x: 10.0, y: 0.0),
+    ///     (x: 15.0, y: 0.1),
+    ///     (x: 20.0, y: 0.0),
+    /// ];
+    ///
+    /// let simplified = line_string.simplify_idx_with_pins(&1.0, &[3]).unwrap();
+    ///
+    /// assert_eq!(vec![0_usize, 3_usize, 4_usize], simplified);
+    /// ```
+    fn simplify_idx_with_pins(
+        &self,
+        epsilon: &T,
+        pinned: &[usize],
+    ) -> Result<Vec<usize>, PinOutOfBoundsError>
+    where
+        T: GeoFloat;
+}
+
+/// A pinned index passed to [`SimplifyWithPins`] is not the index of a vertex.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PinOutOfBoundsError {
+    /// The pinned index
+    pub index: usize,
+    /// The number of vertices of the geometry
+    pub len: usize,
+}
+
+impl fmt::Display for PinOutOfBoundsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "pinned index {} is out of bounds for a geometry with {} vertices",
+            self.index, self.len
+        )
+    }
+}
+
+impl error::Error for PinOutOfBoundsError {}
+
+impl<T> SimplifyWithPins<T> for LineString<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_with_pins(
+        &self,
+        epsilon: &T,
+        pinned: &[usize],
+    ) -> Result<Self, PinOutOfBoundsError> {
+        let indices = self.simplify_idx_with_pins(epsilon, pinned)?;
+        Ok(LineString::new(
+            indices.into_iter().map(|i| self.0[i]).collect(),
+        ))
+    }
+
+    fn simplify_idx_with_pins(
+        &self,
+        epsilon: &T,
+        pinned: &[usize],
+    ) -> Result<Vec<usize>, PinOutOfBoundsError> {
+        let len = self.0.len();
+        if let Some(&index) = pinned.iter().find(|&&index| index >= len) {
+            return Err(PinOutOfBoundsError { index, len });
+        }
+        if len == 0 {
+            return Ok(vec![]);
+        }
+
+        let mut splits: Vec<usize> = pinned.to_vec();
+        splits.push(len - 1);
+        splits.sort_unstable();
+        splits.dedup();
+
+        let rdp_indices = self
+            .0
+            .iter()
+            .enumerate()
+            .map(|(idx, coord)| RdpIndex {
+                index: idx,
+                coord: *coord,
+            })
+            .collect::<Vec<RdpIndex<T>>>();
+
+        if *epsilon <= T::zero() {
+            return Ok((0..len).collect());
+        }
+
+        // The pieces share the count of retained indices, so that the line string as a whole
+        // keeps the same minimum number of coordinates as with `SimplifyIdx`
+        let mut simplified_len = len;
+        let mut simplified = vec![0];
+        let mut start = 0;
+        for end in splits {
+            if end == start {
+                continue;
+            }
+            let piece = compute_rdp::<T, LINE_STRING_INITIAL_MIN>(
+                &rdp_indices[start..=end],
+                &mut simplified_len,
+                epsilon,
+            );
+            // Don't include the first index of each piece twice
+            simplified.extend(piece[1..].iter().map(|rdp_index| rdp_index.index));
+            start = end;
+        }
+        Ok(simplified)
+    }
+}
+
 impl<T> Simplify<T> for MultiLineString<T>
 where
     T: GeoFloat,
@@ -285,6 +460,229 @@ where
     }
 }
 
+/// Simplifies a geometry, preserving its topology.
+///
+/// This runs the [Ramer–Douglas–Peucker
+/// algorithm](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm), but rejects the
+/// removal of any points which would make the result intersect itself: whenever a simplified
+/// segment crosses another segment, the farthest of the points it replaces is retained, until
+/// no crossings remain. For `Polygon`s, the segments of each ring must also not cross any other
+/// ring of the polygon, nor move past one (e.g. leave an interior ring outside of the
+/// exterior). For `MultiPolygon`s, this applies to the rings of all of its polygons, so the
+/// polygons do not overlap after simplification either.
+///
+/// The result is valid as long as the input is, but the number of retained points is usually
+/// larger than with [`Simplify`].
+///
+/// An epsilon less than or equal to zero will return an unaltered version of the geometry.
+pub trait SimplifyPreserve<T, Epsilon = T> {
+    /// Returns the simplified representation of a geometry, using a topology-preserving variant of
+    /// the [Ramer–Douglas–Peucker](https://en.wikipedia.org/wiki/Ramer–Douglas–Peucker_algorithm)
+    /// algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{Simplify, SimplifyPreserve};
+    /// use geo::polygon;
+    ///
+    /// let polygon = polygon!(
+    ///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 5., y: 11.), (x: 0., y: 10.)],
+    ///     interiors: [[(x: 4., y: 9.5), (x: 6., y: 9.5), (x: 5., y: 10.5)]],
+    /// );
+    ///
+    /// // removing (5, 11) would make the exterior cross the interior
+    /// assert_eq!(polygon.simplify(&2.).exterior().0.len(), 5);
+    /// assert_eq!(polygon.simplify_preserve(&2.).exterior().0.len(), 6);
+    /// ```
+    fn simplify_preserve(&self, epsilon: &T) -> Self
+    where
+        T: GeoFloat;
+}
+
+impl<T> SimplifyPreserve<T> for LineString<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_preserve(&self, epsilon: &T) -> Self {
+        rdp_preserve::<_, LINE_STRING_INITIAL_MIN>(&[self], epsilon, false)
+            .pop()
+            .unwrap()
+    }
+}
+
+impl<T> SimplifyPreserve<T> for MultiLineString<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_preserve(&self, epsilon: &T) -> Self {
+        MultiLineString::new(self.iter().map(|l| l.simplify_preserve(epsilon)).collect())
+    }
+}
+
+impl<T> SimplifyPreserve<T> for Polygon<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_preserve(&self, epsilon: &T) -> Self {
+        let rings: Vec<_> = std::iter::once(self.exterior())
+            .chain(self.interiors())
+            .collect();
+        let mut simplified = rdp_preserve::<_, POLYGON_INITIAL_MIN>(&rings, epsilon, true);
+        let exterior = simplified.remove(0);
+        Polygon::new(exterior, simplified)
+    }
+}
+
+impl<T> SimplifyPreserve<T> for MultiPolygon<T>
+where
+    T: GeoFloat,
+{
+    fn simplify_preserve(&self, epsilon: &T) -> Self {
+        let rings: Vec<_> = self
+            .iter()
+            .flat_map(|p| std::iter::once(p.exterior()).chain(p.interiors()))
+            .collect();
+        let mut simplified =
+            rdp_preserve::<_, POLYGON_INITIAL_MIN>(&rings, epsilon, true).into_iter();
+        MultiPolygon::new(
+            self.iter()
+                .map(|p| {
+                    let exterior = simplified.next().unwrap();
+                    let interiors = simplified.by_ref().take(p.interiors().len()).collect();
+                    Polygon::new(exterior, interiors)
+                })
+                .collect(),
+        )
+    }
+}
+
+// Topology-preserving RDP: simplify each of `lines` independently, then keep retaining the
+// farthest removed point of any simplified segment which crosses another one, until none do.
+// If `closed`, the lines are rings, and a segment must also not move past the vertices of
+// another ring.
+fn rdp_preserve<T, const INITIAL_MIN: usize>(
+    lines: &[&LineString<T>],
+    epsilon: &T,
+    closed: bool,
+) -> Vec<LineString<T>>
+where
+    T: GeoFloat,
+{
+    let mut kept: Vec<Vec<usize>> = lines
+        .iter()
+        .map(|line| {
+            calculate_rdp_indices::<T, INITIAL_MIN>(
+                &line
+                    .0
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, coord)| RdpIndex {
+                        index: idx,
+                        coord: *coord,
+                    })
+                    .collect::<Vec<RdpIndex<T>>>(),
+                epsilon,
+            )
+        })
+        .collect();
+
+    // Retaining a point replaces a segment with two others, which may cross segments that were
+    // already checked, so repeat until nothing changes. This terminates, as the input is
+    // reached eventually.
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for line in 0..lines.len() {
+            let mut k = 0;
+            while k + 1 < kept[line].len() {
+                let (a, b) = (kept[line][k], kept[line][k + 1]);
+                if b > a + 1 && crosses_others(lines, &kept, line, k, closed) {
+                    let coords = &lines[line].0;
+                    let segment = Line::new(coords[a], coords[b]);
+                    let (farthest, _) = (a + 1..b)
+                        .map(|i| (i, coords[i].euclidean_distance(&segment)))
+                        .fold((a + 1, T::zero()), |farthest, candidate| {
+                            if candidate.1 > farthest.1 {
+                                candidate
+                            } else {
+                                farthest
+                            }
+                        });
+                    kept[line].insert(k + 1, farthest);
+                    changed = true;
+                } else {
+                    k += 1;
+                }
+            }
+        }
+    }
+
+    lines
+        .iter()
+        .zip(kept)
+        .map(|(line, kept)| kept.into_iter().map(|i| line.0[i]).collect())
+        .collect()
+}
+
+// Whether the `k`th simplified segment of `lines[line]` crosses any other simplified segment,
+// or, if `closed`, whether the region between it and the points it replaces contains a vertex
+// of another ring.
+fn crosses_others<T>(
+    lines: &[&LineString<T>],
+    kept: &[Vec<usize>],
+    line: usize,
+    k: usize,
+    closed: bool,
+) -> bool
+where
+    T: GeoFloat,
+{
+    let coords = &lines[line].0;
+    let (a, b) = (kept[line][k], kept[line][k + 1]);
+    let segment = Line::new(coords[a], coords[b]);
+
+    let crosses_segment = kept.iter().enumerate().any(|(other_line, other_kept)| {
+        let other_coords = &lines[other_line].0;
+        other_kept.windows(2).enumerate().any(|(j, pair)| {
+            let other = Line::new(other_coords[pair[0]], other_coords[pair[1]]);
+            (other_line != line || j != k)
+                && segment.intersects(&other)
+                && !touches_at_endpoint(segment, other)
+        })
+    });
+    if crosses_segment || !closed {
+        return crosses_segment;
+    }
+
+    let mut region = LineString::from(coords[a..=b].to_vec());
+    region.close();
+    kept.iter()
+        .enumerate()
+        .filter(|&(other_line, _)| other_line != line)
+        .any(|(other_line, other_kept)| {
+            other_kept.iter().any(|&i| {
+                coord_pos_relative_to_ring(lines[other_line].0[i], &region) == CoordPos::Inside
+            })
+        })
+}
+
+// Whether two intersecting segments only touch at a shared endpoint, like consecutive segments
+// of a line do.
+fn touches_at_endpoint<T>(segment: Line<T>, other: Line<T>) -> bool
+where
+    T: GeoFloat,
+{
+    for (shared, far) in [(segment.start, segment.end), (segment.end, segment.start)] {
+        for (other_shared, other_far) in [(other.start, other.end), (other.end, other.start)] {
+            if shared == other_shared {
+                return !segment.intersects(&other_far) && !other.intersects(&far);
+            }
+        }
+    }
+    false
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -468,4 +866,159 @@ mod test {
             result,
         );
     }
+
+    #[test]
+    fn simplify_with_pins() {
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 5., y: 0.1),
+            (x: 10., y: 0.),
+            (x: 15., y: 0.1),
+            (x: 20., y: 0.),
+        ];
+        assert_eq!(
+            ls.simplify_idx_with_pins(&1., &[]).unwrap(),
+            ls.simplify_idx(&1.)
+        );
+        // unsorted, duplicate, and endpoint pins are all fine
+        assert_eq!(
+            ls.simplify_idx_with_pins(&1., &[3, 1, 3, 0, 4]).unwrap(),
+            vec![0, 1, 3, 4]
+        );
+        assert_eq!(
+            ls.simplify_with_pins(&1., &[1, 3]).unwrap(),
+            line_string![(x: 0., y: 0.), (x: 5., y: 0.1), (x: 15., y: 0.1), (x: 20., y: 0.)]
+        );
+    }
+
+    #[test]
+    fn simplify_with_pins_splits_pieces() {
+        // the pinned vertices are retained, and each piece between them is simplified on its own
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 1., y: 5.),
+            (x: 2., y: 0.),
+            (x: 3., y: 0.5),
+            (x: 4., y: 0.),
+            (x: 5., y: 5.),
+            (x: 6., y: 0.),
+        ];
+        let indices = ls.simplify_idx_with_pins(&1., &[2, 4]).unwrap();
+        assert_eq!(indices, vec![0, 1, 2, 4, 5, 6]);
+        let simplified = ls.simplify_with_pins(&1., &[2, 4]).unwrap();
+        assert_eq!(
+            simplified.0,
+            indices.iter().map(|&i| ls.0[i]).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn simplify_with_pins_negative_epsilon() {
+        let ls = line_string![(x: 0., y: 0.), (x: 5., y: 0.1), (x: 10., y: 0.)];
+        assert_eq!(ls.simplify_with_pins(&-1., &[1]).unwrap(), ls);
+        assert_eq!(ls.simplify_idx_with_pins(&0., &[]).unwrap(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn simplify_with_pins_out_of_bounds() {
+        let ls = line_string![(x: 0., y: 0.), (x: 5., y: 0.1), (x: 10., y: 0.)];
+        assert_eq!(
+            ls.simplify_with_pins(&1., &[1, 3]),
+            Err(PinOutOfBoundsError { index: 3, len: 3 })
+        );
+        let empty = LineString::<f64>::new(vec![]);
+        assert_eq!(empty.simplify_idx_with_pins(&1., &[]).unwrap(), vec![]);
+        assert_eq!(
+            empty.simplify_idx_with_pins(&1., &[0]),
+            Err(PinOutOfBoundsError { index: 0, len: 0 })
+        );
+    }
+
+    #[test]
+    fn simplify_preserve_line_string() {
+        // the spike from (10, -5) reaches under the bump at (5, 1), so removing the bump would
+        // make the line string cross itself
+        let ls = line_string![
+            (x: 0., y: 0.),
+            (x: 5., y: 1.),
+            (x: 11., y: 0.),
+            (x: 10., y: -5.),
+            (x: 5., y: 0.5),
+            (x: 0., y: -5.),
+        ];
+        let naive = ls.simplify(&1.5);
+        assert_eq!(naive.0.len(), 5);
+        assert!(Line::new(naive.0[0], naive.0[1]).intersects(&Line::new(naive.0[2], naive.0[3])));
+
+        assert_eq!(ls.simplify_preserve(&1.5), ls);
+        assert_eq!(ls.simplify_preserve(&-1.), ls);
+
+        // simplifying doesn't cause crossings elsewhere, so it still happens
+        let ls = line_string![(x: 0., y: 0.), (x: 5., y: 0.1), (x: 10., y: 0.), (x: 10., y: 5.)];
+        assert_eq!(ls.simplify_preserve(&1.), ls.simplify(&1.));
+    }
+
+    #[test]
+    fn simplify_preserve_polygon_crossing_interior() {
+        let poly = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 5., y: 11.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 9.5), (x: 6., y: 9.5), (x: 5., y: 10.5)]],
+        );
+        // removing (5, 11) is within the tolerance, but the exterior then crosses the interior
+        let naive = poly.simplify(&2.);
+        assert_eq!(naive.exterior().0.len(), 5);
+        assert!(naive.exterior().intersects(&poly.interiors()[0]));
+
+        let simplified = poly.simplify_preserve(&2.);
+        assert_eq!(simplified, poly);
+
+        // other points can still be removed
+        let poly = polygon!(
+            exterior: [
+                (x: 0., y: 0.), (x: 5., y: 0.5), (x: 10., y: 0.), (x: 10., y: 10.), (x: 5., y: 11.),
+                (x: 0., y: 10.),
+            ],
+            interiors: [[(x: 4., y: 9.5), (x: 6., y: 9.5), (x: 5., y: 10.5)]],
+        );
+        let simplified = poly.simplify_preserve(&2.);
+        assert!(!simplified.exterior().0.contains(&coord! { x: 5., y: 0.5 }));
+        assert!(simplified.exterior().0.contains(&coord! { x: 5., y: 11. }));
+    }
+
+    #[test]
+    fn simplify_preserve_polygon_interior_outside() {
+        // the interior doesn't cross the simplified exterior, but would be left outside of it
+        let poly = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 5., y: 11.), (x: 0., y: 10.)],
+            interiors: [[(x: 4.5, y: 10.2), (x: 5.5, y: 10.2), (x: 5., y: 10.6)]],
+        );
+        let naive = poly.simplify(&2.);
+        assert!(!naive.exterior().intersects(&poly.interiors()[0]));
+        assert_eq!(
+            coord_pos_relative_to_ring(poly.interiors()[0].0[0], naive.exterior()),
+            CoordPos::Outside
+        );
+
+        assert_eq!(poly.simplify_preserve(&2.), poly);
+    }
+
+    #[test]
+    fn simplify_preserve_multi_polygon() {
+        // the second polygon sits in a notch of the first, which would cover it if the notch
+        // was removed
+        let mpoly = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 5., y: 9.), (x: 0., y: 10.)],
+            polygon![(x: 4.5, y: 9.6), (x: 5., y: 9.2), (x: 5.5, y: 9.6)],
+        ]);
+        let naive = mpoly.simplify(&2.);
+        assert_eq!(
+            coord_pos_relative_to_ring(mpoly.0[1].exterior().0[0], naive.0[0].exterior()),
+            CoordPos::Inside
+        );
+
+        assert_eq!(mpoly.simplify_preserve(&2.), mpoly);
+
+        // on their own, the polygons are simplified as usual
+        assert_eq!(mpoly.0[0].simplify_preserve(&2.), mpoly.0[0].simplify(&2.));
+    }
 }
@@ -80,6 +80,10 @@ pub trait Skew<T: CoordNum> {
     /// which case you can just use [`skew`](Self::skew) or [`skew_xy`](Self::skew_xy), but this method allows you
     /// to specify any point.
     ///
+    /// To compose the skew with other transformations, build it with
+    /// [`AffineTransform::skew`](crate::AffineTransform::skew), which also describes what happens
+    /// as the angles approach 90°.
+    ///
     /// # Examples
     ///
     /// ```
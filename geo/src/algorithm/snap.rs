@@ -0,0 +1,278 @@
+use num_traits::FromPrimitive;
+
+use crate::coords_iter::CoordsIter;
+use crate::{
+    Coord, EuclideanDistance, GeoFloat, Line, LineString, MapCoords, MultiLineString, MultiPoint,
+    MultiPolygon, Point, Polygon, RemoveRepeatedPoints,
+};
+
+/// Snap the vertices of a geometry to those of another, like [JTS's `GeometrySnapper`][jts].
+///
+/// Each vertex of `self` within `tolerance` of a vertex of `other` is moved onto the nearest
+/// such vertex. Then each vertex of `other` within `tolerance` of a segment of `self`, but not
+/// already a vertex of it, is inserted into the nearest such segment. This makes the two
+/// share the vertices, and so the edges, along which they were digitized slightly apart, so
+/// that [boolean operations] on them don't leave slivers.
+///
+/// Segments collapsed to zero length are removed, with
+/// [`RemoveRepeatedPoints`]. Snapping may otherwise make a geometry invalid, for example when
+/// the tolerance is larger than its narrowest parts, so keep the tolerance small: just larger
+/// than the distances between the vertices meant to be the same.
+///
+/// Each segment is compared with each vertex of `other`, so this takes time proportional to
+/// the product of their sizes. See [`snap`] to snap two geometries to each other.
+///
+/// [jts]: https://locationtech.github.io/jts/javadoc/org/locationtech/jts/operation/overlay/snap/GeometrySnapper.html
+/// [boolean operations]: crate::BooleanOps
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, Snap};
+///
+/// let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.01), (x: 20., y: 0.)];
+/// let other = line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 15., y: 0.)];
+///
+/// assert_eq!(
+///     line_string.snap_to(&other, 0.1),
+///     line_string![(x: 0., y: 0.), (x: 10., y: 0.), (x: 15., y: 0.), (x: 20., y: 0.)]
+/// );
+/// ```
+pub trait Snap<T: GeoFloat> {
+    /// Returns `self` with its vertices snapped to those of `other` within `tolerance`.
+    fn snap_to<'a, G>(&self, other: &'a G, tolerance: T) -> Self
+    where
+        G: CoordsIter<'a, Scalar = T>;
+}
+
+/// Snap two geometries to each other.
+///
+/// This snaps `a` to `b`, and then `b` to the snapped `a`, as by [`Snap::snap_to`], so that
+/// their vertices within `tolerance` of each other become the same.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, snap, BooleanOps};
+///
+/// let a = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+/// let b = polygon![(x: 0., y: 0.), (x: 10.001, y: 0.), (x: 10., y: 9.999), (x: 0., y: 10.)];
+///
+/// let (a, b) = snap(&a, &b, 0.01);
+/// assert!(a.difference(&b).0.is_empty());
+/// ```
+pub fn snap<T, A, B>(a: &A, b: &B, tolerance: T) -> (A, B)
+where
+    T: GeoFloat,
+    A: Snap<T> + for<'a> CoordsIter<'a, Scalar = T>,
+    B: Snap<T> + for<'a> CoordsIter<'a, Scalar = T>,
+{
+    let a = a.snap_to(b, tolerance);
+    let b = b.snap_to(&a, tolerance);
+    (a, b)
+}
+
+/// The vertices to snap to, without repeats.
+fn snap_coords<'a, T, G>(other: &'a G) -> Vec<Coord<T>>
+where
+    T: GeoFloat,
+    G: CoordsIter<'a, Scalar = T>,
+{
+    let mut coords: Vec<Coord<T>> = vec![];
+    for coord in other.coords_iter() {
+        if !coords.contains(&coord) {
+            coords.push(coord);
+        }
+    }
+    coords
+}
+
+/// The nearest of `snap_coords` to `coord` within `tolerance`, or else `coord`.
+fn snap_vertex<T: GeoFloat>(coord: Coord<T>, snap_coords: &[Coord<T>], tolerance: T) -> Coord<T> {
+    snap_coords
+        .iter()
+        .map(|snap_coord| (snap_coord, snap_coord.euclidean_distance(&coord)))
+        .filter(|(_, distance)| *distance <= tolerance)
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+        .map_or(coord, |(snap_coord, _)| *snap_coord)
+}
+
+fn snap_line_string<T: GeoFloat + FromPrimitive>(
+    line_string: &LineString<T>,
+    snap_coords: &[Coord<T>],
+    tolerance: T,
+) -> LineString<T> {
+    let mut snapped = line_string.map_coords(|coord| snap_vertex(coord, snap_coords, tolerance));
+
+    for snap_coord in snap_coords {
+        if snapped.0.contains(snap_coord) {
+            continue;
+        }
+        let nearest = snapped
+            .lines()
+            .enumerate()
+            .map(|(i, line): (usize, Line<T>)| (i, snap_coord.euclidean_distance(&line)))
+            .filter(|(_, distance)| *distance <= tolerance)
+            .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap());
+        if let Some((i, _)) = nearest {
+            snapped.0.insert(i + 1, *snap_coord);
+        }
+    }
+
+    snapped.remove_repeated_points()
+}
+
+impl<T: GeoFloat> Snap<T> for Point<T> {
+    fn snap_to<'a, G>(&self, other: &'a G, tolerance: T) -> Self
+    where
+        G: CoordsIter<'a, Scalar = T>,
+    {
+        let snap_coords = snap_coords(other);
+        self.map_coords(|coord| snap_vertex(coord, &snap_coords, tolerance))
+    }
+}
+
+impl<T: GeoFloat> Snap<T> for MultiPoint<T> {
+    fn snap_to<'a, G>(&self, other: &'a G, tolerance: T) -> Self
+    where
+        G: CoordsIter<'a, Scalar = T>,
+    {
+        let snap_coords = snap_coords(other);
+        self.map_coords(|coord| snap_vertex(coord, &snap_coords, tolerance))
+    }
+}
+
+impl<T: GeoFloat + FromPrimitive> Snap<T> for LineString<T> {
+    fn snap_to<'a, G>(&self, other: &'a G, tolerance: T) -> Self
+    where
+        G: CoordsIter<'a, Scalar = T>,
+    {
+        snap_line_string(self, &snap_coords(other), tolerance)
+    }
+}
+
+impl<T: GeoFloat + FromPrimitive> Snap<T> for MultiLineString<T> {
+    fn snap_to<'a, G>(&self, other: &'a G, tolerance: T) -> Self
+    where
+        G: CoordsIter<'a, Scalar = T>,
+    {
+        let snap_coords = snap_coords(other);
+        MultiLineString::new(
+            self.iter()
+                .map(|line_string| snap_line_string(line_string, &snap_coords, tolerance))
+                .collect(),
+        )
+    }
+}
+
+fn snap_polygon<T: GeoFloat + FromPrimitive>(
+    polygon: &Polygon<T>,
+    snap_coords: &[Coord<T>],
+    tolerance: T,
+) -> Polygon<T> {
+    Polygon::new(
+        snap_line_string(polygon.exterior(), snap_coords, tolerance),
+        polygon
+            .interiors()
+            .iter()
+            .map(|ring| snap_line_string(ring, snap_coords, tolerance))
+            .collect(),
+    )
+}
+
+impl<T: GeoFloat + FromPrimitive> Snap<T> for Polygon<T> {
+    fn snap_to<'a, G>(&self, other: &'a G, tolerance: T) -> Self
+    where
+        G: CoordsIter<'a, Scalar = T>,
+    {
+        snap_polygon(self, &snap_coords(other), tolerance)
+    }
+}
+
+impl<T: GeoFloat + FromPrimitive> Snap<T> for MultiPolygon<T> {
+    fn snap_to<'a, G>(&self, other: &'a G, tolerance: T) -> Self
+    where
+        G: CoordsIter<'a, Scalar = T>,
+    {
+        let snap_coords = snap_coords(other);
+        MultiPolygon::new(
+            self.iter()
+                .map(|polygon| snap_polygon(polygon, &snap_coords, tolerance))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{line_string, point, polygon, Area, BooleanOps};
+
+    #[test]
+    fn snap_points() {
+        let other = line_string![(x: 0., y: 0.), (x: 1., y: 0.)];
+        assert_eq!(
+            point!(x: 0.95, y: 0.).snap_to(&other, 0.1),
+            point!(x: 1., y: 0.)
+        );
+        assert_eq!(
+            point!(x: 0.5, y: 0.).snap_to(&other, 0.1),
+            point!(x: 0.5, y: 0.)
+        );
+    }
+
+    #[test]
+    fn snap_collapsed_segments() {
+        // both vertices of the middle segment snap to the same vertex
+        let line_string = line_string![
+            (x: 0., y: 0.),
+            (x: 4.95, y: 0.),
+            (x: 5.05, y: 0.),
+            (x: 10., y: 0.),
+        ];
+        let other = line_string![(x: 5., y: 0.), (x: 5., y: 5.)];
+        assert_eq!(
+            line_string.snap_to(&other, 0.1),
+            line_string![(x: 0., y: 0.), (x: 5., y: 0.), (x: 10., y: 0.)]
+        );
+    }
+
+    #[test]
+    fn snap_into_segments() {
+        // several vertices of `other` are inserted into the same segment, in order
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        let other = line_string![(x: 7., y: 0.05), (x: 3., y: -0.05), (x: 5., y: 2.)];
+        assert_eq!(
+            line_string.snap_to(&other, 0.1),
+            line_string![(x: 0., y: 0.), (x: 3., y: -0.05), (x: 7., y: 0.05), (x: 10., y: 0.)]
+        );
+    }
+
+    #[test]
+    fn snap_almost_identical_polygons() {
+        // the same square with a hole, digitized slightly differently, and with an extra vertex
+        let a = polygon![
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 4., y: 4.), (x: 6., y: 4.), (x: 6., y: 6.), (x: 4., y: 6.)]],
+        ];
+        let b = polygon![
+            exterior: [
+                (x: 0.001, y: -0.002),
+                (x: 5., y: 0.003),
+                (x: 10., y: 0.),
+                (x: 9.998, y: 10.001),
+                (x: 0., y: 10.),
+            ],
+            interiors: [[(x: 4., y: 4.002), (x: 6.001, y: 4.), (x: 6., y: 6.), (x: 4., y: 5.999)]],
+        ];
+        assert!(a.difference(&b).unsigned_area() > 0.);
+        assert!(b.difference(&a).unsigned_area() > 0.);
+
+        let (a, b) = snap(&a, &b, 0.01);
+        assert!(a.difference(&b).0.is_empty());
+        assert!(b.difference(&a).0.is_empty());
+        assert_eq!(a.exterior().0.len(), 6);
+        assert_eq!(a.exterior(), b.exterior());
+        assert_eq!(a.interiors(), b.interiors());
+    }
+}
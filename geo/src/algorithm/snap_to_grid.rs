@@ -0,0 +1,302 @@
+use num_traits::FromPrimitive;
+
+use crate::algorithm::area::twice_signed_ring_area;
+use crate::{
+    Coord, GeoFloat, Geometry, GeometryCollection, Line, LineString, MapCoords, MultiLineString,
+    MultiPoint, MultiPolygon, Point, Polygon, Rect, RemoveRepeatedPoints, Triangle,
+};
+
+/// Reduce the precision of a geometry, by rounding its coordinates to a grid.
+///
+/// Each coordinate is rounded to the nearest multiple of `grid_size`, which must be positive,
+/// such as `1e-7` to round degrees of longitude and latitude to about a centimetre. Unlike
+/// rounding with [`MapCoords`], this also removes what collapses:
+///
+/// - repeated consecutive coordinates of `LineString`s and rings, and repeated points of a
+///   `MultiPoint`;
+/// - `LineString`s reduced to a single coordinate, which become empty, and are left out of
+///   a `MultiLineString`;
+/// - rings reduced to fewer than four coordinates, or to no area at all, such as thin slivers
+///   whose vertices all round onto a line. A `Polygon` whose exterior collapses becomes empty,
+///   and is left out of a `MultiPolygon`.
+///
+/// So the result never has degenerate rings, but rounding can still make a polygon invalid, for
+/// example by moving a vertex across another ring or making a ring cross itself. Use
+/// [`MakeValid`](crate::MakeValid) on the result to repair it.
+///
+/// `Point`s, `Line`s, `Rect`s and `Triangle`s only have their coordinates rounded, so may be
+/// left with no length or area. To instead round a `Rect` outward, so that it still contains
+/// the original, use [`Rect::snap_outward_to_grid`].
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, IsValid, MakeValid, SnapToGrid};
+///
+/// let polygon = polygon![
+///     exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///     interiors: [
+///         // a hole which is a thin sliver
+///         [(x: 2., y: 2.), (x: 8., y: 2.2), (x: 8., y: 2.3)],
+///         // a hole which rounds onto the exterior ring, making the polygon invalid
+///         [(x: 9., y: 5.), (x: 9.9, y: 4.), (x: 9.9, y: 6.)],
+///     ],
+/// ];
+///
+/// let snapped = polygon.snap_to_grid(1.);
+/// assert_eq!(
+///     snapped,
+///     polygon![
+///         exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+///         interiors: [[(x: 9., y: 5.), (x: 10., y: 4.), (x: 10., y: 6.)]],
+///     ]
+/// );
+/// assert!(!snapped.is_valid());
+/// assert!(snapped.make_valid().is_valid());
+/// ```
+pub trait SnapToGrid<T: GeoFloat> {
+    /// Returns `self` with its coordinates rounded to multiples of `grid_size`, and what
+    /// collapses removed.
+    fn snap_to_grid(&self, grid_size: T) -> Self;
+}
+
+fn round<T: GeoFloat>(coord: Coord<T>, grid_size: T) -> Coord<T> {
+    Coord {
+        x: (coord.x / grid_size).round() * grid_size,
+        y: (coord.y / grid_size).round() * grid_size,
+    }
+}
+
+/// The rounded `ring`, unless it collapses.
+fn snap_ring<T: GeoFloat + FromPrimitive>(
+    ring: &LineString<T>,
+    grid_size: T,
+) -> Option<LineString<T>> {
+    let ring = ring.snap_to_grid(grid_size);
+    (ring.0.len() >= 4 && twice_signed_ring_area(&ring) != T::zero()).then_some(ring)
+}
+
+impl<T: GeoFloat> SnapToGrid<T> for Point<T> {
+    fn snap_to_grid(&self, grid_size: T) -> Self {
+        self.map_coords(|coord| round(coord, grid_size))
+    }
+}
+
+impl<T: GeoFloat> SnapToGrid<T> for Line<T> {
+    fn snap_to_grid(&self, grid_size: T) -> Self {
+        self.map_coords(|coord| round(coord, grid_size))
+    }
+}
+
+impl<T: GeoFloat> SnapToGrid<T> for Rect<T> {
+    fn snap_to_grid(&self, grid_size: T) -> Self {
+        self.map_coords(|coord| round(coord, grid_size))
+    }
+}
+
+impl<T: GeoFloat> SnapToGrid<T> for Triangle<T> {
+    fn snap_to_grid(&self, grid_size: T) -> Self {
+        self.map_coords(|coord| round(coord, grid_size))
+    }
+}
+
+impl<T: GeoFloat + FromPrimitive> SnapToGrid<T> for MultiPoint<T> {
+    fn snap_to_grid(&self, grid_size: T) -> Self {
+        self.map_coords(|coord| round(coord, grid_size))
+            .remove_repeated_points()
+    }
+}
+
+impl<T: GeoFloat + FromPrimitive> SnapToGrid<T> for LineString<T> {
+    fn snap_to_grid(&self, grid_size: T) -> Self {
+        let line_string = self
+            .map_coords(|coord| round(coord, grid_size))
+            .remove_repeated_points();
+        if line_string.0.len() < 2 {
+            LineString::new(vec![])
+        } else {
+            line_string
+        }
+    }
+}
+
+impl<T: GeoFloat + FromPrimitive> SnapToGrid<T> for MultiLineString<T> {
+    fn snap_to_grid(&self, grid_size: T) -> Self {
+        MultiLineString::new(
+            self.iter()
+                .map(|line_string| line_string.snap_to_grid(grid_size))
+                .filter(|line_string| !line_string.0.is_empty())
+                .collect(),
+        )
+    }
+}
+
+impl<T: GeoFloat + FromPrimitive> SnapToGrid<T> for Polygon<T> {
+    fn snap_to_grid(&self, grid_size: T) -> Self {
+        match snap_ring(self.exterior(), grid_size) {
+            Some(exterior) => Polygon::new(
+                exterior,
+                self.interiors()
+                    .iter()
+                    .filter_map(|ring| snap_ring(ring, grid_size))
+                    .collect(),
+            ),
+            None => Polygon::new(LineString::new(vec![]), vec![]),
+        }
+    }
+}
+
+impl<T: GeoFloat + FromPrimitive> SnapToGrid<T> for MultiPolygon<T> {
+    fn snap_to_grid(&self, grid_size: T) -> Self {
+        MultiPolygon::new(
+            self.iter()
+                .map(|polygon| polygon.snap_to_grid(grid_size))
+                .filter(|polygon| !polygon.exterior().0.is_empty())
+                .collect(),
+        )
+    }
+}
+
+impl<T: GeoFloat + FromPrimitive> SnapToGrid<T> for GeometryCollection<T> {
+    fn snap_to_grid(&self, grid_size: T) -> Self {
+        GeometryCollection::new_from(self.iter().map(|g| g.snap_to_grid(grid_size)).collect())
+    }
+}
+
+impl<T: GeoFloat + FromPrimitive> SnapToGrid<T> for Geometry<T> {
+    fn snap_to_grid(&self, grid_size: T) -> Self {
+        match self {
+            Geometry::Point(p) => Geometry::Point(p.snap_to_grid(grid_size)),
+            Geometry::Line(l) => Geometry::Line(l.snap_to_grid(grid_size)),
+            Geometry::LineString(ls) => Geometry::LineString(ls.snap_to_grid(grid_size)),
+            Geometry::Polygon(p) => Geometry::Polygon(p.snap_to_grid(grid_size)),
+            Geometry::MultiPoint(mp) => Geometry::MultiPoint(mp.snap_to_grid(grid_size)),
+            Geometry::MultiLineString(mls) => {
+                Geometry::MultiLineString(mls.snap_to_grid(grid_size))
+            }
+            Geometry::MultiPolygon(mp) => Geometry::MultiPolygon(mp.snap_to_grid(grid_size)),
+            Geometry::Rect(r) => Geometry::Rect(r.snap_to_grid(grid_size)),
+            Geometry::Triangle(t) => Geometry::Triangle(t.snap_to_grid(grid_size)),
+            Geometry::GeometryCollection(gc) => {
+                Geometry::GeometryCollection(gc.snap_to_grid(grid_size))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{line_string, point, polygon, Area};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// Asserts that no ring has fewer than four coordinates, repeated consecutive coordinates,
+    /// or no area.
+    fn assert_no_degenerate_rings(multi_polygon: &MultiPolygon<f64>) {
+        for polygon in multi_polygon {
+            assert!(!polygon.exterior().0.is_empty());
+            for ring in std::iter::once(polygon.exterior()).chain(polygon.interiors()) {
+                assert!(ring.0.len() >= 4, "{ring:?}");
+                assert!(ring.0.windows(2).all(|w| w[0] != w[1]), "{ring:?}");
+                assert_ne!(twice_signed_ring_area(ring), 0., "{ring:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn snap_coords() {
+        assert_eq!(
+            point!(x: 1.23456789, y: -0.00000004).snap_to_grid(1e-3),
+            point!(x: 1.235, y: 0.)
+        );
+        assert_eq!(
+            line_string![(x: 0.2, y: 0.), (x: 0.4, y: 0.), (x: 1.4, y: 0.), (x: 1.6, y: 0.)]
+                .snap_to_grid(1.),
+            line_string![(x: 0., y: 0.), (x: 1., y: 0.), (x: 2., y: 0.)]
+        );
+        assert_eq!(
+            line_string![(x: 0.2, y: 0.), (x: 0.4, y: 0.1)].snap_to_grid(1.),
+            line_string![]
+        );
+        assert_eq!(
+            MultiPoint::from(vec![(0.1, 0.), (0.2, 0.), (1., 0.)]).snap_to_grid(1.),
+            MultiPoint::from(vec![(0., 0.), (1., 0.)])
+        );
+        // to the nearest corners, unlike `Rect::snap_outward_to_grid`
+        let rect = Rect::new((0.2, -0.2), (1.8, 2.));
+        assert_eq!(rect.snap_to_grid(1.), Rect::new((0., 0.), (2., 2.)));
+        assert_eq!(
+            rect.snap_outward_to_grid(1.),
+            Rect::new((0., -1.), (2., 2.))
+        );
+    }
+
+    #[test]
+    fn collapsed_exterior() {
+        // a sliver, whose vertices round onto a line
+        let sliver = polygon![(x: 0., y: 0.), (x: 10., y: 0.2), (x: 20., y: -0.2)];
+        assert!(sliver.snap_to_grid(1.).exterior().0.is_empty());
+        let multi_polygon = MultiPolygon::new(vec![
+            sliver,
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)],
+        ]);
+        assert_eq!(
+            multi_polygon.snap_to_grid(1.),
+            MultiPolygon::new(vec![
+                polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.)]
+            ])
+        );
+    }
+
+    #[test]
+    fn adversarial_slivers() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut random = move |max: f64| rng.gen::<f64>() * max;
+        let mut dropped = 0;
+        for _ in 0..100 {
+            // thin slivers and spikes, some a little wider than the grid, and most narrower
+            let polygons = (0..10)
+                .map(|_| {
+                    let (x, y) = (random(100.), random(100.));
+                    let (dx, dy) = (random(20.) - 10., random(20.) - 10.);
+                    let (width, bend) = (random(2.), random(1.));
+                    let ring = |scale: f64| {
+                        LineString::from(vec![
+                            (x, y),
+                            (x + scale * dx, y + scale * dy),
+                            (
+                                x + scale * dx - width * dy / 10.,
+                                y + scale * dy + width * dx / 10.,
+                            ),
+                            (x + scale * dx / 2., y + scale * dy / 2. + bend),
+                        ])
+                    };
+                    Polygon::new(ring(1.), vec![ring(0.5)])
+                })
+                .collect();
+            let multi_polygon = MultiPolygon::new(polygons);
+
+            let snapped = multi_polygon.snap_to_grid(1.);
+            assert_no_degenerate_rings(&snapped);
+            dropped += multi_polygon.0.len() - snapped.0.len();
+        }
+        assert!(dropped > 100, "only {dropped} polygons collapsed");
+    }
+
+    #[test]
+    fn snap_geometry() {
+        let geometry = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            Geometry::Polygon(polygon![(x: 0., y: 0.), (x: 0.4, y: 0.), (x: 0.4, y: 0.4)]),
+            Geometry::Line(Line::new((0.2, 0.9), (1.6, 0.))),
+        ]));
+        assert_eq!(
+            geometry.snap_to_grid(1.),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+                Geometry::Polygon(Polygon::new(LineString::new(vec![]), vec![])),
+                Geometry::Line(Line::new((0., 1.), (2., 0.))),
+            ]))
+        );
+        assert_eq!(geometry.snap_to_grid(1.).unsigned_area(), 0.);
+    }
+}
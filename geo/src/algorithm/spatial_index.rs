@@ -0,0 +1,248 @@
+use std::iter::FromIterator;
+
+use rstar::{PointDistance, RTree, RTreeObject, AABB};
+
+use crate::{BoundingRect, Closest, ClosestPoint, CoordsIter, GeoFloat, Geometry, Point, Rect};
+
+/// An [R-tree] of geometries, which can be queried for the geometries near a `Point` or
+/// overlapping a `Rect`.
+///
+/// Building the index once and querying it many times is much faster than testing every
+/// geometry for every query, and it saves depending on the [`rstar`] crate directly.
+///
+/// Each geometry is identified by its position in the iterator the index was built from. Empty
+/// geometries, which have no bounding rectangle, can't be located and are left out of the index.
+///
+/// [R-tree]: https://en.wikipedia.org/wiki/R-tree
+/// [`rstar`]: https://docs.rs/rstar
+///
+/// # Examples
+///
+/// ```
+/// use geo::{line_string, point, polygon, Geometry, Intersects, Rect, SpatialIndex};
+///
+/// let geometries: Vec<Geometry> = vec![
+///     point!(x: 1., y: 1.).into(),
+///     line_string![(x: 5., y: 0.), (x: 5., y: 10.)].into(),
+///     polygon![(x: 20., y: 20.), (x: 30., y: 20.), (x: 30., y: 30.)].into(),
+///     // a diagonal line whose bounding rect, but not the line itself, overlaps the query
+///     line_string![(x: 9., y: 0.), (x: 0., y: 9.)].into(),
+/// ];
+/// let index = SpatialIndex::new(geometries);
+///
+/// let query = Rect::new((0., 0.), (6., 2.));
+/// let mut found: Vec<usize> = index
+///     .locate_in_envelope_intersecting(&query)
+///     // the index only compares bounding rects, so check the geometries themselves
+///     .filter(|(_, geometry)| geometry.intersects(&query))
+///     .map(|(position, _)| position)
+///     .collect();
+/// found.sort();
+/// assert_eq!(found, vec![0, 1]);
+///
+/// let (position, _) = index.nearest_neighbor(&point!(x: 18., y: 25.)).unwrap();
+/// assert_eq!(position, 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpatialIndex<T: GeoFloat> {
+    tree: RTree<IndexedGeometry<T>>,
+}
+
+impl<T: GeoFloat> SpatialIndex<T> {
+    /// Build an index of `geometries`, by bulk loading them into an R-tree.
+    pub fn new<I>(geometries: I) -> Self
+    where
+        I: IntoIterator<Item = Geometry<T>>,
+    {
+        let indexed: Vec<IndexedGeometry<T>> = geometries
+            .into_iter()
+            .enumerate()
+            .filter_map(|(position, geometry)| {
+                let rect = geometry.bounding_rect()?;
+                Some(IndexedGeometry {
+                    position,
+                    envelope: AABB::from_corners(rect.min().into(), rect.max().into()),
+                    geometry,
+                })
+            })
+            .collect();
+        SpatialIndex {
+            tree: RTree::bulk_load(indexed),
+        }
+    }
+
+    /// The number of geometries in the index.
+    pub fn len(&self) -> usize {
+        self.tree.size()
+    }
+
+    /// Whether the index has no geometries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The geometry which is nearest to `point`, with its position, or `None` if the index is
+    /// empty.
+    ///
+    /// Distances are measured to the geometry itself rather than its bounding rect, so any
+    /// geometry which contains `point` is at a distance of zero. If several geometries are as
+    /// near as each other, any one of them may be returned.
+    pub fn nearest_neighbor(&self, point: &Point<T>) -> Option<(usize, &Geometry<T>)> {
+        self.tree
+            .nearest_neighbor(point)
+            .map(|indexed| (indexed.position, &indexed.geometry))
+    }
+
+    /// All the geometries whose bounding rects intersect `rect`, with their positions, in no
+    /// particular order.
+    ///
+    /// This is a cheap filter: it can return geometries which don't themselves intersect `rect`,
+    /// but it won't miss any which do.
+    pub fn locate_in_envelope_intersecting(
+        &self,
+        rect: &Rect<T>,
+    ) -> impl Iterator<Item = (usize, &Geometry<T>)> {
+        let envelope = AABB::from_corners(rect.min().into(), rect.max().into());
+        self.tree
+            .locate_in_envelope_intersecting(&envelope)
+            .map(|indexed| (indexed.position, &indexed.geometry))
+    }
+}
+
+impl<T: GeoFloat> FromIterator<Geometry<T>> for SpatialIndex<T> {
+    fn from_iter<I: IntoIterator<Item = Geometry<T>>>(iter: I) -> Self {
+        SpatialIndex::new(iter)
+    }
+}
+
+/// A geometry in the R-tree, with its position in the input and its cached bounding rect.
+#[derive(Clone, Debug)]
+struct IndexedGeometry<T: GeoFloat> {
+    position: usize,
+    envelope: AABB<Point<T>>,
+    geometry: Geometry<T>,
+}
+
+impl<T: GeoFloat> RTreeObject for IndexedGeometry<T> {
+    type Envelope = AABB<Point<T>>;
+
+    fn envelope(&self) -> Self::Envelope {
+        self.envelope
+    }
+}
+
+impl<T: GeoFloat> PointDistance for IndexedGeometry<T> {
+    fn distance_2(&self, point: &Point<T>) -> T {
+        let distance_2 = |other: Point<T>| {
+            let delta = other - *point;
+            delta.x() * delta.x() + delta.y() * delta.y()
+        };
+        match self.geometry.closest_point(point) {
+            Closest::Intersection(_) => T::zero(),
+            Closest::SinglePoint(closest) => distance_2(closest),
+            // a degenerate geometry, such as a zero length line, whose coordinates are all the
+            // same, so the nearest one is as near as any other
+            Closest::Indeterminate => self
+                .geometry
+                .coords_iter()
+                .map(|coord| distance_2(coord.into()))
+                .fold(T::infinity(), T::min),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, EuclideanDistance, Line, LineString, MultiPoint};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn empty_index() {
+        let index = SpatialIndex::<f64>::new(vec![]);
+        assert!(index.is_empty());
+        assert_eq!(index.nearest_neighbor(&point!(x: 0., y: 0.)), None);
+        let query = Rect::new((0., 0.), (1., 1.));
+        assert_eq!(index.locate_in_envelope_intersecting(&query).count(), 0);
+    }
+
+    #[test]
+    fn empty_geometries_are_skipped() {
+        let index: SpatialIndex<f64> = vec![
+            LineString::new(vec![]).into(),
+            point!(x: 1., y: 1.).into(),
+            MultiPoint::<f64>::new(vec![]).into(),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(index.len(), 1);
+        let (position, geometry) = index.nearest_neighbor(&point!(x: 0., y: 0.)).unwrap();
+        assert_eq!(position, 1);
+        assert_eq!(geometry, &Geometry::Point(point!(x: 1., y: 1.)));
+    }
+
+    #[test]
+    fn nearest_neighbor_uses_exact_distance() {
+        let geometries: Vec<Geometry> = vec![
+            // a long diagonal line, whose bounding rect contains the query point
+            line_string![(x: 0., y: 0.), (x: 100., y: 100.)].into(),
+            point!(x: 55., y: 44.).into(),
+            // a polygon containing the query point
+            polygon![(x: 40., y: 60.), (x: 60., y: 60.), (x: 50., y: 45.)].into(),
+        ];
+        let index = SpatialIndex::new(geometries);
+
+        let (position, _) = index.nearest_neighbor(&point!(x: 50., y: 53.)).unwrap();
+        assert_eq!(position, 2);
+        let (position, _) = index.nearest_neighbor(&point!(x: 55., y: 45.)).unwrap();
+        assert_eq!(position, 1);
+        let (position, _) = index.nearest_neighbor(&point!(x: 20., y: 21.)).unwrap();
+        assert_eq!(position, 0);
+
+        // a zero length line
+        let index = SpatialIndex::new(vec![
+            Line::new((5., 5.), (5., 5.)).into(),
+            point!(x: 7., y: 5.).into(),
+        ]);
+        let (position, _) = index.nearest_neighbor(&point!(x: 5.5, y: 5.)).unwrap();
+        assert_eq!(position, 0);
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut next = move || rng.gen::<f64>() * 100.;
+
+        let points: Vec<Point> = (0..500).map(|_| point!(x: next(), y: next())).collect();
+        let index: SpatialIndex<f64> = points.iter().map(|&p| Geometry::Point(p)).collect();
+        assert_eq!(index.len(), 500);
+
+        for _ in 0..50 {
+            let query = point!(x: next(), y: next());
+            let (_, nearest) = index.nearest_neighbor(&query).unwrap();
+            let nearest = Point::try_from(nearest.clone()).unwrap();
+            let brute_force = points
+                .iter()
+                .map(|p| p.euclidean_distance(&query))
+                .fold(f64::INFINITY, f64::min);
+            assert_eq!(nearest.euclidean_distance(&query), brute_force);
+
+            let (a, b) = (next(), next());
+            let rect = Rect::new((a, b), (a + 10., b + 10.));
+            let mut found: Vec<usize> = index
+                .locate_in_envelope_intersecting(&rect)
+                .map(|(position, _)| position)
+                .collect();
+            found.sort_unstable();
+            let expected: Vec<usize> = (0..points.len())
+                .filter(|&i| {
+                    let p = points[i];
+                    (rect.min().x..=rect.max().x).contains(&p.x())
+                        && (rect.min().y..=rect.max().y).contains(&p.y())
+                })
+                .collect();
+            assert_eq!(found, expected);
+        }
+    }
+}
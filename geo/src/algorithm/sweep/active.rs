@@ -1,10 +1,4 @@
-use std::{
-    borrow::Borrow,
-    cmp::Ordering,
-    collections::BTreeSet,
-    fmt::Debug,
-    ops::{Bound, Deref},
-};
+use std::{borrow::Borrow, cmp::Ordering, ops::Deref};
 
 /// A segment currently active in the sweep.
 ///
@@ -13,20 +7,13 @@ use std::{
 /// efficient access to the neighbors of a segment is a key aspect of
 /// planar-sweep algorithms.
 ///
-/// We assert `Ord` even though the inner-type is typically only `T:
-/// PartialOrd`. It is a logical error to compare two Active which cannot be
-/// compared. This is ensured by the algorithm (and cannot be inferred by the
-/// compiler?).
+/// The inner-type is typically only `T: PartialOrd`. Rounding can leave two
+/// active segments which cannot be compared, which the sweep detects, and
+/// fails on.
 #[derive(Debug, Clone, Copy, PartialEq)]
 #[repr(transparent)]
 pub(super) struct Active<T>(pub(super) T);
 
-impl<T> Active<T> {
-    pub(super) fn active_ref(t: &T) -> &Active<T> {
-        unsafe { std::mem::transmute(t) }
-    }
-}
-
 impl<T> Borrow<T> for Active<T> {
     fn borrow(&self) -> &T {
         &self.0
@@ -41,86 +28,8 @@ impl<T> Deref for Active<T> {
     }
 }
 
-/// Assert total equality.
-impl<T: PartialEq> Eq for Active<T> {}
-
-/// Assert total ordering of active segments.
-impl<T: PartialOrd + Debug> Ord for Active<T> {
-    fn cmp(&self, other: &Self) -> Ordering {
-        if let Some(c) = T::partial_cmp(self, other) {
-            c
-        } else {
-            warn!("could not compare segments:\n\t{self:?}\n\t{other:?}");
-            panic!("unable to compare active segments!");
-        }
-        // T::partial_cmp(self, other).unwrap()
-        // T::partial_cmp(self, other).unwrap()
-    }
-}
-
-impl<T: PartialOrd + Debug> PartialOrd for Active<T> {
+impl<T: PartialOrd> PartialOrd for Active<T> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-/// Trait abstracting a container of active segments.
-pub(super) trait ActiveSet: Default {
-    type Seg;
-    fn previous_find<F: FnMut(&Active<Self::Seg>) -> bool>(
-        &self,
-        segment: &Self::Seg,
-        f: F,
-    ) -> Option<&Active<Self::Seg>>;
-    fn previous(&self, segment: &Self::Seg) -> Option<&Active<Self::Seg>> {
-        self.previous_find(segment, |_| true)
-    }
-    fn next_find<F: FnMut(&Active<Self::Seg>) -> bool>(
-        &self,
-        segment: &Self::Seg,
-        f: F,
-    ) -> Option<&Active<Self::Seg>>;
-    fn next(&self, segment: &Self::Seg) -> Option<&Active<Self::Seg>> {
-        self.next_find(segment, |_| true)
-    }
-    fn insert_active(&mut self, segment: Self::Seg);
-    fn remove_active(&mut self, segment: &Self::Seg);
-}
-
-impl<T: PartialOrd + Debug> ActiveSet for BTreeSet<Active<T>> {
-    type Seg = T;
-
-    fn previous_find<F: FnMut(&Active<Self::Seg>) -> bool>(
-        &self,
-        segment: &Self::Seg,
-        mut f: F,
-    ) -> Option<&Active<Self::Seg>> {
-        self.range::<Active<_>, _>((
-            Bound::Unbounded,
-            Bound::Excluded(Active::active_ref(segment)),
-        ))
-        .rev()
-        .find(|&a| f(a))
-    }
-    fn next_find<F: FnMut(&Active<Self::Seg>) -> bool>(
-        &self,
-        segment: &Self::Seg,
-        mut f: F,
-    ) -> Option<&Active<Self::Seg>> {
-        self.range::<Active<_>, _>((
-            Bound::Excluded(Active::active_ref(segment)),
-            Bound::Unbounded,
-        ))
-        .find(|&a| f(a))
-    }
-
-    fn insert_active(&mut self, segment: Self::Seg) {
-        let result = self.insert(Active(segment));
-        debug_assert!(result);
-    }
-
-    fn remove_active(&mut self, segment: &Self::Seg) {
-        let result = self.remove(Active::active_ref(segment));
-        debug_assert!(result);
+        T::partial_cmp(self, other)
     }
 }
@@ -3,13 +3,14 @@ use std::{fmt::Debug, rc::Rc, sync::Arc};
 use geo_types::Line;
 
 use super::*;
-use crate::GeoFloat;
+use crate::GeoNum;
 
 /// Interface for types that can be processed to detect crossings.
 ///
-/// This type is implemented by [`LineOrPoint`], but users may also implement
-/// this on custom types to store extra information. Any type that represents an
-/// ordered line-segment may implement this.
+/// This type is implemented by [`LineOrPoint`], [`Line`], and a `Line` paired
+/// with any extra data, as in `(Line<T>, D)`. Users may also implement this on
+/// custom types to store extra information. Any type that represents an ordered
+/// line-segment may implement this.
 ///
 /// # Cloning
 ///
@@ -19,7 +20,7 @@ use crate::GeoFloat;
 /// are supported via blanket trait implementations.
 pub trait Cross: Sized + Debug {
     /// Scalar used the coordinates.
-    type Scalar: GeoFloat;
+    type Scalar: GeoNum;
 
     /// The geometry associated with this type. Use a `Line` with the
     /// `start` and `end` coordinates to represent a point.
@@ -34,7 +35,7 @@ impl<'a, T: Cross> Cross for &'a T {
     }
 }
 
-impl<T: GeoFloat> Cross for LineOrPoint<T> {
+impl<T: GeoNum> Cross for LineOrPoint<T> {
     type Scalar = T;
 
     fn line(&self) -> LineOrPoint<Self::Scalar> {
@@ -42,7 +43,7 @@ impl<T: GeoFloat> Cross for LineOrPoint<T> {
     }
 }
 
-impl<T: GeoFloat> Cross for Line<T> {
+impl<T: GeoNum> Cross for Line<T> {
     type Scalar = T;
 
     fn line(&self) -> LineOrPoint<Self::Scalar> {
@@ -50,6 +51,16 @@ impl<T: GeoFloat> Cross for Line<T> {
     }
 }
 
+/// A `Line` with some associated data, such as an id to map crossings back
+/// to the input.
+impl<T: GeoNum, D: Debug> Cross for (Line<T>, D) {
+    type Scalar = T;
+
+    fn line(&self) -> LineOrPoint<Self::Scalar> {
+        self.0.into()
+    }
+}
+
 macro_rules! blanket_impl_smart_pointer {
     ($ty:ty) => {
         impl<T: Cross> Cross for $ty {
@@ -1,7 +1,7 @@
 use std::cmp::Ordering;
 
 use super::*;
-use crate::{line_intersection::line_intersection, Coord, LineIntersection};
+use crate::{line_intersection::line_intersection, Coord, GeoFloat, LineIntersection};
 
 /// A segment of a input [`Cross`] type.
 ///
@@ -42,15 +42,14 @@ pub(crate) struct Crossing<C: Cross> {
     pub(super) segment: IMSegment<C>,
 }
 
-pub(crate) fn compare_crossings<X: Cross>(a: &Crossing<X>, b: &Crossing<X>) -> Ordering {
-    a.at_left.cmp(&b.at_left).then_with(|| {
-        let ord = a.segment.partial_cmp(&b.segment).unwrap();
-        if a.at_left {
-            ord
-        } else {
-            ord.reverse()
-        }
-    })
+/// Compare crossings by `at_left`, and then by the order of their segments around the
+/// intersection, or `None` if the segments can't be ordered.
+fn compare_crossings<X: Cross>(a: &Crossing<X>, b: &Crossing<X>) -> Option<Ordering> {
+    let ord = match a.at_left.cmp(&b.at_left) {
+        Ordering::Equal => a.segment.partial_cmp(&b.segment)?,
+        ord => return Some(ord),
+    };
+    Some(if a.at_left { ord } else { ord.reverse() })
 }
 
 impl<C: Cross + Clone> Crossing<C> {
@@ -128,11 +127,54 @@ where
         &self.segments
     }
 
-    pub(crate) fn prev_active(&self, c: &Crossing<C>) -> Option<(LineOrPoint<C::Scalar>, C)> {
+    /// Sort the segments of the last intersection with [`compare_crossings`].
+    pub(crate) fn sort_intersections(&mut self) -> Result<(), SweepError<C::Scalar>> {
+        let mut failed = None;
+        self.segments.sort_unstable_by(|a, b| {
+            compare_crossings(a, b).unwrap_or_else(|| {
+                failed = Some(a.line.left());
+                Ordering::Equal
+            })
+        });
+        failed.map_or(Ok(()), |point| Err(point.into()))
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub(crate) fn prev_active(
+        &self,
+        c: &Crossing<C>,
+    ) -> Result<Option<(LineOrPoint<C::Scalar>, C)>, SweepError<C::Scalar>> {
         self.sweep
             .with_prev_active(c, |s| (s.geom, s.cross.clone()))
     }
 
+    /// Advance to the next intersection, like [`Iterator::next`], computing with the arithmetic
+    /// `A`, but returning an error instead of panicking if the sweep fails.
+    pub(crate) fn try_next<A: SweepArith<C::Scalar>>(
+        &mut self,
+    ) -> Result<Option<Coord<C::Scalar>>, SweepError<C::Scalar>> {
+        let segments = &mut self.segments;
+
+        segments.clear();
+        let mut last_point = self.sweep.peek_point();
+        debug!("pt: {last_point:?}");
+        while last_point == self.sweep.peek_point() && self.sweep.peek_point().is_some() {
+            last_point = self.sweep.next_event::<A, _>(|seg, ty| {
+                trace!(
+                    "cb: {seg:?} {ty:?} (crossable = {cross:?})",
+                    cross = seg.cross_cloned().line()
+                );
+                segments.push(Crossing::from_segment(seg, ty))
+            })?;
+        }
+
+        if segments.is_empty() {
+            Ok(None)
+        } else {
+            Ok(last_point.map(|p| *p))
+        }
+    }
+
     fn new_ex<T: IntoIterator<Item = C>>(iter: T, is_simple: bool) -> Self {
         let iter = iter.into_iter();
         let size = {
@@ -157,30 +199,13 @@ where
 impl<C> Iterator for CrossingsIter<C>
 where
     C: Cross + Clone,
+    C::Scalar: GeoFloat,
 {
     type Item = Coord<C::Scalar>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let segments = &mut self.segments;
-
-        segments.clear();
-        let mut last_point = self.sweep.peek_point();
-        debug!("pt: {last_point:?}");
-        while last_point == self.sweep.peek_point() && self.sweep.peek_point().is_some() {
-            last_point = self.sweep.next_event(|seg, ty| {
-                trace!(
-                    "cb: {seg:?} {ty:?} (crossable = {cross:?})",
-                    cross = seg.cross_cloned().line()
-                );
-                segments.push(Crossing::from_segment(seg, ty))
-            });
-        }
-
-        if segments.is_empty() {
-            None
-        } else {
-            last_point.map(|p| *p)
-        }
+        self.try_next::<FloatArith>()
+            .unwrap_or_else(|err| panic!("{err}"))
     }
 }
 
@@ -195,6 +220,10 @@ where
 /// search for intersections across all pairs of input segments if k,
 /// the number of intersections is small compared to n^2.
 ///
+/// Lines are split at their intersections as they are found. With
+/// floating point coordinates, the split points are rounded, so lines
+/// which only touch, very near other intersections, may be missed.
+///
 /// ## Usage
 ///
 /// Construct from an iterator of any type implementing the
@@ -217,6 +246,31 @@ where
 /// assert_eq!(iter.count(), 6);
 /// ```
 ///
+/// To map the intersections back to the input, pair each line with an id, or
+/// any other data. Overlapping lines are reported with the extent of their
+/// overlap, as [`LineIntersection::Collinear`], directed from left to right,
+/// whatever the direction of the input lines.
+///
+/// ```rust
+/// use geo::sweep::Intersections;
+/// use geo::{coord, Line, LineIntersection};
+/// let input = vec![
+///     (Line::from([(0., 0.), (2., 0.)]), "a"),
+///     (Line::from([(1., 0.), (3., 0.)]), "b"),
+///     (Line::from([(0., 1.), (0., 2.)]), "c"),
+/// ];
+/// let mut iter: Intersections<_> = input.into_iter().collect();
+/// let ((_, a), (_, b), intersection) = iter.next().unwrap();
+/// assert_eq!((a, b), ("a", "b"));
+/// assert_eq!(
+///     intersection,
+///     LineIntersection::Collinear {
+///         intersection: Line::new(coord! { x: 1., y: 0. }, coord! { x: 2., y: 0. }),
+///     }
+/// );
+/// assert!(iter.next().is_none());
+/// ```
+///
 /// [Bentley-Ottman]: //en.wikipedia.org/wiki/Bentley%E2%80%93Ottmann_algorithm
 pub struct Intersections<C: Cross + Clone> {
     inner: CrossingsIter<C>,
@@ -244,6 +298,7 @@ where
 impl<C> Intersections<C>
 where
     C: Cross + Clone,
+    C::Scalar: GeoFloat,
 {
     fn intersection(&mut self) -> Option<(C, C, LineIntersection<C::Scalar>)> {
         let (si, sj) = {
@@ -316,6 +371,7 @@ where
 impl<C> Iterator for Intersections<C>
 where
     C: Cross + Clone,
+    C::Scalar: GeoFloat,
 {
     type Item = (C, C, LineIntersection<C::Scalar>);
 
@@ -335,9 +391,11 @@ where
 
 #[cfg(test)]
 pub(super) mod tests {
-    use crate::Line;
+    use crate::{coord, line_intersection::line_intersection, Line};
     use log::info;
     use pretty_env_logger::env_logger;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
     use std::{io::Write, rc::Rc};
 
     use super::*;
@@ -400,6 +458,67 @@ pub(super) mod tests {
         assert_eq!(count, verify);
     }
 
+    #[test]
+    fn random_intersections_with_ids() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut random = move |max: f64| {
+            // multiples of 1/1024, so the overlapping lines below are exact
+            rng.gen_range(0..max as u64 * 1024) as f64 / 1024.
+        };
+        let mut lines = vec![];
+        for i in 0..2000 {
+            let start = coord! { x: random(100.), y: random(100.) };
+            let end = coord! { x: start.x + random(10.) - 5., y: start.y + random(10.) - 5. };
+            lines.push(Line::new(start, end));
+            // some horizontal and vertical lines overlap, partially or entirely
+            if i % 20 == 0 {
+                let (x, y, length) = (start.x, start.y, random(10.));
+                let line = |from: f64, to: f64| {
+                    if i % 40 == 0 {
+                        Line::from([(from, y), (to, y)])
+                    } else {
+                        Line::from([(y, from), (y, to)])
+                    }
+                };
+                lines.push(line(x, x + length));
+                lines.push(line(x + 2. * length, x + length / 2.));
+                lines.push(line(x + length, x));
+            }
+        }
+        let input: Vec<_> = lines.into_iter().zip(0..).collect();
+
+        let mut expected = vec![];
+        for (i, (p, _)) in input.iter().enumerate() {
+            for (j, (q, _)) in input.iter().enumerate().skip(i + 1) {
+                if line_intersection(*p, *q).is_some() {
+                    expected.push((i, j));
+                }
+            }
+        }
+
+        // the direction of an overlap is that of the sweep, not of the input
+        let undirected = |intersection| match intersection {
+            LineIntersection::Collinear { intersection } => LineIntersection::Collinear {
+                intersection: LineOrPoint::from(intersection).line(),
+            },
+            single_point => single_point,
+        };
+        let mut overlaps = 0;
+        let mut found: Vec<_> = Intersections::<_>::from_iter(&input)
+            .map(|((p, i), (q, j), intersection)| {
+                let expected = line_intersection(*p, *q).unwrap();
+                assert_eq!(undirected(intersection), undirected(expected));
+                if let LineIntersection::Collinear { .. } = intersection {
+                    overlaps += 1;
+                }
+                (*i.min(j), *i.max(j))
+            })
+            .collect();
+        assert!(overlaps >= 300, "only {overlaps} overlaps");
+        found.sort_unstable();
+        assert_eq!(found, expected);
+    }
+
     #[test]
     #[ignore]
     fn check_adhoc_crossings() {
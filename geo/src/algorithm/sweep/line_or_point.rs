@@ -1,9 +1,7 @@
 use std::{cmp::Ordering, ops::Deref};
 
-use super::SweepPoint;
-use crate::{
-    line_intersection::line_intersection, Coord, GeoFloat, GeoNum, Kernel, Line, LineIntersection,
-};
+use super::{FloatArith, SweepArith, SweepPoint};
+use crate::{Coord, GeoFloat, GeoNum, Kernel, Line, LineIntersection};
 
 /// Either a line segment or a point.
 ///
@@ -105,6 +103,12 @@ impl<T: GeoNum> LineOrPoint<T> {
     pub fn new(left: SweepPoint<T>, right: SweepPoint<T>) -> Self {
         Self { left, right }
     }
+
+    /// Whether the end points can be compared, which they can't if any coordinate is NaN.
+    #[inline]
+    pub(crate) fn is_ordered(&self) -> bool {
+        self.left.partial_cmp(&self.right).is_some()
+    }
 }
 
 /// Equality based on ordering defined for segments as per algorithm.
@@ -176,6 +180,17 @@ impl<T: GeoFloat> LineOrPoint<T> {
     ///
     /// The `other` argument must be a line variant (debug builds will panic otherwise).
     pub fn intersect_line(&self, other: &Self) -> Option<Self> {
+        self.intersect_line_with::<FloatArith>(other)
+    }
+
+    pub fn intersect_line_ordered(&self, other: &Self) -> Option<Self> {
+        self.intersect_line_ordered_with::<FloatArith>(other)
+    }
+}
+
+impl<T: GeoNum> LineOrPoint<T> {
+    /// [`LineOrPoint::intersect_line`], with the arithmetic `A`.
+    pub(crate) fn intersect_line_with<A: SweepArith<T>>(&self, other: &Self) -> Option<Self> {
         debug_assert!(other.is_line(), "tried to intersect with a point variant!");
 
         let line = other.line();
@@ -188,7 +203,7 @@ impl<T: GeoFloat> LineOrPoint<T> {
                 None
             }
         } else {
-            line_intersection(self.line(), line).map(|l| match l {
+            A::line_intersection(self.line(), line).map(|l| match l {
                 LineIntersection::SinglePoint {
                     intersection,
                     is_proper,
@@ -196,9 +211,9 @@ impl<T: GeoFloat> LineOrPoint<T> {
                     let mut pt = intersection;
                     if is_proper && (&pt == self.left.deref()) {
                         if self.left.x == self.right.x {
-                            pt.y = pt.y.next_after(T::infinity());
+                            pt.y = A::next_up(pt.y);
                         } else {
-                            pt.x = pt.x.next_after(T::infinity());
+                            pt.x = A::next_up(pt.x);
                         }
                     }
                     pt.into()
@@ -208,10 +223,15 @@ impl<T: GeoFloat> LineOrPoint<T> {
         }
     }
 
-    pub fn intersect_line_ordered(&self, other: &Self) -> Option<Self> {
+    /// [`LineOrPoint::intersect_line_ordered`], with the arithmetic `A`.
+    pub(crate) fn intersect_line_ordered_with<A: SweepArith<T>>(
+        &self,
+        other: &Self,
+    ) -> Option<Self> {
         let ord = self.partial_cmp(other);
-        match self.intersect_line(other) {
-            Some(lp) if !lp.is_line() => {
+        match self.intersect_line_with::<A>(other) {
+            // leave it to the caller to reject an intersection which can't be compared
+            Some(lp) if !lp.is_line() && lp.is_ordered() => {
                 // NOTE: A key issue with using non-exact numbers (f64, etc.) in
                 // this algo. is that line-intersection may return
                 // counter-intuitive points.
@@ -243,7 +263,7 @@ impl<T: GeoFloat> LineOrPoint<T> {
 
                 let c = self.left;
                 if x == c.x && y < c.y {
-                    x = x.next_after(T::infinity());
+                    x = A::next_up(x);
                 }
 
                 let pt: SweepPoint<_> = Coord { x, y }.into();
@@ -270,8 +290,9 @@ impl<T: GeoFloat> LineOrPoint<T> {
                         left: other.left,
                         right: pt,
                     };
-                    let cmp = l1.partial_cmp(&l2).unwrap();
-                    if l1.is_line() && l2.is_line() && cmp.then(ord) != ord {
+                    let cmp = l1.partial_cmp(&l2);
+                    if l1.is_line() && l2.is_line() && cmp.map_or(true, |cmp| cmp.then(ord) != ord)
+                    {
                         debug!(
                             "ordering changed by intersection: {l1:?} {ord:?} {l2:?}",
                             l1 = self,
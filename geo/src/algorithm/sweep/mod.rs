@@ -1,6 +1,12 @@
 mod point;
 pub use point::SweepPoint;
 
+mod num;
+pub use num::SweepNum;
+pub(crate) use num::{FloatArith, NumArith, SweepArith};
+
+mod snap_round;
+
 mod events;
 pub(crate) use events::{Event, EventType};
 
@@ -14,7 +20,7 @@ mod segment;
 use segment::{Segment, SplitSegments};
 
 mod active;
-use active::{Active, ActiveSet};
+use active::Active;
 
 mod im_segment;
 use im_segment::IMSegment;
@@ -24,7 +30,8 @@ pub(crate) use vec_set::VecSet;
 
 mod proc;
 use proc::Sweep;
+pub(crate) use proc::SweepError;
 
 mod iter;
 pub use iter::Intersections;
-pub(crate) use iter::{compare_crossings, Crossing, CrossingsIter};
+pub(crate) use iter::{Crossing, CrossingsIter};
@@ -0,0 +1,224 @@
+use std::convert::TryFrom;
+
+use float_next_after::NextAfter;
+
+use super::snap_round::snap_round;
+use crate::{
+    line_intersection::{intersection_by, line_intersection},
+    Coord, GeoFloat, GeoNum, HasKernel, Line, LineIntersection,
+};
+
+/// A scalar which the planar sweep, and so [`BooleanOps`](crate::BooleanOps), can compute with.
+///
+/// This is implemented for `f32` and `f64`, whose intersections are computed by
+/// [`line_intersection`](crate::line_intersection::line_intersection), and for `i64`.
+///
+/// The lines of `i64` coordinates are intersected exactly, in `i128`, and the intersection
+/// rounded to the nearest integer coordinates, with halves rounded up. So the result only
+/// depends on the lines, and not on which of them comes first, or the order of their end
+/// points. Before a sweep, the lines are [snap rounded](SweepNum::snap_round) with these
+/// intersections, so the sweep never has to round them itself. As the orientation of integer
+/// coordinates is computed in `i64`, the coordinates must have magnitudes of less than
+/// 2<sup>30</sup>, which is its [`max_magnitude`](SweepNum::max_magnitude).
+pub trait SweepNum: GeoNum {
+    /// The intersection of two lines, with the same conventions as
+    /// [`line_intersection`](crate::line_intersection::line_intersection).
+    fn line_intersection(p: Line<Self>, q: Line<Self>) -> Option<LineIntersection<Self>>;
+
+    /// The least value greater than `self`.
+    fn next_up(self) -> Self;
+
+    /// Split `lines` before a sweep, so that their pieces only meet at their end points, or
+    /// overlap, returning the vertices of the pieces of each line.
+    ///
+    /// By default, this is `None`, and the sweep splits the lines at their intersections as it
+    /// finds them.
+    fn snap_round(_lines: &[Line<Self>]) -> Option<Vec<Vec<Coord<Self>>>> {
+        None
+    }
+
+    /// The greatest magnitude of a coordinate the sweep can compute with, without overflowing,
+    /// or `None` if it's only limited by being finite.
+    fn max_magnitude() -> Option<Self> {
+        None
+    }
+}
+
+macro_rules! float_sweep_num {
+    ($t:ident) => {
+        impl SweepNum for $t {
+            fn line_intersection(p: Line<Self>, q: Line<Self>) -> Option<LineIntersection<Self>> {
+                line_intersection(p, q)
+            }
+
+            fn next_up(self) -> Self {
+                self.next_after($t::INFINITY)
+            }
+        }
+    };
+}
+
+float_sweep_num!(f64);
+float_sweep_num!(f32);
+
+impl SweepNum for i64 {
+    fn line_intersection(p: Line<Self>, q: Line<Self>) -> Option<LineIntersection<Self>> {
+        intersection_by::<_, <i64 as HasKernel>::Ker>(p, q, rounded_intersection)
+    }
+
+    fn next_up(self) -> Self {
+        self + 1
+    }
+
+    fn snap_round(lines: &[Line<Self>]) -> Option<Vec<Vec<Coord<Self>>>> {
+        Some(snap_round(lines))
+    }
+
+    fn max_magnitude() -> Option<Self> {
+        // the orientation of three points multiplies differences of coordinates, so with
+        // magnitudes of less than 2^30, the products are less than 2^62, and their difference
+        // fits in an `i64`
+        Some((1 << 30) - 1)
+    }
+}
+
+/// The arithmetic the sweep computes intersections with, for scalars `T`.
+///
+/// This is [`NumArith`] for [`SweepNum`] scalars, and [`FloatArith`] for any [`GeoFloat`], so
+/// the crate's float algorithms don't have to require `SweepNum`.
+pub(crate) trait SweepArith<T: GeoNum> {
+    fn line_intersection(p: Line<T>, q: Line<T>) -> Option<LineIntersection<T>>;
+    fn next_up(x: T) -> T;
+    fn snap_round(lines: &[Line<T>]) -> Option<Vec<Vec<Coord<T>>>>;
+    fn max_magnitude() -> Option<T>;
+}
+
+/// The arithmetic of a [`SweepNum`].
+pub(crate) struct NumArith;
+
+impl<T: SweepNum> SweepArith<T> for NumArith {
+    fn line_intersection(p: Line<T>, q: Line<T>) -> Option<LineIntersection<T>> {
+        T::line_intersection(p, q)
+    }
+
+    fn next_up(x: T) -> T {
+        x.next_up()
+    }
+
+    fn snap_round(lines: &[Line<T>]) -> Option<Vec<Vec<Coord<T>>>> {
+        T::snap_round(lines)
+    }
+
+    fn max_magnitude() -> Option<T> {
+        T::max_magnitude()
+    }
+}
+
+/// The arithmetic of a [`GeoFloat`], as for `f32` and `f64` as [`SweepNum`]s.
+pub(crate) struct FloatArith;
+
+impl<T: GeoFloat> SweepArith<T> for FloatArith {
+    fn line_intersection(p: Line<T>, q: Line<T>) -> Option<LineIntersection<T>> {
+        line_intersection(p, q)
+    }
+
+    fn next_up(x: T) -> T {
+        x.next_after(T::infinity())
+    }
+
+    fn snap_round(_lines: &[Line<T>]) -> Option<Vec<Vec<Coord<T>>>> {
+        None
+    }
+
+    fn max_magnitude() -> Option<T> {
+        None
+    }
+}
+
+/// The intersection of two lines crossing in their interiors, computed exactly, and rounded to
+/// the nearest integer coordinates.
+fn rounded_intersection(p: Line<i64>, q: Line<i64>) -> Coord<i64> {
+    let wide = |coord: Coord<i64>| (i128::from(coord.x), i128::from(coord.y));
+    let cross = |(ax, ay): (i128, i128), (bx, by): (i128, i128)| ax * by - ay * bx;
+    let sub = |(ax, ay): (i128, i128), (bx, by): (i128, i128)| (ax - bx, ay - by);
+
+    let (p_start, q_start) = (wide(p.start), wide(q.start));
+    let r = sub(wide(p.end), p_start);
+    let s = sub(wide(q.end), q_start);
+
+    // the intersection is at p_start + r * t / denom
+    let mut denom = cross(r, s);
+    let mut t = cross(sub(q_start, p_start), s);
+    debug_assert!(
+        denom != 0,
+        "lines crossing in their interiors can't be parallel"
+    );
+    if denom < 0 {
+        denom = -denom;
+        t = -t;
+    }
+    let ordinate = |start: i128, delta: i128| {
+        // round (start * denom + delta * t) / denom to the nearest integer, and halves up
+        let rounded = (2 * (start * denom + delta * t) + denom).div_euclid(2 * denom);
+        i64::try_from(rounded).expect("intersection is between the end points")
+    };
+    Coord {
+        x: ordinate(p_start.0, r.0),
+        y: ordinate(p_start.1, r.1),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord;
+
+    #[test]
+    fn rounded_intersections() {
+        let p = Line::new(coord! { x: 0, y: 0 }, coord! { x: 10, y: 5 });
+        let q = Line::new(coord! { x: 0, y: 4 }, coord! { x: 10, y: 0 });
+        // exactly (40/9, 20/9)
+        let expected = LineIntersection::SinglePoint {
+            intersection: coord! { x: 4, y: 2 },
+            is_proper: true,
+        };
+        for (p, q) in [(p, q), (q, p)] {
+            for p in [p, Line::new(p.end, p.start)] {
+                for q in [q, Line::new(q.end, q.start)] {
+                    assert_eq!(i64::line_intersection(p, q), Some(expected));
+                }
+            }
+        }
+
+        // exactly (-1.5, -1.5), so halves are rounded up
+        let p = Line::new(coord! { x: -3, y: 0 }, coord! { x: 0, y: -3 });
+        let q = Line::new(coord! { x: -3, y: -3 }, coord! { x: 0, y: 0 });
+        assert_eq!(
+            i64::line_intersection(p, q),
+            Some(LineIntersection::SinglePoint {
+                intersection: coord! { x: -1, y: -1 },
+                is_proper: true,
+            })
+        );
+    }
+
+    #[test]
+    fn integer_endpoint_intersections() {
+        let p = Line::new(coord! { x: 0, y: 0 }, coord! { x: 10, y: 10 });
+        let q = Line::new(coord! { x: 5, y: 5 }, coord! { x: 20, y: 0 });
+        assert_eq!(
+            i64::line_intersection(p, q),
+            Some(LineIntersection::SinglePoint {
+                intersection: coord! { x: 5, y: 5 },
+                is_proper: false,
+            })
+        );
+        let q = Line::new(coord! { x: 5, y: 5 }, coord! { x: 20, y: 20 });
+        assert_eq!(
+            i64::line_intersection(p, q),
+            Some(LineIntersection::Collinear {
+                intersection: Line::new(coord! { x: 5, y: 5 }, coord! { x: 10, y: 10 }),
+            })
+        );
+    }
+}
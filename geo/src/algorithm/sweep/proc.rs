@@ -1,8 +1,32 @@
-use std::{cmp::Ordering, collections::BinaryHeap};
-
-use crate::GeoFloat;
+use std::{cmp::Ordering, collections::BinaryHeap, error, fmt};
 
 use super::*;
+use crate::{Coord, GeoNum};
+
+/// The sweep failed at `point`, as rounding broke its invariants, as can happen with degenerate
+/// or nearly degenerate input: segments which can't be ordered, or intersections out of order.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct SweepError<T: GeoNum> {
+    pub point: Coord<T>,
+}
+
+impl<T: GeoNum> fmt::Display for SweepError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "sweep failed at ({:?}, {:?})",
+            self.point.x, self.point.y
+        )
+    }
+}
+
+impl<T: GeoNum> error::Error for SweepError<T> {}
+
+impl<T: GeoNum> From<SweepPoint<T>> for SweepError<T> {
+    fn from(point: SweepPoint<T>) -> Self {
+        SweepError { point: *point }
+    }
+}
 
 pub(crate) struct Sweep<C: Cross> {
     is_simple: bool,
@@ -37,27 +61,34 @@ impl<C: Cross + Clone> Sweep<C> {
     ///
     /// Calls the callback unless the event is spurious.
     #[inline]
-    pub(super) fn next_event<F>(&mut self, mut cb: F) -> Option<SweepPoint<C::Scalar>>
+    pub(super) fn next_event<A, F>(
+        &mut self,
+        mut cb: F,
+    ) -> Result<Option<SweepPoint<C::Scalar>>, SweepError<C::Scalar>>
     where
+        A: SweepArith<C::Scalar>,
         F: for<'a> FnMut(&'a IMSegment<C>, EventType),
     {
-        self.events.pop().map(|event| {
-            let pt = event.point;
-            self.handle_event(event, &mut cb);
-
-            pt
-        })
+        match self.events.pop() {
+            Some(event) => {
+                let pt = event.point;
+                self.handle_event::<A, F>(event, &mut cb)?;
+                Ok(Some(pt))
+            }
+            None => Ok(None),
+        }
     }
 
     /// Process two adjacent segments.
     ///
     /// The first argument must be an active segment, and the other may or may not be.
     /// Overlaps are chained from active -> other.
-    fn process_adjacent_segments(
+    fn process_adjacent_segments<A: SweepArith<C::Scalar>>(
         &mut self,
         active: Active<IMSegment<C>>,
         other: &IMSegment<C>,
-    ) -> AdjProcOutput<C::Scalar> {
+        point: SweepPoint<C::Scalar>,
+    ) -> Result<AdjProcOutput<C::Scalar>, SweepError<C::Scalar>> {
         // NOTE: The below logic is a loop instead of a
         // conditional due to FP issues. Specifically,
         // sometimes, two non-overlapping lines may become
@@ -77,8 +108,19 @@ impl<C: Cross + Clone> Sweep<C> {
             should_continue: true,
             should_callback: false,
         };
-        while let Some(isec) = other.geom().intersect_line_ordered(&active.geom()) {
+        while let Some(isec) = other
+            .geom()
+            .intersect_line_ordered_with::<A>(&active.geom())
+        {
             trace!("Found intersection (LL):\n\tsegment1: {:?}\n\tsegment2: {:?}\n\tintersection: {:?}", other, active, isec);
+            // an intersection which can't be ordered, or is found again, would never be passed
+            if !isec.is_ordered()
+                || out
+                    .isec
+                    .map_or(false, |prev| prev.end_points() == isec.end_points())
+            {
+                return Err(point.into());
+            }
             out.isec = Some(isec);
 
             // 1. Split adj_segment, and extra splits to storage
@@ -87,13 +129,11 @@ impl<C: Cross + Clone> Sweep<C> {
             // 2. Split segment, adding extra segments as needed.
             let seg_overlap = other.adjust_one_segment(isec, |e| self.events.push(e));
 
-            assert_eq!(
-                adj_overlap.is_some(),
-                seg_overlap.is_some(),
-                "one of the intersecting segments had an overlap, but not the other!"
-            );
-            if let Some(adj_ovl) = adj_overlap {
-                let tgt = seg_overlap.unwrap();
+            // one of the intersecting segments had an overlap, but not the other
+            if adj_overlap.is_some() != seg_overlap.is_some() {
+                return Err(point.into());
+            }
+            if let (Some(adj_ovl), Some(tgt)) = (adj_overlap, seg_overlap) {
                 trace!("setting overlap: {adj_ovl:?} -> {tgt:?}");
                 adj_ovl.chain_overlap(tgt.clone());
 
@@ -110,7 +150,7 @@ impl<C: Cross + Clone> Sweep<C> {
 
                 // Overlaps are exact compute, so we do not need
                 // to re-run the loop.
-                return out;
+                return Ok(out);
             }
 
             if active.geom().partial_cmp(&other.geom()) == Some(Ordering::Equal) {
@@ -119,18 +159,24 @@ impl<C: Cross + Clone> Sweep<C> {
                 break;
             }
         }
-        out
+        Ok(out)
     }
 
-    fn handle_event<F>(&mut self, event: Event<C::Scalar, IMSegment<C>>, cb: &mut F) -> bool
+    fn handle_event<A, F>(
+        &mut self,
+        event: Event<C::Scalar, IMSegment<C>>,
+        cb: &mut F,
+    ) -> Result<bool, SweepError<C::Scalar>>
     where
+        A: SweepArith<C::Scalar>,
         F: for<'a> FnMut(&'a IMSegment<C>, EventType),
     {
         use EventType::*;
         let segment = match IMSegment::is_correct(&event) {
-            false => return false,
+            false => return Ok(false),
             _ => event.payload,
         };
+        let failed = SweepError::from(event.point);
         trace!(
             "handling event: {pt:?} ({ty:?}) @ {seg:?}",
             pt = event.point,
@@ -144,7 +190,7 @@ impl<C: Cross + Clone> Sweep<C> {
         match &event.ty {
             LineLeft => {
                 let mut should_add = true;
-                let mut insert_idx = self.active_segments.index_not_of(&segment);
+                let mut insert_idx = self.active_segments.index_not_of(&segment).ok_or(failed)?;
                 if !self.is_simple {
                     for is_next in [true, false].into_iter() {
                         let active = if is_next {
@@ -162,7 +208,11 @@ impl<C: Cross + Clone> Sweep<C> {
                             isec,
                             should_continue,
                             should_callback,
-                        } = self.process_adjacent_segments(active.clone(), &segment);
+                        } = self.process_adjacent_segments::<A>(
+                            active.clone(),
+                            &segment,
+                            event.point,
+                        )?;
                         let isec = match isec {
                             Some(isec) => isec,
                             None => continue,
@@ -179,9 +229,11 @@ impl<C: Cross + Clone> Sweep<C> {
                             int_pt != active.geom().left() && int_pt == segment.geom().left()
                         };
                         if handle_end_event {
-                            let event = self.events.pop().unwrap();
-                            let done = self.handle_event(event, cb);
-                            debug_assert!(done, "special right-end event handling failed");
+                            let event = self.events.pop().ok_or(failed)?;
+                            if !self.handle_event::<A, F>(event, cb)? {
+                                // special right-end event handling failed
+                                return Err(failed);
+                            }
                             if !is_next {
                                 // The prev-segment is now removed
                                 insert_idx -= 1;
@@ -191,7 +243,7 @@ impl<C: Cross + Clone> Sweep<C> {
                         if !should_continue {
                             should_add = false;
                             if !should_callback {
-                                return true;
+                                return Ok(true);
                             }
                             break;
                         }
@@ -229,11 +281,13 @@ impl<C: Cross + Clone> Sweep<C> {
                 // Safety: `self.segments` is a `Box` that is not
                 // de-allocated until `self` is dropped.
                 debug!("remove_active: {segment:?}");
-                let el_idx = self.active_segments.index_of(&segment);
+                let el_idx = self.active_segments.index_of(&segment).ok_or(failed)?;
                 let prev = (el_idx > 0).then(|| self.active_segments[el_idx - 1].clone());
                 let next = (1 + el_idx < self.active_segments.len())
                     .then(|| self.active_segments[el_idx + 1].clone());
-                assert_eq!(self.active_segments.remove_at(el_idx), segment);
+                if self.active_segments.remove_at(el_idx) != segment {
+                    return Err(failed);
+                }
 
                 let mut cb_seg = Some(segment);
                 while let Some(seg) = cb_seg {
@@ -245,8 +299,12 @@ impl<C: Cross + Clone> Sweep<C> {
                     if let (Some(prev), Some(next)) = (prev, next) {
                         let prev_geom = prev.geom();
                         let next_geom = next.geom();
-                        if let Some(adj_intersection) = prev_geom.intersect_line_ordered(&next_geom)
+                        if let Some(adj_intersection) =
+                            prev_geom.intersect_line_ordered_with::<A>(&next_geom)
                         {
+                            if !adj_intersection.is_ordered() {
+                                return Err(failed);
+                            }
                             // 1. Split prev_segment, and extra splits to storage
                             let first = prev
                                 .adjust_one_segment(adj_intersection, |e| self.events.push(e))
@@ -254,17 +312,17 @@ impl<C: Cross + Clone> Sweep<C> {
                             let second = next
                                 .adjust_one_segment(adj_intersection, |e| self.events.push(e))
                                 .is_none();
-                            debug_assert!(
-                                first && second,
-                                "adjacent segments @ removal can't overlap!"
-                            );
+                            // adjacent segments @ removal can't overlap
+                            if !(first && second) {
+                                return Err(failed);
+                            }
                         }
                     }
                 }
             }
             PointLeft => {
                 if !self.is_simple {
-                    let insert_idx = self.active_segments.index_not_of(&segment);
+                    let insert_idx = self.active_segments.index_not_of(&segment).ok_or(failed)?;
                     let prev =
                         (insert_idx > 0).then(|| self.active_segments[insert_idx - 1].clone());
                     let next = (insert_idx < self.active_segments.len())
@@ -272,15 +330,21 @@ impl<C: Cross + Clone> Sweep<C> {
 
                     for adj_segment in prev.into_iter().chain(next.into_iter()) {
                         let geom = adj_segment.geom();
-                        if let Some(adj_intersection) = segment.geom().intersect_line_ordered(&geom)
+                        if let Some(adj_intersection) =
+                            segment.geom().intersect_line_ordered_with::<A>(&geom)
                         {
                             trace!("Found intersection (PL):\n\tsegment1: {:?}\n\tsegment2: {:?}\n\tintersection: {:?}", segment, adj_segment, adj_intersection);
+                            if !adj_intersection.is_ordered() {
+                                return Err(failed);
+                            }
                             // 1. Split adj_segment, and extra splits to storage
                             let adj_overlap = adj_segment
                                 .adjust_one_segment(adj_intersection, |e| self.events.push(e));
 
                             // Can't have overlap with a point
-                            debug_assert!(adj_overlap.is_none());
+                            if adj_overlap.is_some() {
+                                return Err(failed);
+                            }
                         }
                     }
                 }
@@ -294,7 +358,7 @@ impl<C: Cross + Clone> Sweep<C> {
                 // are confident about the logic.
             }
         }
-        true
+        Ok(true)
     }
 
     #[inline]
@@ -302,7 +366,7 @@ impl<C: Cross + Clone> Sweep<C> {
         &self,
         c: &Crossing<C>,
         f: F,
-    ) -> Option<R> {
+    ) -> Result<Option<R>, SweepError<C::Scalar>> {
         debug_assert!(c.at_left);
         // {
         //     // Expensive debug block
@@ -318,8 +382,13 @@ impl<C: Cross + Clone> Sweep<C> {
         //         false
         //     });
         // }
-        self.active_segments
-            .previous(&c.segment)
+        let idx = self
+            .active_segments
+            .position(&c.segment)
+            .ok_or(SweepError::from(c.line.left()))?;
+        Ok(idx
+            .checked_sub(1)
+            .map(|idx| &self.active_segments[idx])
             // .previous_find(&c.segment, |aseg| {
             //     let is_ovl = aseg.0.geom().partial_cmp(&c.line) == Some(Ordering::Equal);
             //     if is_ovl {
@@ -331,7 +400,7 @@ impl<C: Cross + Clone> Sweep<C> {
             //     }
             //     !is_ovl
             // })
-            .map(|aseg| aseg.with_segment(f))
+            .map(|aseg| aseg.with_segment(f)))
     }
 
     #[inline]
@@ -341,7 +410,7 @@ impl<C: Cross + Clone> Sweep<C> {
 }
 
 /// Internal enum to communicate result from `process_adjacent_segments`
-struct AdjProcOutput<T: GeoFloat> {
+struct AdjProcOutput<T: GeoNum> {
     isec: Option<LineOrPoint<T>>,
     should_continue: bool,
     should_callback: bool,
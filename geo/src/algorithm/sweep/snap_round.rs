@@ -0,0 +1,238 @@
+use std::cmp::Ordering;
+
+use super::SweepNum;
+use crate::{Coord, Line, LineIntersection};
+
+/// Snap round `lines`, returning the vertices of the pieces of each line.
+///
+/// This is [Hobby's snap rounding][snap]. The hot pixels are the half-open unit squares around
+/// the end points of the lines, and around their intersections, rounded as by
+/// [`SweepNum::line_intersection`], so that each intersection lies within its hot pixel. Each
+/// line is then replaced by the path through the centres of the hot pixels it passes through,
+/// in order. The pieces of these paths only meet at their end points, or overlap, so the sweep
+/// doesn't have to compute, and round, any more intersections.
+///
+/// [snap]: https://doi.org/10.1016/S0925-7721(99)00017-6
+pub(super) fn snap_round(lines: &[Line<i64>]) -> Vec<Vec<Coord<i64>>> {
+    let hot_pixels = hot_pixels(lines);
+    lines
+        .iter()
+        .map(|&line| {
+            let (min_x, max_x) = min_max(line.start.x, line.end.x);
+            let (min_y, max_y) = min_max(line.start.y, line.end.y);
+            // only the pixels centred within the line's bounding rect can meet it
+            let first = hot_pixels.partition_point(|pixel| pixel.x < min_x);
+            let mut path: Vec<_> = hot_pixels[first..]
+                .iter()
+                .take_while(|pixel| pixel.x <= max_x)
+                .filter(|pixel| min_y <= pixel.y && pixel.y <= max_y)
+                .filter_map(|&pixel| Some((entry(line, pixel)?, pixel)))
+                .collect();
+            path.sort_by_key(|&(entry, _)| entry);
+            path.into_iter().map(|(_, pixel)| pixel).collect()
+        })
+        .collect()
+}
+
+/// The sorted centres of the hot pixels of `lines`.
+fn hot_pixels(lines: &[Line<i64>]) -> Vec<Coord<i64>> {
+    let mut pixels: Vec<_> = lines
+        .iter()
+        .flat_map(|line| [line.start, line.end])
+        .collect();
+
+    // visit the pairs of lines with overlapping bounding rects, in order of their left ends
+    let mut order: Vec<_> = (0..lines.len()).collect();
+    order.sort_by_key(|&a| min_max(lines[a].start.x, lines[a].end.x).0);
+    for (i, &a) in order.iter().enumerate() {
+        let (_, max_x) = min_max(lines[a].start.x, lines[a].end.x);
+        let (min_y, max_y) = min_max(lines[a].start.y, lines[a].end.y);
+        for &b in order[i + 1..]
+            .iter()
+            .take_while(|&&b| min_max(lines[b].start.x, lines[b].end.x).0 <= max_x)
+        {
+            let (other_min_y, other_max_y) = min_max(lines[b].start.y, lines[b].end.y);
+            if other_max_y < min_y || max_y < other_min_y {
+                continue;
+            }
+            // improper intersections are end points, which are already hot
+            if let Some(LineIntersection::SinglePoint {
+                intersection,
+                is_proper: true,
+            }) = i64::line_intersection(lines[a], lines[b])
+            {
+                pixels.push(intersection);
+            }
+        }
+    }
+
+    pixels.sort_by_key(|pixel| (pixel.x, pixel.y));
+    pixels.dedup();
+    pixels
+}
+
+/// Where `line` enters the hot pixel centred on `pixel`, or `None` if it doesn't.
+///
+/// The pixel is the half-open square `[x - 1/2, x + 1/2) × [y - 1/2, y + 1/2)`, which is
+/// worked with at twice the scale, to keep to integers.
+fn entry(line: Line<i64>, pixel: Coord<i64>) -> Option<Bound> {
+    // the points of the line are start + t * delta, for t from 0 to 1
+    let mut low = Bound::closed(0, 1);
+    let mut high = Bound::closed(1, 1);
+    for (start, end, centre) in [
+        (line.start.x, line.end.x, pixel.x),
+        (line.start.y, line.end.y, pixel.y),
+    ] {
+        let start = 2 * i128::from(start);
+        let delta = 2 * i128::from(end) - start;
+        let (min, max) = (2 * i128::from(centre) - 1, 2 * i128::from(centre) + 1);
+        // min <= start + t * delta < max
+        match delta.cmp(&0) {
+            Ordering::Equal if min <= start && start < max => continue,
+            Ordering::Equal => return None,
+            Ordering::Greater => {
+                low = low.max(Bound::closed(min - start, delta));
+                high = high.min(Bound::open(max - start, delta));
+            }
+            Ordering::Less => {
+                low = low.max(Bound::open(start - max, -delta));
+                high = high.min(Bound::closed(start - min, -delta));
+            }
+        }
+    }
+    match low.value_cmp(&high) {
+        Ordering::Less => Some(low),
+        Ordering::Equal if !low.open && !high.open => Some(low),
+        _ => None,
+    }
+}
+
+/// A bound `numer / denom` of the parameter of a line, excluding the value itself if `open`.
+///
+/// Bounds are ordered by value, with closed before open, as a lower bound excluding its value
+/// is entered just after one including it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Bound {
+    numer: i128,
+    denom: i128,
+    open: bool,
+}
+
+impl Bound {
+    fn closed(numer: i128, denom: i128) -> Self {
+        debug_assert!(denom > 0);
+        Bound {
+            numer,
+            denom,
+            open: false,
+        }
+    }
+
+    fn open(numer: i128, denom: i128) -> Self {
+        Bound {
+            open: true,
+            ..Bound::closed(numer, denom)
+        }
+    }
+
+    fn value_cmp(&self, other: &Self) -> Ordering {
+        (self.numer * other.denom).cmp(&(other.numer * self.denom))
+    }
+}
+
+impl PartialOrd for Bound {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Bound {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value_cmp(other).then(self.open.cmp(&other.open))
+    }
+}
+
+fn min_max(a: i64, b: i64) -> (i64, i64) {
+    (a.min(b), a.max(b))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::coord;
+
+    #[test]
+    fn crossing_lines() {
+        let lines = [
+            Line::new(coord! { x: 0, y: 0 }, coord! { x: 10, y: 5 }),
+            Line::new(coord! { x: 0, y: 4 }, coord! { x: 10, y: 0 }),
+        ];
+        // they cross at (40/9, 20/9), rounded to (4, 2)
+        assert_eq!(
+            snap_round(&lines),
+            vec![
+                vec![
+                    coord! { x: 0, y: 0 },
+                    coord! { x: 4, y: 2 },
+                    coord! { x: 10, y: 5 }
+                ],
+                vec![
+                    coord! { x: 0, y: 4 },
+                    coord! { x: 4, y: 2 },
+                    coord! { x: 10, y: 0 }
+                ],
+            ]
+        );
+    }
+
+    #[test]
+    fn passing_near_hot_pixel() {
+        let lines = [
+            Line::new(coord! { x: 0, y: 0 }, coord! { x: 10, y: 3 }),
+            Line::new(coord! { x: 3, y: 1 }, coord! { x: 3, y: 5 }),
+        ];
+        // the first passes through the pixel of (3, 1), which the second starts in
+        let pieces = snap_round(&lines);
+        assert_eq!(
+            pieces[0],
+            vec![
+                coord! { x: 0, y: 0 },
+                coord! { x: 3, y: 1 },
+                coord! { x: 10, y: 3 }
+            ]
+        );
+        assert_eq!(
+            pieces[1],
+            vec![coord! { x: 3, y: 1 }, coord! { x: 3, y: 5 }]
+        );
+    }
+
+    #[test]
+    fn half_open_pixels() {
+        // passes exactly through the corner at (1/2, 1/2), which is only in the pixel of (1, 1)
+        let line = Line::new(coord! { x: 0, y: 0 }, coord! { x: 2, y: 2 });
+        assert!(entry(line, coord! { x: 1, y: 0 }).is_none());
+        assert!(entry(line, coord! { x: 0, y: 1 }).is_none());
+        assert_eq!(
+            entry(line, coord! { x: 1, y: 1 }),
+            Some(Bound::closed(1, 4))
+        );
+        // and the other way, just touching the pixel of (1, 1), but not of (0, 0)
+        let line = Line::new(coord! { x: 0, y: 1 }, coord! { x: 1, y: 0 });
+        assert_eq!(
+            entry(line, coord! { x: 1, y: 1 }),
+            Some(Bound::closed(1, 2))
+        );
+        assert!(entry(line, coord! { x: 0, y: 0 }).is_none());
+        // so it's snapped to (1, 1), once that's hot
+        let other = Line::new(coord! { x: 1, y: 1 }, coord! { x: 2, y: 2 });
+        assert_eq!(
+            snap_round(&[line, other])[0],
+            vec![
+                coord! { x: 0, y: 1 },
+                coord! { x: 1, y: 1 },
+                coord! { x: 1, y: 0 }
+            ]
+        );
+    }
+}
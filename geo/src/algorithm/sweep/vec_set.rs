@@ -1,13 +1,13 @@
-use super::{Active, ActiveSet};
-use std::{cmp::Ordering, fmt::Debug, ops::Index};
+use super::Active;
+use std::{cmp::Ordering, ops::Index};
 
 /// A simple ordered set implementation backed by a `Vec`.
 #[derive(Debug, Clone)]
-pub struct VecSet<T: Ord> {
+pub struct VecSet<T> {
     data: Vec<T>,
 }
 
-impl<T: Ord> Default for VecSet<T> {
+impl<T> Default for VecSet<T> {
     fn default() -> Self {
         Self {
             data: Default::default(),
@@ -15,18 +15,36 @@ impl<T: Ord> Default for VecSet<T> {
     }
 }
 
-impl<T: PartialOrd + Debug> VecSet<Active<T>> {
-    pub fn index_of(&self, segment: &T) -> usize {
-        self.data
-            .binary_search(Active::active_ref(segment))
-            .expect("segment not found in active-vec-set")
+impl<T: PartialOrd> VecSet<Active<T>> {
+    /// Binary search for `segment`, or `None` if it can't be compared with one of the segments
+    /// it is compared with, as happens when rounding breaks the ordering of the segments.
+    fn search(&self, segment: &T) -> Option<Result<usize, usize>> {
+        let mut is_ordered = true;
+        let result = self.data.binary_search_by(|probe| {
+            probe.0.partial_cmp(segment).unwrap_or_else(|| {
+                is_ordered = false;
+                Ordering::Equal
+            })
+        });
+        is_ordered.then_some(result)
     }
 
-    pub fn index_not_of(&self, segment: &T) -> usize {
-        self.data
-            .binary_search(Active::active_ref(segment))
-            .expect_err("segment already found in active-vec-set")
+    /// The index of `segment`, or `None` if it isn't found.
+    pub fn index_of(&self, segment: &T) -> Option<usize> {
+        self.search(segment)?.ok()
     }
+
+    /// The index to insert `segment` at, or `None` if it is already present.
+    pub fn index_not_of(&self, segment: &T) -> Option<usize> {
+        self.search(segment)?.err()
+    }
+
+    /// The index of `segment`, or that to insert it at if it isn't present.
+    pub fn position(&self, segment: &T) -> Option<usize> {
+        self.search(segment)
+            .map(|result| result.unwrap_or_else(|idx| idx))
+    }
+
     pub fn len(&self) -> usize {
         self.data.len()
     }
@@ -42,7 +60,7 @@ impl<T: PartialOrd + Debug> VecSet<Active<T>> {
 
     #[allow(unused)]
     pub fn check_swap(&mut self, idx: usize) -> bool {
-        if self.data[idx].cmp(&self.data[idx + 1]) == Ordering::Greater {
+        if self.data[idx].partial_cmp(&self.data[idx + 1]) == Some(Ordering::Greater) {
             self.data.swap(idx, idx + 1);
             true
         } else {
@@ -51,59 +69,10 @@ impl<T: PartialOrd + Debug> VecSet<Active<T>> {
     }
 }
 
-impl<T: Ord> Index<usize> for VecSet<T> {
+impl<T> Index<usize> for VecSet<T> {
     type Output = T;
 
     fn index(&self, index: usize) -> &Self::Output {
         &self.data[index]
     }
 }
-
-impl<T: PartialOrd + Debug> ActiveSet for VecSet<Active<T>> {
-    type Seg = T;
-
-    fn previous_find<F: FnMut(&Active<Self::Seg>) -> bool>(
-        &self,
-        segment: &Self::Seg,
-        mut f: F,
-    ) -> Option<&Active<Self::Seg>> {
-        let segment = Active::active_ref(segment);
-        let ub = match self.data.binary_search(segment) {
-            Ok(i) => i,
-            Err(i) => i,
-        };
-        self.data[..ub].iter().rev().find(|s| f(s))
-    }
-
-    fn next_find<F: FnMut(&Active<Self::Seg>) -> bool>(
-        &self,
-        segment: &Self::Seg,
-        mut f: F,
-    ) -> Option<&Active<Self::Seg>> {
-        let segment = Active::active_ref(segment);
-        let start = match self.data.binary_search(segment) {
-            Ok(i) => i + 1,
-            Err(i) => i,
-        };
-        self.data[start..].iter().find(|s| f(s))
-    }
-
-    fn insert_active(&mut self, segment: Self::Seg) {
-        let idx = {
-            let segment = Active::active_ref(&segment);
-            self.data
-                .binary_search(segment)
-                .expect_err("element already in active-vec-set")
-        };
-        self.data.insert(idx, Active(segment));
-    }
-
-    fn remove_active(&mut self, segment: &Self::Seg) {
-        let segment = Active::active_ref(segment);
-        let idx = self
-            .data
-            .binary_search(segment)
-            .expect("element not found in active-vec-set");
-        self.data.remove(idx);
-    }
-}
@@ -0,0 +1,145 @@
+use crate::dimensions::{Dimensions, HasDimensions};
+use crate::{BoundingRect, GeoFloat, Intersects, Rect, Relate};
+
+/// Tests if two geometries touch: they meet, but only at their boundaries.
+///
+/// Following the [OGC Simple Features] definition, the interiors of the two geometries don't
+/// intersect, though the geometries themselves do. In other words, the [DE-9IM] intersection
+/// matrix for (Self, Rhs) is `[FT*******]`, `[F**T*****]` or `[F***T****]`.
+///
+/// Points have no boundary, so a point can only touch the boundary of a geometry of higher
+/// dimension, and two points or multi-points never touch. Empty geometries don't touch anything.
+///
+/// This is the same as [`IntersectionMatrix::is_touches`] on the result of [`Relate`], but
+/// answers quickly when the geometries are points, or their bounding rectangles are disjoint,
+/// without computing the whole matrix.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, polygon, Touches};
+///
+/// let parcel = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+/// let neighbour = polygon![(x: 10., y: 0.), (x: 20., y: 0.), (x: 20., y: 10.), (x: 10., y: 10.)];
+/// let overlapping = polygon![(x: 5., y: 0.), (x: 15., y: 0.), (x: 15., y: 10.), (x: 5., y: 10.)];
+///
+/// assert!(parcel.touches(&neighbour));
+/// assert!(!parcel.touches(&overlapping));
+///
+/// // a point on the boundary touches, but one in the interior doesn't
+/// assert!(point!(x: 10., y: 5.).touches(&parcel));
+/// assert!(!point!(x: 5., y: 5.).touches(&parcel));
+/// ```
+///
+/// [OGC Simple Features]: https://www.ogc.org/standards/sfa
+/// [DE-9IM]: https://en.wikipedia.org/wiki/DE-9IM
+/// [`IntersectionMatrix::is_touches`]: crate::relate::IntersectionMatrix::is_touches
+pub trait Touches<F, Rhs> {
+    fn touches(&self, other: &Rhs) -> bool;
+}
+
+impl<F, G1, G2> Touches<F, G2> for G1
+where
+    F: GeoFloat,
+    G1: Relate<F, G2> + BoundingRect<F> + HasDimensions,
+    G2: BoundingRect<F> + HasDimensions,
+{
+    fn touches(&self, other: &G2) -> bool {
+        match (self.dimensions(), other.dimensions()) {
+            (Dimensions::Empty, _) | (_, Dimensions::Empty) => return false,
+            (Dimensions::ZeroDimensional, Dimensions::ZeroDimensional) => return false,
+            _ => {}
+        }
+        let rects: (Option<Rect<F>>, Option<Rect<F>>) =
+            (self.bounding_rect().into(), other.bounding_rect().into());
+        match rects {
+            (Some(a), Some(b)) if a.intersects(&b) => self.relate(other).is_touches(),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{line_string, point, polygon, Geometry, Line, LineString, MultiPoint, Polygon};
+
+    fn square(x: f64, y: f64, size: f64) -> Polygon {
+        polygon![
+            (x: x, y: y),
+            (x: x + size, y: y),
+            (x: x + size, y: y + size),
+            (x: x, y: y + size),
+        ]
+    }
+
+    #[test]
+    fn polygons() {
+        let parcel = square(0., 0., 10.);
+        // sharing an edge, and only a corner
+        assert!(parcel.touches(&square(10., 0., 10.)));
+        assert!(parcel.touches(&square(10., 10., 10.)));
+        assert!(square(10., 10., 10.).touches(&parcel));
+        // sharing part of an edge
+        assert!(parcel.touches(&square(10., 5., 10.)));
+
+        // overlapping, containing and disjoint
+        assert!(!parcel.touches(&square(5., 5., 10.)));
+        assert!(!parcel.touches(&square(2., 2., 2.)));
+        assert!(!parcel.touches(&parcel));
+        assert!(!parcel.touches(&square(20., 20., 10.)));
+        // disjoint, though their bounding rectangles overlap
+        let triangle = polygon![(x: 11., y: 0.), (x: 11., y: -10.), (x: 1., y: -10.)];
+        assert!(!triangle.touches(&polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 0., y: 10.)]));
+    }
+
+    #[test]
+    fn points() {
+        let parcel = square(0., 0., 10.);
+        assert!(point!(x: 0., y: 5.).touches(&parcel));
+        assert!(parcel.touches(&point!(x: 0., y: 5.)));
+        assert!(!point!(x: 5., y: 5.).touches(&parcel));
+        assert!(!point!(x: 15., y: 5.).touches(&parcel));
+
+        // the boundary of a line string is its ends
+        let line_string = line_string![(x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 5.)];
+        assert!(point!(x: 5., y: 5.).touches(&line_string));
+        assert!(!point!(x: 5., y: 0.).touches(&line_string));
+
+        // points never touch each other
+        let point = point!(x: 1., y: 1.);
+        assert!(!point.touches(&point));
+        assert!(!MultiPoint::from(vec![point]).touches(&point));
+    }
+
+    #[test]
+    fn lines() {
+        let line_string = line_string![(x: 0., y: 0.), (x: 10., y: 0.)];
+        // ending on the other's interior
+        assert!(Line::new((5., 0.), (5., 5.)).touches(&line_string));
+        // end to end
+        assert!(Line::new((10., 0.), (10., 5.)).touches(&line_string));
+        // crossing
+        assert!(!Line::new((5., -5.), (5., 5.)).touches(&line_string));
+        // overlapping
+        assert!(!Line::new((5., 0.), (15., 0.)).touches(&line_string));
+
+        // along the boundary of a polygon, or ending on it from outside
+        let parcel = square(0., 0., 10.);
+        assert!(line_string.touches(&parcel));
+        assert!(Line::new((5., -5.), (5., 0.)).touches(&parcel));
+        assert!(!Line::new((5., -5.), (5., 5.)).touches(&parcel));
+    }
+
+    #[test]
+    fn empty_and_geometry() {
+        let parcel = square(0., 0., 10.);
+        assert!(!LineString::<f64>::new(vec![]).touches(&parcel));
+        assert!(!parcel.touches(&Polygon::new(LineString::new(vec![]), vec![])));
+
+        let a = Geometry::from(parcel);
+        let b = Geometry::from(square(10., 0., 10.));
+        assert!(a.touches(&b));
+        assert!(!a.touches(&a));
+    }
+}
@@ -0,0 +1,397 @@
+use std::collections::HashMap;
+
+use crate::kernels::{Kernel, Orientation};
+use crate::utils::lex_cmp;
+use crate::{Coord, GeoFloat, MultiPoint, Point, Triangle};
+
+/// Triangulate a set of points such that no point lies inside the circumcircle of any triangle,
+/// i.e. compute their [Delaunay triangulation].
+///
+/// The triangles cover the convex hull of the points, and each of them is wound
+/// counter-clockwise. Duplicate points are only used once, and a set of fewer than three
+/// distinct points, or of points which are all collinear, has no triangles. When four or more
+/// points lie on a common circle, the triangulation is not unique, and any one of the valid
+/// triangulations is returned.
+///
+/// The triangulation is built by inserting the points in lexicographic order while keeping track
+/// of their convex hull, and then flipping edges until every triangle is Delaunay. Both steps
+/// use exact predicates, so the result is valid even for nearly degenerate inputs.
+///
+/// # Panics
+///
+/// May panic if any of the points has a NaN coordinate. The points must also be finite for the
+/// predicates to be meaningful, so an infinite coordinate gives an unspecified triangulation.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, MultiPoint, TriangulateDelaunay};
+///
+/// let points = MultiPoint::new(vec![
+///     point!(x: 0., y: 0.),
+///     point!(x: 10., y: 0.),
+///     point!(x: 10., y: 10.),
+///     point!(x: 0., y: 10.),
+///     point!(x: 4., y: 5.),
+/// ]);
+///
+/// let triangles = points.delaunay_triangulation();
+/// assert_eq!(triangles.len(), 4);
+///
+/// // the same triangles, as the indices of their vertices
+/// let indices = points.delaunay_triangulation_indices();
+/// assert_eq!(indices.len(), 4);
+/// assert!(indices.iter().all(|triangle| triangle.contains(&4)));
+/// ```
+///
+/// [Delaunay triangulation]: https://en.wikipedia.org/wiki/Delaunay_triangulation
+pub trait TriangulateDelaunay<T: GeoFloat> {
+    fn delaunay_triangulation(&self) -> Vec<Triangle<T>>;
+
+    /// The triangles of the Delaunay triangulation, as the indices of their vertices among the
+    /// points, in the same order as [`delaunay_triangulation`](Self::delaunay_triangulation)
+    /// returns them. Of duplicate points, only the first is used.
+    fn delaunay_triangulation_indices(&self) -> Vec<[usize; 3]>;
+}
+
+impl<T: GeoFloat> TriangulateDelaunay<T> for MultiPoint<T> {
+    fn delaunay_triangulation(&self) -> Vec<Triangle<T>> {
+        self.0.delaunay_triangulation()
+    }
+
+    fn delaunay_triangulation_indices(&self) -> Vec<[usize; 3]> {
+        self.0.delaunay_triangulation_indices()
+    }
+}
+
+impl<T: GeoFloat> TriangulateDelaunay<T> for [Point<T>] {
+    fn delaunay_triangulation(&self) -> Vec<Triangle<T>> {
+        let coords: Vec<_> = self.iter().map(|point| point.0).collect();
+        Triangulation::new(coords).triangles().collect()
+    }
+
+    fn delaunay_triangulation_indices(&self) -> Vec<[usize; 3]> {
+        let coords: Vec<_> = self.iter().map(|point| point.0).collect();
+        let triangulation = Triangulation::new(coords.clone());
+
+        // the index of the first of each distinct coordinate, in the triangulation's order
+        let mut indices: Vec<usize> = (0..coords.len()).collect();
+        indices.sort_by(|&a, &b| lex_cmp(&coords[a], &coords[b]));
+        indices.dedup_by(|a, b| coords[*a] == coords[*b]);
+        debug_assert_eq!(indices.len(), triangulation.coords.len());
+
+        triangulation
+            .triangles
+            .iter()
+            .map(|triangle| triangle.map(|vertex| indices[vertex]))
+            .collect()
+    }
+}
+
+/// A triangulation of a set of distinct coordinates, as triangles of indices into them.
+pub(crate) struct Triangulation<T: GeoFloat> {
+    /// The distinct coordinates, in lexicographic order
+    pub(crate) coords: Vec<Coord<T>>,
+    /// The counter-clockwise triangles
+    pub(crate) triangles: Vec<[usize; 3]>,
+}
+
+impl<T: GeoFloat> Triangulation<T> {
+    /// The Delaunay triangulation of `coords`.
+    pub(crate) fn new(mut coords: Vec<Coord<T>>) -> Self {
+        coords.sort_unstable_by(lex_cmp);
+        coords.dedup();
+        let mut triangulation = Triangulation {
+            coords,
+            triangles: vec![],
+        };
+        triangulation.sweep();
+        triangulation.flip_to_delaunay();
+        triangulation
+    }
+
+    pub(crate) fn triangles(&self) -> impl Iterator<Item = Triangle<T>> + '_ {
+        self.triangles
+            .iter()
+            .map(|&[a, b, c]| Triangle::new(self.coords[a], self.coords[b], self.coords[c]))
+    }
+
+    fn orient(&self, a: usize, b: usize, c: usize) -> Orientation {
+        T::Ker::orient2d(self.coords[a], self.coords[b], self.coords[c])
+    }
+
+    /// Build an arbitrary triangulation by adding the (sorted) coordinates one at a time. Each of
+    /// them lies outside the convex hull of the previous ones, and is connected to every edge of
+    /// the hull which it can see.
+    fn sweep(&mut self) {
+        let n = self.coords.len();
+
+        // The first coordinates may all be collinear, so start from the first which isn't.
+        let first = match (2..n).find(|&i| self.orient(0, 1, i) != Orientation::Collinear) {
+            Some(first) => first,
+            None => return,
+        };
+        let ccw = self.orient(0, 1, first) == Orientation::CounterClockwise;
+        // The hull, in counter-clockwise order
+        let mut hull = Vec::with_capacity(first + 1);
+        for i in 0..first - 1 {
+            self.triangles.push(if ccw {
+                [i, i + 1, first]
+            } else {
+                [i + 1, i, first]
+            });
+        }
+        if ccw {
+            hull.extend(0..=first);
+        } else {
+            hull.push(first);
+            hull.extend((0..first).rev());
+        }
+
+        for i in first + 1..n {
+            // The edge from `hull[j]` to `hull[j + 1]` is visible if `i` is strictly on its right.
+            // As `i` is outside the hull, the visible edges form a single, non-empty, chain.
+            let visible: Vec<bool> = (0..hull.len())
+                .map(|j| {
+                    let (a, b) = (hull[j], hull[(j + 1) % hull.len()]);
+                    self.orient(a, b, i) == Orientation::Clockwise
+                })
+                .collect();
+            let start = (0..hull.len())
+                .find(|&j| visible[j] && !visible[(j + hull.len() - 1) % hull.len()])
+                .expect("a coordinate outside the hull must see one of its edges");
+            let mut end = start;
+            while visible[end % hull.len()] {
+                let (a, b) = (hull[end % hull.len()], hull[(end + 1) % hull.len()]);
+                self.triangles.push([b, a, i]);
+                end += 1;
+            }
+
+            // Replace the vertices between the visible edges with `i`
+            let mut next_hull = Vec::with_capacity(hull.len() + 1);
+            next_hull.extend((end..=start + hull.len()).map(|j| hull[j % hull.len()]));
+            next_hull.push(i);
+            hull = next_hull;
+        }
+    }
+
+    /// Flip the edges of the triangulation until none of them is illegal, i.e. the opposite
+    /// vertex of each neighbouring triangle lies outside the circumcircle of each triangle.
+    fn flip_to_delaunay(&mut self) {
+        // The triangle on the left of each directed edge
+        let mut edges = HashMap::with_capacity(3 * self.triangles.len());
+        for (t, triangle) in self.triangles.iter().enumerate() {
+            for k in 0..3 {
+                edges.insert((triangle[k], triangle[(k + 1) % 3]), t);
+            }
+        }
+
+        let mut stack: Vec<_> = self
+            .triangles
+            .iter()
+            .flat_map(|&[a, b, c]| [(a, b), (b, c), (c, a)])
+            .filter(|(a, b)| a < b)
+            .collect();
+        while let Some((a, b)) = stack.pop() {
+            let (t1, t2) = match (edges.get(&(a, b)), edges.get(&(b, a))) {
+                (Some(&t1), Some(&t2)) => (t1, t2),
+                _ => continue,
+            };
+            let c = opposite(self.triangles[t1], a, b);
+            let d = opposite(self.triangles[t2], b, a);
+            if !self.in_circumcircle(a, b, c, d) {
+                continue;
+            }
+
+            // Replace the diagonal from `a` to `b` with the one from `c` to `d`
+            for [p, q, r] in [[a, b, c], [b, a, d]] {
+                for edge in [(p, q), (q, r), (r, p)] {
+                    edges.remove(&edge);
+                }
+            }
+            self.triangles[t1] = [c, a, d];
+            self.triangles[t2] = [d, b, c];
+            for t in [t1, t2] {
+                let [p, q, r] = self.triangles[t];
+                for edge in [(p, q), (q, r), (r, p)] {
+                    edges.insert(edge, t);
+                }
+            }
+            stack.extend([(a, d), (d, b), (b, c), (c, a)]);
+        }
+    }
+
+    /// Whether `d` lies strictly inside the circumcircle of the counter-clockwise triangle
+    /// `a`, `b`, `c`.
+    fn in_circumcircle(&self, a: usize, b: usize, c: usize, d: usize) -> bool {
+        let [a, b, c, d] = [a, b, c, d].map(|i| {
+            let coord = self.coords[i];
+            robust::Coord {
+                x: coord.x.to_f64().unwrap(),
+                y: coord.y.to_f64().unwrap(),
+            }
+        });
+        robust::incircle(a, b, c, d) > 0.
+    }
+}
+
+/// The vertex of `triangle` which isn't on its edge from `a` to `b`.
+fn opposite(triangle: [usize; 3], a: usize, b: usize) -> usize {
+    triangle
+        .into_iter()
+        .find(|&v| v != a && v != b)
+        .expect("triangle must have a third vertex")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, Area, ConvexHull};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn random_points(n: usize, seed: u64) -> Vec<Point> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..n)
+            .map(|_| point!(x: rng.gen_range(0. ..100.), y: rng.gen_range(0. ..100.)))
+            .collect()
+    }
+
+    fn assert_delaunay(points: &[Point], triangles: &[Triangle]) {
+        for triangle in triangles {
+            let [a, b, c] = triangle.to_array().map(|coord| robust::Coord {
+                x: coord.x,
+                y: coord.y,
+            });
+            assert!(robust::orient2d(a, b, c) > 0., "{triangle:?} isn't ccw");
+            for point in points {
+                let d = robust::Coord {
+                    x: point.x(),
+                    y: point.y(),
+                };
+                assert!(
+                    robust::incircle(a, b, c, d) <= 0.,
+                    "{point:?} inside the circumcircle of {triangle:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn square_with_center() {
+        let points = vec![
+            point!(x: 0., y: 0.),
+            point!(x: 1., y: 0.),
+            point!(x: 1., y: 1.),
+            point!(x: 0., y: 1.),
+            point!(x: 0.5, y: 0.5),
+        ];
+        let triangles = points.delaunay_triangulation();
+        assert_eq!(triangles.len(), 4);
+        let area: f64 = triangles.iter().map(|t| t.unsigned_area()).sum();
+        assert_relative_eq!(area, 1.);
+        assert_delaunay(&points, &triangles);
+    }
+
+    #[test]
+    fn flips_illegal_edge() {
+        // Inserting these in order first connects the two far apart points, which must be flipped
+        let points = vec![
+            point!(x: 0., y: 0.),
+            point!(x: 5., y: -1.),
+            point!(x: 5., y: 1.),
+            point!(x: 10., y: 0.),
+        ];
+        let triangles = points.delaunay_triangulation();
+        assert_eq!(triangles.len(), 2);
+        assert_delaunay(&points, &triangles);
+        for triangle in &triangles {
+            assert!(triangle.to_array().contains(&point!(x: 5., y: -1.).0));
+            assert!(triangle.to_array().contains(&point!(x: 5., y: 1.).0));
+        }
+    }
+
+    #[test]
+    fn random_points_are_delaunay() {
+        let points = random_points(200, 42);
+        let triangles = points.delaunay_triangulation();
+        assert_delaunay(&points, &triangles);
+
+        // the triangles cover the convex hull
+        let hull_area = MultiPoint::new(points.clone())
+            .convex_hull()
+            .unsigned_area();
+        let area: f64 = triangles.iter().map(|t| t.unsigned_area()).sum();
+        assert_relative_eq!(area, hull_area, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn grid_is_cocircular() {
+        // Every square of the grid has four cocircular corners
+        let points: Vec<_> = (0..5)
+            .flat_map(|x| (0..5).map(move |y| point!(x: x as f64, y: y as f64)))
+            .collect();
+        let triangles = points.delaunay_triangulation();
+        assert_eq!(triangles.len(), 2 * 4 * 4);
+        assert_delaunay(&points, &triangles);
+    }
+
+    #[test]
+    fn duplicate_points() {
+        let points = MultiPoint::new(vec![
+            point!(x: 0., y: 0.),
+            point!(x: 1., y: 0.),
+            point!(x: 0., y: 0.),
+            point!(x: 0., y: 1.),
+            point!(x: 1., y: 0.),
+        ]);
+        let triangles = points.delaunay_triangulation();
+        assert_eq!(triangles.len(), 1);
+
+        // the first of each duplicate is used
+        let indices = points.delaunay_triangulation_indices();
+        assert_eq!(indices.len(), 1);
+        let mut vertices = indices[0];
+        vertices.sort();
+        assert_eq!(vertices, [0, 1, 3]);
+    }
+
+    #[test]
+    fn indices_match_triangles() {
+        let points = random_points(100, 7);
+        let triangles = points.delaunay_triangulation();
+        let indices = points.delaunay_triangulation_indices();
+        assert_eq!(triangles.len(), indices.len());
+        for (triangle, vertices) in triangles.iter().zip(&indices) {
+            assert_eq!(triangle.to_array(), vertices.map(|vertex| points[vertex].0));
+        }
+    }
+
+    #[test]
+    fn collinear_points() {
+        let points: Vec<_> = (0..10)
+            .map(|i| point!(x: i as f64, y: 2. * i as f64))
+            .collect();
+        assert!(points.delaunay_triangulation().is_empty());
+
+        // Only the last point is off the line
+        let mut points = points;
+        points.push(point!(x: 20., y: 0.));
+        let triangles = points.delaunay_triangulation();
+        assert_eq!(triangles.len(), 9);
+        assert_delaunay(&points, &triangles);
+    }
+
+    #[test]
+    fn too_few_points() {
+        assert!(MultiPoint::<f64>::new(vec![])
+            .delaunay_triangulation()
+            .is_empty());
+        assert!([point!(x: 0., y: 0.), point!(x: 1., y: 1.)]
+            .delaunay_triangulation()
+            .is_empty());
+        assert!([point!(x: 0., y: 0.), point!(x: 1., y: 1.)]
+            .delaunay_triangulation_indices()
+            .is_empty());
+    }
+}
@@ -0,0 +1,508 @@
+use crate::kernels::{Kernel, Orientation};
+use crate::line_intersection::line_intersection;
+use crate::utils::lex_cmp;
+use crate::winding_order::{Winding, WindingOrder};
+use crate::{Coord, GeoFloat, Line, LineString, MultiPolygon, Polygon, Triangle};
+
+/// Triangulate polygons using an [ear clipping] algorithm.
+///
+/// Each interior ring is first bridged into the exterior ring, by connecting one of its vertices
+/// to a vertex of the exterior which it can see, so that the polygon is described by a single
+/// ring. Triangles are then repeatedly clipped from that ring, at a vertex whose neighbours can
+/// be connected without leaving the polygon. The triangles cover the polygon exactly, and each
+/// of them is wound counter-clockwise.
+///
+/// Rings with fewer than four coordinates, or with no area, are degenerate and ignored: a
+/// polygon with such an exterior has no triangles.
+///
+/// Ear clipping takes `O(n²)` time for a polygon with `n` vertices in the worst case.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{polygon, Area, TriangulateEarcut};
+///
+/// let square_with_hole = polygon!(
+///     exterior: [(x: 0., y: 0.), (x: 4., y: 0.), (x: 4., y: 4.), (x: 0., y: 4.)],
+///     interiors: [[(x: 1., y: 1.), (x: 1., y: 3.), (x: 3., y: 3.), (x: 3., y: 1.)]],
+/// );
+///
+/// let triangles = square_with_hole.earcut_triangles();
+/// assert_eq!(triangles.len(), 8);
+///
+/// let area: f64 = triangles.iter().map(|triangle| triangle.unsigned_area()).sum();
+/// assert_eq!(area, 12.);
+/// ```
+///
+/// [ear clipping]: https://en.wikipedia.org/wiki/Polygon_triangulation#Ear_clipping_method
+pub trait TriangulateEarcut<T: GeoFloat> {
+    /// The triangles of the triangulation.
+    fn earcut_triangles(&self) -> Vec<Triangle<T>> {
+        let raw = self.earcut_triangles_raw();
+        let coord = |i: usize| Coord {
+            x: raw.vertices[2 * i],
+            y: raw.vertices[2 * i + 1],
+        };
+        raw.triangle_indices
+            .chunks_exact(3)
+            .map(|triangle| {
+                Triangle::new(coord(triangle[0]), coord(triangle[1]), coord(triangle[2]))
+            })
+            .collect()
+    }
+
+    /// The triangulation as a flat list of vertex coordinates and a list of indices into it,
+    /// e.g. to be uploaded as the vertex and index buffers of a GPU.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::{polygon, TriangulateEarcut};
+    ///
+    /// let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+    ///
+    /// let raw = square.earcut_triangles_raw();
+    /// assert_eq!(raw.vertices, vec![0., 0., 1., 0., 1., 1., 0., 1.]);
+    /// assert_eq!(raw.triangle_indices.len(), 2 * 3);
+    /// ```
+    fn earcut_triangles_raw(&self) -> RawTriangulation<T>;
+}
+
+/// A triangulation which refers to its vertices by index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawTriangulation<T: GeoFloat> {
+    /// The coordinates of the vertices, flattened as `[x0, y0, x1, y1, ...]`.
+    pub vertices: Vec<T>,
+    /// The indices of the vertices of each triangle, three at a time. The vertex with index `i`
+    /// is at `(vertices[2 * i], vertices[2 * i + 1])`.
+    pub triangle_indices: Vec<usize>,
+}
+
+impl<T: GeoFloat> TriangulateEarcut<T> for Polygon<T> {
+    fn earcut_triangles_raw(&self) -> RawTriangulation<T> {
+        let mut earcut = Earcut::default();
+        earcut.add_polygon(self);
+        earcut.into_raw()
+    }
+}
+
+impl<T: GeoFloat> TriangulateEarcut<T> for MultiPolygon<T> {
+    fn earcut_triangles_raw(&self) -> RawTriangulation<T> {
+        let mut earcut = Earcut::default();
+        for polygon in self {
+            earcut.add_polygon(polygon);
+        }
+        earcut.into_raw()
+    }
+}
+
+/// The vertices and triangles of a triangulation in progress.
+struct Earcut<T: GeoFloat> {
+    coords: Vec<Coord<T>>,
+    triangles: Vec<usize>,
+}
+
+impl<T: GeoFloat> Default for Earcut<T> {
+    fn default() -> Self {
+        Earcut {
+            coords: vec![],
+            triangles: vec![],
+        }
+    }
+}
+
+impl<T: GeoFloat> Earcut<T> {
+    fn into_raw(self) -> RawTriangulation<T> {
+        RawTriangulation {
+            vertices: self
+                .coords
+                .iter()
+                .flat_map(|coord| [coord.x, coord.y])
+                .collect(),
+            triangle_indices: self.triangles,
+        }
+    }
+
+    fn add_polygon(&mut self, polygon: &Polygon<T>) {
+        let exterior = match self.add_ring(polygon.exterior(), WindingOrder::CounterClockwise) {
+            Some(exterior) => exterior,
+            None => return,
+        };
+        let holes: Vec<_> = polygon
+            .interiors()
+            .iter()
+            .filter_map(|interior| self.add_ring(interior, WindingOrder::Clockwise))
+            .collect();
+        let outline = self.bridge_holes(exterior, holes);
+        self.clip_ears(outline);
+    }
+
+    /// Add the vertices of the closed `ring`, and return their indices in the given winding
+    /// order, or `None` if the ring is degenerate.
+    fn add_ring(&mut self, ring: &LineString<T>, winding: WindingOrder) -> Option<Vec<usize>> {
+        let ring_winding = ring.winding_order()?;
+        let start = self.coords.len();
+        self.coords.extend(&ring.0[..ring.0.len() - 1]);
+        let mut indices: Vec<usize> = (start..self.coords.len()).collect();
+        if ring_winding != winding {
+            indices.reverse();
+        }
+        Some(indices)
+    }
+
+    fn orient(&self, a: usize, b: usize, c: usize) -> Orientation {
+        T::Ker::orient2d(self.coords[a], self.coords[b], self.coords[c])
+    }
+
+    /// Merge the clockwise `holes` into the counter-clockwise `outer` ring, by connecting each of
+    /// them to a vertex it can see with a pair of coincident edges.
+    fn bridge_holes(&self, mut outer: Vec<usize>, mut holes: Vec<Vec<usize>>) -> Vec<usize> {
+        let rightmost = |ring: &[usize]| {
+            (0..ring.len())
+                .max_by(|&i, &j| lex_cmp(&self.coords[ring[i]], &self.coords[ring[j]]))
+                .expect("rings are not empty")
+        };
+        // Starting from the right keeps the bridges short, as each one can reach the holes
+        // which were already merged.
+        holes.sort_by(|a, b| lex_cmp(&self.coords[b[rightmost(b)]], &self.coords[a[rightmost(a)]]));
+
+        while !holes.is_empty() {
+            let hole = holes.remove(0);
+            let m = rightmost(&hole);
+            let k = self.bridge_vertex(&outer, &hole, &holes, hole[m]);
+
+            let mut merged = Vec::with_capacity(outer.len() + hole.len() + 2);
+            merged.extend(&outer[..=k]);
+            merged.extend(&hole[m..]);
+            merged.extend(&hole[..=m]);
+            merged.extend(&outer[k..]);
+            outer = merged;
+        }
+        outer
+    }
+
+    /// The position in `outer` of the closest vertex which `from`, a vertex of `hole`, can be
+    /// connected to without crossing any ring.
+    fn bridge_vertex(
+        &self,
+        outer: &[usize],
+        hole: &[usize],
+        other_holes: &[Vec<usize>],
+        from: usize,
+    ) -> usize {
+        let origin = self.coords[from];
+        let mut candidates: Vec<usize> = (0..outer.len()).collect();
+        candidates.sort_by(|&i, &j| {
+            let distance = |k: usize| {
+                let delta = self.coords[outer[k]] - origin;
+                delta.x * delta.x + delta.y * delta.y
+            };
+            distance(i).partial_cmp(&distance(j)).unwrap()
+        });
+
+        let rings = std::iter::once(outer)
+            .chain(std::iter::once(hole))
+            .chain(other_holes.iter().map(Vec::as_slice));
+        let edges: Vec<(usize, usize)> = rings
+            .flat_map(|ring| (0..ring.len()).map(move |i| (ring[i], ring[(i + 1) % ring.len()])))
+            .collect();
+
+        let in_wedge = |k: usize| {
+            let prev = outer[(k + outer.len() - 1) % outer.len()];
+            let next = outer[(k + 1) % outer.len()];
+            let left_of_prev = self.orient(prev, outer[k], from) == Orientation::CounterClockwise;
+            let left_of_next = self.orient(outer[k], next, from) == Orientation::CounterClockwise;
+            if self.orient(prev, outer[k], next) == Orientation::Clockwise {
+                left_of_prev || left_of_next
+            } else {
+                left_of_prev && left_of_next
+            }
+        };
+        let visible = |k: usize| {
+            let target = self.coords[outer[k]];
+            let bridge = Line::new(origin, target);
+            edges.iter().all(|&(a, b)| {
+                let (a, b) = (self.coords[a], self.coords[b]);
+                [origin, target].contains(&a)
+                    || [origin, target].contains(&b)
+                    || line_intersection(bridge, Line::new(a, b)).is_none()
+            })
+        };
+
+        // Every hole can see some vertex, but fall back to the closest one in case the rings
+        // are invalid.
+        candidates
+            .iter()
+            .copied()
+            .find(|&k| in_wedge(k) && visible(k))
+            .or_else(|| candidates.iter().copied().find(|&k| in_wedge(k)))
+            .unwrap_or(candidates[0])
+    }
+
+    /// Triangulate the counter-clockwise `ring` by clipping its ears.
+    fn clip_ears(&mut self, mut ring: Vec<usize>) {
+        let mut i = 0;
+        // The number of vertices visited since the last one was clipped
+        let mut stalled = 0;
+        while ring.len() > 3 {
+            let n = ring.len();
+            i %= n;
+            let (a, b, c) = (ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]);
+            match self.orient(a, b, c) {
+                Orientation::Collinear => {
+                    // no area is lost by removing `b`
+                    ring.remove(i);
+                    stalled = 0;
+                }
+                // After a full pass without any ears, the ring must be invalid (e.g. self
+                // intersecting), so clip any convex vertex to make progress.
+                Orientation::CounterClockwise if stalled >= n || self.is_ear(&ring, i) => {
+                    self.triangles.extend([a, b, c]);
+                    ring.remove(i);
+                    stalled = 0;
+                }
+                _ => {
+                    i += 1;
+                    stalled += 1;
+                    if stalled >= 2 * n {
+                        return;
+                    }
+                }
+            }
+        }
+        if let [a, b, c] = ring[..] {
+            if self.orient(a, b, c) == Orientation::CounterClockwise {
+                self.triangles.extend([a, b, c]);
+            }
+        }
+    }
+
+    /// Whether the convex vertex at position `i` of `ring` can be clipped, i.e. no other vertex
+    /// lies in the triangle it forms with its neighbours.
+    fn is_ear(&self, ring: &[usize], i: usize) -> bool {
+        let n = ring.len();
+        let (a, b, c) = (ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]);
+        let corners = [self.coords[a], self.coords[b], self.coords[c]];
+        ring.iter().all(|&p| {
+            // this also skips the duplicated endpoints of bridges
+            corners.contains(&self.coords[p])
+                || self.orient(a, b, p) == Orientation::Clockwise
+                || self.orient(b, c, p) == Orientation::Clockwise
+                || self.orient(c, a, p) == Orientation::Clockwise
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, polygon, Area, Contains, Point};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn area(triangles: &[Triangle]) -> f64 {
+        triangles
+            .iter()
+            .map(|triangle| triangle.unsigned_area())
+            .sum()
+    }
+
+    fn assert_ccw(triangles: &[Triangle]) {
+        for triangle in triangles {
+            assert!(triangle.signed_area() > 0., "{triangle:?} isn't ccw");
+        }
+    }
+
+    #[test]
+    fn square() {
+        let square = polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)];
+        let triangles = square.earcut_triangles();
+        assert_eq!(triangles.len(), 2);
+        assert_ccw(&triangles);
+        assert_relative_eq!(area(&triangles), 1.);
+
+        // the result doesn't depend on the winding of the input
+        let mut square = square;
+        square.exterior_mut(|exterior| exterior.0.reverse());
+        let triangles = square.earcut_triangles();
+        assert_eq!(triangles.len(), 2);
+        assert_ccw(&triangles);
+    }
+
+    #[test]
+    fn concave() {
+        // a comb with three teeth
+        let comb = polygon![
+            (x: 0., y: 0.), (x: 5., y: 0.), (x: 5., y: 3.), (x: 4., y: 3.), (x: 4., y: 1.),
+            (x: 3., y: 1.), (x: 3., y: 3.), (x: 2., y: 3.), (x: 2., y: 1.), (x: 1., y: 1.),
+            (x: 1., y: 3.), (x: 0., y: 3.),
+        ];
+        let triangles = comb.earcut_triangles();
+        // vertices which become collinear with their neighbours are dropped without a triangle
+        assert!(triangles.len() <= 10);
+        assert_ccw(&triangles);
+        assert_relative_eq!(area(&triangles), comb.unsigned_area());
+    }
+
+    #[test]
+    fn holes() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)],
+                [(x: 6., y: 6.), (x: 6., y: 8.), (x: 8., y: 8.), (x: 8., y: 6.)],
+            ],
+        );
+        let triangles = polygon.earcut_triangles();
+        assert_ccw(&triangles);
+        assert_relative_eq!(area(&triangles), 92.);
+        // no triangle covers either of the holes
+        for triangle in &triangles {
+            let centroid = Point::from((triangle.0 + triangle.1 + triangle.2) / 3.);
+            assert!(polygon.contains(&centroid));
+        }
+    }
+
+    #[test]
+    fn raw_indices() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 2., y: 2.), (x: 4., y: 2.), (x: 4., y: 4.), (x: 2., y: 4.)]],
+        );
+        let raw = polygon.earcut_triangles_raw();
+        // every vertex of every ring, without the closing ones
+        assert_eq!(raw.vertices.len(), 2 * 8);
+        assert_eq!(raw.vertices[..2], [0., 0.]);
+        assert_eq!(raw.vertices[8..10], [2., 2.]);
+        // a polygon with a hole has two more triangles than its vertex count minus two
+        assert_eq!(raw.triangle_indices.len(), 3 * 8);
+        assert!(raw.triangle_indices.iter().all(|&i| i < 8));
+    }
+
+    #[test]
+    fn multi_polygon() {
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon![(x: 0., y: 0.), (x: 1., y: 0.), (x: 1., y: 1.), (x: 0., y: 1.)],
+            polygon![(x: 2., y: 0.), (x: 4., y: 0.), (x: 4., y: 1.), (x: 2., y: 1.)],
+        ]);
+        let raw = multi_polygon.earcut_triangles_raw();
+        assert_eq!(raw.vertices.len(), 2 * 8);
+        // the triangles of the second polygon refer to its own vertices
+        assert!(raw.triangle_indices[6..].iter().all(|&i| i >= 4));
+        assert_relative_eq!(area(&multi_polygon.earcut_triangles()), 3.);
+    }
+
+    #[test]
+    fn degenerate_rings() {
+        let line = polygon![(x: 0., y: 0.), (x: 1., y: 1.)];
+        assert_eq!(line.exterior().0.len(), 3);
+        let raw = line.earcut_triangles_raw();
+        assert!(raw.vertices.is_empty());
+        assert!(raw.triangle_indices.is_empty());
+        assert!(line.earcut_triangles().is_empty());
+
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        assert!(empty.earcut_triangles().is_empty());
+
+        // a degenerate hole is ignored
+        let polygon = Polygon::new(
+            LineString::from(vec![(0., 0.), (1., 0.), (1., 1.), (0., 1.)]),
+            vec![LineString::from(vec![(0.5, 0.5), (0.6, 0.6)])],
+        );
+        let raw = polygon.earcut_triangles_raw();
+        assert_eq!(raw.vertices.len(), 2 * 4);
+        assert_relative_eq!(area(&polygon.earcut_triangles()), 1.);
+    }
+
+    #[test]
+    fn area_matches_polygon() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut next = move || rng.gen::<f64>();
+
+        for _ in 0..50 {
+            // a star shaped exterior around a 3 x 3 grid of square holes
+            let count = 8 + (next() * 40.) as usize;
+            let exterior: Vec<_> = (0..count)
+                .map(|i| {
+                    let angle = i as f64 / count as f64 * std::f64::consts::TAU;
+                    let radius = 5. + 5. * next();
+                    coord! { x: radius * angle.cos(), y: radius * angle.sin() }
+                })
+                .collect();
+            let mut interiors = vec![];
+            for x in -1..=1 {
+                for y in -1..=1 {
+                    if next() < 0.3 {
+                        continue;
+                    }
+                    let center = coord! { x: x as f64 * 2.5, y: y as f64 * 2.5 };
+                    let size = 0.2 + next();
+                    let mut hole: Vec<_> = [(-1., -1.), (1., -1.), (1., 1.), (-1., 1.)]
+                        .iter()
+                        .map(|&(dx, dy)| center + coord! { x: dx * size, y: dy * size })
+                        .collect();
+                    // holes may be wound either way
+                    if next() < 0.5 {
+                        hole.reverse();
+                    }
+                    interiors.push(LineString::new(hole));
+                }
+            }
+            let polygon = Polygon::new(LineString::new(exterior), interiors);
+
+            let triangles = polygon.earcut_triangles();
+            assert_ccw(&triangles);
+            assert_relative_eq!(
+                area(&triangles),
+                polygon.unsigned_area(),
+                max_relative = 1e-10
+            );
+        }
+    }
+
+    #[test]
+    fn slivers() {
+        // a thin sliver, with a nearly collinear vertex along its long side
+        let sliver = polygon![
+            (x: 0., y: 0.),
+            (x: 5., y: 1e-12),
+            (x: 10., y: 0.),
+            (x: 10., y: 1e-9),
+            (x: 0., y: 1e-9),
+        ];
+        let triangles = sliver.earcut_triangles();
+        assert_eq!(triangles.len(), 3);
+        assert_ccw(&triangles);
+        assert_relative_eq!(
+            area(&triangles),
+            sliver.unsigned_area(),
+            max_relative = 1e-6
+        );
+
+        // a square with a sliver of a hole, and a hole with a sliver cut into it
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 9., y: 1.), (x: 9., y: 1. + 1e-9), (x: 1., y: 1. + 1e-9)],
+                [
+                    (x: 2., y: 3.),
+                    (x: 8., y: 3.),
+                    (x: 8., y: 8.),
+                    (x: 5., y: 3. + 1e-9),
+                    (x: 2., y: 8.),
+                ],
+            ],
+        );
+        let triangles = polygon.earcut_triangles();
+        assert_ccw(&triangles);
+        assert_relative_eq!(
+            area(&triangles),
+            polygon.unsigned_area(),
+            max_relative = 1e-10
+        );
+        for triangle in &triangles {
+            let centroid = Point::from((triangle.0 + triangle.1 + triangle.2) / 3.);
+            assert!(polygon.contains(&centroid));
+        }
+    }
+}
@@ -0,0 +1,738 @@
+//! Check that geometries are valid according to the [OGC Simple Feature Access] specification.
+//!
+//! Many algorithms, such as [`BooleanOps`](crate::BooleanOps), assume that their input is valid,
+//! and may return wrong results or panic if it is not. Geometries from untrusted sources can be
+//! checked with [`IsValid`] first.
+//!
+//! [OGC Simple Feature Access]: https://www.ogc.org/standards/sfa
+
+use crate::coordinate_position::{coord_pos_relative_to_ring, CoordPos};
+use crate::line_intersection::{line_intersection, LineIntersection};
+use crate::utils::partial_min;
+use crate::{
+    Coord, CoordNum, CoordinatePosition, GeoFloat, Geometry, GeometryCollection, Line, LineString,
+    MultiLineString, MultiPoint, MultiPolygon, Point, Polygon, Rect, Triangle,
+};
+
+/// Check whether a geometry is valid, and if not, why.
+///
+/// A geometry is valid if:
+///
+/// - all of its coordinates are finite;
+/// - every `Line` and `LineString` has at least two distinct coordinates, unless it is empty;
+/// - every ring of a `Polygon` has at least four coordinates, of which three are distinct;
+/// - no ring of a `Polygon` intersects itself;
+/// - the rings of a `Polygon` only touch each other at single points, and never cross;
+/// - every interior ring of a `Polygon` is inside its exterior ring, and outside of the other
+///   interior rings;
+/// - the polygons of a `MultiPolygon` only touch each other at single points, and none of them is
+///   inside another.
+///
+/// A `LineString` may intersect itself, and consecutive repeated coordinates are allowed
+/// everywhere, although they don't count towards the number of coordinates needed. Empty
+/// geometries are valid.
+///
+/// # Examples
+///
+/// ```
+/// use geo::validation::{InvalidityReason, Location, RingRole};
+/// use geo::{coord, polygon, IsValid};
+///
+/// let square = polygon![(x: 0., y: 0.), (x: 2., y: 0.), (x: 2., y: 2.), (x: 0., y: 2.)];
+/// assert!(square.is_valid());
+///
+/// // a "bow tie", whose exterior ring crosses itself
+/// let bow_tie = polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+/// assert!(!bow_tie.is_valid());
+/// assert_eq!(
+///     bow_tie.explain_invalidity(),
+///     Some(vec![InvalidityReason::SelfIntersection(
+///         Location::new().ring(RingRole::Exterior).coord(0),
+///         coord! { x: 1., y: 1. },
+///     )])
+/// );
+/// ```
+pub trait IsValid<T: GeoFloat> {
+    /// Whether the geometry is valid.
+    fn is_valid(&self) -> bool {
+        self.explain_invalidity().is_none()
+    }
+
+    /// Every reason the geometry is invalid, or `None` if it is valid.
+    fn explain_invalidity(&self) -> Option<Vec<InvalidityReason<T>>>;
+}
+
+/// The reason a geometry is not valid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidityReason<T: CoordNum> {
+    /// A coordinate is NaN or infinite.
+    NonFiniteCoord(Location),
+    /// A `Line` or `LineString` has fewer than two distinct coordinates, or a ring has fewer than
+    /// four coordinates once consecutive repeated ones are removed.
+    TooFewCoords(Location),
+    /// A ring intersects itself at the given coordinate. The location is of the start of one of
+    /// the intersecting segments.
+    SelfIntersection(Location, Coord<T>),
+    /// A ring crosses or overlaps the given other ring of the same `Polygon`, at the given
+    /// coordinate.
+    RingsIntersect(Location, RingRole, Coord<T>),
+    /// An interior ring is not inside the exterior ring of its `Polygon`.
+    HoleOutsideShell(Location),
+    /// An interior ring is inside the interior ring, with the given index, of the same `Polygon`.
+    NestedHoles(Location, usize),
+    /// A polygon of a `MultiPolygon` crosses or overlaps the polygon, with the given index, of
+    /// the same `MultiPolygon`, at the given coordinate.
+    PolygonsIntersect(Location, usize, Coord<T>),
+    /// A polygon of a `MultiPolygon` is inside the polygon, with the given index, of the same
+    /// `MultiPolygon`.
+    NestedShells(Location, usize),
+}
+
+/// Where a geometry is invalid.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Location {
+    /// The index of the invalid geometry in each enclosing `GeometryCollection` or
+    /// multi-geometry, outermost first. This is empty if the problem is with the geometry itself.
+    pub geometry: Vec<usize>,
+    /// The invalid ring, if the problem is with a ring of a `Polygon`.
+    pub ring: Option<RingRole>,
+    /// The index of the invalid coordinate in its `LineString` or ring, if the problem is at a
+    /// particular coordinate.
+    pub coord: Option<usize>,
+}
+
+impl Location {
+    /// The location of a whole geometry.
+    pub fn new() -> Self {
+        Location::default()
+    }
+
+    /// The same location, in the geometry with the given index of a `GeometryCollection` or
+    /// multi-geometry.
+    pub fn in_geometry(mut self, index: usize) -> Self {
+        self.geometry.insert(0, index);
+        self
+    }
+
+    /// The same location, in the given ring of a `Polygon`.
+    pub fn ring(mut self, ring: RingRole) -> Self {
+        self.ring = Some(ring);
+        self
+    }
+
+    /// The same location, at the coordinate with the given index.
+    pub fn coord(mut self, index: usize) -> Self {
+        self.coord = Some(index);
+        self
+    }
+}
+
+/// A ring of a `Polygon`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RingRole {
+    Exterior,
+    /// The interior ring with the given index.
+    Interior(usize),
+}
+
+impl RingRole {
+    fn from_index(index: usize) -> Self {
+        match index {
+            0 => RingRole::Exterior,
+            _ => RingRole::Interior(index - 1),
+        }
+    }
+}
+
+impl<T: CoordNum> InvalidityReason<T> {
+    fn location_mut(&mut self) -> &mut Location {
+        match self {
+            InvalidityReason::NonFiniteCoord(location)
+            | InvalidityReason::TooFewCoords(location)
+            | InvalidityReason::SelfIntersection(location, _)
+            | InvalidityReason::RingsIntersect(location, _, _)
+            | InvalidityReason::HoleOutsideShell(location)
+            | InvalidityReason::NestedHoles(location, _)
+            | InvalidityReason::PolygonsIntersect(location, _, _)
+            | InvalidityReason::NestedShells(location, _) => location,
+        }
+    }
+}
+
+fn explanation<T: CoordNum>(reasons: Vec<InvalidityReason<T>>) -> Option<Vec<InvalidityReason<T>>> {
+    if reasons.is_empty() {
+        None
+    } else {
+        Some(reasons)
+    }
+}
+
+/// The reasons each of `geometries` is invalid, located in the enclosing geometry.
+fn explain_each<'a, T, G, I>(geometries: I) -> Vec<InvalidityReason<T>>
+where
+    T: GeoFloat,
+    G: IsValid<T> + 'a,
+    I: IntoIterator<Item = &'a G>,
+{
+    let mut reasons = vec![];
+    for (index, geometry) in geometries.into_iter().enumerate() {
+        for mut reason in geometry.explain_invalidity().unwrap_or_default() {
+            let location = reason.location_mut();
+            *location = std::mem::take(location).in_geometry(index);
+            reasons.push(reason);
+        }
+    }
+    reasons
+}
+
+fn is_finite<T: GeoFloat>(coord: Coord<T>) -> bool {
+    coord.x.is_finite() && coord.y.is_finite()
+}
+
+/// Report every non-finite coordinate of `coords` at `location`.
+fn check_finite<T: GeoFloat>(
+    coords: &[Coord<T>],
+    location: &Location,
+    reasons: &mut Vec<InvalidityReason<T>>,
+) {
+    for (index, &coord) in coords.iter().enumerate() {
+        if !is_finite(coord) {
+            reasons.push(InvalidityReason::NonFiniteCoord(
+                location.clone().coord(index),
+            ));
+        }
+    }
+}
+
+/// The coordinates of `ring` without consecutive repeats, with their indices in `ring`.
+fn dedup_ring<T: GeoFloat>(ring: &LineString<T>) -> Vec<(usize, Coord<T>)> {
+    let mut coords: Vec<(usize, Coord<T>)> = ring.0.iter().copied().enumerate().collect();
+    coords.dedup_by(|(_, a), (_, b)| a == b);
+    coords
+}
+
+impl<T: GeoFloat> IsValid<T> for Point<T> {
+    fn explain_invalidity(&self) -> Option<Vec<InvalidityReason<T>>> {
+        let mut reasons = vec![];
+        check_finite(&[self.0], &Location::new(), &mut reasons);
+        explanation(reasons)
+    }
+}
+
+impl<T: GeoFloat> IsValid<T> for MultiPoint<T> {
+    fn explain_invalidity(&self) -> Option<Vec<InvalidityReason<T>>> {
+        explanation(explain_each(&self.0))
+    }
+}
+
+impl<T: GeoFloat> IsValid<T> for Line<T> {
+    fn explain_invalidity(&self) -> Option<Vec<InvalidityReason<T>>> {
+        let mut reasons = vec![];
+        check_finite(&[self.start, self.end], &Location::new(), &mut reasons);
+        if reasons.is_empty() && self.start == self.end {
+            reasons.push(InvalidityReason::TooFewCoords(Location::new()));
+        }
+        explanation(reasons)
+    }
+}
+
+impl<T: GeoFloat> IsValid<T> for LineString<T> {
+    fn explain_invalidity(&self) -> Option<Vec<InvalidityReason<T>>> {
+        let mut reasons = vec![];
+        check_finite(&self.0, &Location::new(), &mut reasons);
+        if reasons.is_empty() && !self.0.is_empty() && self.0.iter().all(|&c| c == self.0[0]) {
+            reasons.push(InvalidityReason::TooFewCoords(Location::new()));
+        }
+        explanation(reasons)
+    }
+}
+
+impl<T: GeoFloat> IsValid<T> for MultiLineString<T> {
+    fn explain_invalidity(&self) -> Option<Vec<InvalidityReason<T>>> {
+        explanation(explain_each(&self.0))
+    }
+}
+
+/// A segment of a ring, for finding intersections between rings.
+#[derive(Debug, Clone, Copy)]
+struct RingSegment<T: GeoFloat> {
+    line: Line<T>,
+    /// The index of the polygon in its `MultiPolygon`
+    polygon: usize,
+    /// The index of the ring in its polygon, with the exterior first
+    ring: usize,
+    /// The index of the segment in its ring, without repeated coordinates
+    segment: usize,
+    /// The index of the start of the segment in its ring
+    coord: usize,
+    /// The number of segments of the ring
+    ring_len: usize,
+}
+
+impl<T: GeoFloat> RingSegment<T> {
+    fn location(&self) -> Location {
+        Location::new()
+            .ring(RingRole::from_index(self.ring))
+            .coord(self.coord)
+    }
+
+    /// Whether `self` and `other` are consecutive segments of the same ring.
+    fn is_adjacent(&self, other: &RingSegment<T>) -> bool {
+        self.polygon == other.polygon
+            && self.ring == other.ring
+            && ((self.segment + 1) % self.ring_len == other.segment
+                || (other.segment + 1) % self.ring_len == self.segment)
+    }
+}
+
+/// The segments of every ring of `polygon`, which has the given index in its `MultiPolygon`.
+fn ring_segments<T: GeoFloat>(polygon: &Polygon<T>, index: usize) -> Vec<RingSegment<T>> {
+    let mut segments = vec![];
+    let rings = std::iter::once(polygon.exterior()).chain(polygon.interiors());
+    for (ring_index, ring) in rings.enumerate() {
+        let coords = dedup_ring(ring);
+        let ring_len = coords.len().saturating_sub(1);
+        for (segment, pair) in coords.windows(2).enumerate() {
+            segments.push(RingSegment {
+                line: Line::new(pair[0].1, pair[1].1),
+                polygon: index,
+                ring: ring_index,
+                segment,
+                coord: pair[0].0,
+                ring_len,
+            });
+        }
+    }
+    segments
+}
+
+/// Every pair of `segments` which intersect, and their intersection.
+///
+/// The segments are sorted by their leftmost `x`, so that each only needs to be compared with
+/// the ones which start before it ends. Unlike a full sweep line, this can't be confused by
+/// segments which cross each other in invalid geometries.
+fn intersections<T: GeoFloat>(
+    mut segments: Vec<RingSegment<T>>,
+) -> Vec<(RingSegment<T>, RingSegment<T>, LineIntersection<T>)> {
+    let min_x = |segment: &RingSegment<T>| partial_min(segment.line.start.x, segment.line.end.x);
+    segments.sort_by(|a, b| min_x(a).partial_cmp(&min_x(b)).unwrap());
+
+    let mut intersections = vec![];
+    for (index, a) in segments.iter().enumerate() {
+        let max_x = a.line.start.x.max(a.line.end.x);
+        for b in segments[index + 1..]
+            .iter()
+            .take_while(|b| min_x(b) <= max_x)
+        {
+            if let Some(intersection) = line_intersection(a.line, b.line) {
+                intersections.push((*a, *b, intersection));
+            }
+        }
+    }
+    intersections
+}
+
+/// Whether two segments of valid rings intersect only by touching at a single point.
+fn is_touch<T: GeoFloat>(intersection: &LineIntersection<T>) -> bool {
+    matches!(
+        intersection,
+        LineIntersection::SinglePoint {
+            is_proper: false,
+            ..
+        }
+    )
+}
+
+fn intersection_coord<T: GeoFloat>(intersection: &LineIntersection<T>) -> Coord<T> {
+    match intersection {
+        LineIntersection::SinglePoint { intersection, .. } => *intersection,
+        LineIntersection::Collinear { intersection } => intersection.start,
+    }
+}
+
+/// Whether `inner` is inside `outer`, judged by its first coordinate which is not on the
+/// boundary of `outer`. The rings must not cross each other.
+fn ring_position<T: GeoFloat>(inner: &LineString<T>, outer: &LineString<T>) -> CoordPos {
+    inner
+        .0
+        .iter()
+        .map(|&coord| coord_pos_relative_to_ring(coord, outer))
+        .find(|&position| position != CoordPos::OnBoundary)
+        .unwrap_or(CoordPos::OnBoundary)
+}
+
+impl<T: GeoFloat> IsValid<T> for Polygon<T> {
+    fn explain_invalidity(&self) -> Option<Vec<InvalidityReason<T>>> {
+        if self.exterior().0.is_empty() && self.interiors().is_empty() {
+            return None;
+        }
+
+        let mut reasons = vec![];
+        let rings = std::iter::once(self.exterior()).chain(self.interiors());
+        for (index, ring) in rings.enumerate() {
+            let location = Location::new().ring(RingRole::from_index(index));
+            let count = reasons.len();
+            check_finite(&ring.0, &location, &mut reasons);
+            if reasons.len() == count && dedup_ring(ring).len() < 4 {
+                reasons.push(InvalidityReason::TooFewCoords(location));
+            }
+        }
+        if !reasons.is_empty() {
+            // the rings can't be compared with each other
+            return Some(reasons);
+        }
+
+        for (a, b, intersection) in intersections(ring_segments(self, 0)) {
+            let coord = intersection_coord(&intersection);
+            if a.ring == b.ring {
+                let is_overlap = matches!(intersection, LineIntersection::Collinear { .. });
+                if is_overlap || !a.is_adjacent(&b) {
+                    let first = if a.coord <= b.coord { a } else { b };
+                    reasons.push(InvalidityReason::SelfIntersection(first.location(), coord));
+                }
+            } else if !is_touch(&intersection) {
+                let (first, second) = if a.ring < b.ring { (a, b) } else { (b, a) };
+                reasons.push(InvalidityReason::RingsIntersect(
+                    first.location(),
+                    RingRole::from_index(second.ring),
+                    coord,
+                ));
+            }
+        }
+        if !reasons.is_empty() {
+            return Some(reasons);
+        }
+
+        // The rings only touch, so each is either entirely inside or outside of each other one
+        for (index, interior) in self.interiors().iter().enumerate() {
+            let location = Location::new().ring(RingRole::Interior(index));
+            if ring_position(interior, self.exterior()) == CoordPos::Outside {
+                reasons.push(InvalidityReason::HoleOutsideShell(location.clone()));
+            }
+            for (other_index, other) in self.interiors().iter().enumerate() {
+                if other_index != index && ring_position(interior, other) == CoordPos::Inside {
+                    reasons.push(InvalidityReason::NestedHoles(location.clone(), other_index));
+                }
+            }
+        }
+        explanation(reasons)
+    }
+}
+
+impl<T: GeoFloat> IsValid<T> for MultiPolygon<T> {
+    fn explain_invalidity(&self) -> Option<Vec<InvalidityReason<T>>> {
+        let reasons = explain_each(&self.0);
+        if !reasons.is_empty() {
+            // the polygons can't be compared with each other
+            return Some(reasons);
+        }
+
+        let mut reasons = vec![];
+        let segments = self
+            .iter()
+            .enumerate()
+            .flat_map(|(index, polygon)| ring_segments(polygon, index))
+            .collect();
+        for (a, b, intersection) in intersections(segments) {
+            if a.polygon != b.polygon && !is_touch(&intersection) {
+                let (first, second) = if a.polygon < b.polygon {
+                    (a, b)
+                } else {
+                    (b, a)
+                };
+                reasons.push(InvalidityReason::PolygonsIntersect(
+                    first.location().in_geometry(first.polygon),
+                    second.polygon,
+                    intersection_coord(&intersection),
+                ));
+            }
+        }
+        if !reasons.is_empty() {
+            return Some(reasons);
+        }
+
+        // The polygons only touch, so each is either entirely inside or outside of each other one
+        for (index, polygon) in self.iter().enumerate() {
+            for (other_index, other) in self.iter().enumerate() {
+                let is_inside = index != other_index
+                    && polygon
+                        .exterior()
+                        .0
+                        .iter()
+                        .map(|coord| other.coordinate_position(coord))
+                        .find(|&position| position != CoordPos::OnBoundary)
+                        == Some(CoordPos::Inside);
+                if is_inside {
+                    reasons.push(InvalidityReason::NestedShells(
+                        Location::new().in_geometry(index),
+                        other_index,
+                    ));
+                }
+            }
+        }
+        explanation(reasons)
+    }
+}
+
+impl<T: GeoFloat> IsValid<T> for Rect<T> {
+    fn explain_invalidity(&self) -> Option<Vec<InvalidityReason<T>>> {
+        self.to_polygon().explain_invalidity()
+    }
+}
+
+impl<T: GeoFloat> IsValid<T> for Triangle<T> {
+    fn explain_invalidity(&self) -> Option<Vec<InvalidityReason<T>>> {
+        self.to_polygon().explain_invalidity()
+    }
+}
+
+impl<T: GeoFloat> IsValid<T> for GeometryCollection<T> {
+    fn explain_invalidity(&self) -> Option<Vec<InvalidityReason<T>>> {
+        explanation(explain_each(&self.0))
+    }
+}
+
+impl<T: GeoFloat> IsValid<T> for Geometry<T> {
+    crate::geometry_delegate_impl! {
+        fn explain_invalidity(&self) -> Option<Vec<InvalidityReason<T>>>;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{coord, line_string, point, polygon};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn valid_geometries() {
+        let square = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 1., y: 1.), (x: 4., y: 1.), (x: 4., y: 4.), (x: 1., y: 4.)],
+                // touches the other hole and the exterior at single points
+                [(x: 4., y: 4.), (x: 10., y: 5.), (x: 5., y: 8.)],
+            ],
+        );
+        assert_eq!(square.explain_invalidity(), None);
+        assert!(square.is_valid());
+
+        // repeated coordinates, and an empty geometry
+        let line_string = line_string![(x: 0., y: 0.), (x: 0., y: 0.), (x: 1., y: 1.)];
+        let empty = Polygon::<f64>::new(LineString::new(vec![]), vec![]);
+        let collection = GeometryCollection::new_from(vec![
+            point!(x: 1., y: 2.).into(),
+            line_string.into(),
+            empty.into(),
+            Rect::new((0., 0.), (1., 1.)).into(),
+        ]);
+        assert!(collection.is_valid());
+    }
+
+    #[test]
+    fn non_finite_coords() {
+        assert_eq!(
+            point!(x: f64::NAN, y: 0.).explain_invalidity(),
+            Some(vec![InvalidityReason::NonFiniteCoord(
+                Location::new().coord(0)
+            )])
+        );
+
+        let line_string = line_string![(x: 0., y: 0.), (x: 1., y: f64::INFINITY), (x: 2., y: 0.)];
+        assert_eq!(
+            line_string.explain_invalidity(),
+            Some(vec![InvalidityReason::NonFiniteCoord(
+                Location::new().coord(1)
+            )])
+        );
+    }
+
+    #[test]
+    fn too_few_coords() {
+        let line = Line::new(coord! { x: 1., y: 1. }, coord! { x: 1., y: 1. });
+        assert_eq!(
+            line.explain_invalidity(),
+            Some(vec![InvalidityReason::TooFewCoords(Location::new())])
+        );
+
+        let line_string = line_string![(x: 1., y: 1.)];
+        assert!(!line_string.is_valid());
+
+        // three coordinates, but only two are distinct
+        let polygon = polygon![(x: 0., y: 0.), (x: 1., y: 1.), (x: 1., y: 1.)];
+        assert_eq!(
+            polygon.explain_invalidity(),
+            Some(vec![InvalidityReason::TooFewCoords(
+                Location::new().ring(RingRole::Exterior)
+            )])
+        );
+
+        let rect = Rect::new((0., 0.), (0., 1.));
+        assert!(!rect.is_valid());
+    }
+
+    #[test]
+    fn self_intersections() {
+        // a spike, which doubles back on itself
+        let spike = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 0.),
+            (x: 2., y: 2.),
+            (x: 2., y: 4.),
+            (x: 2., y: 3.),
+            (x: 0., y: 2.),
+        ];
+        // it overlaps the segment it doubles back along, and touches it away from their ends
+        assert_eq!(
+            spike.explain_invalidity(),
+            Some(vec![
+                InvalidityReason::SelfIntersection(
+                    Location::new().ring(RingRole::Exterior).coord(2),
+                    coord! { x: 2., y: 3. },
+                ),
+                InvalidityReason::SelfIntersection(
+                    Location::new().ring(RingRole::Exterior).coord(2),
+                    coord! { x: 2., y: 4. },
+                ),
+            ])
+        );
+
+        // a figure of eight, which touches itself at a vertex
+        let figure_eight = polygon![
+            (x: 0., y: 0.),
+            (x: 2., y: 2.),
+            (x: 4., y: 0.),
+            (x: 4., y: 4.),
+            (x: 2., y: 2.),
+            (x: 0., y: 4.),
+        ];
+        let reasons = figure_eight.explain_invalidity().unwrap();
+        assert!(reasons.iter().all(|reason| matches!(
+            reason,
+            InvalidityReason::SelfIntersection(_, coord) if *coord == coord! { x: 2., y: 2. }
+        )));
+
+        // a line string may intersect itself
+        let line_string =
+            line_string![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+        assert!(line_string.is_valid());
+    }
+
+    #[test]
+    fn ring_intersections() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 5., y: 5.), (x: 15., y: 5.), (x: 5., y: 8.)]],
+        );
+        let reasons = polygon.explain_invalidity().unwrap();
+        assert_eq!(reasons.len(), 2);
+        assert!(reasons.iter().all(|reason| matches!(
+            reason,
+            InvalidityReason::RingsIntersect(location, RingRole::Interior(0), _)
+                if location.ring == Some(RingRole::Exterior)
+        )));
+
+        // a hole which shares an edge with the exterior
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [[(x: 0., y: 2.), (x: 5., y: 2.), (x: 5., y: 5.), (x: 0., y: 5.)]],
+        );
+        assert_eq!(
+            polygon.explain_invalidity(),
+            Some(vec![InvalidityReason::RingsIntersect(
+                Location::new().ring(RingRole::Exterior).coord(3),
+                RingRole::Interior(0),
+                coord! { x: 0., y: 5. },
+            )])
+        );
+    }
+
+    #[test]
+    fn holes_outside_shell() {
+        let polygon = polygon!(
+            exterior: [(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)],
+            interiors: [
+                [(x: 20., y: 20.), (x: 21., y: 20.), (x: 21., y: 21.)],
+                [(x: 1., y: 1.), (x: 5., y: 1.), (x: 5., y: 5.), (x: 1., y: 5.)],
+                [(x: 2., y: 2.), (x: 3., y: 2.), (x: 3., y: 3.)],
+            ],
+        );
+        assert_eq!(
+            polygon.explain_invalidity(),
+            Some(vec![
+                InvalidityReason::HoleOutsideShell(Location::new().ring(RingRole::Interior(0))),
+                InvalidityReason::NestedHoles(Location::new().ring(RingRole::Interior(2)), 1),
+            ])
+        );
+    }
+
+    #[test]
+    fn multi_polygons() {
+        let outer = polygon![(x: 0., y: 0.), (x: 10., y: 0.), (x: 10., y: 10.), (x: 0., y: 10.)];
+        let inner = polygon![(x: 1., y: 1.), (x: 2., y: 1.), (x: 2., y: 2.), (x: 1., y: 2.)];
+        let touching = polygon![(x: 10., y: 10.), (x: 12., y: 10.), (x: 12., y: 12.)];
+        let crossing = polygon![(x: 9., y: 1.), (x: 12., y: 1.), (x: 12., y: 2.)];
+
+        let multi_polygon = MultiPolygon::new(vec![outer.clone(), touching.clone()]);
+        assert!(multi_polygon.is_valid());
+
+        let multi_polygon = MultiPolygon::new(vec![outer.clone(), touching, inner]);
+        assert_eq!(
+            multi_polygon.explain_invalidity(),
+            Some(vec![InvalidityReason::NestedShells(
+                Location::new().in_geometry(2),
+                0
+            )])
+        );
+
+        let multi_polygon = MultiPolygon::new(vec![outer, crossing]);
+        let reasons = multi_polygon.explain_invalidity().unwrap();
+        assert_eq!(reasons.len(), 2);
+        assert!(reasons.iter().all(|reason| matches!(
+            reason,
+            InvalidityReason::PolygonsIntersect(location, 1, coord)
+                if location.geometry == vec![0] && coord.x == 10.
+        )));
+    }
+
+    #[test]
+    fn nested_locations() {
+        let bow_tie = polygon![(x: 0., y: 0.), (x: 2., y: 2.), (x: 2., y: 0.), (x: 0., y: 2.)];
+        let multi_polygon = MultiPolygon::new(vec![
+            polygon![(x: 5., y: 5.), (x: 6., y: 5.), (x: 6., y: 6.)],
+            bow_tie,
+        ]);
+        let collection = Geometry::GeometryCollection(GeometryCollection::new_from(vec![
+            point!(x: 0., y: 0.).into(),
+            Geometry::GeometryCollection(GeometryCollection::new_from(vec![multi_polygon.into()])),
+        ]));
+        assert_eq!(
+            collection.explain_invalidity(),
+            Some(vec![InvalidityReason::SelfIntersection(
+                Location {
+                    geometry: vec![1, 0, 1],
+                    ring: Some(RingRole::Exterior),
+                    coord: Some(0),
+                },
+                coord! { x: 1., y: 1. },
+            )])
+        );
+    }
+
+    #[test]
+    fn random_rings_do_not_panic() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let mut next = move || (rng.gen::<f64>() * 10.).round();
+
+        for count in 3..30 {
+            let exterior: LineString = (0..count).map(|_| (next(), next())).collect();
+            let interior: LineString = (0..count).map(|_| (next(), next())).collect();
+            let polygon = Polygon::new(exterior, vec![interior]);
+            let reasons = polygon.explain_invalidity();
+            assert_eq!(polygon.is_valid(), reasons.is_none());
+            let multi_polygon = MultiPolygon::new(vec![polygon.clone(), polygon]);
+            assert!(!multi_polygon.is_valid());
+        }
+    }
+}
@@ -5,6 +5,7 @@
 // - https://github.com/janantala/GPS-distance/blob/master/java/Distance.java
 
 use crate::{CoordFloat, Point, EARTH_FLATTENING, EQUATORIAL_EARTH_RADIUS, POLAR_EARTH_RADIUS};
+use geographiclib_rs::{Geodesic, InverseGeodesic};
 use num_traits::FromPrimitive;
 use std::{error, fmt};
 
@@ -41,6 +42,40 @@ pub trait VincentyDistance<T, Rhs = Self> {
     ///
     /// [Vincenty’s formulae]: https://en.wikipedia.org/wiki/Vincenty%27s_formulae
     fn vincenty_distance(&self, rhs: &Rhs) -> Result<T, FailedToConvergeError>;
+
+    /// Determine the distance between two geometries using [Vincenty’s
+    /// formulae], falling back to [Karney (2013)] where they don't converge.
+    ///
+    /// Vincenty’s formulae fail to converge for nearly antipodal points. For
+    /// those, the distance is calculated with the same method as
+    /// [`GeodesicDistance`](crate::GeodesicDistance), which always converges
+    /// and is accurate to within 15 nanometers, on the same WGS-84
+    /// ellipsoid. Because that method is implemented only for `f64`, the
+    /// coordinates are converted to `f64` for it.
+    ///
+    /// # Units
+    ///
+    /// - return value: meters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geo::prelude::*;
+    /// use geo::point;
+    ///
+    /// let p1 = point!(x: 0f64, y: 0f64);
+    /// let p2 = point!(x: 179.7f64, y: 0.5f64);
+    ///
+    /// assert!(p1.vincenty_distance(&p2).is_err());
+    /// assert_eq!(
+    ///     19_944_127., // meters
+    ///     p1.vincenty_distance_with_fallback(&p2).round()
+    /// );
+    /// ```
+    ///
+    /// [Vincenty’s formulae]: https://en.wikipedia.org/wiki/Vincenty%27s_formulae
+    /// [Karney (2013)]: https://arxiv.org/pdf/1109.4448.pdf
+    fn vincenty_distance_with_fallback(&self, rhs: &Rhs) -> T;
 }
 
 impl<T> VincentyDistance<T, Point<T>> for Point<T>
@@ -160,6 +195,19 @@ where
 
         Ok(s)
     }
+
+    fn vincenty_distance_with_fallback(&self, rhs: &Point<T>) -> T {
+        self.vincenty_distance(rhs).unwrap_or_else(|_| {
+            let to_f64 = |value: T| value.to_f64().unwrap();
+            let distance: f64 = Geodesic::wgs84().inverse(
+                to_f64(self.y()),
+                to_f64(self.x()),
+                to_f64(rhs.y()),
+                to_f64(rhs.x()),
+            );
+            T::from(distance).unwrap()
+        })
+    }
 }
 
 #[derive(Eq, PartialEq, Debug)]
@@ -238,4 +286,50 @@ mod test {
         let b = Point::new(-178.0, -4.0);
         assert_eq!(a.vincenty_distance(&b), Err(FailedToConvergeError))
     }
+
+    #[test]
+    fn test_vincenty_distance_with_fallback() {
+        // reference values from GeographicLib's `Geodesic::Inverse`
+        let a = Point::new(0.0, 0.0);
+        let b = Point::new(179.7, 0.5);
+        assert_eq!(a.vincenty_distance(&b), Err(FailedToConvergeError));
+        assert_relative_eq!(
+            a.vincenty_distance_with_fallback(&b),
+            19944127.420750,
+            epsilon = 1.0e-6
+        );
+
+        // the shortest route between antipodal points passes over a pole
+        let a = Point::new(2.0, 4.0);
+        let b = Point::new(-178.0, -4.0);
+        assert_relative_eq!(
+            a.vincenty_distance_with_fallback(&b),
+            20003931.458625,
+            epsilon = 1.0e-6
+        );
+
+        // pole to pole, where Vincenty's formulae converge, though only to within a few
+        // micrometers
+        let a = Point::new(0.0, 90.0);
+        let b = Point::new(0.0, -90.0);
+        assert_relative_eq!(
+            a.vincenty_distance_with_fallback(&b),
+            20003931.458625,
+            epsilon = 1.0e-5
+        );
+        let b = Point::new(123.0, -90.0);
+        assert_relative_eq!(
+            a.vincenty_distance_with_fallback(&b),
+            20003931.458625,
+            epsilon = 1.0e-5
+        );
+
+        // where Vincenty's formulae converge, they're used unchanged
+        let a = Point::new(17.1f32, 48.1);
+        let b = Point::new(16.4, 48.2);
+        assert_eq!(
+            a.vincenty_distance_with_fallback(&b),
+            a.vincenty_distance(&b).unwrap()
+        );
+    }
 }
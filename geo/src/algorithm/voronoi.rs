@@ -0,0 +1,396 @@
+use std::cmp::Ordering;
+use std::{error, fmt};
+
+use crate::triangulate_delaunay::Triangulation;
+use crate::utils::lex_cmp;
+use crate::{BoundingRect, Coord, GeoFloat, LineString, MultiPoint, MultiPolygon, Polygon, Rect};
+
+/// Compute the [Voronoi diagram] of a set of points: the partition of the plane into one cell
+/// per point, containing every location which is closer to that point than to any other.
+///
+/// The cells of the points on the convex hull are unbounded, so every cell is clipped against a
+/// rectangle. This is either the provided `clip` rectangle, or the bounding rectangle of the
+/// points, grown on every side by half of its largest dimension.
+///
+/// The returned `MultiPolygon` has exactly one polygon per input point, in the same order, so
+/// the `i`th polygon is the cell of the `i`th point. A cell which lies entirely outside of the
+/// clipping rectangle is an empty polygon. An empty `MultiPoint` has an empty diagram.
+///
+/// The neighbours of each point are found using its [Delaunay triangulation], so the diagram
+/// takes `O(n log n)` time to compute for well-distributed points.
+///
+/// # Errors
+///
+/// The cells of coincident points are not defined, so a [`VoronoiError::DuplicatePoint`] is
+/// returned if any two of the points are equal. A [`VoronoiError::NonFinite`] is returned if any
+/// of the points has a NaN or infinite coordinate.
+///
+/// # Examples
+///
+/// ```
+/// use geo::{point, Area, MultiPoint, Voronoi, Rect};
+///
+/// let points = MultiPoint::new(vec![point!(x: 1., y: 1.), point!(x: 3., y: 1.)]);
+/// let clip = Rect::new((0., 0.), (4., 2.));
+///
+/// let cells = points.voronoi_diagram(Some(clip)).unwrap();
+/// assert_eq!(cells.0.len(), 2);
+/// assert_eq!(cells.0[0].unsigned_area(), 4.);
+/// assert_eq!(cells.0[0].exterior().0.iter().map(|c| c.x).fold(0., f64::max), 2.);
+/// ```
+///
+/// [Voronoi diagram]: https://en.wikipedia.org/wiki/Voronoi_diagram
+/// [Delaunay triangulation]: crate::TriangulateDelaunay
+pub trait Voronoi<T: GeoFloat> {
+    fn voronoi_diagram(&self, clip: Option<Rect<T>>) -> Result<MultiPolygon<T>, VoronoiError>;
+}
+
+impl<T: GeoFloat> Voronoi<T> for MultiPoint<T> {
+    fn voronoi_diagram(&self, clip: Option<Rect<T>>) -> Result<MultiPolygon<T>, VoronoiError> {
+        let bounds = match clip.or_else(|| self.bounding_rect()) {
+            Some(bounds) => bounds,
+            None => return Ok(MultiPolygon::new(vec![])),
+        };
+        let bounds = if clip.is_some() {
+            bounds
+        } else {
+            expand(bounds)
+        };
+
+        let coords: Vec<Coord<T>> = self.iter().map(|point| point.0).collect();
+        check_finite(&coords)?;
+        check_distinct(&coords)?;
+
+        let triangulation = Triangulation::new(coords.clone());
+        let neighbours = neighbours(&triangulation);
+        let cells = coords
+            .iter()
+            .map(|coord| {
+                // `Triangulation` sorts its coordinates, so look this one up again
+                let index = triangulation
+                    .coords
+                    .binary_search_by(|other| lex_cmp(other, coord))
+                    .expect("every input coordinate is in the triangulation");
+                let cell = neighbours[index]
+                    .iter()
+                    .fold(rect_ring(bounds), |cell, &other| {
+                        clip_closer(cell, *coord, triangulation.coords[other])
+                    });
+                Polygon::new(LineString::new(cell), vec![])
+            })
+            .collect();
+        Ok(MultiPolygon::new(cells))
+    }
+}
+
+/// The reason a Voronoi diagram could not be computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoronoiError {
+    /// The points at these two indices are equal.
+    DuplicatePoint(usize, usize),
+    /// The point at this index has a NaN or infinite coordinate.
+    NonFinite(usize),
+}
+
+impl fmt::Display for VoronoiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VoronoiError::DuplicatePoint(first, second) => {
+                write!(f, "points {} and {} are equal", first, second)
+            }
+            VoronoiError::NonFinite(index) => {
+                write!(f, "point {} has a non-finite coordinate", index)
+            }
+        }
+    }
+}
+
+impl error::Error for VoronoiError {}
+
+/// Grow `rect` on every side by half of its largest dimension, so that the cells of the points
+/// on its boundary are not degenerate.
+fn expand<T: GeoFloat>(rect: Rect<T>) -> Rect<T> {
+    let two = T::one() + T::one();
+    let mut margin = rect.width().max(rect.height()) / two;
+    if margin == T::zero() {
+        margin = T::one();
+    }
+    let margin = Coord {
+        x: margin,
+        y: margin,
+    };
+    Rect::new(rect.min() - margin, rect.max() + margin)
+}
+
+/// Check that every one of `coords` is finite, which the Delaunay triangulation requires.
+fn check_finite<T: GeoFloat>(coords: &[Coord<T>]) -> Result<(), VoronoiError> {
+    match coords
+        .iter()
+        .position(|coord| !coord.x.is_finite() || !coord.y.is_finite())
+    {
+        Some(index) => Err(VoronoiError::NonFinite(index)),
+        None => Ok(()),
+    }
+}
+
+/// Check that no two of `coords` are equal.
+fn check_distinct<T: GeoFloat>(coords: &[Coord<T>]) -> Result<(), VoronoiError> {
+    let mut order: Vec<usize> = (0..coords.len()).collect();
+    order.sort_unstable_by(|&a, &b| lex_cmp(&coords[a], &coords[b]).then(a.cmp(&b)));
+    match order
+        .windows(2)
+        .find(|pair| lex_cmp(&coords[pair[0]], &coords[pair[1]]) == Ordering::Equal)
+    {
+        Some(pair) => Err(VoronoiError::DuplicatePoint(pair[0], pair[1])),
+        None => Ok(()),
+    }
+}
+
+/// The indices of the neighbours of each coordinate of `triangulation`, i.e. of the points
+/// whose cells may share an edge with its cell.
+fn neighbours<T: GeoFloat>(triangulation: &Triangulation<T>) -> Vec<Vec<usize>> {
+    let mut neighbours = vec![vec![]; triangulation.coords.len()];
+    if triangulation.triangles.is_empty() {
+        // the points are collinear, and sorted along their common line
+        for i in 1..triangulation.coords.len() {
+            neighbours[i - 1].push(i);
+            neighbours[i].push(i - 1);
+        }
+        return neighbours;
+    }
+    for &[a, b, c] in &triangulation.triangles {
+        // every interior edge is visited twice, once from each of its triangles
+        for (from, to) in [(a, b), (b, c), (c, a)] {
+            if !neighbours[from].contains(&to) {
+                neighbours[from].push(to);
+                neighbours[to].push(from);
+            }
+        }
+    }
+    neighbours
+}
+
+/// The closed, counter-clockwise ring of `rect`.
+fn rect_ring<T: GeoFloat>(rect: Rect<T>) -> Vec<Coord<T>> {
+    let (min, max) = (rect.min(), rect.max());
+    vec![
+        min,
+        Coord { x: max.x, y: min.y },
+        max,
+        Coord { x: min.x, y: max.y },
+        min,
+    ]
+}
+
+/// Clip the closed, convex `ring` to the half-plane of locations which are at least as close to
+/// `site` as to `other`.
+fn clip_closer<T: GeoFloat>(ring: Vec<Coord<T>>, site: Coord<T>, other: Coord<T>) -> Vec<Coord<T>> {
+    if ring.is_empty() {
+        return ring;
+    }
+    let two = T::one() + T::one();
+    let mid = (site + other) / two;
+    let normal = other - site;
+    // positive on the side of `other`
+    let side = |coord: Coord<T>| {
+        let offset = coord - mid;
+        offset.x * normal.x + offset.y * normal.y
+    };
+
+    let mut clipped = Vec::with_capacity(ring.len() + 1);
+    for pair in ring.windows(2) {
+        let (start, end) = (pair[0], pair[1]);
+        let (start_side, end_side) = (side(start), side(end));
+        if start_side <= T::zero() {
+            clipped.push(start);
+        }
+        if (start_side < T::zero() && end_side > T::zero())
+            || (start_side > T::zero() && end_side < T::zero())
+        {
+            let t = start_side / (start_side - end_side);
+            clipped.push(start + (end - start) * t);
+        }
+    }
+    if clipped.len() < 3 {
+        return vec![];
+    }
+    clipped.push(clipped[0]);
+    clipped
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{point, Area, Contains, EuclideanDistance};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// A pseudo-random set of distinct points in the unit square.
+    fn random_points(count: usize) -> MultiPoint<f64> {
+        let mut rng = StdRng::seed_from_u64(42);
+        (0..count)
+            .map(|_| point!(x: rng.gen::<f64>(), y: rng.gen::<f64>()))
+            .collect()
+    }
+
+    #[test]
+    fn cells_match_input_order() {
+        let points = random_points(100);
+        let clip = Rect::new((-1., -1.), (2., 2.));
+        let cells = points.voronoi_diagram(Some(clip)).unwrap();
+        assert_eq!(cells.0.len(), points.0.len());
+
+        for (i, (point, cell)) in points.iter().zip(&cells).enumerate() {
+            assert!(
+                cell.contains(point),
+                "cell {} does not contain its point",
+                i
+            );
+            // every vertex of the cell is at least as close to its own point as to any other
+            for vertex in cell.exterior().points() {
+                let own = vertex.euclidean_distance(point);
+                for other in points.iter() {
+                    assert!(own <= vertex.euclidean_distance(other) + 1e-9);
+                }
+            }
+        }
+
+        // the cells tile the clipping rectangle
+        let area: f64 = cells.iter().map(|cell| cell.unsigned_area()).sum();
+        assert_relative_eq!(area, 9., epsilon = 1e-9);
+    }
+
+    #[test]
+    fn hand_computed_diagram() {
+        // the corners of a square, and its centre, whose cell is a diamond
+        let points = MultiPoint::new(vec![
+            point!(x: 1., y: 1.),
+            point!(x: 3., y: 1.),
+            point!(x: 3., y: 3.),
+            point!(x: 1., y: 3.),
+            point!(x: 2., y: 2.),
+        ]);
+        let clip = Rect::new((0., 0.), (4., 4.));
+        let cells = points.voronoi_diagram(Some(clip)).unwrap();
+
+        let has_vertex = |cell: &Polygon<f64>, x: f64, y: f64| {
+            cell.exterior()
+                .0
+                .iter()
+                .any(|c| c.euclidean_distance(&Coord { x, y }) < 1e-9)
+        };
+        for (x, y) in [(2., 1.), (3., 2.), (2., 3.), (1., 2.)] {
+            assert!(has_vertex(&cells.0[4], x, y));
+        }
+        assert_relative_eq!(cells.0[4].unsigned_area(), 2.);
+        for cell in &cells.0[..4] {
+            assert_relative_eq!(cell.unsigned_area(), 3.5);
+        }
+
+        // cells are adjacent when they share an edge, so two vertices. The first vertex of a
+        // ring is repeated at its end, so it's skipped.
+        let adjacent = |i: usize, j: usize| {
+            let cell: &Polygon<f64> = &cells.0[i];
+            let shared = cell.exterior().coords().skip(1);
+            shared.filter(|c| has_vertex(&cells.0[j], c.x, c.y)).count() >= 2
+        };
+        for i in 0..4 {
+            assert!(adjacent(i, 4));
+            assert!(adjacent(i, (i + 1) % 4));
+            assert!(!adjacent(i, (i + 2) % 4));
+        }
+    }
+
+    #[test]
+    fn reversed_input_reverses_cells() {
+        let points = random_points(20);
+        let reversed: MultiPoint<f64> = points.0.iter().rev().cloned().collect();
+        let cells = points.voronoi_diagram(None).unwrap();
+        let reversed_cells = reversed.voronoi_diagram(None).unwrap();
+        for (cell, reversed_cell) in cells.iter().zip(reversed_cells.0.iter().rev()) {
+            assert_relative_eq!(cell.unsigned_area(), reversed_cell.unsigned_area());
+        }
+    }
+
+    #[test]
+    fn default_clip_is_expanded_bounds() {
+        let points = MultiPoint::new(vec![point!(x: 0., y: 0.), point!(x: 2., y: 1.)]);
+        let cells = points.voronoi_diagram(None).unwrap();
+        // the bounding rect is 2 x 1, grown by 1 on every side
+        let area: f64 = cells.iter().map(|cell| cell.unsigned_area()).sum();
+        assert_relative_eq!(area, 12.);
+    }
+
+    #[test]
+    fn collinear_points() {
+        let points: MultiPoint<f64> = (0..5).map(|i| point!(x: i as f64, y: 0.)).collect();
+        let clip = Rect::new((-0.5, -1.), (4.5, 1.));
+        let cells = points.voronoi_diagram(Some(clip)).unwrap();
+        for cell in &cells {
+            assert_relative_eq!(cell.unsigned_area(), 2.);
+        }
+    }
+
+    #[test]
+    fn single_point() {
+        let points = MultiPoint::new(vec![point!(x: 1., y: 2.)]);
+        let cells = points.voronoi_diagram(None).unwrap();
+        assert_eq!(cells.0.len(), 1);
+        // a single point has no extent, so the clip is grown by 1 on every side
+        assert_relative_eq!(cells.0[0].unsigned_area(), 4.);
+    }
+
+    #[test]
+    fn cell_outside_clip_is_empty() {
+        let points = MultiPoint::new(vec![point!(x: 0., y: 0.), point!(x: 10., y: 0.)]);
+        let clip = Rect::new((-1., -1.), (1., 1.));
+        let cells = points.voronoi_diagram(Some(clip)).unwrap();
+        assert_relative_eq!(cells.0[0].unsigned_area(), 4.);
+        assert!(cells.0[1].exterior().0.is_empty());
+    }
+
+    #[test]
+    fn duplicate_points() {
+        let points = MultiPoint::new(vec![
+            point!(x: 0., y: 0.),
+            point!(x: 1., y: 0.),
+            point!(x: 0., y: 1.),
+            point!(x: 1., y: 0.),
+        ]);
+        assert_eq!(
+            points.voronoi_diagram(None),
+            Err(VoronoiError::DuplicatePoint(1, 3))
+        );
+    }
+
+    #[test]
+    fn non_finite_points() {
+        let clip = Rect::new((-1., -1.), (2., 2.));
+        for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let points = MultiPoint::new(vec![
+                point!(x: 0., y: 0.),
+                point!(x: 1., y: 0.),
+                point!(x: 0., y: value),
+            ]);
+            assert_eq!(
+                points.voronoi_diagram(None),
+                Err(VoronoiError::NonFinite(2))
+            );
+            assert_eq!(
+                points.voronoi_diagram(Some(clip)),
+                Err(VoronoiError::NonFinite(2))
+            );
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let points = MultiPoint::<f64>::new(vec![]);
+        assert_eq!(points.voronoi_diagram(None), Ok(MultiPolygon::new(vec![])));
+        let clip = Rect::new((0., 0.), (1., 1.));
+        assert_eq!(
+            points.voronoi_diagram(Some(clip)),
+            Ok(MultiPolygon::new(vec![]))
+        );
+    }
+}
@@ -35,16 +35,27 @@
 //!
 //! - **[`Area`](Area)**: Calculate the planar area of a geometry
 //! - **[`ChamberlainDuquetteArea`](ChamberlainDuquetteArea)**: Calculate the geodesic area of a geometry
+//! - **[`GeodesicArea`](GeodesicArea)**: Calculate the geodesic area and perimeter of a geometry using the algorithm presented in _Algorithms for geodesics_ by Charles Karney (2013)
 //!
 //! ## Boolean Operations
 //!
 //! - **[`BooleanOps`](BooleanOps)**: combine or split (Multi)Polygons using intersecton, union, xor, or difference operations
+//! - **[`unary_union`]**: union many polygons at once, much faster than folding `BooleanOps::union`
+//! - **[`GeometryBooleanOps`](GeometryBooleanOps)**: boolean operations on any `Geometry`, clipping lines and filtering points by areas
+//! - **[`Buffer`](Buffer)**: grow or shrink a geometry by a distance
+//! - **[`Snap`](Snap)**: snap the vertices of a geometry to those of another within a tolerance, before boolean operations on geometries digitized slightly apart
+//! - **[`PolygonSplit`](PolygonSplit)**: cut polygons into pieces along lines
+//! - **[`RectClip`](RectClip)**: clip a geometry to an axis-aligned rectangle, much faster than the general boolean operations
 //!
 //! ## Distance
 //!
 //! - **[`EuclideanDistance`](EuclideanDistance)**: Calculate the minimum euclidean distance between geometries
+//! - **[`SignedEuclideanDistance`](SignedEuclideanDistance)**: Calculate the signed euclidean distance from a point to the boundary of a polygon, negative inside it
 //! - **[`GeodesicDistance`](GeodesicDistance)**: Calculate the minimum geodesic distance between geometries using the algorithm presented in _Algorithms for geodesics_ by Charles Karney (2013)
+//! - **[`NearestPoints`](NearestPoints)**: Calculate the nearest points of two geometries, between which is the minimum euclidean distance
+//! - **[`LongestLine`](LongestLine)**: Calculate the maximum euclidean distance between geometries, and the line realizing it
 //! - **[`HaversineDistance`](HaversineDistance)**: Calculate the minimum geodesic distance between geometries using the haversine formula
+//! - **[`RhumbDistance`](RhumbDistance)**: Calculate the distance between points along a rhumb line, which has a constant bearing
 //! - **[`VincentyDistance`](VincentyDistance)**: Calculate the minimum geodesic distance between geometries using Vincenty’s formula
 //!
 //! ## Length
@@ -57,11 +68,16 @@
 //! ## Outlier Detection
 //!
 //! - **[`OutlierDetection`](OutlierDetection)**: Detect outliers in a group of points using [LOF](https://en.wikipedia.org/wiki/Local_outlier_factor)
+//! - **[`k_means`](clustering::k_means)**: Divide a group of points into clusters using [k-means clustering](https://en.wikipedia.org/wiki/K-means_clustering)
+//! - **[`dbscan`](clustering::dbscan)**: Divide a group of points into clusters and noise using [DBSCAN](https://en.wikipedia.org/wiki/DBSCAN)
+//! - **[`GeometricMedian`](GeometricMedian)**: Calculate the geometric median or medoid of a group of points, which unlike the centroid are robust to outliers
 //!
 //! ## Simplification
 //!
 //! - **[`Simplify`](Simplify)**: Simplify a geometry using the Ramer–Douglas–Peucker algorithm
 //! - **[`SimplifyIdx`](SimplifyIdx)**: Calculate a simplified geometry using the Ramer–Douglas–Peucker algorithm, returning coordinate indices
+//! - **[`SimplifyPreserve`](SimplifyPreserve)**: Simplify a geometry using a topology-preserving variant of the Ramer–Douglas–Peucker algorithm
+//! - **[`SimplifyWithPins`](SimplifyWithPins)**: Simplify a geometry using the Ramer–Douglas–Peucker algorithm, retaining a set of pinned vertices
 //! - **[`SimplifyVw`](SimplifyVw)**: Simplify a geometry using the Visvalingam-Whyatt algorithm
 //! - **[`SimplifyVwPreserve`](SimplifyVwPreserve)**: Simplify a geometry using a topology-preserving variant of the Visvalingam-Whyatt algorithm
 //! - **[`SimplifyVwIdx`](SimplifyVwIdx)**: Calculate a simplified geometry using a topology-preserving variant of the Visvalingam-Whyatt algorithm, returning coordinate indices
@@ -69,33 +85,78 @@
 //! ## Query
 //!
 //! - **[`Bearing`](Bearing)**: Calculate the bearing between points
+//! - **[`GeodesicBearing`](GeodesicBearing)**: Calculate the bearing between points on an
+//!   ellipsoidal model of the earth
+//! - **[`RhumbBearing`](RhumbBearing)**: Calculate the constant bearing of the rhumb line between points
 //! - **[`ClosestPoint`](ClosestPoint)**: Find the point on a geometry
 //!   closest to a given point
+//! - **[`HaversineClosestPoint`](HaversineClosestPoint)**: Find the point on a
+//!   geometry closest to a given point, along great circles
+//! - **[`NearestNeighbor`](NearestNeighbor)**: Find the geometries of a collection nearest
+//!   to a given point
+//! - **[`HaversineNearestNeighbor`](HaversineNearestNeighbor)**: Find the points of a
+//!   collection nearest to a given point, along great circles
+//! - **[`HaversineTrackDistance`](HaversineTrackDistance)**: Calculate the cross-track and
+//!   along-track distances of a point from a path on a sphere
 //! - **[`IsConvex`](IsConvex)**: Calculate the convexity of a
 //!   [`LineString`]
 //! - **[`LineInterpolatePoint`](LineInterpolatePoint)**:
 //!   Generates a point that lies a given fraction along the line
+//! - **[`LineInterpolatePoints`](LineInterpolatePoints)**:
+//!   Generates many points along the line at once, at given fractions or a fixed spacing
 //! - **[`LineLocatePoint`](LineLocatePoint)**: Calculate the
 //!   fraction of a line’s total length representing the location of the closest point on the
 //!   line to the given point
+//! - **[`LineLocatePointFull`](LineLocatePointFull)**: Calculate the fraction, length
+//!   and segment of a line at the closest point on the line to the given point
+//! - **[`LineStringSegmentize`](LineStringSegmentize)**: Split a line string into pieces
+//!   of equal length
+//! - **[`HaversineLineStringSegmentize`](HaversineLineStringSegmentize)**: Split a line
+//!   string into pieces of equal length along great circles
+//! - **[`LineSplit`](LineSplit)**: Split a line string at fractions of its length, or at
+//!   the location nearest to a point
+//! - **[`LineSubstring`](LineSubstring)**: Extract the part of a line string between two
+//!   fractions of its length
+//! - **[`HaversineLineSubstring`](HaversineLineSubstring)**: Extract the part of a line
+//!   string between two fractions of its length along great circles
 //!
 //! ## Similarity
 //!
 //! - **[`FrechetDistance`](FrechetDistance)**: Calculate the similarity between [`LineString`]s using the Fréchet distance
+//! - **[`HausdorffDistance`](HausdorffDistance)**: Calculate the similarity between geometries using the Hausdorff distance
 //!
 //! ## Topology
 //!
 //! - **[`Contains`](Contains)**: Calculate if a geometry contains another
 //!   geometry
+//! - **[`ContainsProperly`](ContainsProperly)**: Calculate if a geometry lies in the interior of
+//!   another geometry, which unlike [`Contains`] excludes it touching the boundary
+//! - **[`Covers`](Covers)**: Calculate if a geometry covers another geometry, which unlike
+//!   [`Contains`] includes it lying on the boundary
+//! - **[`CoveredBy`](CoveredBy)**: Calculate if a geometry is covered by another geometry
 //! - **[`CoordinatePosition`](CoordinatePosition)**: Calculate
 //!   the position of a coordinate relative to a geometry
+//! - **[`Crosses`](Crosses)**: Calculate if a geometry crosses another geometry, their
+//!   interiors meeting in fewer dimensions than the larger has
+//! - **[`Equals`](Equals)**: Calculate if two geometries are topologically equal, covering
+//!   exactly the same points
 //! - **[`HasDimensions`](HasDimensions)**: Determine the dimensions of a geometry
 //! - **[`Intersects`](Intersects)**: Calculate if a geometry intersects
 //!   another geometry
+//! - **[`IsValid`](IsValid)**: Check whether a geometry is valid according to the OGC Simple
+//!   Feature Access specification, and explain why not
+//! - **[`MakeValid`](MakeValid)**: Repair an invalid polygonal geometry
+//! - **[`Overlaps`](Overlaps)**: Calculate if two geometries of the same dimension overlap,
+//!   without either containing the other
+//! - **[`PreparedGeometry`](PreparedGeometry)**: Index a geometry once, to speed up
+//!   testing whether it contains or intersects many others
 //! - **[`line_intersection`](line_intersection::line_intersection)**: Calculates the
 //!   intersection, if any, between two lines.
 //! - **[`Relate`](Relate)**: Topologically relate two geometries based on
 //!   [DE-9IM](https://en.wikipedia.org/wiki/DE-9IM) semantics.
+//! - **[`SharedPaths`](SharedPaths)**: Find the paths two line strings share, running the same
+//!   or opposite ways
+//! - **[`Touches`](Touches)**: Calculate if two geometries meet only at their boundaries
 //! - **[`Within`]**: Calculate if a geometry lies completely within another geometry.
 //!
 //! ## Winding
@@ -120,12 +181,32 @@
 //!
 //! - **[`BoundingRect`](BoundingRect)**: Calculate the axis-aligned
 //!   bounding rectangle of a geometry
+//! - **[`BoundingRectAccumulator`](BoundingRectAccumulator)**: Calculate the bounding
+//!   rectangle of a stream of geometries
 //! - **[`ConcaveHull`](ConcaveHull)**: Calculate the concave hull of a
 //!   geometry
 //! - **[`ConvexHull`](ConvexHull)**: Calculate the convex hull of a
 //!   geometry
+//! - **[`ConvexHullBuilder`](ConvexHullBuilder)**: Build a convex hull incrementally, as
+//!   points arrive
+//! - **[`ConvexHullIndices`](ConvexHullIndices)**: Find which of a collection of points
+//!   are on its convex hull
 //! - **[`Extremes`](Extremes)**: Calculate the extreme coordinates and
 //!   indices of a geometry
+//! - **[`MaximumInscribedCircle`](MaximumInscribedCircle)**: Calculate the largest circle
+//!   inscribed in a polygon, i.e. its pole of inaccessibility
+//! - **[`MinimumBoundingCircle`](MinimumBoundingCircle)**: Calculate the smallest circle
+//!   containing a geometry
+//! - **[`MinimumRotatedRect`](MinimumRotatedRect)**: Calculate the minimum bounding rectangle of
+//!   a geometry, in any orientation
+//!
+//! ## Triangulation
+//!
+//! - **[`TriangulateDelaunay`](TriangulateDelaunay)**: Calculate the Delaunay triangulation of a
+//!   set of points
+//! - **[`TriangulateEarcut`](TriangulateEarcut)**: Triangulate polygons using the ear clipping
+//!   algorithm
+//! - **[`Voronoi`](Voronoi)**: Calculate the Voronoi diagram of a set of points
 //!
 //! ## Affine transformations
 //!
@@ -143,13 +224,25 @@
 //! ## Miscellaneous
 //!
 //! - **[`Centroid`](Centroid)**: Calculate the centroid of a geometry
+//! - **[`WeightedCentroid`](WeightedCentroid)**: Calculate the weighted centroid of a set of points
+//! - **[`GeodesicDestination`](GeodesicDestination)**: Calculate a destination point on an ellipsoidal model of the earth, given a bearing and a distance
 //! - **[`HaversineDestination`](HaversineDestination)**:
 //! - **[`HaversineIntermediate`](HaversineIntermediate)**:
+//! - **[`RhumbDestination`](RhumbDestination)**: Calculate a destination point along a rhumb line, given a constant bearing and a distance
+//! - **[`RhumbIntermediate`](RhumbIntermediate)**: Calculate points along a rhumb line
 //! - **[`proj`](proj)**: Project geometries with the `proj` crate (requires the `use-proj` feature)
 //! - **[`ChaikinSmoothing`](ChaikinSmoothing)**: Smoothen `LineString`, `Polygon`, `MultiLineString` and `MultiPolygon` using Chaikins algorithm.
 //! - **[`Densify`](Densify)**: Densify linear geometry components by interpolating points
+//! - **[`DensifyGeodesic`](DensifyGeodesic)**: Densify linear geometry components by interpolating points along geodesics on an ellipsoidal model of the earth
+//! - **[`DensifyHaversine`](DensifyHaversine)**: Densify linear geometry components by interpolating points along great circles
 //! - **[`Transform`](Transform)**: Transform a geometry using Proj.
 //! - **[`RemoveRepeatedPoints`](RemoveRepeatedPoints)**: Remove repeated points from a geometry.
+//! - **[`MinimumClearance`](MinimumClearance)**: Calculate the smallest move of a vertex which can make a geometry invalid
+//! - **[`SnapToGrid`](SnapToGrid)**: Round the coordinates of a geometry to a grid, removing collapsed rings
+//! - **[`LineMerge`](LineMerge)**: Merge line strings which meet end to end into maximal paths
+//! - **[`node_lines`]**: Split a set of lines wherever they cross, touch or overlap
+//! - **[`polygonize`]**: Build the polygons enclosed by a set of noded lines
+//! - **[`SpatialIndex`](SpatialIndex)**: Index geometries in an R-tree, to find those nearest a point or intersecting a rectangle
 //!
 //! # Features
 //!